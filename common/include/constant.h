@@ -48,6 +48,7 @@ enum ExceptionErrorCode : int32_t {
     E_TASK_NOT_FOUND = 21900006,
     E_TASK_STATE = 21900007,
     E_GROUP_NOT_FOUND = 21900008,
+    E_CLIENT_CERT_INVALID = 21900009,
 };
 
 struct ExceptionError {
@@ -68,6 +69,11 @@ static constexpr const char *E_TASK_MODE_INFO = "Operation with wrong task mode"
 static constexpr const char *E_TASK_NOT_FOUND_INFO = "Task removed or not found";
 static constexpr const char *E_TASK_STATE_INFO = "Operation with wrong task state";
 static constexpr const char *E_GROUP_NOT_FOUND_INFO = "Group deleted or not found";
+static constexpr const char *E_CLIENT_CERT_INVALID_INFO = "Invalid client certificate or private key";
+
+// Mirrors request_utils::method, keep both in sync.
+static constexpr const char *DOWNLOAD_METHODS[] = { "GET", "POST", "PATCH", "DELETE", "HEAD", "OPTIONS" };
+static constexpr const char *UPLOAD_METHODS[] = { "PUT", "POST", "PATCH" };
 
 static constexpr const char *FUNCTION_PAUSE = "pause";
 static constexpr const char *FUNCTION_QUERY = "query";
@@ -84,6 +90,7 @@ static constexpr const char *FUNCTION_GET_TASK_MIME_TYPE = "getTaskMimeType";
 static constexpr const char *FUNCTION_DELETE = "delete";
 static constexpr const char *FUNCTION_RESTORE = "restore";
 static constexpr const char *FUNCTION_SET_MAX_SPEED = "setMaxSpeed";
+static constexpr const char *FUNCTION_SET_AUTH_TOKEN = "setAuthToken";
 
 constexpr const std::uint32_t CONFIG_PARAM_AT_FIRST = 0;
 constexpr const std::uint32_t CONFIG_PARAM_AT_SECOND = 1;
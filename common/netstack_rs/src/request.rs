@@ -22,9 +22,9 @@ use std::sync::Arc;
 use cxx::{let_cxx_string, UniquePtr};
 use request_utils::task_id::TaskId;
 
-use crate::error::HttpClientError;
+use crate::error::{HttpClientError, HttpErrorCode};
 use crate::info::{DownloadInfo, DownloadInfoMgr};
-use crate::response::Response;
+use crate::response::{Response, ResponseCode};
 use crate::task::RequestTask;
 use crate::wrapper::ffi::{HttpClientRequest, NewHttpClientRequest, SetBody, SetRequestSslType};
 /// Builder for creating HTTP requests with configurable options.
@@ -408,6 +408,24 @@ pub trait RequestCallback {
     /// * `error` - The error that occurred during the request
     fn on_fail(&mut self, error: HttpClientError, info: DownloadInfo) {}
 
+    /// Called when the server answers a conditional request with
+    /// `304 Not Modified`.
+    ///
+    /// By default the response is reported through `on_fail`, like any other
+    /// status outside the 2xx range.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The 304 response, whose headers update the cached ones
+    /// * `info` - Download information of the request
+    fn on_not_modified(&mut self, response: Response, info: DownloadInfo) {
+        let code = ResponseCode::NotModified as u32;
+        self.on_fail(
+            HttpClientError::new(HttpErrorCode::HttpNoneErr, code.to_string()),
+            info,
+        );
+    }
+
     /// Called when the request is canceled by the user.
     ///
     /// This callback is triggered when the request is explicitly canceled,
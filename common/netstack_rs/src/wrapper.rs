@@ -161,9 +161,13 @@ impl CallbackWrapper {
         };
         // Convert FFI response to Rust response
         let response = Response::from_ffi(response);
-        // Check if the status code indicates success (200-299 range)
-        if (response.status().clone() as u32 >= 300) || (response.status().clone() as u32) < 200 {
-            // For non-success codes, create an error
+        if response.status() == ResponseCode::NotModified {
+            // A conditional request is answered without body when not modified
+            callback.on_not_modified(response, self.info.clone());
+        } else if (response.status().clone() as u32 >= 300)
+            || (response.status().clone() as u32) < 200
+        {
+            // For non-success codes (outside the 200-299 range), create an error
             let error = HttpClientError::new(
                 HttpErrorCode::HttpNoneErr,
                 (response.status() as u32).to_string(),
@@ -78,6 +78,39 @@ pub struct TaskConfig {
     pub min_speed: MinSpeed,
     /// Timeout configuration for the task.
     pub timeout: Timeout,
+    /// Number of concurrent range segments for a download.
+    pub segments: u32,
+    /// Expected SHA-256 digest of the downloaded file (empty disables
+    /// verification).
+    pub digest: String,
+    /// Size of each chunk of a resumable chunked upload (0 disables chunked
+    /// uploading).
+    pub chunk_size: u64,
+    /// Retry policy applied to failed attempts.
+    pub retry_policy: RetryPolicy,
+    /// IDs of the tasks that must complete before this task starts.
+    pub prerequisites: Vec<u32>,
+    /// Wall-clock time in milliseconds before which the task does not start
+    /// (0 starts the task immediately).
+    pub not_before: u64,
+    /// Recurrence rule of the task.
+    pub recurrence: Recurrence,
+    /// Size in bytes declared for the downloaded file, checked against the
+    /// free space when the server sends no `Content-Length` (0 when unknown).
+    pub expected_size: u64,
+    /// Whether to reserve the space of the downloaded file before writing it.
+    pub preallocate: bool,
+    /// Whether to transfer the body compressed (gzip or deflate).
+    pub compression: bool,
+    /// Whether to send and store cookies through the cookie jar of the app.
+    pub cookie_jar: bool,
+    /// Authentication of the requests of the task.
+    pub auth: Auth,
+    /// Path to the PEM client certificate chain presented in TLS handshakes,
+    /// empty for none.
+    pub client_cert_path: String,
+    /// Path to the PEM private key of the client certificate.
+    pub client_key_path: String,
 }
 
 /// Builder for creating a `TaskConfig` with a fluent interface.
@@ -125,6 +158,19 @@ pub struct TaskConfigBuilder {
     // notification: Option<Notification>,
     min_speed: Option<MinSpeed>,
     timeout: Option<Timeout>,
+    segments: Option<u32>,
+    digest: Option<String>,
+    chunk_size: Option<u64>,
+    retry_policy: Option<RetryPolicy>,
+    prerequisites: Option<Vec<u32>>,
+    not_before: Option<u64>,
+    recurrence: Option<Recurrence>,
+    expected_size: Option<u64>,
+    preallocate: Option<bool>,
+    compression: Option<bool>,
+    cookie_jar: Option<bool>,
+    auth: Option<Auth>,
+    client_cert: Option<(String, String)>,
 }
 
 impl TaskConfigBuilder {
@@ -151,6 +197,19 @@ impl TaskConfigBuilder {
             // notification: None,
             min_speed: None,
             timeout: None,
+            segments: None,
+            digest: None,
+            chunk_size: None,
+            retry_policy: None,
+            prerequisites: None,
+            not_before: None,
+            recurrence: None,
+            expected_size: None,
+            preallocate: None,
+            compression: None,
+            cookie_jar: None,
+            auth: None,
+            client_cert: None,
         }
     }
 
@@ -267,12 +326,94 @@ impl TaskConfigBuilder {
         self
     }
 
+    /// Sets the number of concurrent range segments for a download.
+    pub fn segments(&mut self, segments: u32) -> &mut Self {
+        self.segments = Some(segments);
+        self
+    }
+
+    /// Sets the expected digest of the downloaded file.
+    pub fn digest(&mut self, digest: String) -> &mut Self {
+        self.digest = Some(digest);
+        self
+    }
+
+    /// Sets the chunk size of a resumable chunked upload.
+    pub fn chunk_size(&mut self, chunk_size: u64) -> &mut Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Sets the retry policy applied to failed attempts.
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the IDs of the tasks that must complete before this task starts.
+    pub fn prerequisites(&mut self, prerequisites: Vec<u32>) -> &mut Self {
+        self.prerequisites = Some(prerequisites);
+        self
+    }
+
+    /// Sets the wall-clock time in milliseconds before which the task does
+    /// not start.
+    pub fn not_before(&mut self, not_before: u64) -> &mut Self {
+        self.not_before = Some(not_before);
+        self
+    }
+
+    /// Sets the recurrence rule of the task.
+    pub fn recurrence(&mut self, recurrence: Recurrence) -> &mut Self {
+        self.recurrence = Some(recurrence);
+        self
+    }
+
+    /// Sets the size in bytes the downloaded file is expected to have.
+    pub fn expected_size(&mut self, expected_size: u64) -> &mut Self {
+        self.expected_size = Some(expected_size);
+        self
+    }
+
+    /// Sets whether to reserve the space of the downloaded file before
+    /// writing it.
+    pub fn preallocate(&mut self, preallocate: bool) -> &mut Self {
+        self.preallocate = Some(preallocate);
+        self
+    }
+
+    /// Sets whether to transfer the body compressed.
+    pub fn compression(&mut self, compression: bool) -> &mut Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Sets whether to use the cookie jar of the app.
+    pub fn cookie_jar(&mut self, cookie_jar: bool) -> &mut Self {
+        self.cookie_jar = Some(cookie_jar);
+        self
+    }
+
+    /// Sets the authentication of the requests of the task.
+    pub fn auth(&mut self, auth: Auth) -> &mut Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Sets the client certificate chain and private key presented in TLS
+    /// handshakes, both PEM files in the app sandbox.
+    pub fn client_cert(&mut self, cert_path: String, key_path: String) -> &mut Self {
+        self.client_cert = Some((cert_path, key_path));
+        self
+    }
+
     /// Constructs a `TaskConfig` with the current builder configuration.
     ///
     /// # Notes
     ///
     /// Default values are used for any unspecified fields.
     pub fn build(self) -> TaskConfig {
+        let (client_cert_path, client_key_path) = self.client_cert.unwrap_or_default();
         TaskConfig {
             bundle: "".to_string(),
             bundle_type: 0,
@@ -338,6 +479,20 @@ impl TaskConfigBuilder {
                 connection_timeout: 60,
                 total_timeout: 604800,
             }),
+            segments: self.segments.unwrap_or(0),
+            digest: self.digest.unwrap_or_default(),
+            chunk_size: self.chunk_size.unwrap_or(0),
+            retry_policy: self.retry_policy.unwrap_or_default(),
+            prerequisites: self.prerequisites.unwrap_or_default(),
+            not_before: self.not_before.unwrap_or(0),
+            recurrence: self.recurrence.unwrap_or_default(),
+            expected_size: self.expected_size.unwrap_or(0),
+            preallocate: self.preallocate.unwrap_or(false),
+            compression: self.compression.unwrap_or(false),
+            cookie_jar: self.cookie_jar.unwrap_or(false),
+            auth: self.auth.unwrap_or_default(),
+            client_cert_path,
+            client_key_path,
         }
     }
 }
@@ -439,6 +594,73 @@ impl ipc::parcel::Serialize for TaskConfig {
             parcel.write(extra.1)?;
         }
 
+        // Serialize segmented download configuration
+        parcel.write(&self.segments)?;
+
+        // Serialize integrity verification configuration
+        parcel.write(&self.digest)?;
+
+        // Serialize chunked upload configuration
+        parcel.write(&self.chunk_size)?;
+
+        // Serialize retry policy
+        parcel.write(&self.retry_policy.max_attempts)?;
+        parcel.write(&self.retry_policy.base_delay)?;
+        parcel.write(&self.retry_policy.backoff_factor)?;
+        parcel.write(&self.retry_policy.jitter)?;
+        parcel.write(&(self.retry_policy.reasons.len() as u32))?;
+        for reason in self.retry_policy.reasons.iter() {
+            parcel.write(&(*reason as u32))?;
+        }
+        parcel.write(&(self.retry_policy.statuses.len() as u32))?;
+        for status in self.retry_policy.statuses.iter() {
+            parcel.write(&(*status as u32))?;
+        }
+
+        // Serialize prerequisite tasks
+        parcel.write(&(self.prerequisites.len() as u32))?;
+        for task_id in self.prerequisites.iter() {
+            parcel.write(&task_id.to_string())?;
+        }
+
+        // Serialize deferred start and recurrence
+        parcel.write(&self.not_before)?;
+        let (kind, interval, start, end) = match self.recurrence {
+            Recurrence::Once => (0u32, 0u64, 0u32, 0u32),
+            Recurrence::Interval(interval) => (1, interval, 0, 0),
+            Recurrence::Daily { start, end } => (2, 0, start, end),
+        };
+        parcel.write(&kind)?;
+        parcel.write(&interval)?;
+        parcel.write(&start)?;
+        parcel.write(&end)?;
+
+        // Serialize storage space options
+        parcel.write(&self.expected_size)?;
+        parcel.write(&self.preallocate)?;
+
+        // Serialize compressed transfer option
+        parcel.write(&self.compression)?;
+
+        // Serialize cookie jar option
+        parcel.write(&self.cookie_jar)?;
+
+        // Serialize authentication
+        let (scheme, username, secret, refresh) = match &self.auth {
+            Auth::None => (0u32, "", "", false),
+            Auth::Basic { username, password } => (1, username.as_str(), password.as_str(), false),
+            Auth::Digest { username, password } => (2, username.as_str(), password.as_str(), false),
+            Auth::Bearer { token, refresh } => (3, "", token.as_str(), *refresh),
+        };
+        parcel.write(&scheme)?;
+        parcel.write(&username.to_string())?;
+        parcel.write(&secret.to_string())?;
+        parcel.write(&refresh)?;
+
+        // Serialize client certificate paths
+        parcel.write(&self.client_cert_path)?;
+        parcel.write(&self.client_key_path)?;
+
         // Serialize notification fields
         if let Some(title) = &self.notification.title {
             parcel.write(&true)?;
@@ -607,6 +829,93 @@ pub struct Timeout {
     pub total_timeout: u64,
 }
 
+/// Retry policy of a task.
+///
+/// A failed attempt is retried with exponential backoff while its reason, or
+/// the HTTP status of a protocol error, is retryable. A `max_attempts` of 0
+/// disables the policy.
+#[derive(Clone, Debug, Default)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry, in milliseconds.
+    pub base_delay: u64,
+    /// Factor the delay is multiplied by after each retry.
+    pub backoff_factor: u32,
+    /// Percentage of the delay randomly added or subtracted, at most 100.
+    pub jitter: u32,
+    /// Retryable reason codes, empty for the service defaults.
+    pub reasons: Vec<u8>,
+    /// Retryable HTTP status codes, empty for the service defaults.
+    pub statuses: Vec<u16>,
+}
+
+/// Recurrence rule of a task.
+///
+/// A recurring task is created again by the service after each completion,
+/// deferred to its next occurrence.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Recurrence {
+    /// The task runs once.
+    #[default]
+    Once,
+    /// The task runs again every given number of seconds, at least 15
+    /// minutes.
+    Interval(u64),
+    /// The task runs once a day within a window, in minutes since midnight
+    /// UTC. The window spans midnight when `start` is after `end`.
+    Daily {
+        /// Start of the window.
+        start: u32,
+        /// End of the window, exclusive.
+        end: u32,
+    },
+}
+
+/// Authentication of the requests of a task.
+///
+/// Credentials are only sent when the task has no `Authorization` header of
+/// its own, and are left out of the `Debug` output.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub enum Auth {
+    /// No authentication.
+    #[default]
+    None,
+    /// HTTP Basic authentication, sent with every request.
+    Basic {
+        /// User name, which must not contain a colon.
+        username: String,
+        /// Password.
+        password: String,
+    },
+    /// HTTP Digest authentication, answering the challenge of the server.
+    Digest {
+        /// User name.
+        username: String,
+        /// Password.
+        password: String,
+    },
+    /// Bearer token authentication.
+    Bearer {
+        /// Token sent with every request.
+        token: String,
+        /// Whether to ask `Callback::on_token_refresh` for a new token once
+        /// the server rejects it.
+        refresh: bool,
+    },
+}
+
+impl std::fmt::Debug for Auth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Auth::None => write!(f, "None"),
+            Auth::Basic { username, .. } => write!(f, "Basic({})", username),
+            Auth::Digest { username, .. } => write!(f, "Digest({})", username),
+            Auth::Bearer { refresh, .. } => write!(f, "Bearer(refresh: {})", refresh),
+        }
+    }
+}
+
 /// Common configuration parameters for network tasks.
 ///
 /// Contains general task settings that apply to both download and upload
@@ -811,6 +1120,20 @@ impl ipc::parcel::Deserialize for TaskConfig {
                 connection_timeout: 0,
                 total_timeout: 0,
             },
+            segments: 0,
+            digest: String::new(),
+            chunk_size: 0,
+            retry_policy: RetryPolicy::default(),
+            prerequisites: vec![],
+            not_before: 0,
+            recurrence: Recurrence::Once,
+            expected_size: 0,
+            preallocate: false,
+            compression: false,
+            cookie_jar: false,
+            auth: Auth::None,
+            client_cert_path: String::new(),
+            client_key_path: String::new(),
         })
     }
 }
@@ -0,0 +1,220 @@
+// Copyright (C) 2025 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Error types shared by the request clients.
+//!
+//! This module defines `RequestError`, the error returned by every operation
+//! of the client API. It maps the error codes replied by the download service
+//! to named variants, while keeping the specific information of errors
+//! detected on the client side, such as the configuration field that failed
+//! verification or the download path that was rejected.
+
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::error_code::{
+    CHANNEL_NOT_OPEN, CLIENT_CERT_INVALID, EXCEPTION_SERVICE, FILE_OPERATION_ERR, GROUP_NOT_FOUND,
+    IPC_SIZE_TOO_LARGE, PARAMETER_CHECK, PERMISSION, SYSTEM_API, TASK_ENQUEUE_ERR, TASK_MODE_ERR,
+    TASK_NOT_FOUND, TASK_STATE_ERR,
+};
+
+/// Error types that can occur when calling the client API.
+///
+/// Each variant corresponds to an error code of the download service, which
+/// `code` returns so that it can be reported to applications unchanged.
+#[derive(Debug)]
+pub enum RequestError {
+    /// The caller lacks the permission required by the operation
+    Permission,
+    /// The caller is not allowed to use a system API
+    SystemApi,
+    /// A parameter of the operation is invalid
+    Parameter,
+    /// A field of the task configuration failed verification
+    InvalidField {
+        /// Name of the field, as exposed to applications
+        field: &'static str,
+        /// Error code reported by the verifier
+        code: i32,
+    },
+    /// Download path validation error
+    DownloadPath(DownloadPathError),
+    /// A file of the task could not be accessed
+    FileOperation,
+    /// The download service is unavailable
+    ServiceUnavailable,
+    /// The notification channel with the service is not open
+    ChannelNotOpen,
+    /// The request exceeds the maximum size of an IPC message
+    IpcSizeTooLarge,
+    /// The task queue is full
+    QueueFull,
+    /// The operation does not apply to the mode of the task
+    TaskMode,
+    /// The task does not exist
+    TaskNotFound,
+    /// The operation does not apply to the current state of the task
+    TaskState,
+    /// The task group does not exist
+    GroupNotFound,
+    /// The client certificate or private key of the task is invalid
+    ClientCertInvalid,
+    /// Any other error, with its code
+    Other(i32),
+}
+
+impl RequestError {
+    /// Returns the error code reported to applications for this error.
+    pub fn code(&self) -> i32 {
+        match self {
+            RequestError::Permission => PERMISSION,
+            RequestError::SystemApi => SYSTEM_API,
+            RequestError::Parameter => PARAMETER_CHECK,
+            RequestError::InvalidField { code, .. } => *code,
+            RequestError::DownloadPath(DownloadPathError::InvalidPath) => PARAMETER_CHECK,
+            RequestError::DownloadPath(_) | RequestError::FileOperation => FILE_OPERATION_ERR,
+            RequestError::ServiceUnavailable => EXCEPTION_SERVICE,
+            RequestError::ChannelNotOpen => CHANNEL_NOT_OPEN,
+            RequestError::IpcSizeTooLarge => IPC_SIZE_TOO_LARGE,
+            RequestError::QueueFull => TASK_ENQUEUE_ERR,
+            RequestError::TaskMode => TASK_MODE_ERR,
+            RequestError::TaskNotFound => TASK_NOT_FOUND,
+            RequestError::TaskState => TASK_STATE_ERR,
+            RequestError::GroupNotFound => GROUP_NOT_FOUND,
+            RequestError::ClientCertInvalid => CLIENT_CERT_INVALID,
+            RequestError::Other(code) => *code,
+        }
+    }
+}
+
+/// Converts a `DownloadPathError` into a `RequestError`.
+///
+/// Enables the `?` operator to automatically convert path validation errors
+/// into request errors during error propagation.
+impl From<DownloadPathError> for RequestError {
+    fn from(error: DownloadPathError) -> Self {
+        RequestError::DownloadPath(error)
+    }
+}
+
+/// Converts an error code replied by the download service into a
+/// `RequestError`.
+///
+/// Unknown codes are kept in `RequestError::Other`.
+impl From<i32> for RequestError {
+    fn from(code: i32) -> Self {
+        match code {
+            PERMISSION => RequestError::Permission,
+            SYSTEM_API => RequestError::SystemApi,
+            PARAMETER_CHECK => RequestError::Parameter,
+            FILE_OPERATION_ERR => RequestError::FileOperation,
+            EXCEPTION_SERVICE => RequestError::ServiceUnavailable,
+            CHANNEL_NOT_OPEN => RequestError::ChannelNotOpen,
+            IPC_SIZE_TOO_LARGE => RequestError::IpcSizeTooLarge,
+            TASK_ENQUEUE_ERR => RequestError::QueueFull,
+            TASK_MODE_ERR => RequestError::TaskMode,
+            TASK_NOT_FOUND => RequestError::TaskNotFound,
+            TASK_STATE_ERR => RequestError::TaskState,
+            GROUP_NOT_FOUND => RequestError::GroupNotFound,
+            CLIENT_CERT_INVALID => RequestError::ClientCertInvalid,
+            code => RequestError::Other(code),
+        }
+    }
+}
+
+/// Compares the error code of a `RequestError` with an integer code.
+impl PartialEq<i32> for RequestError {
+    fn eq(&self, other: &i32) -> bool {
+        self.code() == *other
+    }
+}
+
+impl Display for RequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestError::Permission => f.write_str("permission denied"),
+            RequestError::SystemApi => f.write_str("not a system application"),
+            RequestError::Parameter => f.write_str("invalid parameter"),
+            RequestError::InvalidField { field, .. } => {
+                write!(f, "invalid task config field `{}`", field)
+            }
+            RequestError::DownloadPath(e) => write!(f, "invalid download path: {}", e),
+            RequestError::FileOperation => f.write_str("file operation failed"),
+            RequestError::ServiceUnavailable => f.write_str("download service unavailable"),
+            RequestError::ChannelNotOpen => f.write_str("notification channel not open"),
+            RequestError::IpcSizeTooLarge => f.write_str("IPC message too large"),
+            RequestError::QueueFull => f.write_str("task queue is full"),
+            RequestError::TaskMode => f.write_str("task mode mismatch"),
+            RequestError::TaskNotFound => f.write_str("task not found"),
+            RequestError::TaskState => f.write_str("task state mismatch"),
+            RequestError::GroupNotFound => f.write_str("group not found"),
+            RequestError::ClientCertInvalid => f.write_str("invalid client certificate or key"),
+            RequestError::Other(code) => write!(f, "request error {}", code),
+        }?;
+        write!(f, " ({})", self.code())
+    }
+}
+
+impl Error for RequestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RequestError::DownloadPath(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Error types for download path validation and permission operations.
+#[derive(Debug)]
+pub enum DownloadPathError {
+    /// The provided path is empty
+    EmptyPath,
+    /// The path exceeds the maximum allowed length
+    TooLongPath,
+    /// The path is not in an allowed storage area
+    InvalidPath,
+    /// The bundle name in the path doesn't match the application
+    BundleNameNotMap,
+    /// The file already exists and overwrite is disabled
+    AlreadyExists,
+    /// File creation failed with an IO error
+    CreateFile(std::io::Error),
+    /// Setting file permissions failed with an IO error
+    SetPermission(std::io::Error),
+    /// Setting ACL access permissions failed
+    AclAccess(i32),
+}
+
+impl Error for DownloadPathError {}
+
+impl Display for DownloadPathError {
+    /// Formats the error as a human-readable string via the `Display` trait.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DownloadPathError::EmptyPath => f.write_str("file path is empty"),
+            DownloadPathError::TooLongPath => {
+                f.write_str("file path exceeds maximum allowed length")
+            }
+            DownloadPathError::InvalidPath => f.write_str("invalid path"),
+            DownloadPathError::BundleNameNotMap => {
+                f.write_str("file path bundle name does not match the application")
+            }
+            DownloadPathError::AlreadyExists => f.write_str("file already exists"),
+            DownloadPathError::CreateFile(e) => write!(f, "failed to create file: {}", e),
+            DownloadPathError::SetPermission(e) => {
+                write!(f, "failed to set file permissions: {}", e)
+            }
+            DownloadPathError::AclAccess(e) => write!(f, "ACL permission denied: {}", e),
+        }
+    }
+}
@@ -57,3 +57,6 @@ pub const TASK_STATE_ERR: i32 = 21900007;
 
 /// Requested task group not found.
 pub const GROUP_NOT_FOUND: i32 = 21900008;
+
+/// Client certificate or private key of the task is invalid.
+pub const CLIENT_CERT_INVALID: i32 = 21900009;
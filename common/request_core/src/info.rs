@@ -183,8 +183,10 @@ impl From<Reason> for Faults {
             | Reason::NetworkApp
             | Reason::NetworkAccount
             | Reason::NetworkAppAccount => Faults::Disconnected,
-            Reason::BuildClientFailed | Reason::BuildRequestFailed => Faults::Param,
-            Reason::GetFilesizeFailed | Reason::IoError => Faults::Fsio,
+            Reason::BuildClientFailed | Reason::BuildRequestFailed | Reason::ClientCertInvalid => {
+                Faults::Param
+            }
+            Reason::GetFilesizeFailed | Reason::IoError | Reason::IntegrityMismatch => Faults::Fsio,
             Reason::ContinuousTaskTimeout => Faults::Timeout,
             Reason::ConnectError => Faults::Tcp,
             Reason::RequestError | Reason::ProtocolError | Reason::UnsupportRangeRequest => {
@@ -193,7 +195,7 @@ impl From<Reason> for Faults {
             Reason::RedirectError => Faults::Redirect,
             Reason::DNS => Faults::Dns,
             Reason::TCP => Faults::Tcp,
-            Reason::SSL => Faults::Ssl,
+            Reason::SSL | Reason::ClientCertRejected => Faults::Ssl,
             Reason::LowSpeed => Faults::LowSpeed,
             _ => Faults::Others,
         }
@@ -267,6 +269,22 @@ pub enum Reason {
     NetworkAppAccount,
     /// Transfer speed fell below the configured minimum.
     LowSpeed,
+    /// Downloaded file does not match the expected digest.
+    IntegrityMismatch,
+    /// Prerequisite tasks have not completed yet.
+    PrerequisiteWaiting,
+    /// A prerequisite task failed or was removed.
+    PrerequisiteFailed,
+    /// The scheduled start time of the task has not been reached yet.
+    StartTimeWaiting,
+    /// The storage space is not enough for the rest of the download.
+    StorageSpaceWaiting,
+    /// The client certificate or its private key cannot be loaded.
+    ClientCertInvalid,
+    /// The server rejected the client certificate in the TLS handshake.
+    ClientCertRejected,
+    /// The task waits for the delay of its retry policy.
+    RetryWaiting,
 }
 
 impl From<u32> for Reason {
@@ -304,6 +322,14 @@ impl From<u32> for Reason {
             29 => Reason::AppAccount,
             30 => Reason::NetworkAppAccount,
             31 => Reason::LowSpeed,
+            32 => Reason::IntegrityMismatch,
+            33 => Reason::PrerequisiteWaiting,
+            34 => Reason::PrerequisiteFailed,
+            35 => Reason::StartTimeWaiting,
+            36 => Reason::StorageSpaceWaiting,
+            37 => Reason::ClientCertInvalid,
+            38 => Reason::ClientCertRejected,
+            39 => Reason::RetryWaiting,
             _ => unimplemented!(),
         }
     }
@@ -329,7 +355,7 @@ pub struct Wait {
 }
 
 /// Reason a task has entered a waiting state.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 #[repr(u32)]
 pub enum WaitingReason {
     /// Task queue is full.
@@ -340,6 +366,14 @@ pub enum WaitingReason {
     APP_BACKGROUND = 0x02,
     /// User is inactive.
     USER_INACTIVATED = 0x03,
+    /// Storage space is not enough for the download.
+    INSUFFICIENT_SPACE = 0x04,
+    /// Prerequisite tasks are not completed.
+    PREREQUISITE_WAITING = 0x05,
+    /// Scheduled start time is not reached.
+    START_TIME_WAITING = 0x06,
+    /// Retry delay after a failure is not elapsed.
+    RETRY_WAITING = 0x07,
 }
 
 impl From<u32> for WaitingReason {
@@ -349,13 +383,17 @@ impl From<u32> for WaitingReason {
             0x01 => WaitingReason::NETWORK_NOT_MATCH,
             0x02 => WaitingReason::APP_BACKGROUND,
             0x03 => WaitingReason::USER_INACTIVATED,
+            0x04 => WaitingReason::INSUFFICIENT_SPACE,
+            0x05 => WaitingReason::PREREQUISITE_WAITING,
+            0x06 => WaitingReason::START_TIME_WAITING,
+            0x07 => WaitingReason::RETRY_WAITING,
             _ => unimplemented!(),
         }
     }
 }
 
 /// HTTP response received for a task.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Response {
     /// Unique identifier of the task associated with this response.
     pub task_id: String,
@@ -383,7 +421,7 @@ pub struct TaskState {
 /// Progress information for a task.
 ///
 /// Contains current state, processed bytes, and other progress metrics.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Progress {
     /// Current state of the task.
     pub state: State,
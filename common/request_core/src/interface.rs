@@ -57,11 +57,22 @@ pub const ATTACH_GROUP: u32 = 19;
 pub const DELETE_GROUP: u32 = 20;
 /// Set the max speed of a task
 pub const SET_MAX_SPEED: u32 = 21;
+/// Set the device or application bandwidth budget.
+pub const SET_BANDWIDTH_BUDGET: u32 = 23;
+/// Seed the cookie jar of an application.
+pub const SET_COOKIES: u32 = 24;
+/// Clear the cookie jar of an application.
+pub const CLEAR_COOKIES: u32 = 25;
+/// Hand over a refreshed bearer token of a task.
+pub const SET_AUTH_TOKEN: u32 = 26;
 /// Change task mode.
 pub const SET_MODE: u32 = 100;
 /// Change task mode.
 pub const DISABLE_TASK_NOTIFICATION: u32 = 101;
 
+/// Notify a run count subscriber of the number of running tasks.
+pub const NOTIFY_RUN_COUNT: u32 = 2;
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -88,7 +99,12 @@ mod test {
         assert_eq!(18, CREATE_GROUP);
         assert_eq!(19, ATTACH_GROUP);
         assert_eq!(20, DELETE_GROUP);
+        assert_eq!(23, SET_BANDWIDTH_BUDGET);
+        assert_eq!(24, SET_COOKIES);
+        assert_eq!(25, CLEAR_COOKIES);
+        assert_eq!(26, SET_AUTH_TOKEN);
         assert_eq!(100, SET_MODE);
         assert_eq!(101, DISABLE_TASK_NOTIFICATION);
+        assert_eq!(2, NOTIFY_RUN_COUNT);
     }
 }
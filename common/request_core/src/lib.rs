@@ -17,6 +17,7 @@
 //! file handling, filters, runtime info, and external interfaces.
 
 pub mod config;
+pub mod error;
 pub mod error_code;
 pub mod file;
 pub mod filter;
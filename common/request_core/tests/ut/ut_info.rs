@@ -150,6 +150,8 @@ fn ut_faults_from_reason() {
     assert_eq!(Faults::from(Reason::TCP), Faults::Tcp);
     assert_eq!(Faults::from(Reason::SSL), Faults::Ssl);
     assert_eq!(Faults::from(Reason::LowSpeed), Faults::LowSpeed);
+    assert_eq!(Faults::from(Reason::ClientCertInvalid), Faults::Param);
+    assert_eq!(Faults::from(Reason::ClientCertRejected), Faults::Ssl);
 }
 
 // @tc.name: ut_reason_from_u32
@@ -171,6 +173,14 @@ fn ut_reason_from_u32() {
     assert_eq!(Reason::from(24), Reason::TCP);
     assert_eq!(Reason::from(25), Reason::SSL);
     assert_eq!(Reason::from(31), Reason::LowSpeed);
+    assert_eq!(Reason::from(32), Reason::IntegrityMismatch);
+    assert_eq!(Reason::from(33), Reason::PrerequisiteWaiting);
+    assert_eq!(Reason::from(34), Reason::PrerequisiteFailed);
+    assert_eq!(Reason::from(35), Reason::StartTimeWaiting);
+    assert_eq!(Reason::from(36), Reason::StorageSpaceWaiting);
+    assert_eq!(Reason::from(37), Reason::ClientCertInvalid);
+    assert_eq!(Reason::from(38), Reason::ClientCertRejected);
+    assert_eq!(Reason::from(39), Reason::RetryWaiting);
 }
 
 // @tc.name: ut_waiting_reason_from_u32
@@ -187,6 +197,10 @@ fn ut_waiting_reason_from_u32() {
     assert_eq!(WaitingReason::from(0x01), WaitingReason::NETWORK_NOT_MATCH);
     assert_eq!(WaitingReason::from(0x02), WaitingReason::APP_BACKGROUND);
     assert_eq!(WaitingReason::from(0x03), WaitingReason::USER_INACTIVATED);
+    assert_eq!(WaitingReason::from(0x04), WaitingReason::INSUFFICIENT_SPACE);
+    assert_eq!(WaitingReason::from(0x05), WaitingReason::PREREQUISITE_WAITING);
+    assert_eq!(WaitingReason::from(0x06), WaitingReason::START_TIME_WAITING);
+    assert_eq!(WaitingReason::from(0x07), WaitingReason::RETRY_WAITING);
 }
 
 // @tc.name: ut_faults_copy
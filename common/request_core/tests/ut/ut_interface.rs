@@ -249,6 +249,46 @@ fn ut_interface_set_max_speed() {
     assert_eq!(SET_MAX_SPEED, 21);
 }
 
+// @tc.name: ut_interface_set_bandwidth_budget
+// @tc.desc: Test SET_BANDWIDTH_BUDGET constant value
+// @tc.precon: NA
+// @tc.step: 1. Check SET_BANDWIDTH_BUDGET value
+//           2. Verify it equals 23
+// @tc.expect: SET_BANDWIDTH_BUDGET equals 23
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_interface_set_bandwidth_budget() {
+    assert_eq!(SET_BANDWIDTH_BUDGET, 23);
+}
+
+// @tc.name: ut_interface_cookies
+// @tc.desc: Test SET_COOKIES and CLEAR_COOKIES constant values
+// @tc.precon: NA
+// @tc.step: 1. Check SET_COOKIES and CLEAR_COOKIES values
+//           2. Verify they equal 24 and 25
+// @tc.expect: SET_COOKIES equals 24 and CLEAR_COOKIES equals 25
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_interface_cookies() {
+    assert_eq!(SET_COOKIES, 24);
+    assert_eq!(CLEAR_COOKIES, 25);
+}
+
+// @tc.name: ut_interface_set_auth_token
+// @tc.desc: Test SET_AUTH_TOKEN constant value
+// @tc.precon: NA
+// @tc.step: 1. Check SET_AUTH_TOKEN value
+//           2. Verify it equals 26
+// @tc.expect: SET_AUTH_TOKEN equals 26
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_interface_set_auth_token() {
+    assert_eq!(SET_AUTH_TOKEN, 26);
+}
+
 // @tc.name: ut_interface_set_mode
 // @tc.desc: Test SET_MODE constant value
 // @tc.precon: NA
@@ -289,7 +329,8 @@ fn ut_interface_all_distinct() {
         CONSTRUCT, PAUSE, QUERY, QUERY_MIME_TYPE, REMOVE, RESUME, START, STOP,
         SHOW, TOUCH, SEARCH, GET_TASK, CLEAR, OPEN_CHANNEL, SUBSCRIBE, UNSUBSCRIBE,
         SUB_RUN_COUNT, UNSUB_RUN_COUNT, CREATE_GROUP, ATTACH_GROUP, DELETE_GROUP,
-        SET_MAX_SPEED, SET_MODE, DISABLE_TASK_NOTIFICATION,
+        SET_MAX_SPEED, SET_BANDWIDTH_BUDGET, SET_COOKIES, CLEAR_COOKIES, SET_AUTH_TOKEN,
+        SET_MODE, DISABLE_TASK_NOTIFICATION,
     ];
     
     for i in 0..codes.len() {
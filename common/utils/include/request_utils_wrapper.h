@@ -24,6 +24,7 @@
 #include "context.h"
 #include "cxx.h"
 #include "file_uri.h"
+#include "openssl/sha.h"
 
 namespace OHOS::Request {
 using namespace OHOS::AbilityRuntime;
@@ -33,6 +34,20 @@ struct AniObject;
 
 rust::string SHA256(rust::str input);
 
+rust::string MD5(rust::str input);
+
+class Sha256Context {
+public:
+    Sha256Context();
+    void Update(rust::Slice<const uint8_t> data);
+    rust::string Finish();
+
+private:
+    SHA256_CTX ctx_;
+};
+
+std::unique_ptr<Sha256Context> NewSha256Context();
+
 bool IsStageContext(AniEnv *env, AniObject *obj);
 
 std::shared_ptr<Context> GetStageModeContext(AniEnv **env, AniObject *obj);
@@ -23,12 +23,23 @@
 #include "ani_common_want_agent.h"
 #include "data_ability_helper.h"
 #include "network_security_config.h"
+#include "openssl/md5.h"
 #include "openssl/sha.h"
 #include "want_agent.h"
 #include "want_agent_helper.h"
 
 namespace OHOS::Request {
 
+static rust::string ToHex(const unsigned char *hash, size_t len)
+{
+    std::stringstream ss;
+    for (size_t i = 0; i < len; i++) {
+        // 2 means setting hte width of the output.
+        ss << std::hex << std::setw(2) << std::setfill('0') << static_cast<int>(hash[i]);
+    }
+    return ss.str();
+}
+
 rust::string SHA256(rust::str input)
 {
     unsigned char hash[SHA256_DIGEST_LENGTH];
@@ -36,12 +47,40 @@ rust::string SHA256(rust::str input)
     SHA256_Init(&sha256);
     SHA256_Update(&sha256, input.data(), input.length());
     SHA256_Final(hash, &sha256);
-    std::stringstream ss;
-    for (int i = 0; i < SHA256_DIGEST_LENGTH; i++) {
-        // 2 means setting hte width of the output.
-        ss << std::hex << std::setw(2) << std::setfill('0') << static_cast<int>(hash[i]);
-    }
-    return ss.str();
+    return ToHex(hash, SHA256_DIGEST_LENGTH);
+}
+
+rust::string MD5(rust::str input)
+{
+    unsigned char hash[MD5_DIGEST_LENGTH];
+    MD5_CTX md5;
+    MD5_Init(&md5);
+    MD5_Update(&md5, input.data(), input.length());
+    MD5_Final(hash, &md5);
+    return ToHex(hash, MD5_DIGEST_LENGTH);
+}
+
+Sha256Context::Sha256Context()
+{
+    SHA256_Init(&ctx_);
+}
+
+void Sha256Context::Update(rust::Slice<const uint8_t> data)
+{
+    SHA256_Update(&ctx_, data.data(), data.size());
+}
+
+rust::string Sha256Context::Finish()
+{
+    unsigned char hash[SHA256_DIGEST_LENGTH];
+    SHA256_Final(hash, &ctx_);
+    SHA256_Init(&ctx_);
+    return ToHex(hash, SHA256_DIGEST_LENGTH);
+}
+
+std::unique_ptr<Sha256Context> NewSha256Context()
+{
+    return std::make_unique<Sha256Context>();
 }
 
 bool IsStageContext(AniEnv *env, AniObject *obj)
@@ -0,0 +1,115 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dates of HTTP headers.
+//!
+//! Parses the IMF-fixdate format of HTTP dates, such as
+//! `Sun, 06 Nov 1994 08:49:37 GMT`, and provides the date arithmetic of the
+//! proleptic Gregorian calendar for parsers of other date formats, such as
+//! the one of cookies.
+
+/// Abbreviated month names of an HTTP date.
+pub const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Latest year of an HTTP date.
+const MAX_YEAR: i64 = 9999;
+
+const SECONDS_IN_ONE_DAY: i64 = 24 * 60 * 60;
+
+/// Parses an HTTP date in the IMF-fixdate format into seconds since the
+/// epoch.
+///
+/// Returns `None` if the date is malformed, does not exist or lies outside
+/// the years 1970 to 9999.
+pub fn parse_http_date(value: &str) -> Option<u64> {
+    let mut parts = value.split_whitespace();
+    if !parts.next()?.ends_with(',') {
+        return None;
+    }
+    let day = parts.next()?.parse::<u32>().ok()?;
+    let month = month_from_name(parts.next()?)?;
+    let year = parts.next()?.parse::<i64>().ok()?;
+    let mut time = parts.next()?.split(':');
+    let hour = time.next()?.parse::<u32>().ok()?;
+    let minute = time.next()?.parse::<u32>().ok()?;
+    let second = time.next()?.parse::<u32>().ok()?;
+    if parts.next()? != "GMT" || parts.next().is_some() || time.next().is_some() {
+        return None;
+    }
+    if !(1970..=MAX_YEAR).contains(&year) {
+        return None;
+    }
+    timestamp(year, month, day, hour, minute, second).map(|secs| secs as u64)
+}
+
+/// Returns the month, from 1 to 12, of an abbreviated month name, ignoring
+/// ASCII case.
+pub fn month_from_name(name: &str) -> Option<u32> {
+    MONTHS
+        .iter()
+        .position(|month| month.eq_ignore_ascii_case(name))
+        .map(|i| i as u32 + 1)
+}
+
+/// Returns the number of days of a month.
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days from 1970-01-01 to a date, negative for a date
+/// before it.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let (month, day) = (month as i64, day as i64);
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = (month + 9) % 12;
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Returns the seconds since the epoch of a date and time, negative for a
+/// time before it.
+///
+/// Returns `None` if the date does not exist or the time is out of range. A
+/// second of 60 is accepted for leap seconds.
+pub fn timestamp(
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+) -> Option<i64> {
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    let time = (hour * 3600 + minute * 60 + second) as i64;
+    Some(days_from_civil(year, month, day) * SECONDS_IN_ONE_DAY + time)
+}
+
+#[cfg(test)]
+mod ut_date {
+    include!("../tests/ut/ut_date.rs");
+}
@@ -0,0 +1,24 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! MD5 hashing utility.
+//!
+//! MD5 is not suitable to protect data. It is only provided for protocols
+//! that still require it, such as HTTP Digest authentication.
+
+use crate::wrapper::MD5;
+
+/// Computes the MD5 digest of `input` and returns it as a hex string.
+pub fn md5(input: &str) -> String {
+    MD5(input)
+}
@@ -13,11 +13,15 @@
 
 //! Hashing utilities for the request common utils.
 //!
-//! Aggregates the `sha256` module (OpenHarmony only) and the cross-platform
-//! `url` module, re-exporting `url_hash` for hashing URL strings.
+//! Aggregates the `sha256` and `md5` modules (OpenHarmony only) and the
+//! cross-platform `url` module, re-exporting `url_hash` for hashing URL
+//! strings and the SHA-256 and MD5 helpers.
 
 cfg_ohos! {
+    mod md5;
     mod sha256;
+    pub use md5::md5;
+    pub use sha256::{sha256, Sha256};
 }
 
 mod url;
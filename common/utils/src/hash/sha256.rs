@@ -14,11 +14,45 @@
 //! SHA-256 hashing utility.
 //!
 //! Wraps the platform SHA-256 implementation and returns the digest of a
-//! string input as a lowercase hex string.
+//! string input as a lowercase hex string. [`Sha256`] hashes data that
+//! arrives in pieces, such as a file being written.
 
-use crate::wrapper::SHA256;
+use cxx::UniquePtr;
+
+use crate::wrapper::{NewSha256Context, Sha256Context, SHA256};
 
 /// Computes the SHA-256 digest of `input` and returns it as a hex string.
 pub fn sha256(input: &str) -> String {
     SHA256(input)
 }
+
+/// Incremental SHA-256 hasher.
+pub struct Sha256 {
+    inner: UniquePtr<Sha256Context>,
+}
+
+impl Sha256 {
+    /// Creates a hasher with no data fed in.
+    pub fn new() -> Self {
+        Self {
+            inner: NewSha256Context(),
+        }
+    }
+
+    /// Feeds `data` into the hasher.
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.pin_mut().Update(data);
+    }
+
+    /// Returns the digest of all data fed so far as a lowercase hex string,
+    /// and resets the hasher.
+    pub fn finish(&mut self) -> String {
+        self.inner.pin_mut().Finish()
+    }
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -25,6 +25,9 @@
 #[macro_use]
 mod macros;
 
+/// Dates of HTTP headers.
+pub mod date;
+
 /// Fast pseudorandom number generation utilities.
 pub mod fastrand;
 
@@ -40,6 +43,9 @@ mod hilog;
 /// Least Recently Used (LRU) cache implementation.
 pub mod lru;
 
+/// HTTP methods supported by request tasks.
+pub mod method;
+
 /// Task ID generation and management utilities.
 pub mod task_id;
 
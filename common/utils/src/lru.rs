@@ -209,6 +209,26 @@ impl<K: Hash + Eq + Clone, V> LRUCache<K, V> {
         None
     }
 
+    /// Returns a reference to the value corresponding to the key if it exists,
+    /// without changing its position in the access order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use request_utils::lru::LRUCache;
+    ///
+    /// let mut cache = LRUCache::new();
+    /// cache.insert(1, "one");
+    /// cache.insert(2, "two");
+    ///
+    /// assert_eq!(cache.peek(&1), Some(&"one"));
+    /// // 1 is still the least recently used
+    /// assert_eq!(cache.pop(), Some("one"));
+    /// ```
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|&node| unsafe { &(*node).value })
+    }
+
     /// Returns a mutable reference to the value corresponding to the key if it
     /// exists.
     ///
@@ -313,6 +333,40 @@ impl<K: Hash + Eq + Clone, V> LRUCache<K, V> {
         }
     }
 
+    /// Removes and returns the least recently used value whose key matches
+    /// the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use request_utils::lru::LRUCache;
+    ///
+    /// let mut cache = LRUCache::new();
+    /// cache.insert(1, "one");
+    /// cache.insert(2, "two");
+    ///
+    /// assert_eq!(cache.pop_by(|key| *key != 1), Some("two"));
+    /// assert_eq!(cache.pop_by(|key| *key != 1), None);
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn pop_by<F>(&mut self, mut f: F) -> Option<V>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        let mut node = self.list.tail;
+        while !node.is_null() {
+            unsafe {
+                // Walk from the least recently used node towards the head
+                if f(&(*node).key) {
+                    let key = (*node).key.clone();
+                    return self.remove(&key);
+                }
+                node = (*node).prev;
+            }
+        }
+        None
+    }
+
     /// Removes and returns the value associated with the key if it exists.
     ///
     /// # Examples
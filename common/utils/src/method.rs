@@ -0,0 +1,24 @@
+// Copyright (C) 2025 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HTTP methods supported by request tasks.
+//!
+//! Shared by the client-side config verification and the service so both
+//! accept the same methods. The NAPI layer mirrors these lists in
+//! `constant.h`.
+
+/// HTTP methods accepted for download tasks.
+pub const DOWNLOAD_METHODS: [&str; 6] = ["GET", "POST", "PATCH", "DELETE", "HEAD", "OPTIONS"];
+
+/// HTTP methods accepted for upload tasks, all of which carry a body.
+pub const UPLOAD_METHODS: [&str; 3] = ["PUT", "POST", "PATCH"];
@@ -54,6 +54,11 @@ impl From<SharedPtr<ffi::ApplicationInfo>> for super::context::ApplicationInfo {
     }
 }
 
+// SAFETY: `Sha256Context` owns a plain OpenSSL `SHA256_CTX` with no
+// thread-affine state, and all mutation goes through `Pin<&mut>`.
+unsafe impl Send for ffi::Sha256Context {}
+unsafe impl Sync for ffi::Sha256Context {}
+
 // CXX bridge module for FFI bindings to C++ code
 #[cxx::bridge(namespace = "OHOS::Request")]
 mod ffi {
@@ -140,6 +145,31 @@ mod ffi {
         /// The SHA-256 hash of the input as a string.
         fn SHA256(input: &str) -> String;
 
+        /// Computes the MD5 hash of an input string.
+        ///
+        /// # Arguments
+        ///
+        /// * `input` - The string to hash
+        ///
+        /// # Returns
+        ///
+        /// The MD5 hash of the input as a lowercase hex string.
+        fn MD5(input: &str) -> String;
+
+        /// Incremental SHA-256 hashing context.
+        type Sha256Context;
+
+        /// Creates a new, initialized SHA-256 hashing context.
+        fn NewSha256Context() -> UniquePtr<Sha256Context>;
+
+        /// Feeds `data` into the hashing context.
+        fn Update(self: Pin<&mut Sha256Context>, data: &[u8]);
+
+        /// Finishes hashing and returns the digest as a lowercase hex string.
+        ///
+        /// The context is reinitialized afterwards and may be reused.
+        fn Finish(self: Pin<&mut Sha256Context>) -> String;
+
         /// Checks if the given environment is a stage context.
         ///
         /// # Safety
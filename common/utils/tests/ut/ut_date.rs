@@ -0,0 +1,125 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Sun, 06 Nov 1994 08:49:37 GMT in seconds since the epoch.
+const DATE: u64 = 784_111_777;
+
+// @tc.name: ut_parse_http_date_valid
+// @tc.desc: Test parse_http_date with valid IMF-fixdate dates
+// @tc.precon: NA
+// @tc.step: 1. Parse dates around the epoch, a leap day and a leap second
+// @tc.expect: The dates are converted to seconds since the epoch
+// @tc.type: FUNC
+#[test]
+fn ut_parse_http_date_valid() {
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Some(DATE));
+    assert_eq!(
+        parse_http_date(" Sun,  06 Nov 1994 08:49:37 GMT "),
+        Some(DATE)
+    );
+    assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+    assert_eq!(
+        parse_http_date("Tue, 29 Feb 2000 00:00:10 GMT"),
+        Some(951_782_410)
+    );
+    assert_eq!(
+        parse_http_date("Sat, 31 Dec 2016 23:59:60 GMT"),
+        Some(1_483_228_800)
+    );
+    assert_eq!(
+        parse_http_date("Fri, 31 Dec 9999 23:59:59 GMT"),
+        Some(253_402_300_799)
+    );
+}
+
+// @tc.name: ut_parse_http_date_invalid
+// @tc.desc: Test parse_http_date with malformed or nonexistent dates
+// @tc.precon: NA
+// @tc.step: 1. Parse dates with a wrong layout, zone, month, day, time or
+//              year
+// @tc.expect: The dates are rejected
+// @tc.type: FUNC
+#[test]
+fn ut_parse_http_date_invalid() {
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37"), None);
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 UTC"), None);
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT x"), None);
+    assert_eq!(parse_http_date("Sun 06 Nov 1994 08:49:37 GMT"), None);
+    assert_eq!(parse_http_date("Sun, 06 Foo 1994 08:49:37 GMT"), None);
+    assert_eq!(parse_http_date("Sun, 00 Nov 1994 08:49:37 GMT"), None);
+    assert_eq!(parse_http_date("Fri, 29 Feb 2019 08:49:37 GMT"), None);
+    assert_eq!(parse_http_date("Sun, 31 Nov 1994 08:49:37 GMT"), None);
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 24:00:00 GMT"), None);
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:60:00 GMT"), None);
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37:00 GMT"), None);
+    assert_eq!(parse_http_date("Wed, 31 Dec 1969 23:59:59 GMT"), None);
+    assert_eq!(parse_http_date("Sat, 01 Jan 10000 00:00:00 GMT"), None);
+    assert_eq!(
+        parse_http_date("Sun, 06 Nov 18446744073709551615 08:49:37 GMT"),
+        None
+    );
+    assert_eq!(parse_http_date("0"), None);
+    assert_eq!(parse_http_date(""), None);
+}
+
+// @tc.name: ut_month_from_name
+// @tc.desc: Test month_from_name with month abbreviations
+// @tc.precon: NA
+// @tc.step: 1. Look up abbreviations in several cases and unknown names
+// @tc.expect: Abbreviations map to their month ignoring case, unknown names
+//             to None
+// @tc.type: FUNC
+#[test]
+fn ut_month_from_name() {
+    assert_eq!(month_from_name("Jan"), Some(1));
+    assert_eq!(month_from_name("dec"), Some(12));
+    assert_eq!(month_from_name("SEP"), Some(9));
+    assert_eq!(month_from_name("Sept"), None);
+    assert_eq!(month_from_name(""), None);
+}
+
+// @tc.name: ut_days_from_civil
+// @tc.desc: Test days_from_civil around the epoch and across leap years
+// @tc.precon: NA
+// @tc.step: 1. Convert dates before, at and after the epoch
+// @tc.expect: The days since the epoch are returned, negative before it
+// @tc.type: FUNC
+#[test]
+fn ut_days_from_civil() {
+    assert_eq!(days_from_civil(1970, 1, 1), 0);
+    assert_eq!(days_from_civil(1969, 12, 31), -1);
+    assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+    assert_eq!(days_from_civil(1601, 1, 1), -134_774);
+    assert_eq!(days_in_month(2000, 2), 29);
+    assert_eq!(days_in_month(1900, 2), 28);
+    assert_eq!(days_in_month(2024, 4), 30);
+}
+
+// @tc.name: ut_timestamp
+// @tc.desc: Test timestamp with valid and out of range fields
+// @tc.precon: NA
+// @tc.step: 1. Convert valid dates and times, and ones out of range
+// @tc.expect: Valid ones are converted to seconds since the epoch, the others
+//             rejected
+// @tc.type: FUNC
+#[test]
+fn ut_timestamp() {
+    assert_eq!(timestamp(1994, 11, 6, 8, 49, 37), Some(DATE as i64));
+    assert_eq!(timestamp(1969, 12, 31, 23, 59, 59), Some(-1));
+    assert_eq!(timestamp(1994, 13, 6, 8, 49, 37), None);
+    assert_eq!(timestamp(1994, 0, 6, 8, 49, 37), None);
+    assert_eq!(timestamp(1994, 2, 29, 8, 49, 37), None);
+    assert_eq!(timestamp(1994, 11, 6, 8, 49, 61), None);
+}
@@ -253,3 +253,48 @@ fn ut_test_insert_after_pop() {
     assert!(!cache.is_empty());
     assert_eq!(Some(Cache::from_u(1)), cache.pop());
 }
+
+// @tc.name: ut_lru_cache_peek
+// @tc.desc: Test peeking entries without changing the access order
+// @tc.precon: NA
+// @tc.step: 1. Create LRUCache instance
+//           2. Insert two entries and peek the oldest one
+//           3. Pop the least recently used entry
+// @tc.expect: Peek returns the entry and pop still removes it first
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+// @tc.level: level3
+#[test]
+fn ut_test_peek() {
+    let mut cache = LRUCache::new();
+    cache.insert("key0", Cache::from_u(0));
+    cache.insert("key1", Cache::from_u(1));
+    assert_eq!(Some(&Cache::from_u(0)), cache.peek(&"key0"));
+    assert_eq!(None, cache.peek(&"key2"));
+    assert_eq!(Some(Cache::from_u(0)), cache.pop());
+    assert_eq!(1, cache.len());
+}
+
+// @tc.name: ut_lru_cache_pop_by
+// @tc.desc: Test popping the least recently used entry matching a predicate
+// @tc.precon: NA
+// @tc.step: 1. Create LRUCache instance
+//           2. Insert three entries and access the oldest one
+//           3. Pop entries skipping one key
+// @tc.expect: Entries are popped in LRU order, skipping the excluded key
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: level3
+#[test]
+fn ut_test_pop_by() {
+    let mut cache = LRUCache::new();
+    cache.insert("key0", Cache::from_u(0));
+    cache.insert("key1", Cache::from_u(1));
+    cache.insert("key2", Cache::from_u(2));
+    cache.get(&"key0");
+    assert_eq!(Some(Cache::from_u(2)), cache.pop_by(|key| *key != "key1"));
+    assert_eq!(Some(Cache::from_u(0)), cache.pop_by(|key| *key != "key1"));
+    assert_eq!(None, cache.pop_by(|key| *key != "key1"));
+    assert_eq!(1, cache.len());
+    assert_eq!(Some(Cache::from_u(1)), cache.pop());
+}
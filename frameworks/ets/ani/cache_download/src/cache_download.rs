@@ -212,7 +212,7 @@ pub fn cancel(url: String) -> Result<(), BusinessError> {
             "url exceeds the maximum length".to_string(),
         ));
     }
-    CacheDownloadService::get_instance().cancel(&url);
+    CacheDownloadService::get_instance().cancel(&DownloadRequest::new(&url));
     Ok(())
 }
 
@@ -311,7 +311,7 @@ pub fn get_download_info(url: String) -> Result<Option<DownloadInfo>, BusinessEr
         ));
     }
     let info = CacheDownloadService::get_instance()
-        .get_download_info(&url)
+        .get_download_info(&DownloadRequest::new(&url))
         .map(|info| {
             DownloadInfo::from_native(
                 preload_native_rlib::info::RustDownloadInfo::from_download_info(info),
@@ -22,7 +22,7 @@ use ani_rs::business_error::BusinessError;
 use ani_rs::objects::{AniObject, AniRef};
 use ani_rs::AniEnv;
 use request_client::check::file::DownloadPathError;
-use request_client::client::error::CreateTaskError;
+use request_client::client::error::RequestError;
 use request_client::RequestClient;
 use request_core::config::{TaskConfig, Version};
 use request_core::filter::SearchFilter;
@@ -123,7 +123,7 @@ pub fn check_config(env: &AniEnv, context: AniRef, config: Config) -> Result<i64
             error!("Create task failed: {:?}", e);
             // Handle specific error types and return appropriate business errors
             match e {
-                CreateTaskError::DownloadPath(err) => {
+                RequestError::DownloadPath(err) => {
                     let (code, message): (i32, String) = match err {
                         DownloadPathError::InvalidPath => (401, "Invalid Path".to_string()),
                         DownloadPathError::EmptyPath => {
@@ -153,9 +153,11 @@ pub fn check_config(env: &AniEnv, context: AniRef, config: Config) -> Result<i64
                     };
                     Err(BusinessError::new(code, message))
                 }
-                CreateTaskError::Code(code) => {
-                    Err(BusinessError::new_static(code, "Create Task Failed"))
-                }
+                RequestError::InvalidField { field, code } => Err(BusinessError::new(
+                    code,
+                    format!("Parameter verification failed, invalid {}", field),
+                )),
+                e => Err(BusinessError::new_static(e.code(), "Create Task Failed")),
             }
         }
     }
@@ -213,7 +215,7 @@ pub fn create(env: &AniEnv, context: AniRef, seq: i64) -> Result<String, Busines
             error!("Create task failed: {:?}", e);
             // Handle specific error types and return appropriate business errors
             match e {
-                CreateTaskError::DownloadPath(err) => {
+                RequestError::DownloadPath(err) => {
                     let (code, message): (i32, String) = match err {
                         DownloadPathError::InvalidPath => (401, "Invalid Path".to_string()),
                         DownloadPathError::EmptyPath => {
@@ -243,9 +245,11 @@ pub fn create(env: &AniEnv, context: AniRef, seq: i64) -> Result<String, Busines
                     };
                     Err(BusinessError::new(code, message))
                 }
-                CreateTaskError::Code(code) => {
-                    Err(BusinessError::new_static(code, "Create Task Failed"))
-                }
+                RequestError::InvalidField { field, code } => Err(BusinessError::new(
+                    code,
+                    format!("Parameter verification failed, invalid {}", field),
+                )),
+                e => Err(BusinessError::new_static(e.code(), "Create Task Failed")),
             }
         }
     }
@@ -280,7 +284,7 @@ pub fn get_task(context: AniRef, id: String, token: Option<String>) -> Result<Ta
             tid: id,
             config: Config::from(c),
         })
-        .map_err(|e| BusinessError::new(e, "Failed to get download task".to_string()))
+        .map_err(|e| BusinessError::new(e.code(), "Failed to get download task".to_string()))
 }
 
 /// Removes a task with the specified ID.
@@ -320,7 +324,7 @@ pub fn remove(id: String) -> Result<(), BusinessError> {
     })?;
     RequestClient::get_instance()
         .remove(task_id)
-        .map_err(|e| BusinessError::new_static(e, "Failed to remove task"))?;
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to remove task"))?;
     CallbackManager::get_instance().remove_task(task_id);
     Ok(())
 }
@@ -365,7 +369,7 @@ pub fn show(id: String) -> Result<TaskInfo, BusinessError> {
             info!("Api10 get task info: {:?}", info);
             TaskInfo::from(info)
         })
-        .map_err(|e| BusinessError::new(e, "Failed to get download task info".to_string()))
+        .map_err(|e| BusinessError::new(e.code(), "Failed to get download task info".to_string()))
 }
 
 /// Touches a task with the specified ID and authentication token.
@@ -394,7 +398,7 @@ pub fn touch(id: String, token: String) -> Result<TaskInfo, BusinessError> {
             info!("Api10 touch task info: {:?}", info);
             TaskInfo::from(info)
         })
-        .map_err(|e| BusinessError::new_static(e, "Failed to touch task"))
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to touch task"))
 }
 
 /// Searches for tasks matching the given filter criteria.
@@ -446,7 +450,7 @@ pub fn search(filter: Option<Filter>) -> Result<Vec<String>, BusinessError> {
             info!("Api10 search tasks: {:?}", tasks);
             tasks
         })
-        .map_err(|e| BusinessError::new(e, "Failed to search tasks".to_string()))
+        .map_err(|e| BusinessError::new(e.code(), "Failed to search tasks".to_string()))
 }
 
 /// Queries a task with the specified ID.
@@ -476,5 +480,5 @@ pub fn query(id: String) -> Result<TaskInfo, BusinessError> {
             info!("Api10 query task info: {:?}", info);
             TaskInfo::from(info)
         })
-        .map_err(|e| BusinessError::new_static(e, "Failed to query task"))
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to query task"))
 }
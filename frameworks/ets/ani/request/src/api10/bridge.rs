@@ -530,6 +530,14 @@ pub enum WaitingReason {
     AppBackground = 0x02,
     /// User is currently inactive.
     UserInactivated = 0x03,
+    /// Storage space is not enough for the download.
+    InsufficientSpace = 0x04,
+    /// Prerequisite tasks are not completed.
+    PrerequisiteWaiting = 0x05,
+    /// Scheduled start time is not reached.
+    StartTimeWaiting = 0x06,
+    /// Retry delay after a failure is not elapsed.
+    RetryWaiting = 0x07,
 }
 
 /// Converts from core WaitingReason to API WaitingReason.
@@ -540,6 +548,10 @@ impl From<request_core::info::WaitingReason> for WaitingReason {
             info::WaitingReason::NETWORK_NOT_MATCH => WaitingReason::NetworkNotMatch,
             info::WaitingReason::APP_BACKGROUND => WaitingReason::AppBackground,
             info::WaitingReason::USER_INACTIVATED => WaitingReason::UserInactivated,
+            info::WaitingReason::INSUFFICIENT_SPACE => WaitingReason::InsufficientSpace,
+            info::WaitingReason::PREREQUISITE_WAITING => WaitingReason::PrerequisiteWaiting,
+            info::WaitingReason::START_TIME_WAITING => WaitingReason::StartTimeWaiting,
+            info::WaitingReason::RETRY_WAITING => WaitingReason::RetryWaiting,
         }
     }
 }
@@ -906,6 +918,20 @@ impl From<Config<'_>> for TaskConfig {
                     total_timeout: Some(604800),
                 })
                 .into(),
+            segments: 0,
+            digest: String::new(),
+            chunk_size: 0,
+            retry_policy: config::RetryPolicy::default(),
+            prerequisites: vec![],
+            not_before: 0,
+            recurrence: config::Recurrence::Once,
+            expected_size: 0,
+            preallocate: false,
+            compression: false,
+            cookie_jar: false,
+            auth: config::Auth::None,
+            client_cert_path: String::new(),
+            client_key_path: String::new(),
         }
     }
 }
@@ -126,7 +126,7 @@ pub fn create_group(env: &AniEnv, mut config: GroupConfig) -> Result<String, Bus
             info!("create_group: {:?}", info);
             info
         })
-        .map_err(|e| BusinessError::new_static(e, "Failed to create group"))
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to create group"))
 }
 
 /// Attaches a list of tasks to an existing task group.
@@ -140,7 +140,7 @@ pub fn attach_group(gid: String, tids: Vec<String>) -> Result<(), BusinessError>
     ParseGid(&gid)?;
     RequestClient::get_instance()
         .attach_group(gid, tids)
-        .map_err(|e| BusinessError::new_static(e, "Failed to attach group"))
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to attach group"))
 }
 
 /// Deletes an existing task group.
@@ -154,5 +154,5 @@ pub fn delete_group(gid: String) -> Result<(), BusinessError> {
     ParseGid(&gid)?;
     RequestClient::get_instance()
         .delete_group(gid)
-        .map_err(|e| BusinessError::new_static(e, "Failed to delete group"))
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to delete group"))
 }
@@ -55,7 +55,7 @@ pub fn start(this: Task) -> Result<(), BusinessError> {
     let task_id = this.tid.parse().unwrap();
     RequestClient::get_instance()
         .start(task_id)
-        .map_err(|e| BusinessError::new_static(e, "Failed to start task"))
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to start task"))
 }
 
 /// Pauses a running request task.
@@ -87,7 +87,7 @@ pub fn pause(this: Task) -> Result<(), BusinessError> {
     let task_id = this.tid.parse().unwrap();
     RequestClient::get_instance()
         .pause(task_id)
-        .map_err(|e| BusinessError::new_static(e, "Failed to pause task"))
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to pause task"))
 }
 
 /// Resumes a paused request task.
@@ -119,7 +119,7 @@ pub fn resume(this: Task) -> Result<(), BusinessError> {
     let task_id = this.tid.parse().unwrap();
     RequestClient::get_instance()
         .resume(task_id)
-        .map_err(|e| BusinessError::new_static(e, "Failed to resume task"))
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to resume task"))
 }
 
 /// Stops a running request task.
@@ -151,7 +151,7 @@ pub fn stop(this: Task) -> Result<(), BusinessError> {
     let task_id = this.tid.parse().unwrap();
     RequestClient::get_instance()
         .stop(task_id)
-        .map_err(|e| BusinessError::new_static(e, "Failed to stop task"))
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to stop task"))
 }
 
 /// Sets the maximum speed limit for a request task.
@@ -191,7 +191,7 @@ pub fn set_max_speed(this: Task, speed: i64) -> Result<(), BusinessError> {
     let task_id = this.tid.parse().unwrap();
     RequestClient::get_instance()
         .set_max_speed(task_id, speed)
-        .map_err(|e| BusinessError::new_static(e, "Failed to set task max speed"))
+        .map_err(|e| BusinessError::new_static(e.code(), "Failed to set task max speed"))
 }
 
 /// Validates the maximum speed setting against minimum speed.
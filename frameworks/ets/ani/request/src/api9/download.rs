@@ -25,7 +25,7 @@ use ani_rs::business_error::BusinessError;
 use ani_rs::objects::{AniObject, AniRef};
 use ani_rs::AniEnv;
 use request_client::check::file::DownloadPathError;
-use request_client::client::error::CreateTaskError;
+use request_client::client::error::RequestError;
 use request_client::RequestClient;
 use request_core::config::{TaskConfig, Version};
 use request_core::info::TaskInfo;
@@ -64,7 +64,7 @@ pub fn check_config(
     // Create the download task
     match RequestClient::get_instance().check_config(context, seq, config) {
         Ok(()) => Ok(seq as i64),
-        Err(CreateTaskError::DownloadPath(err)) => {
+        Err(RequestError::DownloadPath(err)) => {
             let message = match err {
                 DownloadPathError::EmptyPath => {
                     "Invalid file or file system error, File path is empty".to_string()
@@ -91,8 +91,8 @@ pub fn check_config(
             };
             return Err(BusinessError::new(13400001, message))
         }
-        Err(CreateTaskError::Code(code)) => {
-            return Err(BusinessError::new(code, "Download failed.".to_string()))
+        Err(e) => {
+            return Err(BusinessError::new(e.code(), "Download failed.".to_string()))
         }
     }
 }
@@ -150,7 +150,7 @@ pub fn download_file(
         Ok(task_id) => DownloadTask {
             task_id: task_id.to_string(),
         },
-        Err(CreateTaskError::DownloadPath(err)) => {
+        Err(RequestError::DownloadPath(err)) => {
             let message = match err {
                 DownloadPathError::EmptyPath => {
                     "Invalid file or file system error, File path is empty".to_string()
@@ -177,8 +177,8 @@ pub fn download_file(
             };
             return Err(BusinessError::new(13400001, message))
         }
-        Err(CreateTaskError::Code(code)) => {
-            return Err(BusinessError::new(code, "Download failed.".to_string()))
+        Err(e) => {
+            return Err(BusinessError::new(e.code(), "Download failed.".to_string()))
         }
     };
 
@@ -192,8 +192,8 @@ pub fn download_file(
         Err(e) => {
             error!("Api9 download start failed, error: {}", e);
             Err(BusinessError::new(
-                e,
-                format!("Download start failed with error code: {}", e),
+                e.code(),
+                format!("Download start failed with error code: {}", e.code()),
             ))
         }
     }
@@ -233,7 +233,7 @@ pub fn delete(this: DownloadTask) -> Result<bool, BusinessError> {
     let task_id = this.task_id.parse().unwrap();
     match RequestClient::get_instance().remove(task_id) {
         Err(e) if e == ExceptionErrorCode::E_PERMISSION as i32 => {
-            return Err(BusinessError::new(e, "Failed to delete download task".to_string()));
+            return Err(BusinessError::new(e.code(), "Failed to delete download task".to_string()));
         }
         _ => {}
     }
@@ -279,7 +279,7 @@ pub fn suspend(this: DownloadTask) -> Result<bool, BusinessError> {
                 Ok(true)
             } else {
                 Err(BusinessError::new(
-                    e,
+                    e.code(),
                     "Failed to delete download task".to_string(),
                 ))
             }
@@ -325,7 +325,7 @@ pub fn restore(this: DownloadTask) -> Result<bool, BusinessError> {
                 Ok(true)
             } else {
                 Err(BusinessError::new(
-                    e,
+                    e.code(),
                     "Failed to delete download task".to_string(),
                 ))
             }
@@ -367,7 +367,7 @@ pub fn get_task_info(this: DownloadTask) -> Result<DownloadInfo, BusinessError>
     RequestClient::get_instance()
         .show_task(this.task_id.parse().unwrap())
         .map(|info| DownloadInfo::from(info))
-        .map_err(|e| BusinessError::new(e, "Failed to get download task info".to_string()))
+        .map_err(|e| BusinessError::new(e.code(), "Failed to get download task info".to_string()))
 }
 
 /// Gets the MIME type of a download task.
@@ -409,7 +409,7 @@ pub fn get_task_mime_type(this: DownloadTask) -> Result<String, BusinessError> {
                 Ok("".to_string())
             } else {
                 Err(BusinessError::new(
-                    e,
+                    e.code(),
                     "Failed to get task mime type".to_string(),
                 ))
             }
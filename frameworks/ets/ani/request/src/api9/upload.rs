@@ -24,7 +24,7 @@ use ani_rs::business_error::BusinessError;
 use ani_rs::objects::{AniObject, AniRef};
 use ani_rs::AniEnv;
 use request_client::check::file::DownloadPathError;
-use request_client::client::error::CreateTaskError;
+use request_client::client::error::RequestError;
 use request_client::RequestClient;
 use request_core::config::{TaskConfig, Version};
 use request_core::info::TaskInfo;
@@ -60,7 +60,7 @@ pub fn check_config(
 
     match RequestClient::get_instance().check_config(context, seq, config) {
         Ok(()) => Ok(seq as i64),
-        Err(CreateTaskError::DownloadPath(err)) => {
+        Err(RequestError::DownloadPath(err)) => {
             let message = match err {
                 DownloadPathError::EmptyPath => {
                     "Invalid file or file system error, File path is empty".to_string()
@@ -87,8 +87,8 @@ pub fn check_config(
             };
             return Err(BusinessError::new(13400001, message))
         }
-        Err(CreateTaskError::Code(code)) => {
-            return Err(BusinessError::new(code, "Upload failed.".to_string()))
+        Err(e) => {
+            return Err(BusinessError::new(e.code(), "Upload failed.".to_string()))
         }
     }
 }
@@ -137,7 +137,7 @@ pub fn upload_file(env: &AniEnv, context: AniRef, seq: i64) -> Result<UploadTask
         Ok(task_id) => UploadTask {
             task_id: task_id.to_string(),
         },
-        Err(CreateTaskError::DownloadPath(err)) => {
+        Err(RequestError::DownloadPath(err)) => {
             let message = match err {
                 DownloadPathError::EmptyPath => {
                     "Invalid file or file system error, File path is empty".to_string()
@@ -164,8 +164,8 @@ pub fn upload_file(env: &AniEnv, context: AniRef, seq: i64) -> Result<UploadTask
             };
             return Err(BusinessError::new(13400001, message))
         }
-        Err(CreateTaskError::Code(code)) => {
-            return Err(BusinessError::new(code, "Upload failed.".to_string()))
+        Err(e) => {
+            return Err(BusinessError::new(e.code(), "Upload failed.".to_string()))
         }
     };
 
@@ -178,8 +178,8 @@ pub fn upload_file(env: &AniEnv, context: AniRef, seq: i64) -> Result<UploadTask
         Err(e) => {
             error!("Api9 upload start failed, error: {}", e);
             Err(BusinessError::new(
-                e,
-                format!("Upload start failed with error code: {}", e),
+                e.code(),
+                format!("Upload start failed with error code: {}", e.code()),
             ))
         }
     }
@@ -216,7 +216,7 @@ pub fn delete(this: UploadTask) -> Result<bool, BusinessError> {
     let task_id = this.task_id.parse().unwrap();
     match RequestClient::get_instance().remove(task_id) {
         Err(e) if e == ExceptionErrorCode::E_PERMISSION as i32 => {
-            return Err(BusinessError::new(e, "Failed to delete upload task".to_string()));
+            return Err(BusinessError::new(e.code(), "Failed to delete upload task".to_string()));
         }
         _ => {}
     }
@@ -114,6 +114,7 @@ fn ut_error_code_task_errors() {
     assert_eq!(ExceptionErrorCode::E_TASK_NOT_FOUND as i32, 21900006);
     assert_eq!(ExceptionErrorCode::E_TASK_STATE as i32, 21900007);
     assert_eq!(ExceptionErrorCode::E_GROUP_NOT_FOUND as i32, 21900008);
+    assert_eq!(ExceptionErrorCode::E_CLIENT_CERT_INVALID as i32, 21900009);
 }
 
 // @tc.name: ut_error_code_ipc_errors
@@ -174,6 +175,7 @@ fn ut_error_code_all_variants() {
         ExceptionErrorCode::E_TASK_NOT_FOUND as i32,
         ExceptionErrorCode::E_TASK_STATE as i32,
         ExceptionErrorCode::E_GROUP_NOT_FOUND as i32,
+        ExceptionErrorCode::E_CLIENT_CERT_INVALID as i32,
     ];
 
     let unique_codes: HashSet<_> = codes.iter().cloned().collect();
@@ -68,7 +68,7 @@ private:
     static bool ParseTitle(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
     static void ParseNetwork(napi_env env, napi_value jsConfig, Network &network);
     static void ParseCertificatePins(napi_env env, std::string &url, std::string &certificatePins);
-    static void ParseMethod(napi_env env, napi_value jsConfig, Config &config);
+    static bool ParseMethod(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
     static void ParseRedirect(napi_env env, napi_value jsConfig, bool &redirect);
     static void ParseRoaming(napi_env env, napi_value jsConfig, Config &config);
     static void ParseRetry(napi_env env, napi_value jsConfig, bool &retry);
@@ -76,6 +76,14 @@ private:
     static bool ParseSaveas(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
     static bool ParseToken(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
     static bool ParseTimeout(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
+    static bool ParseRetryPolicy(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
+    static bool ParsePrerequisites(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
+    static bool ParseSchedule(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
+    static bool ParseStorage(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
+    static bool ParseAuth(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
+    static bool ParseClientCert(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo);
+    static bool ParseRetryCodes(napi_env env, napi_value retryPolicy, const std::string &propertyName,
+        std::vector<uint32_t> &codes, std::string &errInfo);
     static bool ParseDescription(napi_env env, napi_value jsConfig, std::string &description, std::string &errInfo);
     static int64_t ParseEnds(napi_env env, napi_value jsConfig);
     static int64_t ParseBegins(napi_env env, napi_value jsConfig);
@@ -38,6 +38,7 @@ public:
     void OnFaultsReceive(const std::shared_ptr<int32_t> &tid, const std::shared_ptr<SubscribeType> &type,
         const std::shared_ptr<Reason> &reason) override;
     void OnWaitReceive(std::int32_t taskId, WaitingReason reason) override;
+    void OnTokenRefresh(std::int32_t taskId) override;
 
 private:
     bool IsHeaderReceive(const std::shared_ptr<NotifyData> &notifyData);
@@ -47,6 +47,7 @@ public:
     static napi_value Start(napi_env env, napi_callback_info info);
     static napi_value Stop(napi_env env, napi_callback_info info);
     static napi_value SetMaxSpeed(napi_env env, napi_callback_info info);
+    static napi_value SetAuthToken(napi_env env, napi_callback_info info);
     static std::map<Reason, DownloadErrorCode> failMap_;
 
 private:
@@ -64,6 +65,7 @@ private:
         std::string strRes;
         DownloadInfo infoRes;
         int64_t maxSpeed;
+        std::string token;
     };
 
     using Event = std::function<int32_t(const std::shared_ptr<ExecContext> &)>;
@@ -82,6 +84,7 @@ private:
     static int32_t RemoveExec(const std::shared_ptr<ExecContext> &context);
     static int32_t ResumeExec(const std::shared_ptr<ExecContext> &context);
     static int32_t SetMaxSpeedExec(const std::shared_ptr<ExecContext> &context);
+    static int32_t SetAuthTokenExec(const std::shared_ptr<ExecContext> &context);
 
     static napi_status ParseInputParameters(
         napi_env env, size_t argc, napi_value self, const std::shared_ptr<ExecContext> &context);
@@ -89,6 +92,8 @@ private:
         napi_env env, napi_callback_info info, bool IsRequiredParam, JsParam &jsParam);
     static ExceptionError ParseSetMaxSpeedParameters(
         napi_env env, napi_value self, napi_callback_info info, int64_t minSpeed, int64_t &maxSpeed);
+    static ExceptionError ParseSetAuthTokenParameters(
+        napi_env env, napi_value self, napi_callback_info info, std::string &token);
     static napi_status GetResult(
         napi_env env, const std::shared_ptr<ExecContext> &context, const std::string &execType, napi_value &result);
     static void GetDownloadInfo(const TaskInfo &infoRes, DownloadInfo &info);
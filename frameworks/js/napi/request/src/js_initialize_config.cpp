@@ -16,9 +16,12 @@
 #include "js_initialize.h"
 
 #include <algorithm>
+#include <cctype>
 #include <cstdio>
 #include <cstring>
+#include <iterator>
 #include <regex>
+#include <set>
 #include <string>
 
 #include "log.h"
@@ -42,6 +45,9 @@ static constexpr uint32_t NOTIFICATION_TEXT_MAXIMUM = 3072;
 static constexpr uint32_t PROXY_MAXIMUM = 512;
 static constexpr uint32_t MIN_TIMEOUT = 1;
 static constexpr uint32_t MAX_TIMEOUT = 604800;
+static constexpr uint32_t MAX_RETRY_JITTER = 100;
+static constexpr uint64_t MIN_RECURRENCE_INTERVAL = 900;
+static constexpr uint32_t MINUTES_IN_ONE_DAY = 1440;
 
 namespace OHOS::Request {
 
@@ -74,7 +80,6 @@ std::string GetHostnameFromURL(const std::string &url)
 void JsInitialize::ParseConfigInner(napi_env env, napi_value jsConfig, Config &config)
 {
     ParseCertificatePins(env, config.url, config.certificatePins);
-    ParseMethod(env, jsConfig, config);
     ParseRoaming(env, jsConfig, config);
     ParseRedirect(env, jsConfig, config.redirect);
     ParseNetwork(env, jsConfig, config.network);
@@ -148,6 +153,27 @@ bool JsInitialize::ParseConfig(napi_env env, napi_value jsConfig, Config &config
     if (!ParseTimeout(env, jsConfig, config, errInfo)) {
         return false;
     }
+    if (!ParseRetryPolicy(env, jsConfig, config, errInfo)) {
+        return false;
+    }
+    if (!ParsePrerequisites(env, jsConfig, config, errInfo)) {
+        return false;
+    }
+    if (!ParseSchedule(env, jsConfig, config, errInfo)) {
+        return false;
+    }
+    if (!ParseStorage(env, jsConfig, config, errInfo)) {
+        return false;
+    }
+    if (!ParseAuth(env, jsConfig, config, errInfo)) {
+        return false;
+    }
+    if (!ParseClientCert(env, jsConfig, config, errInfo)) {
+        return false;
+    }
+    if (!ParseMethod(env, jsConfig, config, errInfo)) {
+        return false;
+    }
     ParseConfigInner(env, jsConfig, config);
     return true;
 }
@@ -273,6 +299,213 @@ bool JsInitialize::ParseTimeout(napi_env env, napi_value jsConfig, Config &confi
     return true;
 }
 
+bool JsInitialize::ParseRetryPolicy(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo)
+{
+    napi_value retryPolicy = NapiUtils::GetNamedProperty(env, jsConfig, "retryPolicy");
+    if (NapiUtils::GetValueType(env, retryPolicy) == napi_undefined) {
+        return true;
+    }
+    if (NapiUtils::GetValueType(env, retryPolicy) != napi_object) {
+        errInfo = "Incorrect parameter type, retryPolicy type is not of napi_object type";
+        return false;
+    }
+    config.retryPolicy.maxAttempts = NapiUtils::Convert2Uint32(env, retryPolicy, "maxAttempts");
+    int64_t baseDelay = NapiUtils::Convert2Int64(env, retryPolicy, "baseDelay");
+    if (baseDelay < 0) {
+        errInfo = "Parameter verification failed, retryPolicy.baseDelay must be greater than or equal to 0";
+        return false;
+    }
+    config.retryPolicy.baseDelay = static_cast<uint64_t>(baseDelay);
+    config.retryPolicy.backoffFactor = NapiUtils::Convert2Uint32(env, retryPolicy, "backoffFactor");
+    config.retryPolicy.jitter = NapiUtils::Convert2Uint32(env, retryPolicy, "jitter");
+    if (config.retryPolicy.jitter > MAX_RETRY_JITTER) {
+        errInfo = "Parameter verification failed, retryPolicy.jitter must be less than or equal to 100";
+        return false;
+    }
+    if (!ParseRetryCodes(env, retryPolicy, "reasons", config.retryPolicy.reasons, errInfo)) {
+        return false;
+    }
+    return ParseRetryCodes(env, retryPolicy, "statuses", config.retryPolicy.statuses, errInfo);
+}
+
+bool JsInitialize::ParseRetryCodes(napi_env env, napi_value retryPolicy, const std::string &propertyName,
+    std::vector<uint32_t> &codes, std::string &errInfo)
+{
+    napi_value jsCodes = NapiUtils::GetNamedProperty(env, retryPolicy, propertyName);
+    if (NapiUtils::GetValueType(env, jsCodes) == napi_undefined) {
+        return true;
+    }
+    bool isArray = false;
+    napi_is_array(env, jsCodes, &isArray);
+    if (!isArray) {
+        errInfo = "Incorrect parameter type, retryPolicy." + propertyName + " type is not of array type";
+        return false;
+    }
+    uint32_t length = 0;
+    napi_get_array_length(env, jsCodes, &length);
+    for (uint32_t i = 0; i < length; ++i) {
+        napi_value jsCode = nullptr;
+        napi_get_element(env, jsCodes, i, &jsCode);
+        if (NapiUtils::GetValueType(env, jsCode) != napi_number) {
+            errInfo = "Incorrect parameter type, retryPolicy." + propertyName + " element is not of napi_number type";
+            return false;
+        }
+        codes.push_back(NapiUtils::Convert2Uint32(env, jsCode));
+    }
+    return true;
+}
+
+bool JsInitialize::ParsePrerequisites(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo)
+{
+    napi_value jsTaskIds = NapiUtils::GetNamedProperty(env, jsConfig, "prerequisites");
+    if (NapiUtils::GetValueType(env, jsTaskIds) == napi_undefined) {
+        return true;
+    }
+    bool isArray = false;
+    napi_is_array(env, jsTaskIds, &isArray);
+    if (!isArray) {
+        errInfo = "Incorrect parameter type, prerequisites type is not of array type";
+        return false;
+    }
+    uint32_t length = 0;
+    napi_get_array_length(env, jsTaskIds, &length);
+    for (uint32_t i = 0; i < length; ++i) {
+        napi_value jsTaskId = nullptr;
+        napi_get_element(env, jsTaskIds, i, &jsTaskId);
+        if (NapiUtils::GetValueType(env, jsTaskId) != napi_string) {
+            errInfo = "Incorrect parameter type, prerequisites element is not of napi_string type";
+            return false;
+        }
+        std::string taskId = NapiUtils::Convert2String(env, jsTaskId);
+        if (taskId.empty() || !std::all_of(taskId.begin(), taskId.end(), ::isdigit)) {
+            errInfo = "Parameter verification failed, prerequisites element is not a valid task id";
+            return false;
+        }
+        config.prerequisites.push_back(taskId);
+    }
+    return true;
+}
+
+bool JsInitialize::ParseSchedule(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo)
+{
+    if (NapiUtils::GetValueType(env, NapiUtils::GetNamedProperty(env, jsConfig, "notBefore")) != napi_undefined) {
+        int64_t notBefore = NapiUtils::Convert2Int64(env, jsConfig, "notBefore");
+        if (notBefore < 0) {
+            errInfo = "Parameter verification failed, notBefore must be greater than or equal to 0";
+            return false;
+        }
+        config.notBefore = static_cast<uint64_t>(notBefore);
+    }
+    napi_value recurrence = NapiUtils::GetNamedProperty(env, jsConfig, "recurrence");
+    if (NapiUtils::GetValueType(env, recurrence) == napi_undefined) {
+        return true;
+    }
+    if (NapiUtils::GetValueType(env, recurrence) != napi_object) {
+        errInfo = "Incorrect parameter type, recurrence type is not of napi_object type";
+        return false;
+    }
+    if (NapiUtils::GetValueType(env, NapiUtils::GetNamedProperty(env, recurrence, "interval")) != napi_undefined) {
+        int64_t interval = NapiUtils::Convert2Int64(env, recurrence, "interval");
+        if (interval < static_cast<int64_t>(MIN_RECURRENCE_INTERVAL)) {
+            errInfo = "Parameter verification failed, recurrence.interval must be greater than or equal to 900";
+            return false;
+        }
+        config.recurrence.kind = RecurrenceKind::INTERVAL;
+        config.recurrence.interval = static_cast<uint64_t>(interval);
+        return true;
+    }
+    napi_value window = NapiUtils::GetNamedProperty(env, recurrence, "dailyWindow");
+    if (NapiUtils::GetValueType(env, window) != napi_object) {
+        errInfo = "Incorrect parameter type, recurrence needs an interval or a dailyWindow";
+        return false;
+    }
+    uint32_t start = NapiUtils::Convert2Uint32(env, window, "start");
+    uint32_t end = NapiUtils::Convert2Uint32(env, window, "end");
+    if (start >= MINUTES_IN_ONE_DAY || end >= MINUTES_IN_ONE_DAY || start == end) {
+        errInfo = "Parameter verification failed, recurrence.dailyWindow is not a valid window";
+        return false;
+    }
+    config.recurrence.kind = RecurrenceKind::DAILY;
+    config.recurrence.windowStart = start;
+    config.recurrence.windowEnd = end;
+    return true;
+}
+
+bool JsInitialize::ParseStorage(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo)
+{
+    if (NapiUtils::GetValueType(env, NapiUtils::GetNamedProperty(env, jsConfig, "expectedSize")) != napi_undefined) {
+        int64_t expectedSize = NapiUtils::Convert2Int64(env, jsConfig, "expectedSize");
+        if (expectedSize < 0) {
+            errInfo = "Parameter verification failed, expectedSize must be greater than or equal to 0";
+            return false;
+        }
+        config.expectedSize = static_cast<uint64_t>(expectedSize);
+    }
+    if (NapiUtils::GetValueType(env, NapiUtils::GetNamedProperty(env, jsConfig, "preallocate")) != napi_undefined) {
+        config.preallocate = NapiUtils::Convert2Boolean(env, jsConfig, "preallocate");
+    }
+    return true;
+}
+
+bool JsInitialize::ParseAuth(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo)
+{
+    napi_value auth = NapiUtils::GetNamedProperty(env, jsConfig, "auth");
+    if (NapiUtils::GetValueType(env, auth) == napi_undefined) {
+        return true;
+    }
+    if (NapiUtils::GetValueType(env, auth) != napi_object) {
+        errInfo = "Incorrect parameter type, auth type is not of napi_object type";
+        return false;
+    }
+    std::string scheme = NapiUtils::Convert2String(env, auth, "scheme");
+    if (scheme == "bearer") {
+        config.auth.scheme = AuthScheme::BEARER;
+        config.auth.secret = NapiUtils::Convert2String(env, auth, "token");
+        if (config.auth.secret.empty()) {
+            errInfo = "Parameter verification failed, auth.token is empty";
+            return false;
+        }
+        if (NapiUtils::GetValueType(env, NapiUtils::GetNamedProperty(env, auth, "refresh")) != napi_undefined) {
+            config.auth.refresh = NapiUtils::Convert2Boolean(env, auth, "refresh");
+        }
+        return true;
+    }
+    if (scheme == "basic") {
+        config.auth.scheme = AuthScheme::BASIC;
+    } else if (scheme == "digest") {
+        config.auth.scheme = AuthScheme::DIGEST;
+    } else {
+        errInfo = "Parameter verification failed, auth.scheme must be basic, digest or bearer";
+        return false;
+    }
+    config.auth.username = NapiUtils::Convert2String(env, auth, "username");
+    config.auth.secret = NapiUtils::Convert2String(env, auth, "password");
+    if (config.auth.scheme == AuthScheme::BASIC && config.auth.username.find(':') != std::string::npos) {
+        errInfo = "Parameter verification failed, auth.username must not contain a colon";
+        return false;
+    }
+    return true;
+}
+
+bool JsInitialize::ParseClientCert(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo)
+{
+    napi_value clientCert = NapiUtils::GetNamedProperty(env, jsConfig, "clientCert");
+    if (NapiUtils::GetValueType(env, clientCert) == napi_undefined) {
+        return true;
+    }
+    if (NapiUtils::GetValueType(env, clientCert) != napi_object) {
+        errInfo = "Incorrect parameter type, clientCert type is not of napi_object type";
+        return false;
+    }
+    config.clientCertPath = NapiUtils::Convert2String(env, clientCert, "certPath");
+    config.clientKeyPath = NapiUtils::Convert2String(env, clientCert, "keyPath");
+    if (config.clientCertPath.empty() || config.clientKeyPath.empty()) {
+        errInfo = "Parameter verification failed, clientCert needs a certPath and a keyPath";
+        return false;
+    }
+    return true;
+}
+
 void JsInitialize::ParseNetwork(napi_env env, napi_value jsConfig, Network &network)
 {
     network = static_cast<Network>(NapiUtils::Convert2Uint32(env, jsConfig, "network"));
@@ -555,7 +788,7 @@ void JsInitialize::ParseCertificatePins(napi_env env, std::string &url, std::str
     }
 }
 
-void JsInitialize::ParseMethod(napi_env env, napi_value jsConfig, Config &config)
+bool JsInitialize::ParseMethod(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo)
 {
     if (config.version == Version::API10) {
         config.method = config.action == Action::UPLOAD ? "PUT" : "GET";
@@ -563,15 +796,20 @@ void JsInitialize::ParseMethod(napi_env env, napi_value jsConfig, Config &config
         config.method = "POST";
     }
     std::string method = NapiUtils::Convert2String(env, jsConfig, "method");
-    if (!method.empty()) {
-        transform(method.begin(), method.end(), method.begin(), ::toupper);
-        if (config.action == Action::UPLOAD && (method == "POST" || method == "PUT")) {
-            config.method = method;
-        }
-        if (config.action == Action::DOWNLOAD && (method == "POST" || method == "GET")) {
-            config.method = method;
-        }
+    if (method.empty()) {
+        return true;
+    }
+    transform(method.begin(), method.end(), method.begin(), ::toupper);
+    static const std::set<std::string> uploadMethods(std::begin(UPLOAD_METHODS), std::end(UPLOAD_METHODS));
+    static const std::set<std::string> downloadMethods(std::begin(DOWNLOAD_METHODS), std::end(DOWNLOAD_METHODS));
+    const std::set<std::string> &methods = config.action == Action::UPLOAD ? uploadMethods : downloadMethods;
+    if (methods.count(method) == 0) {
+        REQUEST_HILOGE("method %{public}s is not supported", method.c_str());
+        errInfo = "Parameter verification failed, config.method is not supported by the action";
+        return false;
     }
+    config.method = method;
+    return true;
 }
 
 bool JsInitialize::ParseData(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo)
@@ -782,7 +1020,9 @@ bool JsInitialize::ParseConfigV9(napi_env env, napi_value jsConfig, Config &conf
 bool JsInitialize::ParseUploadConfig(napi_env env, napi_value jsConfig, Config &config, std::string &errInfo)
 {
     REQUEST_HILOGD("ParseUploadConfig in");
-    ParseMethod(env, jsConfig, config);
+    if (!ParseMethod(env, jsConfig, config, errInfo)) {
+        return false;
+    }
     napi_value jsFiles = NapiUtils::GetNamedProperty(env, jsConfig, PARAM_KEY_FILES);
     if (jsFiles == nullptr) {
         errInfo = "Parse config files error";
@@ -840,6 +1080,8 @@ void JsInitialize::SetParseConfig(napi_env env, napi_value jsConfig, Config &con
     config.metered = NapiUtils::Convert2Boolean(env, jsConfig, "metered");
     config.gauge = NapiUtils::Convert2Boolean(env, jsConfig, "gauge");
     config.precise = NapiUtils::Convert2Boolean(env, jsConfig, "precise");
+    config.compression = NapiUtils::Convert2Boolean(env, jsConfig, "compression");
+    config.cookieJar = NapiUtils::Convert2Boolean(env, jsConfig, "cookieJar");
     config.priority = ParsePriority(env, jsConfig);
     config.begins = ParseBegins(env, jsConfig);
     config.ends = ParseEnds(env, jsConfig);
@@ -847,6 +1089,10 @@ void JsInitialize::SetParseConfig(napi_env env, napi_value jsConfig, Config &con
     config.headers = ParseMap(env, jsConfig, "headers");
     config.extras = ParseMap(env, jsConfig, "extras");
     config.multipart = NapiUtils::Convert2Boolean(env, jsConfig, "multipart");
+    config.segments = NapiUtils::Convert2Uint32(env, jsConfig, "segments");
+    config.digest = NapiUtils::Convert2String(env, jsConfig, "digest");
+    int64_t chunkSize = NapiUtils::Convert2Int64(env, jsConfig, "chunkSize");
+    config.chunkSize = chunkSize > 0 ? static_cast<uint64_t>(chunkSize) : 0;
     if (config.mode == Mode::BACKGROUND) {
         config.background = true;
     }
@@ -175,6 +175,8 @@ static std::string SubscribeTypeToString(SubscribeType type)
             return "faultOccur";
         case SubscribeType::WAIT:
             return "wait";
+        case SubscribeType::TOKEN_REFRESH:
+            return "tokenRefresh";
         case SubscribeType::BUTT:
             return "butt";
     }
@@ -316,4 +318,26 @@ void JSNotifyDataListener::OnWaitReceive(std::int32_t taskId, WaitingReason reas
     }
 }
 
+void JSNotifyDataListener::OnTokenRefresh(std::int32_t taskId)
+{
+    REQUEST_HILOGI("Notify token refresh, tid %{public}d", taskId);
+    int32_t ret = napi_send_event(
+        this->env_,
+        [me = shared_from_this()]() {
+            napi_handle_scope scope = nullptr;
+            napi_status status = napi_open_handle_scope(me->env_, &scope);
+            if (status != napi_ok || scope == nullptr) {
+                REQUEST_HILOGE("OnTokenRefresh napi_scope failed");
+                return;
+            }
+            me->OnMessageReceive(nullptr, NapiUtils::NO_ARG);
+            napi_close_handle_scope(me->env_, scope);
+        },
+        napi_eprio_high,
+        "request:task.on");
+    if (ret != napi_ok) {
+        REQUEST_HILOGE("napi_send_event failed: %{public}d", ret);
+    }
+}
+
 } // namespace OHOS::Request
\ No newline at end of file
@@ -36,6 +36,7 @@ static constexpr const char *EVENT_COMPLETE = "complete";
 static constexpr const char *EVENT_RESPONSE = "response";
 static constexpr const char *EVENT_FAULT_OCCUR = "faultOccur";
 static constexpr const char *EVENT_WAIT = "wait";
+static constexpr const char *EVENT_TOKEN_REFRESH = "tokenRefresh";
 
 std::map<std::string, SubscribeType> RequestEvent::supportEventsV9_ = {
     { EVENT_COMPLETE, SubscribeType::COMPLETED },
@@ -56,6 +57,7 @@ std::map<std::string, SubscribeType> RequestEvent::supportEventsV10_ = {
     { EVENT_RESPONSE, SubscribeType::RESPONSE },
     { EVENT_FAULT_OCCUR, SubscribeType::FAULT_OCCUR },
     { EVENT_WAIT, SubscribeType::WAIT },
+    { EVENT_TOKEN_REFRESH, SubscribeType::TOKEN_REFRESH },
 };
 
 std::map<std::string, RequestEvent::Event> RequestEvent::requestEvent_ = {
@@ -67,6 +69,7 @@ std::map<std::string, RequestEvent::Event> RequestEvent::requestEvent_ = {
     { FUNCTION_START, RequestEvent::StartExec },
     { FUNCTION_STOP, RequestEvent::StopExec },
     { FUNCTION_SET_MAX_SPEED, RequestEvent::SetMaxSpeedExec },
+    { FUNCTION_SET_AUTH_TOKEN, RequestEvent::SetAuthTokenExec },
 };
 
 std::map<std::string, uint32_t> RequestEvent::resMap_ = {
@@ -78,6 +81,7 @@ std::map<std::string, uint32_t> RequestEvent::resMap_ = {
     { FUNCTION_START, BOOL_RES },
     { FUNCTION_STOP, BOOL_RES },
     { FUNCTION_SET_MAX_SPEED, BOOL_RES },
+    { FUNCTION_SET_AUTH_TOKEN, BOOL_RES },
 };
 
 std::map<State, DownloadStatus> RequestEvent::stateMap_ = {
@@ -187,6 +191,54 @@ napi_value RequestEvent::SetMaxSpeed(napi_env env, napi_callback_info info)
     return asyncCall.Call(context, execType);
 }
 
+napi_value RequestEvent::SetAuthToken(napi_env env, napi_callback_info info)
+{
+    int32_t seq = RequestManager::GetInstance()->GetNextSeq();
+    REQUEST_HILOGD("Begin task set auth token, seq: %{public}d", seq);
+    std::string execType = FUNCTION_SET_AUTH_TOKEN;
+    auto context = std::make_shared<ExecContext>();
+    auto input = [context, seq, info](size_t argc, napi_value *argv, napi_value self) -> napi_status {
+        napi_status ret = ParseInputParameters(context->env_, argc, self, context);
+        if (ret != napi_ok) {
+            REQUEST_HILOGE("End task set auth token, seq: %{public}d, failed: %{public}d", seq, ret);
+            return ret;
+        }
+        ExceptionError err = ParseSetAuthTokenParameters(context->env_, self, info, context->token);
+        if (err.code != E_OK) {
+            REQUEST_HILOGE("End task set auth token, seq: %{public}d, failed: %{public}d", seq, err.code);
+            NapiUtils::ThrowError(context->env_, err.code, err.errInfo, true);
+            return napi_invalid_arg;
+        }
+        return napi_ok;
+    };
+    auto output = [context, execType, seq](napi_value *result) -> napi_status {
+        if (context->innerCode_ != E_OK) {
+            REQUEST_HILOGE("End task %{public}s in AsyncCall output, seq: %{public}d, failed: %{public}d",
+                execType.c_str(), seq, context->innerCode_);
+            return napi_generic_failure;
+        }
+
+        napi_status status = GetResult(context->env_, context, execType, *result);
+        if (status != napi_ok) {
+            REQUEST_HILOGE("End task %{public}s in AsyncCall output, seq: %{public}d, failed: %{public}d",
+                execType.c_str(), seq, status);
+        } else {
+            REQUEST_HILOGI("%{public}s ok seq %{public}d", execType.c_str(), seq);
+        }
+        return status;
+    };
+    auto exec = [context, execType]() {
+        auto handle = requestEvent_.find(execType);
+        if (handle != requestEvent_.end()) {
+            context->innerCode_ = handle->second(context);
+        }
+    };
+
+    context->SetInput(input).SetOutput(output).SetExec(exec);
+    AsyncCall asyncCall(env, info, context);
+    return asyncCall.Call(context, execType);
+}
+
 napi_value RequestEvent::On(napi_env env, napi_callback_info info)
 {
     int32_t seq = RequestManager::GetInstance()->GetNextSeq();
@@ -346,6 +398,34 @@ ExceptionError RequestEvent::ParseSetMaxSpeedParameters(
     return err;
 }
 
+ExceptionError RequestEvent::ParseSetAuthTokenParameters(
+    napi_env env, napi_value self, napi_callback_info info, std::string &token)
+{
+    ExceptionError err = { .code = E_OK };
+    size_t argc = NapiUtils::MAX_ARGC;
+    napi_value argv[NapiUtils::MAX_ARGC] = { nullptr };
+    napi_status status = napi_get_cb_info(env, info, &argc, argv, &self, nullptr);
+    if (status != napi_ok) {
+        err.code = E_PARAMETER_CHECK;
+        err.errInfo = "Parameter verification failed, Failed to obtain parameters";
+        return err;
+    }
+    if (argc < NapiUtils::ONE_ARG) {
+        err.code = E_PARAMETER_CHECK;
+        err.errInfo = "Missing mandatory parameters, Wrong number of arguments";
+        return err;
+    }
+
+    // An empty token declines the refresh.
+    if (NapiUtils::GetValueType(env, argv[NapiUtils::FIRST_ARGV]) != napi_string) {
+        err.code = E_PARAMETER_CHECK;
+        err.errInfo = "Incorrect parameter type, token is not of string type";
+        return err;
+    }
+    token = NapiUtils::Convert2String(env, argv[NapiUtils::FIRST_ARGV]);
+    return err;
+}
+
 ExceptionError RequestEvent::ParseOnOffParameters(
     napi_env env, napi_callback_info info, bool IsRequiredParam, JsParam &jsParam)
 {
@@ -519,6 +599,15 @@ int32_t RequestEvent::SetMaxSpeedExec(const std::shared_ptr<ExecContext> &contex
     return ret;
 }
 
+int32_t RequestEvent::SetAuthTokenExec(const std::shared_ptr<ExecContext> &context)
+{
+    int32_t ret = RequestManager::GetInstance()->SetAuthToken(context->task->GetTid(), context->token);
+    if (ret == E_OK) {
+        context->boolRes = true;
+    }
+    return ret;
+}
+
 int32_t RequestEvent::PauseExec(const std::shared_ptr<ExecContext> &context)
 {
     int32_t ret = RequestManager::GetInstance()->Pause(context->task->GetTid(), context->version_);
@@ -90,6 +90,14 @@ static void NapiCreateWaitingReason(napi_env env, napi_value &waitingReason)
         env, waitingReason, "APP_BACKGROUND", static_cast<uint32_t>(WaitingReason::AppBackground));
     NapiUtils::SetUint32Property(
         env, waitingReason, "USER_INACTIVATED", static_cast<uint32_t>(WaitingReason::UserInactivated));
+    NapiUtils::SetUint32Property(
+        env, waitingReason, "INSUFFICIENT_SPACE", static_cast<uint32_t>(WaitingReason::InsufficientSpace));
+    NapiUtils::SetUint32Property(
+        env, waitingReason, "PREREQUISITE_WAITING", static_cast<uint32_t>(WaitingReason::PrerequisiteWaiting));
+    NapiUtils::SetUint32Property(
+        env, waitingReason, "START_TIME_WAITING", static_cast<uint32_t>(WaitingReason::StartTimeWaiting));
+    NapiUtils::SetUint32Property(
+        env, waitingReason, "RETRY_WAITING", static_cast<uint32_t>(WaitingReason::RetryWaiting));
 }
 
 static void NapiCreateBroadcastEvent(napi_env env, napi_value &broadcastEvent)
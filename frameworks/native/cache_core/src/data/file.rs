@@ -25,18 +25,21 @@
 //! The implementation ensures thread-safe access to cache resources and
 //! provides mechanisms for persisting data across application restarts.
 
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, DirEntry, File, OpenOptions};
 use std::io::{self, Seek, Write};
 use std::os::unix::fs::MetadataExt;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, Mutex, Once};
-use std::time::SystemTime;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use request_utils::task_id::TaskId;
 
 use super::ram::RamCache;
+use crate::entry::EntryMeta;
 use crate::manage::CacheManager;
+use crate::policy::{self, CachePolicy};
 
 /// Suffix appended to files that are fully written and finalized.
 ///
@@ -45,6 +48,32 @@ use crate::manage::CacheManager;
 /// and are considered invalid.
 const FINISH_SUFFIX: &str = "_F";
 
+/// Suffix of the files persisting the cache policy of a cache file.
+///
+/// The policy file of a task is written after its cache file and removed with
+/// it, and is not itself a cache file.
+const POLICY_SUFFIX: &str = "_P";
+
+/// Suffix of the files persisting the response metadata of a cache file.
+///
+/// Like the policy file, the metadata file of a task is written after its
+/// cache file and removed with it.
+const META_SUFFIX: &str = "_M";
+
+/// Name of the file persisting the IDs of the pinned tasks, one per line.
+const PINNED_INDEX: &str = "pinned_index";
+
+/// Name of the file persisting the last access time and expiry of the cache
+/// files, so that their LRU order survives restarts.
+///
+/// Each line holds a task ID, its last access time and its expiry time (or
+/// `-` if it does not expire), in milliseconds since the epoch.
+const ACCESS_INDEX: &str = "access_index";
+
+/// Name of the file the access index is written to before it replaces the
+/// previous one.
+const ACCESS_INDEX_TEMP: &str = "access_index.tmp";
+
 /// Global file store directory manager.
 ///
 /// This static variable manages the directories used for storing cache files.
@@ -113,6 +142,30 @@ pub fn init_curr_store_dir() {
     });
 }
 
+/// Lists the cache namespaces stored in the current cache directory.
+///
+/// # Returns
+/// Names of the namespace directories, empty if the directory is not
+/// initialized or cannot be read
+pub fn stored_namespaces() -> Vec<String> {
+    // SAFETY: This is a read-only operation on the current directory
+    let Some(dir) = (unsafe { FILE_STORE_DIR.dir(None) }) else {
+        return vec![];
+    };
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            error!("read cache namespaces error {}", e);
+            return vec![];
+        }
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect()
+}
+
 /// Gets the path to the current cache directory.
 ///
 /// Returns the path to the directory where cache files are stored. On
@@ -269,6 +322,31 @@ impl FileStoreDir {
             None
         }
     }
+
+    /// Gets the directory of a cache namespace.
+    ///
+    /// The files of a namespace are stored in a subdirectory named after it,
+    /// created if necessary; those of the default namespace in the current
+    /// directory.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace, `None` for the default one
+    ///
+    /// # Returns
+    /// Path to the namespace directory if it exists, None otherwise
+    pub(crate) fn dir(&self, namespace: Option<&str>) -> Option<PathBuf> {
+        let Some(namespace) = namespace else {
+            return self.as_path().map(Path::to_path_buf);
+        };
+        let dir = self.join(namespace.to_string())?;
+        if !dir.is_dir() {
+            if let Err(e) = fs::create_dir_all(dir.as_path()) {
+                error!("create cache namespace dir error {}", e);
+                return None;
+            }
+        }
+        Some(dir)
+    }
 }
 
 /// Manages directory observation for cache maintenance.
@@ -395,12 +473,29 @@ pub(crate) struct FileCache {
     size: u64,
     /// ID of the task associated with this cache
     task_id: TaskId,
+    /// Time of the last access to the cache, in milliseconds since the epoch.
+    last_access: u64,
+    /// Time after which the cache is removed, in milliseconds since the epoch.
+    expires_at: Option<u64>,
 }
 
 impl FileCache {
-    /// Creates a new `FileCache` for the given task and size.
+    /// Creates a new `FileCache` for the given task and size, accessed now
+    /// and never expiring.
     pub(crate) fn new(task_id: TaskId, size: u64) -> Self {
-        Self { size, task_id }
+        Self {
+            size,
+            task_id,
+            last_access: policy::now(),
+            expires_at: None,
+        }
+    }
+
+    /// Sets the last access time and the expiry time of the cache.
+    pub(crate) fn with_access(mut self, last_access: u64, expires_at: Option<u64>) -> Self {
+        self.last_access = last_access;
+        self.expires_at = expires_at;
+        self
     }
 
     /// Returns the cached file size in bytes.
@@ -413,14 +508,46 @@ impl FileCache {
         &self.task_id
     }
 
+    /// Returns the time of the last access to the cache.
+    pub(crate) fn last_access(&self) -> u64 {
+        self.last_access
+    }
+
+    /// Returns the time after which the cache is removed, if any.
+    pub(crate) fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+
+    /// Records an access to the cache at `now`.
+    pub(crate) fn touch(&mut self, now: u64) {
+        self.last_access = now;
+    }
+
+    /// Sets the time after which the cache is removed.
+    pub(crate) fn set_expires_at(&mut self, expires_at: Option<u64>) {
+        self.expires_at = expires_at;
+    }
+
+    /// Checks if the cache has expired at `now`.
+    pub(crate) fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
     /// Releases the file cache resource.
     ///
     /// Removes the cache file from disk and releases the associated memory.
     ///
-    /// # Returns
-    /// Amount of memory released (in bytes)
-    pub(crate) fn remove_file(task_id: &TaskId) {
-        if let Some(path) = Self::path(task_id) {
+    /// # Arguments
+    /// * `task_id` - ID of the task whose cache file should be removed.
+    /// * `namespace` - Namespace of the cache, `None` for the default one.
+    pub(crate) fn remove_file(task_id: &TaskId, namespace: Option<&str>) {
+        if let Some(path) = Self::policy_path(task_id, namespace) {
+            let _ = fs::remove_file(path);
+        }
+        if let Some(path) = Self::meta_path(task_id, namespace) {
+            let _ = fs::remove_file(path);
+        }
+        if let Some(path) = Self::path(task_id, namespace) {
             if let Err(e) = fs::remove_file(path) {
                 // Different logging levels based on error type
                 if let Some(2) = e.raw_os_error() {
@@ -436,7 +563,8 @@ impl FileCache {
     /// Creates a cache file and writes the contents of the RAM cache to it.
     ///
     /// Writes data to a temporary file and then renames it with the finish
-    /// suffix to indicate it's complete.
+    /// suffix to indicate it's complete. The file is stored in the namespace
+    /// of the cache manager of the RAM cache.
     ///
     /// # Arguments
     /// * `task_id` - ID of the task to create the file for
@@ -445,7 +573,8 @@ impl FileCache {
     /// # Returns
     /// `Ok(())` if successful, `Err(io::Error)` if any file operation fails
     pub(crate) fn create_file(task_id: &TaskId, cache: Arc<RamCache>) -> Result<(), io::Error> {
-        if let Some(path) = Self::path(task_id) {
+        let namespace = cache.handle().namespace();
+        if let Some(path) = Self::path(task_id, namespace) {
             // Create the file and write cache contents
             let mut file = OpenOptions::new()
                 .write(true)
@@ -455,6 +584,22 @@ impl FileCache {
             io::copy(&mut cache.cursor(), &mut file)?;
             file.flush()?;
             file.rewind()?;
+            match cache.handle().policy(task_id) {
+                Some(policy) => Self::write_policy(task_id, namespace, &policy)?,
+                None => {
+                    if let Some(path) = Self::policy_path(task_id, namespace) {
+                        let _ = fs::remove_file(path);
+                    }
+                }
+            }
+            match cache.handle().meta(task_id) {
+                Some(meta) => Self::write_meta(task_id, namespace, &meta)?,
+                None => {
+                    if let Some(path) = Self::meta_path(task_id, namespace) {
+                        let _ = fs::remove_file(path);
+                    }
+                }
+            }
             return Ok(());
         }
         Err(io::Error::new(
@@ -467,12 +612,13 @@ impl FileCache {
     ///
     /// # Arguments
     /// * `task_id` - ID of the task whose cache file should be opened.
+    /// * `namespace` - Namespace of the cache, `None` for the default one.
     ///
     /// # Errors
     /// Returns `io::Error` if the cache directory is not initialized or the
     /// file cannot be opened.
-    pub(crate) fn open(task_id: &TaskId) -> Result<File, io::Error> {
-        if let Some(path) = Self::path(task_id) {
+    pub(crate) fn open(task_id: &TaskId, namespace: Option<&str>) -> Result<File, io::Error> {
+        if let Some(path) = Self::path(task_id, namespace) {
             OpenOptions::new().read(true).open(path)
         } else {
             Err(io::Error::new(io::ErrorKind::NotFound, "file not found."))
@@ -489,7 +635,7 @@ impl FileCache {
     /// # Errors
     /// Returns `io::Error` if the file cannot be opened or read.
     pub(crate) fn read(task_id: &TaskId, handle: &'static CacheManager) -> io::Result<RamCache> {
-        let mut file = Self::open(task_id).map_err(|e| {
+        let mut file = Self::open(task_id, handle.namespace()).map_err(|e| {
             error!("{:?} open file failed {:?}", task_id.brief(), e);
             e
         })?;
@@ -510,7 +656,7 @@ impl FileCache {
         task_id: &TaskId,
         handle: &'static CacheManager,
     ) -> io::Result<RamCache> {
-        let mut file = Self::open(task_id).map_err(|e| {
+        let mut file = Self::open(task_id, handle.namespace()).map_err(|e| {
             error!("{:?} file open failed {:?}", task_id.brief(), e);
             e
         })?;
@@ -541,16 +687,146 @@ impl FileCache {
         Ok(cache)
     }
 
+    /// Persists the cache policy of a task next to its cache file.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the cache directory is not initialized or the
+    /// file cannot be written.
+    pub(crate) fn write_policy(
+        task_id: &TaskId,
+        namespace: Option<&str>,
+        policy: &CachePolicy,
+    ) -> io::Result<()> {
+        match Self::policy_path(task_id, namespace) {
+            Some(path) => fs::write(path, policy.serialize()),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "cache store dir not created.",
+            )),
+        }
+    }
+
+    /// Reads the persisted cache policy of a task, `None` if it has none.
+    pub(crate) fn read_policy(task_id: &TaskId, namespace: Option<&str>) -> Option<CachePolicy> {
+        let path = Self::policy_path(task_id, namespace)?;
+        fs::read_to_string(path)
+            .ok()
+            .map(|s| CachePolicy::deserialize(&s))
+    }
+
+    /// Gets the path to the policy file for the given task ID.
+    fn policy_path(task_id: &TaskId, namespace: Option<&str>) -> Option<PathBuf> {
+        // SAFETY: This is a read-only operation that joins a path
+        unsafe { FILE_STORE_DIR.dir(namespace) }
+            .map(|dir| dir.join(task_id.to_string() + POLICY_SUFFIX))
+    }
+
+    /// Persists the response metadata of a task next to its cache file.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the cache directory is not initialized or the
+    /// file cannot be written.
+    pub(crate) fn write_meta(
+        task_id: &TaskId,
+        namespace: Option<&str>,
+        meta: &EntryMeta,
+    ) -> io::Result<()> {
+        match Self::meta_path(task_id, namespace) {
+            Some(path) => fs::write(path, meta.serialize()),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "cache store dir not created.",
+            )),
+        }
+    }
+
+    /// Reads the persisted response metadata of a task, `None` if it has
+    /// none.
+    pub(crate) fn read_meta(task_id: &TaskId, namespace: Option<&str>) -> Option<EntryMeta> {
+        let path = Self::meta_path(task_id, namespace)?;
+        fs::read_to_string(path)
+            .ok()
+            .map(|s| EntryMeta::deserialize(&s))
+    }
+
+    /// Gets the path to the metadata file for the given task ID.
+    fn meta_path(task_id: &TaskId, namespace: Option<&str>) -> Option<PathBuf> {
+        // SAFETY: This is a read-only operation that joins a path
+        unsafe { FILE_STORE_DIR.dir(namespace) }
+            .map(|dir| dir.join(task_id.to_string() + META_SUFFIX))
+    }
+
+    /// Persists the IDs of the pinned tasks.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the cache directory is not initialized or the
+    /// file cannot be written.
+    pub(crate) fn write_pinned_index<'a>(
+        namespace: Option<&str>,
+        pinned: impl Iterator<Item = &'a TaskId>,
+    ) -> io::Result<()> {
+        let s = pinned
+            .map(|task_id| task_id.to_string() + "\n")
+            .collect::<String>();
+        // SAFETY: This is a read-only operation that joins a path
+        match unsafe { FILE_STORE_DIR.dir(namespace) } {
+            Some(dir) => fs::write(dir.join(PINNED_INDEX), s),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "cache store dir not created.",
+            )),
+        }
+    }
+
+    /// Reads the persisted IDs of the pinned tasks, empty if there is none.
+    pub(crate) fn read_pinned_index(namespace: Option<&str>) -> HashSet<TaskId> {
+        // SAFETY: This is a read-only operation that joins a path
+        let Some(dir) = (unsafe { FILE_STORE_DIR.dir(namespace) }) else {
+            return HashSet::new();
+        };
+        fs::read_to_string(dir.join(PINNED_INDEX))
+            .map(|s| {
+                s.lines()
+                    .filter(|line| !line.is_empty())
+                    .map(|line| TaskId::new(line.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Persists a serialized access index of the file caches, replacing the
+    /// previous one atomically.
+    ///
+    /// # Errors
+    /// Returns `io::Error` if the cache directory is not initialized or the
+    /// file cannot be written.
+    pub(crate) fn write_access_index(namespace: Option<&str>, index: &str) -> io::Result<()> {
+        // SAFETY: This is a read-only operation that joins a path
+        match unsafe { FILE_STORE_DIR.dir(namespace) } {
+            Some(dir) => {
+                let temp = dir.join(ACCESS_INDEX_TEMP);
+                fs::write(&temp, index)?;
+                fs::rename(temp, dir.join(ACCESS_INDEX))
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "cache store dir not created.",
+            )),
+        }
+    }
+
     /// Gets the path to the cache file for the given task ID.
     ///
     /// # Arguments
     /// * `task_id` - ID of the task to get the path for
+    /// * `namespace` - Namespace of the cache, `None` for the default one
     ///
     /// # Returns
     /// Path to the cache file if the directory exists, None otherwise
-    pub(crate) fn path(task_id: &TaskId) -> Option<PathBuf> {
+    pub(crate) fn path(task_id: &TaskId, namespace: Option<&str>) -> Option<PathBuf> {
         // SAFETY: This is a read-only operation that joins a path
-        unsafe { FILE_STORE_DIR.join(task_id.to_string() + FINISH_SUFFIX) }
+        unsafe { FILE_STORE_DIR.dir(namespace) }
+            .map(|dir| dir.join(task_id.to_string() + FINISH_SUFFIX))
     }
 }
 
@@ -558,46 +834,115 @@ impl FileCache {
 pub(crate) struct FileCacheInfo {
     /// Task ID associated with the cache file.
     task_id: TaskId,
-    /// Modification time of the cache file.
-    time: SystemTime,
     /// Size of the cache file in bytes.
     size: u64,
+    /// Last access time of the cache file, in milliseconds since the epoch.
+    last_access: u64,
+    /// Expiry time of the cache file, in milliseconds since the epoch.
+    expires_at: Option<u64>,
+    /// Modification time of the cache file, in milliseconds since the epoch.
+    modified: u64,
 }
 
 impl FileCacheInfo {
     /// Creates a new `FileCacheInfo` from the given task ID, time, and size.
+    ///
+    /// The file is considered last accessed at its modification time and
+    /// never expiring, until set otherwise from the access index.
     pub(crate) fn new(task_id: TaskId, time: SystemTime, size: u64) -> Self {
+        let modified = time
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64);
         Self {
             task_id,
-            time,
             size,
+            last_access: modified,
+            expires_at: None,
+            modified,
         }
     }
     /// Returns the task ID associated with this cache file.
     pub(crate) fn task_id(&self) -> &TaskId {
         &self.task_id
     }
-    /// Returns the modification time of this cache file.
-    pub(crate) fn time(&self) -> SystemTime {
-        self.time
-    }
     /// Returns the size of this cache file in bytes.
     pub(crate) fn size(&self) -> u64 {
         self.size
     }
+    /// Returns the last access time of this cache file.
+    pub(crate) fn last_access(&self) -> u64 {
+        self.last_access
+    }
+    /// Returns the expiry time of this cache file, if any.
+    pub(crate) fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+    /// Returns the modification time of this cache file.
+    pub(crate) fn modified(&self) -> u64 {
+        self.modified
+    }
 }
 
-/// Returns an iterator over the metadata of all valid cache files in the
-/// current store directory.
+/// Serializes the access index of the given file caches, one line per cache.
+pub(crate) fn serialize_access_index<'a>(caches: impl Iterator<Item = &'a FileCache>) -> String {
+    let mut s = String::new();
+    for cache in caches {
+        let expires_at = cache
+            .expires_at()
+            .map_or(String::from("-"), |t| t.to_string());
+        s.push_str(&format!(
+            "{} {} {}\n",
+            cache.task_id(),
+            cache.last_access(),
+            expires_at
+        ));
+    }
+    s
+}
+
+/// Parses an access index into the last access and expiry times of the tasks.
+///
+/// Malformed lines are skipped.
+pub(crate) fn deserialize_access_index(s: &str) -> HashMap<TaskId, (u64, Option<u64>)> {
+    let mut index = HashMap::new();
+    for line in s.lines() {
+        let mut fields = line.split_whitespace();
+        let (Some(task_id), Some(last_access), Some(expires_at)) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        let Ok(last_access) = last_access.parse::<u64>() else {
+            continue;
+        };
+        let expires_at = match expires_at {
+            "-" => None,
+            t => match t.parse::<u64>() {
+                Ok(t) => Some(t),
+                Err(_) => continue,
+            },
+        };
+        index.insert(TaskId::new(task_id.to_string()), (last_access, expires_at));
+    }
+    index
+}
+
+/// Returns an iterator over the metadata of all valid cache files of a
+/// namespace in the current store directory.
 ///
 /// Returns `None` if the cache directory has not been initialized.
-pub(crate) fn get_cached_files_info() -> Option<impl Iterator<Item = FileCacheInfo>> {
+pub(crate) fn get_cached_files_info(
+    namespace: Option<&str>,
+) -> Option<impl Iterator<Item = FileCacheInfo>> {
     // SAFETY: This is a read-only operation to get the path
-    unsafe { FILE_STORE_DIR.as_path() }.map(get_info_from_path)
+    unsafe { FILE_STORE_DIR.dir(namespace) }.map(|dir| get_info_from_path(&dir))
 }
 
 /// Scans a directory and returns metadata for each valid cache file, sorted
-/// by modification time.
+/// by last access time.
+///
+/// Access times and expiry are read from the access index of the directory,
+/// falling back to the modification time for files missing from it.
 ///
 /// # Arguments
 /// * `path` - Directory to scan for cache files.
@@ -614,6 +959,19 @@ pub(crate) fn get_info_from_path(path: &Path) -> impl Iterator<Item = FileCacheI
     // Process and filter the directory entries
     let mut v = files
         .into_iter()
+        .filter(|entry| match entry {
+            // Namespace directories are restored by their own cache manager
+            Ok(entry) if entry.file_type().is_ok_and(|t| t.is_dir()) => false,
+            // Policy and metadata files are restored along with their cache file
+            Ok(entry) => !entry.file_name().to_str().is_some_and(|name| {
+                name.ends_with(POLICY_SUFFIX)
+                    || name.ends_with(META_SUFFIX)
+                    || name == ACCESS_INDEX
+                    || name == ACCESS_INDEX_TEMP
+                    || name == PINNED_INDEX
+            }),
+            Err(_) => true,
+        })
         .filter_map(|entry| match get_entry_file_info(entry) {
             Ok(info) => Some(info),
             Err(e) => {
@@ -623,7 +981,17 @@ pub(crate) fn get_info_from_path(path: &Path) -> impl Iterator<Item = FileCacheI
         })
         .collect::<Vec<_>>();
 
-    v.sort_by_key(|info| info.time());
+    let index = fs::read_to_string(path.join(ACCESS_INDEX))
+        .map(|s| deserialize_access_index(&s))
+        .unwrap_or_default();
+    for info in v.iter_mut() {
+        if let Some(&(last_access, expires_at)) = index.get(&info.task_id) {
+            info.last_access = last_access;
+            info.expires_at = expires_at;
+        }
+    }
+
+    v.sort_by_key(|info| info.last_access());
     v.into_iter()
 }
 
@@ -175,6 +175,11 @@ impl RamCache {
         &self.task_id
     }
 
+    /// Returns the cache manager controlling this cache.
+    pub(crate) fn handle(&self) -> &'static CacheManager {
+        self.handle
+    }
+
     /// Returns the current size of the cached data.
     ///
     /// # Returns
@@ -0,0 +1,181 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Metadata of cache entries.
+//!
+//! This module provides the `CacheEntry` struct describing a cache entry for
+//! inspection, and the response metadata of an entry, persisted next to its
+//! cache file so that it survives restarts.
+
+use std::collections::HashMap;
+
+use request_utils::task_id::TaskId;
+
+/// Key of the URL in a persisted metadata.
+const URL: &str = "url";
+/// Key of the creation time in a persisted metadata.
+const CREATED: &str = "created";
+/// Key of a response header in a persisted metadata.
+const HEADER: &str = "header";
+
+/// Response metadata of a cache entry.
+#[derive(Clone, Default)]
+pub(crate) struct EntryMeta {
+    /// URL of the cached content, if known
+    pub(crate) url: Option<String>,
+    /// Time the content was cached, in milliseconds since the epoch
+    pub(crate) created: u64,
+    /// Response headers of the content, with lowercase names
+    pub(crate) headers: HashMap<String, String>,
+}
+
+impl EntryMeta {
+    /// Creates the metadata of content cached at `created`.
+    pub(crate) fn new(url: Option<String>, created: u64, headers: HashMap<String, String>) -> Self {
+        Self {
+            url,
+            created,
+            headers,
+        }
+    }
+
+    /// Serializes the metadata to persist it, one field per line.
+    pub(crate) fn serialize(&self) -> String {
+        let mut s = String::new();
+        if let Some(url) = &self.url {
+            s.push_str(&format!("{}:{}\n", URL, url));
+        }
+        s.push_str(&format!("{}:{}\n", CREATED, self.created));
+        for (name, value) in self.headers.iter() {
+            s.push_str(&format!("{}:{}:{}\n", HEADER, name, value));
+        }
+        s
+    }
+
+    /// Deserializes a persisted metadata, ignoring unknown or invalid lines.
+    pub(crate) fn deserialize(s: &str) -> Self {
+        let mut meta = Self::default();
+        for (key, value) in s.lines().filter_map(|line| line.split_once(':')) {
+            match key {
+                URL => meta.url = Some(value.to_string()),
+                CREATED => meta.created = value.parse().unwrap_or_default(),
+                HEADER => {
+                    if let Some((name, value)) = value.split_once(':') {
+                        meta.headers.insert(name.to_string(), value.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+        meta
+    }
+}
+
+/// Description of a cache entry.
+///
+/// Times are in milliseconds since the epoch.
+pub struct CacheEntry {
+    /// Task ID of the entry
+    pub(crate) task_id: TaskId,
+    /// Namespace of the entry, `None` for the default one
+    pub(crate) namespace: Option<String>,
+    /// URL of the cached content, if known
+    pub(crate) url: Option<String>,
+    /// Size of the cached content in bytes
+    pub(crate) size: u64,
+    /// Time the content was cached
+    pub(crate) created: u64,
+    /// Time of the last access to the entry
+    pub(crate) last_access: u64,
+    /// Whether the content is cached in RAM
+    pub(crate) in_ram: bool,
+    /// Whether the content is cached in a file
+    pub(crate) in_file: bool,
+    /// Whether the entry is pinned against eviction
+    pub(crate) pinned: bool,
+    /// Response headers of the content, with lowercase names
+    pub(crate) headers: HashMap<String, String>,
+}
+
+impl CacheEntry {
+    /// Creates the description of an entry, cached nowhere yet.
+    pub(crate) fn new(task_id: TaskId, namespace: Option<&str>) -> Self {
+        Self {
+            task_id,
+            namespace: namespace.map(str::to_string),
+            url: None,
+            size: 0,
+            created: 0,
+            last_access: 0,
+            in_ram: false,
+            in_file: false,
+            pinned: false,
+            headers: HashMap::new(),
+        }
+    }
+
+    /// Returns the task ID of the entry.
+    pub fn task_id(&self) -> &TaskId {
+        &self.task_id
+    }
+
+    /// Returns the namespace of the entry, `None` for the default one.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Returns the URL of the cached content, `None` if it is unknown.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Returns the size of the cached content in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the time the content was cached, 0 if it is unknown.
+    pub fn created(&self) -> u64 {
+        self.created
+    }
+
+    /// Returns the time of the last access to the entry.
+    pub fn last_access(&self) -> u64 {
+        self.last_access
+    }
+
+    /// Checks if the content is cached in RAM.
+    pub fn in_ram(&self) -> bool {
+        self.in_ram
+    }
+
+    /// Checks if the content is cached in a file.
+    pub fn in_file(&self) -> bool {
+        self.in_file
+    }
+
+    /// Checks if the entry is pinned against eviction.
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// Returns the response headers of the content, with lowercase names.
+    pub fn headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+}
+
+#[cfg(test)]
+mod ut_entry {
+    include!("../tests/ut/ut_entry.rs");
+}
@@ -32,15 +32,24 @@
 extern crate request_utils;
 
 mod data;
+mod entry;
 mod manage;
+mod policy;
+mod read;
 mod update;
 
 pub mod observe;
 
 /// In-memory cache implementation for task data.
-pub use data::{init_curr_store_dir, RamCache};
+pub use data::{init_curr_store_dir, stored_namespaces, RamCache};
+/// Description of a cache entry.
+pub use entry::CacheEntry;
 /// Central manager for cache operations and resources.
 pub use manage::CacheManager;
+/// Freshness and validation policy of cache entries.
+pub use policy::CachePolicy;
+/// Streaming reader of cache entries.
+pub use read::CacheReader;
 /// Handles cache updates and synchronization operations.
 pub use update::Updater;
 
@@ -49,12 +58,18 @@ cfg_ohos! {
     mod wrapper;
     // Use ffrt_spawn for thread spawning on OHOS
     use ffrt_rs::ffrt_spawn as spawn;
+
+    /// Suspends the current spawned task, yielding its FFRT worker.
+    fn sleep(duration: std::time::Duration) {
+        ffrt_rs::ffrt_sleep(duration.as_millis() as u64);
+    }
 }
 
 // Conditional compilation for non-OHOS platforms
 cfg_not_ohos! {
     // Use spawn_blocking for thread spawning on other platforms
     use ylong_runtime::spawn_blocking as spawn;
+    use std::thread::sleep;
 }
 
 use hilog_rust::{HiLogLabel, LogType};
@@ -21,15 +21,21 @@
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Condvar, Mutex, OnceLock, Weak};
+use std::time::Duration;
 
 use request_utils::lru::LRUCache;
 use request_utils::task_id::TaskId;
 
 use super::data::{
-    get_cached_files_info, FileCache, FileCacheInfo, RamCache, SpaceManager, MAX_CACHE_SIZE,
+    get_cached_files_info, serialize_access_index, FileCache, FileCacheInfo, RamCache,
+    SpaceManager, MAX_CACHE_SIZE,
 };
-use crate::spawn;
+use crate::entry::{CacheEntry, EntryMeta};
+use crate::policy::{self, CachePolicy};
+use crate::read::{CacheReader, Receiving};
+use crate::{sleep, spawn};
 
 /// Default maximum size for RAM-based cache storage (20MB).
 const DEFAULT_RAM_CACHE_SIZE: u64 = 1024 * 1024 * 20;
@@ -37,6 +43,9 @@ const DEFAULT_RAM_CACHE_SIZE: u64 = 1024 * 1024 * 20;
 /// Default maximum size for file-based cache storage (100MB).
 const DEFAULT_FILE_CACHE_SIZE: u64 = 1024 * 1024 * 100;
 
+/// Interval between two sweeps of the expired file caches (10 minutes).
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 10);
+
 /// Container holding the file-backed caches, their space budget, and the
 /// per-task queues of pending serialized file operations.
 pub(crate) struct FileCaches {
@@ -48,6 +57,17 @@ pub(crate) struct FileCaches {
 
     /// Per-task queues of pending file operations used to serialize access.
     pub(crate) operations: HashMap<TaskId, Arc<Mutex<VecDeque<Arc<NotifyCondition>>>>>,
+
+    /// Per-task max ages in milliseconds, overriding the default max age.
+    /// `None` keeps the file cache until it is evicted for space.
+    pub(crate) max_ages: HashMap<TaskId, Option<u64>>,
+
+    /// Max age in milliseconds of the file caches without their own.
+    pub(crate) default_max_age: Option<u64>,
+
+    /// IDs of the tasks whose file caches are never evicted, for space or
+    /// expiry.
+    pub(crate) pinned: HashSet<TaskId>,
 }
 
 impl FileCaches {
@@ -57,6 +77,9 @@ impl FileCaches {
             files: LRUCache::new(),
             file_space: SpaceManager::new(DEFAULT_FILE_CACHE_SIZE),
             operations: HashMap::new(),
+            max_ages: HashMap::new(),
+            default_max_age: None,
+            pinned: HashSet::new(),
         }
     }
 
@@ -93,28 +116,38 @@ impl FileCaches {
 
     /// Attempts to restore a file cache entry from persisted info.
     ///
+    /// A pinned entry is restored in place of unpinned ones if the space is
+    /// short.
+    ///
     /// # Arguments
     /// * `info` - Metadata of the cache file to restore.
     ///
     /// # Returns
-    /// `true` if the entry was restored or already present, `false` if there
-    /// was insufficient space to restore it.
-    pub(crate) fn try_restore_file(&mut self, info: &FileCacheInfo) -> bool {
+    /// A tuple of whether the entry was restored or already present and the
+    /// list of operating tasks evicted to restore it.
+    pub(crate) fn try_restore_file(&mut self, info: &FileCacheInfo) -> (bool, Vec<OperatingTask>) {
         if self.files.contains_key(info.task_id()) {
-            return true;
+            return (true, vec![]);
         }
         let size = info.size();
-        if self.file_space.apply_cache_size(size) {
-            let cache = Arc::new(Mutex::new(FileCache::new(info.task_id().clone(), size)));
-            self.files.insert(info.task_id().clone(), cache);
-            return true;
+        let (success, removed) = if self.pinned.contains(info.task_id()) {
+            self.try_apply_size(size)
+        } else {
+            (self.file_space.apply_cache_size(size), vec![])
+        };
+        if success {
+            let cache = FileCache::new(info.task_id().clone(), size)
+                .with_access(info.last_access(), info.expires_at());
+            self.files
+                .insert(info.task_id().clone(), Arc::new(Mutex::new(cache)));
+            return (true, removed);
         }
         info!(
             "restore file for {} failed, size: {}",
             info.task_id().brief(),
             size
         );
-        false
+        (false, removed)
     }
 
     /// Attempts to reserve the requested cache size, evicting LRU entries as
@@ -135,14 +168,16 @@ impl FileCaches {
             if self.file_space.apply_cache_size(apply) {
                 return (true, removed);
             };
-            // No cache in caches - eviction failed
-            match self.files.pop() {
+            // No unpinned cache in caches - eviction failed
+            let pinned = &self.pinned;
+            match self.files.pop_by(|task_id| !pinned.contains(task_id)) {
                 Some(cache) => {
                     let index = cache.lock().unwrap();
                     let size = index.size();
                     let id = index.task_id().clone();
                     let operations = self.get_operations(&id);
                     drop(index);
+                    self.max_ages.remove(&id);
                     self.file_space.release(size);
                     removed.push(OperatingTask::new(id, operations));
                 }
@@ -159,6 +194,60 @@ impl FileCaches {
         self.files.keys().cloned().collect()
     }
 
+    /// Returns the task IDs of the unpinned file entries expired at `now`.
+    pub(crate) fn expired_task_ids(&self, now: u64) -> Vec<TaskId> {
+        self.files
+            .keys()
+            .filter(|task_id| !self.pinned.contains(task_id))
+            .filter(|task_id| {
+                self.files
+                    .peek(task_id)
+                    .is_some_and(|cache| cache.lock().unwrap().is_expired(now))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the expiry time of a file entry of the task written at `now`.
+    pub(crate) fn expires_at(&self, task_id: &TaskId, now: u64) -> Option<u64> {
+        let max_age = match self.max_ages.get(task_id) {
+            Some(max_age) => *max_age,
+            None => self.default_max_age,
+        };
+        max_age.map(|max_age| now.saturating_add(max_age))
+    }
+
+    /// Records an access to a task's file entry at `now`, making it the most
+    /// recently used.
+    pub(crate) fn touch(&mut self, task_id: &TaskId, now: u64) {
+        if let Some(cache) = self.files.get(task_id) {
+            cache.lock().unwrap().touch(now);
+        }
+    }
+
+    /// Serializes the last access and expiry times of the file entries.
+    pub(crate) fn access_index(&self) -> String {
+        let caches = self
+            .files
+            .keys()
+            .filter_map(|task_id| self.files.peek(task_id))
+            .map(|cache| cache.lock().unwrap())
+            .collect::<Vec<_>>();
+        serialize_access_index(caches.iter().map(|cache| &**cache))
+    }
+
+    /// Persists the IDs of the pinned tasks, so that they stay pinned after a
+    /// restart.
+    ///
+    /// # Arguments
+    /// * `namespace` - Namespace of the file entries, `None` for the default
+    ///   one.
+    pub(crate) fn write_pinned_index(&self, namespace: Option<&str>) {
+        if let Err(e) = FileCache::write_pinned_index(namespace, self.pinned.iter()) {
+            error!("write pinned index error: {}", e);
+        }
+    }
+
     /// Returns the operation queue for a task, creating one if absent.
     ///
     /// # Arguments
@@ -186,6 +275,15 @@ pub struct FileManager {
 
     /// Backup RAM cache storage not subject to LRU eviction
     pub(crate) backup_rams: Mutex<HashMap<TaskId, Arc<RamCache>>>,
+
+    /// Namespace whose directory stores the files, `None` for the default one
+    pub(crate) namespace: Option<String>,
+
+    /// Whether the access index changed since it was last persisted
+    pub(crate) access_dirty: AtomicBool,
+
+    /// Held while the access index is persisted
+    pub(crate) access_writing: Mutex<()>,
 }
 
 impl FileManager {
@@ -195,6 +293,50 @@ impl FileManager {
             caches: Mutex::new(FileCaches::new()),
             update_from_file_once: Mutex::new(HashMap::new()),
             backup_rams: Mutex::new(HashMap::new()),
+            namespace: None,
+            access_dirty: AtomicBool::new(false),
+            access_writing: Mutex::new(()),
+        }
+    }
+
+    /// Creates a new, empty file manager storing its files in the directory
+    /// of a namespace.
+    pub(crate) fn with_namespace(namespace: &str) -> Self {
+        Self {
+            namespace: Some(namespace.to_string()),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the namespace of the files, `None` for the default one.
+    pub(crate) fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Persists the last access and expiry times of the file entries, so
+    /// that their order and expiry are restored after a restart.
+    ///
+    /// Must be called without holding the lock on the entries. Changes made
+    /// while another thread persists the index are written by that thread
+    /// in a single batch.
+    pub(crate) fn write_access_index(&self) {
+        self.access_dirty.store(true, Ordering::SeqCst);
+        loop {
+            let Ok(writing) = self.access_writing.try_lock() else {
+                return;
+            };
+            while self.access_dirty.swap(false, Ordering::SeqCst) {
+                let index = self.caches.lock().unwrap().access_index();
+                if let Err(e) = FileCache::write_access_index(self.namespace(), &index) {
+                    error!("write access index error: {}", e);
+                    return;
+                }
+            }
+            drop(writing);
+            // A change made just before the lock was released is written too
+            if !self.access_dirty.load(Ordering::SeqCst) {
+                return;
+            }
         }
     }
 
@@ -214,19 +356,22 @@ impl FileManager {
         }
         drop(caches);
         for (index, task) in removed.into_iter().enumerate() {
-            execute_file_remove(task, &remove_others[index]);
+            execute_file_remove(task, &remove_others[index], self.namespace());
         }
     }
 
     /// Removes file cache entries for tasks that are not currently running.
     ///
+    /// Pinned entries are kept.
+    ///
     /// # Arguments
     /// * `running_tasks` - Task IDs that are still running and must be kept.
     pub(crate) fn clear_file_cache(&self, running_tasks: &HashSet<TaskId>) {
         let mut caches = self.caches.lock().unwrap();
         let mut remove_handles = vec![];
         for id in caches.task_ids() {
-            if !running_tasks.contains(&id) {
+            if !running_tasks.contains(&id) && !caches.pinned.contains(&id) {
+                caches.max_ages.remove(&id);
                 if let Some(task) = caches.remove(&id) {
                     let handle = send_operation_message(&task);
                     remove_handles.push((handle, task));
@@ -235,8 +380,98 @@ impl FileManager {
         }
         drop(caches);
         for (handle, task) in remove_handles {
-            execute_file_remove(task, &handle);
+            execute_file_remove(task, &handle, self.namespace());
+        }
+    }
+
+    /// Sets the max age of the file cache entries without their own.
+    ///
+    /// Applies to the entries written afterwards.
+    pub(crate) fn set_default_max_age(&self, max_age: Option<Duration>) {
+        self.caches.lock().unwrap().default_max_age =
+            max_age.map(|max_age| max_age.as_millis() as u64);
+    }
+
+    /// Sets the max age of a task's file cache entry, counted from now for an
+    /// entry already on disk and from its next write otherwise.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task whose entry max age is set.
+    /// * `max_age` - Max age of the entry, `None` to keep it until evicted.
+    pub(crate) fn set_max_age(&self, task_id: &TaskId, max_age: Option<Duration>) {
+        let max_age = max_age.map(|max_age| max_age.as_millis() as u64);
+        let mut caches = self.caches.lock().unwrap();
+        caches.max_ages.insert(task_id.clone(), max_age);
+        let expires_at = caches.expires_at(task_id, policy::now());
+        let Some(cache) = caches.files.peek(task_id) else {
+            return;
+        };
+        cache.lock().unwrap().set_expires_at(expires_at);
+        drop(caches);
+        self.write_access_index();
+    }
+
+    /// Pins or unpins a task's file cache entry.
+    ///
+    /// A pinned entry is never evicted for space or expiry, only removed
+    /// explicitly. Pins are persisted, and may be set before the entry is
+    /// written.
+    pub(crate) fn set_pinned(&self, task_id: &TaskId, pinned: bool) {
+        let mut caches = self.caches.lock().unwrap();
+        let changed = if pinned {
+            caches.pinned.insert(task_id.clone())
+        } else {
+            caches.pinned.remove(task_id)
+        };
+        if changed {
+            caches.write_pinned_index(self.namespace());
+        }
+    }
+
+    /// Checks if a task's file cache entry is pinned.
+    pub(crate) fn is_pinned(&self, task_id: &TaskId) -> bool {
+        self.caches.lock().unwrap().pinned.contains(task_id)
+    }
+
+    /// Restores the persisted pins of the file cache entries.
+    pub(crate) fn restore_pinned(&self) {
+        let pinned = FileCache::read_pinned_index(self.namespace());
+        self.caches.lock().unwrap().pinned.extend(pinned);
+    }
+
+    /// Records an access to a task's file cache entry.
+    pub(crate) fn touch(&self, task_id: &TaskId) {
+        self.caches.lock().unwrap().touch(task_id, policy::now());
+    }
+
+    /// Removes the expired file cache entries and deletes their files.
+    ///
+    /// Also persists the access index, keeping the access times recorded
+    /// since the last sweep.
+    ///
+    /// # Returns
+    /// The task IDs of the removed entries.
+    pub(crate) fn remove_expired(&self) -> Vec<TaskId> {
+        let mut caches = self.caches.lock().unwrap();
+        let expired = caches.expired_task_ids(policy::now());
+        let mut remove_handles = vec![];
+        for id in expired.iter() {
+            caches.max_ages.remove(id);
+            if let Some(task) = caches.remove(id) {
+                let handle = send_operation_message(&task);
+                remove_handles.push((handle, task));
+            }
+        }
+        drop(caches);
+        self.write_access_index();
+        for (handle, task) in remove_handles {
+            execute_file_remove(task, &handle, self.namespace());
+        }
+        let mut update_from_file_once = self.update_from_file_once.lock().unwrap();
+        for id in expired.iter() {
+            update_from_file_once.remove(id);
         }
+        expired
     }
 
     /// Returns whether the task has a file cache entry or a backup RAM entry.
@@ -353,13 +588,19 @@ impl FileManager {
     }
 
     /// Removes a task's cache entry and deletes its cached file from disk.
+    ///
+    /// The entry is unpinned.
     pub fn remove(&self, task_id: &TaskId) {
         self.backup_rams.lock().unwrap().remove(task_id);
         let mut caches = self.caches.lock().unwrap();
+        caches.max_ages.remove(task_id);
+        if caches.pinned.remove(task_id) {
+            caches.write_pinned_index(self.namespace());
+        }
         if let Some(task) = caches.remove(task_id) {
             let handle = send_operation_message(&task);
             drop(caches);
-            execute_file_remove(task, &handle);
+            execute_file_remove(task, &handle, self.namespace());
         }
         self.update_from_file_once.lock().unwrap().remove(task_id);
     }
@@ -376,11 +617,11 @@ impl FileManager {
         let operating_task = OperatingTask::new(task_id.clone(), caches.get_operations(task_id));
         let notify = send_operation_message(&operating_task);
         drop(caches);
-        execute_file_remove(operating_task, &notify);
+        execute_file_remove(operating_task, &notify, self.namespace());
     }
 
     /// Attempts to restore a file cache entry, removing the disk file on
-    /// failure.
+    /// failure unless the entry is pinned.
     ///
     /// # Arguments
     /// * `info` - Metadata of the cache file to restore.
@@ -389,13 +630,20 @@ impl FileManager {
     /// `true` if restored successfully, `false` otherwise.
     pub(crate) fn try_restore_file(&self, info: &FileCacheInfo) -> bool {
         let mut cache = self.caches.lock().unwrap();
-        let success = cache.try_restore_file(info);
-        if !success {
-            let task =
-                OperatingTask::new(info.task_id().clone(), cache.get_operations(info.task_id()));
-            let notify = send_operation_message(&task);
-            drop(cache);
-            execute_file_remove(task, &notify);
+        let (success, mut removed) = cache.try_restore_file(info);
+        if !success && !cache.pinned.contains(info.task_id()) {
+            removed.push(OperatingTask::new(
+                info.task_id().clone(),
+                cache.get_operations(info.task_id()),
+            ));
+        }
+        let handles = removed
+            .iter()
+            .map(send_operation_message)
+            .collect::<Vec<_>>();
+        drop(cache);
+        for (task, handle) in removed.into_iter().zip(handles) {
+            execute_file_remove(task, &handle, self.namespace());
         }
         self.update_from_file_once
             .lock()
@@ -437,26 +685,27 @@ impl FileManager {
             }
             let mut insert_curr = None;
             if success {
-                caches.files.insert(
-                    task_id.clone(),
-                    Arc::new(Mutex::new(FileCache::new(
-                        task_id.clone(),
-                        cache.size() as u64,
-                    ))),
-                );
+                let now = policy::now();
+                let expires_at = caches.expires_at(&task_id, now);
+                let file_cache = FileCache::new(task_id.clone(), cache.size() as u64)
+                    .with_access(now, expires_at);
+                caches
+                    .files
+                    .insert(task_id.clone(), Arc::new(Mutex::new(file_cache)));
                 let task = OperatingTask::new(task_id.clone(), caches.get_operations(&task_id));
                 let handle = send_operation_message(&task);
                 insert_curr = Some((task, handle));
             }
             drop(caches);
             if let Some((task, handle)) = remove_curr {
-                execute_file_remove(task, &handle);
+                execute_file_remove(task, &handle, self.namespace());
             }
             for (idx, task) in removed.into_iter().enumerate() {
-                execute_file_remove(task, &remove_others[idx]);
+                execute_file_remove(task, &remove_others[idx], self.namespace());
             }
             if let Some((task, handle)) = insert_curr {
                 execute_file_write(task, cache, &handle);
+                self.write_access_index();
             }
             // Clean up backup RAM cache after file update
             self.backup_rams.lock().unwrap().remove(&task_id);
@@ -590,6 +839,18 @@ pub struct CacheManager {
 
     /// Manages file cache resource allocation and capacity
     pub(crate) file_manager: FileManager,
+
+    /// Freshness and validation policies of the cached entries
+    pub(crate) policies: Mutex<HashMap<TaskId, CachePolicy>>,
+
+    /// Whether the background sweep of expired file caches is started
+    pub(crate) sweeping: AtomicBool,
+
+    /// Contents of the entries being received, tailed by their readers
+    pub(crate) receiving: Mutex<HashMap<TaskId, Arc<Receiving>>>,
+
+    /// Response metadata of the cached entries
+    pub(crate) metas: Mutex<HashMap<TaskId, EntryMeta>>,
 }
 
 impl CacheManager {
@@ -605,9 +866,35 @@ impl CacheManager {
             rams: Mutex::new(LRUCache::new()),
             ram_handle: Mutex::new(SpaceManager::new(DEFAULT_RAM_CACHE_SIZE)),
             file_manager: FileManager::new(),
+            policies: Mutex::new(HashMap::new()),
+            sweeping: AtomicBool::new(false),
+            receiving: Mutex::new(HashMap::new()),
+            metas: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Creates a new cache manager of a namespace with default cache sizes.
+    ///
+    /// The caches of a namespace have their own quotas and eviction, and
+    /// their files are stored in a subdirectory named after the namespace.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace, used as a directory name
+    ///
+    /// # Returns
+    /// A new CacheManager instance ready for use
+    pub fn with_namespace(namespace: &str) -> Self {
+        Self {
+            file_manager: FileManager::with_namespace(namespace),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the namespace of the caches, `None` for the default one.
+    pub fn namespace(&self) -> Option<&str> {
+        self.file_manager.namespace()
+    }
+
     /// Sets the maximum size for RAM-based caching.
     ///
     /// Adjusts the total capacity for in-memory caching and triggers cache
@@ -631,11 +918,69 @@ impl CacheManager {
         self.file_manager.set_file_cache_size(size);
     }
 
+    /// Sets the max age of the file-based caches.
+    ///
+    /// File caches older than their max age are removed by the expiry sweep.
+    /// Applies to the caches written afterwards without a max age of their
+    /// own.
+    ///
+    /// # Arguments
+    /// * `max_age` - Max age of the file caches, `None` to keep them until
+    ///   evicted for space
+    pub fn set_file_cache_max_age(&self, max_age: Option<Duration>) {
+        self.file_manager.set_default_max_age(max_age);
+    }
+
+    /// Sets the max age of the file-based cache of a task.
+    ///
+    /// The max age is counted from now if the task is already cached on
+    /// disk, and from its next write otherwise.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID of the entry
+    /// * `max_age` - Max age of the entry, `None` to keep it until evicted
+    ///   for space
+    pub fn set_max_age(&self, task_id: &TaskId, max_age: Option<Duration>) {
+        self.file_manager.set_max_age(task_id, max_age);
+    }
+
+    /// Removes the cache entries whose file cache has expired.
+    pub fn sweep_expired(&self) {
+        let expired = self.file_manager.remove_expired();
+        if expired.is_empty() {
+            return;
+        }
+        info!("remove {} expired file caches", expired.len());
+        let mut rams = self.rams.lock().unwrap();
+        let mut policies = self.policies.lock().unwrap();
+        let mut metas = self.metas.lock().unwrap();
+        for task_id in expired.iter() {
+            rams.remove(task_id);
+            policies.remove(task_id);
+            metas.remove(task_id);
+        }
+    }
+
+    /// Starts the background sweep removing the expired file caches
+    /// periodically.
+    ///
+    /// Does nothing if the sweep is already started.
+    pub fn start_expiry_sweep(&'static self) {
+        if self.sweeping.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        spawn(move || loop {
+            sleep(SWEEP_INTERVAL);
+            self.sweep_expired();
+        });
+    }
+
     /// Restores all valid cache files from the given directory.
     ///
-    /// Scans the directory for valid cache files, filters out incomplete files,
-    /// sorts them by modification time, and returns an iterator over the task
-    /// IDs.
+    /// Scans the directory for valid cache files, filters out incomplete and
+    /// expired files, and restores them in their persisted LRU order, oldest
+    /// access first, along with their pins. Pinned files are kept even if
+    /// expired or over the size limit.
     ///
     /// # Arguments
     /// * `path` - Path to the directory to scan
@@ -643,17 +988,34 @@ impl CacheManager {
     /// # Returns
     /// Iterator over task IDs of valid cache files
     pub fn build_cached_files_index(&'static self) {
-        if let Some(file_info) = get_cached_files_info() {
+        self.file_manager.restore_pinned();
+        if let Some(file_info) = get_cached_files_info(self.namespace()) {
+            let now = policy::now();
             let mut is_continue = true;
             for info in file_info {
-                if !is_continue {
+                let is_pinned = self.file_manager.is_pinned(info.task_id());
+                let is_expired = info.expires_at().is_some_and(|t| t <= now);
+                if !is_pinned && (!is_continue || is_expired) {
                     self.file_manager.try_remove_from_disk(info.task_id());
                     continue;
                 }
                 if !self.file_manager.try_restore_file(&info) {
-                    is_continue = false;
+                    is_continue = is_continue && is_pinned;
                     continue;
                 }
+                if let Some(policy) = FileCache::read_policy(info.task_id(), self.namespace()) {
+                    self.policies
+                        .lock()
+                        .unwrap()
+                        .insert(info.task_id().clone(), policy);
+                }
+                // Files cached without metadata were created when modified
+                let meta = FileCache::read_meta(info.task_id(), self.namespace())
+                    .unwrap_or_else(|| EntryMeta::new(None, info.modified(), HashMap::new()));
+                self.metas
+                    .lock()
+                    .unwrap()
+                    .insert(info.task_id().clone(), meta);
             }
         }
     }
@@ -677,17 +1039,258 @@ impl CacheManager {
         self.get_cache(task_id)
     }
 
+    /// Opens a streaming reader of a cache entry by task ID.
+    ///
+    /// Unlike `fetch`, the content is not loaded into RAM: a cached file is
+    /// read in chunks, and an entry still being received is read while its
+    /// content arrives. Cached entries are preferred to the entries being
+    /// received.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID to read
+    ///
+    /// # Returns
+    /// `Some(CacheReader)` if the entry is cached or being received, `None`
+    /// otherwise
+    pub fn reader(&self, task_id: &TaskId) -> Option<CacheReader> {
+        let res = self.rams.lock().unwrap().get(task_id).cloned();
+        let res = res.or_else(|| {
+            self.file_manager
+                .backup_rams
+                .lock()
+                .unwrap()
+                .get(task_id)
+                .cloned()
+        });
+        if let Some(cache) = res {
+            self.file_manager.touch(task_id);
+            return Some(CacheReader::ram(cache));
+        }
+        if self.file_manager.caches.lock().unwrap().contains(task_id) {
+            match FileCache::open(task_id, self.namespace()) {
+                Ok(file) => {
+                    self.file_manager.touch(task_id);
+                    return Some(CacheReader::file(file));
+                }
+                Err(e) => error!("{} open reader error: {}", task_id.brief(), e),
+            }
+        }
+        self.receiving
+            .lock()
+            .unwrap()
+            .get(task_id)
+            .cloned()
+            .map(CacheReader::receiving)
+    }
+
     /// Removes a cache entry by task ID.
     ///
     /// Removes the entry from all cache storage types (file, backup RAM, and
     /// primary RAM cache), and clears any pending file-to-RAM update
-    /// operations for the task.
+    /// operations for the task. The entry is unpinned.
     ///
     /// # Arguments
     /// * `task_id` - The task ID to remove
     pub fn remove(&self, task_id: TaskId) {
         self.file_manager.remove(&task_id);
         self.rams.lock().unwrap().remove(&task_id);
+        self.policies.lock().unwrap().remove(&task_id);
+        self.metas.lock().unwrap().remove(&task_id);
+    }
+
+    /// Lists the cached entries, least recently accessed first.
+    ///
+    /// Entries still being received are not listed.
+    ///
+    /// # Returns
+    /// The description of each entry cached in RAM or in a file
+    pub fn entries(&self) -> Vec<CacheEntry> {
+        let mut entries = HashMap::new();
+        let mut rams = {
+            let rams = self.rams.lock().unwrap();
+            rams.keys()
+                .filter_map(|task_id| rams.peek(task_id))
+                .map(|cache| (cache.task_id().clone(), cache.size() as u64))
+                .collect::<Vec<_>>()
+        };
+        rams.extend(
+            self.file_manager
+                .backup_rams
+                .lock()
+                .unwrap()
+                .values()
+                .map(|cache| (cache.task_id().clone(), cache.size() as u64)),
+        );
+        for (task_id, size) in rams {
+            let entry = entries
+                .entry(task_id.clone())
+                .or_insert_with(|| CacheEntry::new(task_id, self.namespace()));
+            entry.size = size;
+            entry.in_ram = true;
+        }
+
+        let caches = self.file_manager.caches.lock().unwrap();
+        for cache in caches
+            .files
+            .keys()
+            .filter_map(|task_id| caches.files.peek(task_id))
+        {
+            let cache = cache.lock().unwrap();
+            let entry = entries
+                .entry(cache.task_id().clone())
+                .or_insert_with(|| CacheEntry::new(cache.task_id().clone(), self.namespace()));
+            entry.size = cache.size();
+            entry.last_access = cache.last_access();
+            entry.in_file = true;
+        }
+        for entry in entries.values_mut() {
+            entry.pinned = caches.pinned.contains(&entry.task_id);
+        }
+        drop(caches);
+
+        let metas = self.metas.lock().unwrap();
+        for entry in entries.values_mut() {
+            if let Some(meta) = metas.get(&entry.task_id) {
+                entry.url = meta.url.clone();
+                entry.created = meta.created;
+                entry.headers = meta.headers.clone();
+            }
+            // Accesses to RAM only entries are not recorded
+            if !entry.in_file {
+                entry.last_access = entry.created;
+            }
+        }
+        drop(metas);
+
+        let mut entries = entries.into_values().collect::<Vec<_>>();
+        entries.sort_by_key(|entry| entry.last_access);
+        entries
+    }
+
+    /// Pins or unpins a cache entry by task ID.
+    ///
+    /// The file cache of a pinned entry is never evicted for space or expiry,
+    /// only removed explicitly, guaranteeing that the content stays available
+    /// offline. Pins are persisted, and may be set before the entry is
+    /// cached.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID of the entry
+    /// * `pinned` - Whether to pin the entry
+    pub fn set_pinned(&self, task_id: &TaskId, pinned: bool) {
+        self.file_manager.set_pinned(task_id, pinned);
+    }
+
+    /// Checks if a cache entry is pinned.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID to check
+    pub fn is_pinned(&self, task_id: &TaskId) -> bool {
+        self.file_manager.is_pinned(task_id)
+    }
+
+    /// Returns the freshness and validation policy of a cache entry.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID to look up
+    ///
+    /// # Returns
+    /// `Some(CachePolicy)` if the entry was cached with one, `None` otherwise
+    pub fn policy(&self, task_id: &TaskId) -> Option<CachePolicy> {
+        self.policies.lock().unwrap().get(task_id).cloned()
+    }
+
+    /// Checks if a cache entry may be served without revalidation.
+    ///
+    /// Entries cached without a policy are always fresh.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID to check
+    pub fn is_fresh(&self, task_id: &TaskId) -> bool {
+        self.policy(task_id)
+            .map_or(true, |policy| policy.is_fresh(policy::now()))
+    }
+
+    /// Returns the headers of a conditional request revalidating a cache
+    /// entry.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID of the entry to revalidate
+    ///
+    /// # Returns
+    /// `Some` with the `If-None-Match` and `If-Modified-Since` headers if the
+    /// entry is cached with validators, `None` otherwise
+    pub fn conditional_headers(&self, task_id: &TaskId) -> Option<Vec<(String, String)>> {
+        if !self.contains(task_id) {
+            return None;
+        }
+        self.policy(task_id)
+            .filter(|policy| policy.has_validators())
+            .map(|policy| policy.conditional_headers())
+    }
+
+    /// Registers the content of an entry being received, for readers to tail
+    /// it.
+    pub(crate) fn start_receiving(&self, task_id: TaskId, receiving: Arc<Receiving>) {
+        self.receiving.lock().unwrap().insert(task_id, receiving);
+    }
+
+    /// Unregisters the content of an entry no longer being received, unless
+    /// another content of the entry has been registered since.
+    pub(crate) fn end_receiving(&self, task_id: &TaskId, receiving: &Arc<Receiving>) {
+        let mut contents = self.receiving.lock().unwrap();
+        if contents
+            .get(task_id)
+            .is_some_and(|content| Arc::ptr_eq(content, receiving))
+        {
+            contents.remove(task_id);
+        }
+    }
+
+    /// Returns the response metadata of a cache entry, if any.
+    pub(crate) fn meta(&self, task_id: &TaskId) -> Option<EntryMeta> {
+        self.metas.lock().unwrap().get(task_id).cloned()
+    }
+
+    /// Sets the response metadata of a cache entry about to be finished.
+    pub(crate) fn update_meta(&self, task_id: TaskId, meta: EntryMeta) {
+        self.metas.lock().unwrap().insert(task_id, meta);
+    }
+
+    /// Sets the policy of a cache entry about to be finished.
+    pub(crate) fn update_policy(&self, task_id: TaskId, policy: CachePolicy) {
+        self.policies.lock().unwrap().insert(task_id, policy);
+    }
+
+    /// Refreshes a cache entry revalidated by a 304 response.
+    ///
+    /// Updates the entry policy with the response headers and persists it,
+    /// keeping the cached body.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task ID of the revalidated entry
+    /// * `headers` - Headers of the 304 response, with lowercase names
+    ///
+    /// # Returns
+    /// `Some(Arc<RamCache>)` with the cached body, `None` if the entry is no
+    /// longer cached
+    pub fn revalidate(
+        &'static self,
+        task_id: &TaskId,
+        headers: &HashMap<String, String>,
+    ) -> Option<Arc<RamCache>> {
+        let cache = self.fetch(task_id)?;
+        let policy = self
+            .policy(task_id)
+            .unwrap_or_default()
+            .revalidated(headers, policy::now());
+        if self.file_manager.caches.lock().unwrap().contains(task_id) {
+            if let Err(e) = FileCache::write_policy(task_id, self.namespace(), &policy) {
+                error!("{} write policy error: {}", task_id.brief(), e);
+            }
+        }
+        self.update_policy(task_id.clone(), policy);
+        Some(cache)
     }
 
     /// Checks if a cache entry exists for the given task ID.
@@ -717,15 +1320,20 @@ impl CacheManager {
     /// otherwise
     pub(crate) fn get_cache(&'static self, task_id: &TaskId) -> Option<Arc<RamCache>> {
         let res = self.rams.lock().unwrap().get(task_id).cloned();
-        res.or_else(|| {
-            self.file_manager
-                .backup_rams
-                .lock()
-                .unwrap()
-                .get(task_id)
-                .cloned()
-        })
-        .or_else(|| self.update_ram_from_file(task_id))
+        let res = res
+            .or_else(|| {
+                self.file_manager
+                    .backup_rams
+                    .lock()
+                    .unwrap()
+                    .get(task_id)
+                    .cloned()
+            })
+            .or_else(|| self.update_ram_from_file(task_id));
+        if res.is_some() {
+            self.file_manager.touch(task_id);
+        }
+        res
     }
 
     /// Clears memory cache entries not associated with running tasks.
@@ -752,7 +1360,8 @@ impl CacheManager {
         }
     }
 
-    /// Clears file cache entries not associated with running tasks.
+    /// Clears file cache entries not associated with running tasks, except
+    /// the pinned ones.
     pub fn clear_file_cache(&self, running_tasks: &HashSet<TaskId>) {
         self.file_manager.clear_file_cache(running_tasks);
     }
@@ -862,14 +1471,19 @@ pub fn send_operation_message(task: &OperatingTask) -> NotifyHandle {
 
 /// Removes a task's cached file, waiting for prior operations on the same task
 /// to finish first.
-pub fn execute_file_remove(task: OperatingTask, notify: &NotifyHandle) {
+///
+/// # Arguments
+/// * `task` - Operating task identifying the file to remove.
+/// * `notify` - Handle controlling turn ordering.
+/// * `namespace` - Namespace of the file, `None` for the default one.
+pub fn execute_file_remove(task: OperatingTask, notify: &NotifyHandle, namespace: Option<&str>) {
     let is_first = notify.is_first();
     let pair = notify.handle();
     if is_first {
-        FileCache::remove_file(task.task_id());
+        FileCache::remove_file(task.task_id(), namespace);
     } else {
         pair.wait();
-        FileCache::remove_file(task.task_id());
+        FileCache::remove_file(task.task_id(), namespace);
     }
     notify_next_operation(task);
 }
@@ -0,0 +1,223 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HTTP freshness and validation policy of cache entries.
+//!
+//! This module extracts the response validators (`ETag`, `Last-Modified`) and
+//! the freshness lifetime (`Cache-Control`, `Expires`, `Age`) of a cached
+//! response, following RFC 9111 for a private cache. Fresh entries are served
+//! directly, stale entries are revalidated with a conditional request.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use request_utils::date::parse_http_date;
+
+/// Fraction of the time since `Last-Modified` used as the heuristic freshness
+/// lifetime of responses without explicit freshness information.
+const HEURISTIC_FRACTION: u64 = 10;
+
+/// Number of milliseconds in one second.
+const MILLISECONDS_IN_ONE_SECOND: u64 = 1000;
+
+/// Key of the entity tag in the persisted policy.
+const ETAG: &str = "etag";
+
+/// Key of the last modification date in the persisted policy.
+const LAST_MODIFIED: &str = "last-modified";
+
+/// Key of the expiration time in the persisted policy.
+const EXPIRES_AT: &str = "expires-at";
+
+/// Freshness and validation information of a cached response.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use cache_core::CachePolicy;
+///
+/// let mut headers = HashMap::new();
+/// headers.insert("cache-control".to_string(), "max-age=60".to_string());
+/// headers.insert("etag".to_string(), "\"v1\"".to_string());
+///
+/// let policy = CachePolicy::from_headers(&headers, 0);
+/// assert!(policy.is_fresh(59_999));
+/// assert!(!policy.is_fresh(60_000));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CachePolicy {
+    /// Entity tag of the response.
+    etag: Option<String>,
+    /// `Last-Modified` date of the response, as sent by the server.
+    last_modified: Option<String>,
+    /// Time in milliseconds since the epoch until which the response is
+    /// fresh, `None` if the response carries no freshness information.
+    expires_at: Option<u64>,
+}
+
+impl CachePolicy {
+    /// Builds the policy of a response received at `now`.
+    ///
+    /// # Arguments
+    /// * `headers` - Response headers, with lowercase names
+    /// * `now` - Time in milliseconds since the epoch the response was
+    ///   received at
+    pub fn from_headers(headers: &HashMap<String, String>, now: u64) -> Self {
+        let etag = headers.get("etag").cloned();
+        let last_modified = headers.get("last-modified").cloned();
+        let expires_at = freshness_lifetime(headers, now).map(|lifetime| {
+            // The response has already been this old when it was received
+            let age = headers
+                .get("age")
+                .and_then(|age| age.trim().parse::<u64>().ok())
+                .unwrap_or(0);
+            now.saturating_add(lifetime.saturating_mul(MILLISECONDS_IN_ONE_SECOND))
+                .saturating_sub(age.saturating_mul(MILLISECONDS_IN_ONE_SECOND))
+        });
+        Self {
+            etag,
+            last_modified,
+            expires_at,
+        }
+    }
+
+    /// Builds the policy of an entry revalidated at `now` by a 304 response
+    /// with the given headers, keeping the validators the response does not
+    /// carry.
+    pub fn revalidated(&self, headers: &HashMap<String, String>, now: u64) -> Self {
+        let mut headers = headers.clone();
+        if let Some(etag) = &self.etag {
+            headers
+                .entry("etag".to_string())
+                .or_insert_with(|| etag.clone());
+        }
+        if let Some(last_modified) = &self.last_modified {
+            headers
+                .entry("last-modified".to_string())
+                .or_insert_with(|| last_modified.clone());
+        }
+        Self::from_headers(&headers, now)
+    }
+
+    /// Returns whether the entry may be served at `now` without revalidation.
+    ///
+    /// Entries without freshness information nor validators, such as those
+    /// cached before validators were stored, are always fresh since they
+    /// cannot be revalidated.
+    pub fn is_fresh(&self, now: u64) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now < expires_at,
+            None => !self.has_validators(),
+        }
+    }
+
+    /// Returns whether the entry can be revalidated with a conditional request.
+    pub fn has_validators(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+
+    /// Returns the headers of a conditional request revalidating the entry.
+    pub fn conditional_headers(&self) -> Vec<(String, String)> {
+        let mut headers = vec![];
+        if let Some(etag) = &self.etag {
+            headers.push(("If-None-Match".to_string(), etag.clone()));
+        }
+        if let Some(last_modified) = &self.last_modified {
+            headers.push(("If-Modified-Since".to_string(), last_modified.clone()));
+        }
+        headers
+    }
+
+    /// Serializes the policy for persisting it next to the cache file.
+    pub(crate) fn serialize(&self) -> String {
+        let mut s = String::new();
+        if let Some(etag) = &self.etag {
+            s.push_str(&format!("{}:{}\n", ETAG, etag));
+        }
+        if let Some(last_modified) = &self.last_modified {
+            s.push_str(&format!("{}:{}\n", LAST_MODIFIED, last_modified));
+        }
+        if let Some(expires_at) = self.expires_at {
+            s.push_str(&format!("{}:{}\n", EXPIRES_AT, expires_at));
+        }
+        s
+    }
+
+    /// Deserializes a persisted policy, ignoring unknown or invalid lines.
+    pub(crate) fn deserialize(s: &str) -> Self {
+        let mut policy = Self::default();
+        for (key, value) in s.lines().filter_map(|line| line.split_once(':')) {
+            match key {
+                ETAG => policy.etag = Some(value.to_string()),
+                LAST_MODIFIED => policy.last_modified = Some(value.to_string()),
+                EXPIRES_AT => policy.expires_at = value.parse().ok(),
+                _ => {}
+            }
+        }
+        policy
+    }
+}
+
+/// Returns the current time in milliseconds since the epoch.
+pub(crate) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Returns the freshness lifetime in seconds of a response received at `now`,
+/// or `None` if it carries no freshness information.
+fn freshness_lifetime(headers: &HashMap<String, String>, now: u64) -> Option<u64> {
+    if let Some(cache_control) = headers.get("cache-control") {
+        let mut max_age = None;
+        for directive in cache_control.split(',') {
+            let directive = directive.trim().to_ascii_lowercase();
+            match directive.split_once('=') {
+                Some(("max-age", value)) => {
+                    // An invalid max-age makes the response stale
+                    max_age = Some(value.trim_matches('"').parse::<u64>().unwrap_or(0));
+                }
+                None if directive == "no-cache" || directive == "no-store" => return Some(0),
+                _ => {}
+            }
+        }
+        if max_age.is_some() {
+            return max_age;
+        }
+    }
+
+    let date = headers
+        .get("date")
+        .and_then(|date| parse_http_date(date))
+        .unwrap_or(now / MILLISECONDS_IN_ONE_SECOND);
+    if let Some(expires) = headers.get("expires") {
+        // An invalid Expires date means the response has already expired
+        return Some(
+            parse_http_date(expires)
+                .map(|expires| expires.saturating_sub(date))
+                .unwrap_or(0),
+        );
+    }
+
+    // Heuristic freshness from the time since the last modification
+    let last_modified = parse_http_date(headers.get("last-modified")?)?;
+    Some(date.saturating_sub(last_modified) / HEURISTIC_FRACTION)
+}
+
+#[cfg(test)]
+mod ut_policy {
+    include!("../tests/ut/ut_policy.rs");
+}
@@ -0,0 +1,253 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Streaming reads of cache entries.
+//!
+//! This module provides the `CacheReader` struct, reading the content of a
+//! cache entry without buffering it whole again: from its RAM cache if it is
+//! there, from its cache file in chunks otherwise, and by tailing its
+//! `Updater` while the content is still being received.
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::sync::{Arc, Condvar, Mutex};
+
+use crate::data::RamCache;
+
+/// State of the content of a cache entry being received.
+enum ReceiveState {
+    /// The content is being received into a RAM cache.
+    Receiving(RamCache),
+    /// The content has been received completely.
+    Finished(Arc<RamCache>),
+    /// The content has been reset or abandoned before completion.
+    Aborted,
+}
+
+/// Content of a cache entry being received, shared between its `Updater` and
+/// the readers tailing it.
+pub(crate) struct Receiving {
+    /// Current state of the content
+    state: Mutex<ReceiveState>,
+    /// Wakes up the readers waiting for more content
+    condvar: Condvar,
+}
+
+impl Receiving {
+    /// Creates the receiving content of a new RAM cache.
+    pub(crate) fn new(cache: RamCache) -> Self {
+        Self {
+            state: Mutex::new(ReceiveState::Receiving(cache)),
+            condvar: Condvar::new(),
+        }
+    }
+
+    /// Returns the size of the content received so far.
+    pub(crate) fn size(&self) -> usize {
+        match &*self.state.lock().unwrap() {
+            ReceiveState::Receiving(cache) => cache.size(),
+            ReceiveState::Finished(cache) => cache.size(),
+            ReceiveState::Aborted => 0,
+        }
+    }
+
+    /// Appends received data to the content, waking up the waiting readers.
+    pub(crate) fn write(&self, data: &[u8]) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        let ReceiveState::Receiving(cache) = &mut *state else {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "cache no longer receiving",
+            ));
+        };
+        let res = cache.write_all(data);
+        self.condvar.notify_all();
+        res
+    }
+
+    /// Finalizes the received content, waking up the waiting readers.
+    ///
+    /// # Arguments
+    /// * `store` - Whether to store the content in the RAM and file caches
+    ///
+    /// # Returns
+    /// The finalized cache, or `None` if receiving was aborted
+    pub(crate) fn finish(&self, store: bool) -> Option<Arc<RamCache>> {
+        let mut state = self.state.lock().unwrap();
+        let ReceiveState::Receiving(cache) = mem::replace(&mut *state, ReceiveState::Aborted)
+        else {
+            return None;
+        };
+        let cache = if store {
+            cache.finish_write()
+        } else {
+            Arc::new(cache)
+        };
+        *state = ReceiveState::Finished(cache.clone());
+        self.condvar.notify_all();
+        Some(cache)
+    }
+
+    /// Aborts receiving, releasing the content received so far.
+    ///
+    /// The readers tailing the content fail with `UnexpectedEof`.
+    pub(crate) fn abort(&self) {
+        let mut state = self.state.lock().unwrap();
+        let received = mem::replace(&mut *state, ReceiveState::Aborted);
+        self.condvar.notify_all();
+        // Release the RAM cache once the lock is released.
+        drop(state);
+        drop(received);
+    }
+
+    /// Reads content from a position, waiting for it if it is not received
+    /// yet.
+    ///
+    /// # Returns
+    /// The number of bytes read, along with the finalized cache if receiving
+    /// is finished
+    fn read_at(&self, pos: usize, buf: &mut [u8]) -> io::Result<(usize, Option<Arc<RamCache>>)> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            match &*state {
+                ReceiveState::Receiving(cache) => {
+                    if pos < cache.size() || buf.is_empty() {
+                        return Ok((copy_at(cache, pos, buf), None));
+                    }
+                }
+                ReceiveState::Finished(cache) => {
+                    return Ok((copy_at(cache, pos, buf), Some(cache.clone())));
+                }
+                ReceiveState::Aborted => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "cache receiving aborted",
+                    ));
+                }
+            }
+            state = self.condvar.wait(state).unwrap();
+        }
+    }
+}
+
+/// Source of the content read by a `CacheReader`.
+enum Source {
+    /// Content cached in RAM
+    Ram(Arc<RamCache>),
+    /// Content cached in a file
+    File(File),
+    /// Content still being received
+    Receiving(Arc<Receiving>),
+}
+
+/// Streaming reader of the content of a cache entry.
+///
+/// Reads from the RAM cache of the entry if it is there, and from its cache
+/// file in chunks otherwise. While the content is still being received, the
+/// reader waits for the data it has not received yet, until it is finished.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Read;
+///
+/// use cache_core::CacheManager;
+/// use request_utils::task_id::TaskId;
+///
+/// let manager = CacheManager::new();
+/// let task_id = TaskId::from_url("https://example.com/video.mp4");
+/// if let Some(mut reader) = manager.reader(&task_id) {
+///     let mut buf = [0u8; 4096];
+///     while let Ok(n) = reader.read(&mut buf) {
+///         if n == 0 {
+///             break;
+///         }
+///         // Consume buf[..n]
+///     }
+/// }
+/// ```
+pub struct CacheReader {
+    /// Source of the content
+    source: Source,
+    /// Position of the next byte to read in the content
+    pos: usize,
+}
+
+impl CacheReader {
+    /// Creates a reader of content cached in RAM.
+    pub(crate) fn ram(cache: Arc<RamCache>) -> Self {
+        Self::new(Source::Ram(cache))
+    }
+
+    /// Creates a reader of content cached in a file.
+    pub(crate) fn file(file: File) -> Self {
+        Self::new(Source::File(file))
+    }
+
+    /// Creates a reader of content being received.
+    pub(crate) fn receiving(receiving: Arc<Receiving>) -> Self {
+        Self::new(Source::Receiving(receiving))
+    }
+
+    /// Creates a reader of a source, from the start of the content.
+    fn new(source: Source) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    /// Checks if the reader tails content still being received.
+    pub fn is_receiving(&self) -> bool {
+        matches!(self.source, Source::Receiving(_))
+    }
+}
+
+impl Read for CacheReader {
+    /// Reads the next bytes of the content.
+    ///
+    /// Blocks while the content is being received and no new bytes are
+    /// available yet.
+    ///
+    /// # Errors
+    /// Returns `UnexpectedEof` if receiving the content is aborted, and the
+    /// errors of the cache file otherwise.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = match &mut self.source {
+            Source::Ram(cache) => copy_at(cache, self.pos, buf),
+            Source::File(file) => file.read(buf)?,
+            Source::Receiving(receiving) => {
+                let (n, finished) = receiving.read_at(self.pos, buf)?;
+                // Read the finished content without locking any more.
+                if let Some(cache) = finished {
+                    self.source = Source::Ram(cache);
+                }
+                n
+            }
+        };
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+/// Copies the content of a RAM cache from a position into a buffer.
+fn copy_at(cache: &RamCache, pos: usize, buf: &mut [u8]) -> usize {
+    let data = cache.cursor().into_inner();
+    let data = data.get(pos..).unwrap_or_default();
+    let n = data.len().min(buf.len());
+    buf[..n].copy_from_slice(&data[..n]);
+    n
+}
+
+#[cfg(test)]
+mod ut_read {
+    include!("../tests/ut/ut_read.rs");
+}
@@ -18,13 +18,16 @@
 //! state. It handles the synchronization between incoming data and the caching
 //! system.
 
-use std::io::Write;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use request_utils::task_id::TaskId;
 
 use crate::data::RamCache;
+use crate::entry::EntryMeta;
 use crate::manage::CacheManager;
+use crate::policy::{self, CachePolicy};
+use crate::read::Receiving;
 
 // Previous version of Updater struct (commented out)
 // pub(crate) struct Updater {
@@ -37,7 +40,8 @@ use crate::manage::CacheManager;
 ///
 /// This struct handles the process of receiving data, storing it in a RAM
 /// cache, and finalizing the cache entry. It provides methods for incremental
-/// data updates and cache lifecycle management.
+/// data updates and cache lifecycle management. The data received so far can
+/// be read by the `CacheReader`s of the task while it is being received.
 ///
 /// # Examples
 ///
@@ -61,8 +65,9 @@ pub struct Updater {
     /// Unique identifier for the task being updated
     task_id: TaskId,
 
-    /// Optional RAM cache for storing received data
-    cache: Option<RamCache>,
+    /// Optional RAM cache for storing received data, shared with the readers
+    /// tailing it
+    cache: Option<Arc<Receiving>>,
 
     /// Reference to the global cache manager
     cache_manager: &'static CacheManager,
@@ -97,14 +102,78 @@ impl Updater {
     /// # Returns
     /// An Arc-wrapped RamCache instance containing the cached data
     pub fn cache_finish(&mut self) -> Arc<RamCache> {
-        match self.cache.take() {
-            Some(cache) => cache.finish_write(),
-            None => Arc::new(RamCache::new(
+        self.finish(true)
+    }
+
+    /// Finalizes the received content without caching it.
+    ///
+    /// The content previously cached for the task, if any, is removed, as it
+    /// is outdated by the received one.
+    ///
+    /// # Returns
+    /// An Arc-wrapped RamCache instance containing the received data
+    pub fn cache_discard(&mut self) -> Arc<RamCache> {
+        let cache = self.finish(false);
+        self.cache_manager.remove(self.task_id.clone());
+        cache
+    }
+
+    /// Finalizes the received content, storing it in the caches if `store`.
+    fn finish(&mut self, store: bool) -> Arc<RamCache> {
+        let cache = self.cache.take().and_then(|receiving| {
+            // Finish before unregistering, so that new readers find the cache.
+            let cache = receiving.finish(store);
+            self.cache_manager.end_receiving(&self.task_id, &receiving);
+            cache
+        });
+        cache.unwrap_or_else(|| {
+            Arc::new(RamCache::new(
                 self.task_id.clone(),
                 self.cache_manager,
                 Some(0),
-            )),
-        }
+            ))
+        })
+    }
+
+    /// Sets the freshness and validation policy of the cache from the
+    /// response headers.
+    ///
+    /// Must be called before `cache_finish` for the policy to be persisted
+    /// with the cache file.
+    ///
+    /// # Arguments
+    /// * `headers` - Response headers, with lowercase names
+    pub fn cache_policy(&self, headers: &HashMap<String, String>) {
+        let policy = CachePolicy::from_headers(headers, policy::now());
+        self.cache_manager
+            .update_policy(self.task_id.clone(), policy);
+    }
+
+    /// Sets the response metadata of the cache, listed by
+    /// `CacheManager::entries`.
+    ///
+    /// Must be called before `cache_finish` for the metadata to be persisted
+    /// with the cache file.
+    ///
+    /// # Arguments
+    /// * `url` - URL of the content
+    /// * `headers` - Response headers, with lowercase names
+    pub fn cache_meta(&self, url: &str, headers: &HashMap<String, String>) {
+        let meta = EntryMeta::new(Some(url.to_string()), policy::now(), headers.clone());
+        self.cache_manager.update_meta(self.task_id.clone(), meta);
+    }
+
+    /// Refreshes the cached entry after a 304 response to a conditional
+    /// request, instead of receiving the body again.
+    ///
+    /// # Arguments
+    /// * `headers` - Headers of the 304 response, with lowercase names
+    ///
+    /// # Returns
+    /// The cached entry, or `None` if it is no longer cached
+    pub fn cache_revalidated(&self, headers: &HashMap<String, String>) -> Option<Arc<RamCache>> {
+        info!("{} not modified", self.task_id.brief());
+        self.cache_manager.revalidate(&self.task_id, headers)
     }
 
     /// Receives and caches a chunk of data.
@@ -129,11 +198,14 @@ impl Updater {
             let content_length = content_length();
             let apply_cache =
                 RamCache::new(self.task_id.clone(), self.cache_manager, content_length);
-            self.cache = Some(apply_cache)
+            let receiving = Arc::new(Receiving::new(apply_cache));
+            self.cache_manager
+                .start_receiving(self.task_id.clone(), receiving.clone());
+            self.cache = Some(receiving)
         }
 
         // Write data to cache and log errors without panicking
-        if let Err(e) = self.cache.as_mut().unwrap().write_all(data) {
+        if let Err(e) = self.cache.as_ref().unwrap().write(data) {
             error!("{} cache write error: {}", self.task_id.brief(), e);
         };
     }
@@ -141,12 +213,29 @@ impl Updater {
     /// Resets the cache, releasing its resources.
     ///
     /// Takes ownership of the current cache if it contains data, effectively
-    /// clearing it and releasing associated resources.
+    /// clearing it and releasing associated resources. The readers tailing it
+    /// fail.
     pub fn reset_cache(&mut self) {
         let size = self.cache.as_ref().map(|a| a.size()).unwrap_or(0);
         if size != 0 {
             info!("reset {} cache size {}", self.task_id.brief(), size);
-            self.cache.take();
+            self.abort();
+        }
+    }
+
+    /// Aborts receiving the current cache, if any.
+    fn abort(&mut self) {
+        if let Some(receiving) = self.cache.take() {
+            self.cache_manager.end_receiving(&self.task_id, &receiving);
+            receiving.abort();
         }
     }
 }
+
+impl Drop for Updater {
+    /// Aborts the cache still being received, so that the readers tailing it
+    /// do not wait for it forever.
+    fn drop(&mut self) {
+        self.abort();
+    }
+}
@@ -75,7 +75,7 @@ fn ut_cache_file_create() {
     let read_cache = read_cache.unwrap();
     let data = read_cache.cursor().into_inner();
     assert_eq!(data, TEST_STRING.as_bytes());
-    FileCache::remove_file(&task_id);
+    FileCache::remove_file(&task_id, None);
 }
 
 // @tc.name: ut_cache_file_try_add_beyond_limit
@@ -195,5 +195,99 @@ fn ut_cache_file_update_ram_from_file() {
     for j in v {
         assert!(j.join().unwrap());
     }
-    FileCache::remove_file(&task_id);
+    FileCache::remove_file(&task_id, None);
+}
+
+// @tc.name: ut_cache_file_access_index
+// @tc.desc: Test serialization of the file cache access index
+// @tc.precon: NA
+// @tc.step: 1. Create file caches with and without expiry
+//           2. Serialize and deserialize the access index
+//           3. Deserialize an index containing malformed lines
+// @tc.expect: Access and expiry times are preserved and malformed lines are
+// skipped
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_file_access_index() {
+    let first = FileCache::new(TaskId::new("first".to_string()), TEST_SIZE).with_access(100, None);
+    let second =
+        FileCache::new(TaskId::new("second".to_string()), TEST_SIZE).with_access(200, Some(300));
+    let s = serialize_access_index([&first, &second].into_iter());
+    let index = deserialize_access_index(&s);
+    assert_eq!(index.len(), 2);
+    assert_eq!(index[first.task_id()], (100, None));
+    assert_eq!(index[second.task_id()], (200, Some(300)));
+
+    let index = deserialize_access_index("a 1 -\nb x -\nc 1\nd 1 y\ne 2 3\n");
+    assert_eq!(index.len(), 2);
+    assert_eq!(index[&TaskId::new("a".to_string())], (1, None));
+    assert_eq!(index[&TaskId::new("e".to_string())], (2, Some(3)));
+}
+
+// @tc.name: ut_cache_file_restore_access_order
+// @tc.desc: Test file cache restoration in the persisted access order
+// @tc.precon: NA
+// @tc.step: 1. Create test directory with finished files
+//           2. Write an access index making the oldest file the most recent
+//           3. Call get_info_from_path function
+// @tc.expect: Files are restored by last access with their expiry, and the
+// access index is kept
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_file_restore_access_order() {
+    init();
+    const TEST_DIR: &str = "restore_access_test";
+
+    init_curr_store_dir();
+    let path = unsafe { FILE_STORE_DIR.join(String::from(TEST_DIR)).unwrap() };
+
+    fs::create_dir_all(&path).unwrap();
+    for i in 0..3 {
+        fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .open(path.join(format!("{}{}", i, FINISH_SUFFIX)))
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let now = policy::now();
+    fs::write(
+        path.join(ACCESS_INDEX),
+        format!("0 {} -\n1 1 {}\n", now + 1000, now),
+    )
+    .unwrap();
+
+    let infos = get_info_from_path(path.as_path()).collect::<Vec<_>>();
+    let ids = infos
+        .iter()
+        .map(|info| info.task_id().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["1", "2", "0"]);
+    assert_eq!(infos[0].expires_at(), Some(now));
+    assert_eq!(infos[1].expires_at(), None);
+    assert!(path.join(ACCESS_INDEX).exists());
+    fs::remove_dir_all(&path).unwrap();
+}
+
+// @tc.name: ut_cache_file_stored_namespaces
+// @tc.desc: Test listing the namespaces stored in the cache directory
+// @tc.precon: NA
+// @tc.step: 1. Create the directory of a namespace
+//           2. Call stored_namespaces function
+// @tc.expect: The namespace is listed
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_file_stored_namespaces() {
+    init();
+    const TEST_NAMESPACE: &str = "ut_stored_namespaces";
+
+    init_curr_store_dir();
+    let path = unsafe { FILE_STORE_DIR.dir(Some(TEST_NAMESPACE)).unwrap() };
+
+    let namespaces = stored_namespaces();
+    assert!(namespaces.iter().any(|name| name == TEST_NAMESPACE));
+    fs::remove_dir_all(&path).unwrap();
 }
@@ -0,0 +1,59 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_entry_meta_serialize
+// @tc.desc: Test serialization of the response metadata of an entry
+// @tc.precon: NA
+// @tc.step: 1. Create a metadata with a URL and headers containing colons
+//           2. Serialize and deserialize it
+// @tc.expect: The URL, creation time and headers are preserved
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_entry_meta_serialize() {
+    let headers = HashMap::from([
+        ("content-type".to_string(), "image/png".to_string()),
+        (
+            "last-modified".to_string(),
+            "Wed, 21 Oct 2015 07:28:00 GMT".to_string(),
+        ),
+    ]);
+    let meta = EntryMeta::new(
+        Some("https://example.com:8080/a.png".to_string()),
+        1000,
+        headers.clone(),
+    );
+    let meta = EntryMeta::deserialize(&meta.serialize());
+    assert_eq!(meta.url.as_deref(), Some("https://example.com:8080/a.png"));
+    assert_eq!(meta.created, 1000);
+    assert_eq!(meta.headers, headers);
+}
+
+// @tc.name: ut_entry_meta_deserialize_invalid
+// @tc.desc: Test deserialization of an invalid metadata
+// @tc.precon: NA
+// @tc.step: 1. Deserialize a metadata with unknown and malformed lines
+// @tc.expect: Invalid lines are ignored
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_entry_meta_deserialize_invalid() {
+    let meta = EntryMeta::deserialize("unknown:1\ncreated:x\nheader:invalid\nnoise\n");
+    assert!(meta.url.is_none());
+    assert_eq!(meta.created, 0);
+    assert!(meta.headers.is_empty());
+}
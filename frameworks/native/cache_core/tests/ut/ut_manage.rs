@@ -20,6 +20,7 @@ use request_utils::fastrand::fast_random;
 use request_utils::test::log::init;
 
 use super::*;
+use crate::Updater;
 const TEST_STRING: &str = "你这猴子真让我欢喜";
 const TEST_STRING_SIZE: usize = TEST_STRING.len();
 
@@ -57,7 +58,7 @@ fn ut_cache_manager_update_file() {
         .task_id()
         .clone();
     // files contain cache
-    let mut file = FileCache::open(&task_id).unwrap();
+    let mut file = FileCache::open(&task_id, None).unwrap();
     let mut buf = String::new();
     file.read_to_string(&mut buf).unwrap();
     assert_eq!(buf, TEST_STRING);
@@ -209,3 +210,345 @@ fn ut_cache_manager_update_same() {
     cache.cursor().read_to_string(&mut buf).unwrap();
     assert_eq!(buf, test_string);
 }
+
+// @tc.name: ut_cache_manager_sweep_expired
+// @tc.desc: Test cache manager removes expired file caches
+// @tc.precon: NA
+// @tc.step: 1. Set a zero max age for file caches
+//           2. Create file cache with test data
+//           3. Call sweep_expired method
+// @tc.expect: The expired entry and its file are removed
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_manager_sweep_expired() {
+    init();
+    let task_id = TaskId::new(fast_random().to_string());
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_max_age(Some(Duration::ZERO));
+
+    let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+    cache.write_all(TEST_STRING.as_bytes()).unwrap();
+    cache.finish_write();
+    thread::sleep(Duration::from_millis(100));
+    assert!(CACHE_MANAGER.contains(&task_id));
+
+    CACHE_MANAGER.sweep_expired();
+    assert!(!CACHE_MANAGER.contains(&task_id));
+    assert!(FileCache::open(&task_id, None).is_err());
+}
+
+// @tc.name: ut_cache_manager_set_max_age
+// @tc.desc: Test per-entry max age overrides the default max age
+// @tc.precon: NA
+// @tc.step: 1. Set a zero default max age and no max age for one task
+//           2. Create file caches for that task and another one
+//           3. Call sweep_expired method
+// @tc.expect: Only the entry without its own max age is removed
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_manager_set_max_age() {
+    init();
+    let kept = TaskId::new(fast_random().to_string());
+    let expired = TaskId::new(fast_random().to_string());
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_max_age(Some(Duration::ZERO));
+    CACHE_MANAGER.set_max_age(&kept, None);
+
+    for task_id in [&kept, &expired] {
+        let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+        cache.write_all(TEST_STRING.as_bytes()).unwrap();
+        cache.finish_write();
+    }
+    thread::sleep(Duration::from_millis(100));
+
+    CACHE_MANAGER.sweep_expired();
+    assert!(CACHE_MANAGER.contains(&kept));
+    assert!(!CACHE_MANAGER.contains(&expired));
+    CACHE_MANAGER.remove(kept);
+}
+
+// @tc.name: ut_cache_manager_file_lru_access
+// @tc.desc: Test accessed file caches are evicted last
+// @tc.precon: NA
+// @tc.step: 1. Limit file cache size to two entries
+//           2. Create two file caches and access the first one
+//           3. Create a third file cache
+// @tc.expect: The second entry is evicted, the accessed one is kept
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_manager_file_lru_access() {
+    init();
+    let task_ids = (0..3)
+        .map(|_| TaskId::new(fast_random().to_string()))
+        .collect::<Vec<_>>();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_size(TEST_STRING_SIZE as u64 * 2);
+
+    for (i, task_id) in task_ids.iter().enumerate() {
+        if i == 2 {
+            CACHE_MANAGER.get_cache(&task_ids[0]).unwrap();
+        }
+        let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+        cache.write_all(TEST_STRING.as_bytes()).unwrap();
+        cache.finish_write();
+        thread::sleep(Duration::from_millis(100));
+    }
+
+    let caches = CACHE_MANAGER.file_manager.caches.lock().unwrap();
+    assert!(caches.contains(&task_ids[0]));
+    assert!(!caches.contains(&task_ids[1]));
+    assert!(caches.contains(&task_ids[2]));
+}
+
+// @tc.name: ut_cache_manager_namespace
+// @tc.desc: Test cache manager of a namespace stores its own files
+// @tc.precon: NA
+// @tc.step: 1. Create a cache manager of a namespace
+//           2. Create file cache with test data
+//           3. Check the file location and the default cache manager
+// @tc.expect: The file is in the namespace directory only, and the default
+// cache manager does not restore it
+// @tc.type: FUNC
+// @tc.require: issue#ICN31I
+#[test]
+fn ut_cache_manager_namespace() {
+    init();
+    crate::init_curr_store_dir();
+    const NAMESPACE: &str = "ut_namespace";
+    let task_id = TaskId::new(fast_random().to_string());
+    static CACHE_MANAGER: LazyLock<CacheManager> =
+        LazyLock::new(|| CacheManager::with_namespace(NAMESPACE));
+    static DEFAULT_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    assert_eq!(CACHE_MANAGER.namespace(), Some(NAMESPACE));
+    assert_eq!(DEFAULT_MANAGER.namespace(), None);
+
+    let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+    cache.write_all(TEST_STRING.as_bytes()).unwrap();
+    cache.finish_write();
+    thread::sleep(Duration::from_millis(100));
+
+    assert!(FileCache::open(&task_id, Some(NAMESPACE)).is_ok());
+    assert!(FileCache::open(&task_id, None).is_err());
+    DEFAULT_MANAGER.build_cached_files_index();
+    assert!(!DEFAULT_MANAGER.contains(&task_id));
+
+    CACHE_MANAGER.remove(task_id.clone());
+    assert!(FileCache::open(&task_id, Some(NAMESPACE)).is_err());
+}
+
+// @tc.name: ut_cache_manager_pinned
+// @tc.desc: Test pinned file caches are not evicted for space or expiry
+// @tc.precon: NA
+// @tc.step: 1. Limit file cache size to two entries and pin the first task
+//           2. Create three file caches
+//           3. Set a zero max age and call sweep_expired method
+// @tc.expect: The pinned entry is kept although least recently used, and
+// survives the expiry sweep
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_cache_manager_pinned() {
+    init();
+    let task_ids = (0..3)
+        .map(|_| TaskId::new(fast_random().to_string()))
+        .collect::<Vec<_>>();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_file_cache_size(TEST_STRING_SIZE as u64 * 2);
+    CACHE_MANAGER.set_pinned(&task_ids[0], true);
+    assert!(CACHE_MANAGER.is_pinned(&task_ids[0]));
+
+    for task_id in task_ids.iter() {
+        let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+        cache.write_all(TEST_STRING.as_bytes()).unwrap();
+        cache.finish_write();
+        thread::sleep(Duration::from_millis(100));
+    }
+    {
+        let caches = CACHE_MANAGER.file_manager.caches.lock().unwrap();
+        assert!(caches.contains(&task_ids[0]));
+        assert!(!caches.contains(&task_ids[1]));
+        assert!(caches.contains(&task_ids[2]));
+    }
+
+    CACHE_MANAGER.set_file_cache_max_age(Some(Duration::ZERO));
+    CACHE_MANAGER.set_max_age(&task_ids[0], Some(Duration::ZERO));
+    CACHE_MANAGER.sweep_expired();
+    assert!(CACHE_MANAGER.contains(&task_ids[0]));
+
+    CACHE_MANAGER.remove(task_ids[0].clone());
+    assert!(!CACHE_MANAGER.is_pinned(&task_ids[0]));
+}
+
+// @tc.name: ut_cache_manager_entries
+// @tc.desc: Test listing the cache entries with their metadata
+// @tc.precon: NA
+// @tc.step: 1. Receive content with its response metadata and pin it
+//           2. Wait for the file to be written
+//           3. List the cache entries
+// @tc.expect: The entry is listed with its URL, size, headers, storage tiers
+// and pin
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_cache_manager_entries() {
+    init();
+    const URL: &str = "https://example.com/ut_cache_manager_entries";
+    let task_id = TaskId::new(fast_random().to_string());
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    assert!(CACHE_MANAGER.entries().is_empty());
+
+    let headers = HashMap::from([("content-type".to_string(), "image/png".to_string())]);
+    let mut updater = Updater::new(task_id.clone(), &CACHE_MANAGER);
+    updater.cache_receive(TEST_STRING.as_bytes(), || Some(TEST_STRING_SIZE));
+    updater.cache_meta(URL, &headers);
+    updater.cache_finish();
+    CACHE_MANAGER.set_pinned(&task_id, true);
+    thread::sleep(Duration::from_millis(100));
+
+    let entries = CACHE_MANAGER.entries();
+    assert_eq!(entries.len(), 1);
+    let entry = &entries[0];
+    assert!(entry.task_id() == &task_id);
+    assert_eq!(entry.namespace(), None);
+    assert_eq!(entry.url(), Some(URL));
+    assert_eq!(entry.size(), TEST_STRING_SIZE as u64);
+    assert!(entry.created() > 0);
+    assert!(entry.last_access() >= entry.created());
+    assert!(entry.in_ram());
+    assert!(entry.in_file());
+    assert!(entry.is_pinned());
+    assert_eq!(entry.headers(), &headers);
+
+    CACHE_MANAGER.rams.lock().unwrap().remove(&task_id);
+    let entries = CACHE_MANAGER.entries();
+    assert!(!entries[0].in_ram());
+    assert!(entries[0].in_file());
+
+    CACHE_MANAGER.remove(task_id);
+    assert!(CACHE_MANAGER.entries().is_empty());
+}
+
+// @tc.name: ut_cache_manager_entries_restore
+// @tc.desc: Test restoring the metadata and pins of the cache entries
+// @tc.precon: NA
+// @tc.step: 1. Receive content with its response metadata in a namespace
+//           2. Pin it and wait for the file to be written
+//           3. Restore the files with another cache manager of the namespace
+// @tc.expect: The restored entry keeps its URL, headers and pin
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_cache_manager_entries_restore() {
+    init();
+    crate::init_curr_store_dir();
+    const NAMESPACE: &str = "ut_entries_restore";
+    const URL: &str = "https://example.com/ut_cache_manager_entries_restore";
+    let task_id = TaskId::new(fast_random().to_string());
+    static CACHE_MANAGER: LazyLock<CacheManager> =
+        LazyLock::new(|| CacheManager::with_namespace(NAMESPACE));
+    static RESTORED: LazyLock<CacheManager> =
+        LazyLock::new(|| CacheManager::with_namespace(NAMESPACE));
+
+    let headers = HashMap::from([("etag".to_string(), "\"abc\"".to_string())]);
+    let mut updater = Updater::new(task_id.clone(), &CACHE_MANAGER);
+    updater.cache_receive(TEST_STRING.as_bytes(), || Some(TEST_STRING_SIZE));
+    updater.cache_meta(URL, &headers);
+    updater.cache_finish();
+    CACHE_MANAGER.set_pinned(&task_id, true);
+    thread::sleep(Duration::from_millis(100));
+
+    RESTORED.build_cached_files_index();
+    assert!(RESTORED.is_pinned(&task_id));
+    let entries = RESTORED.entries();
+    let entry = entries
+        .iter()
+        .find(|entry| entry.task_id() == &task_id)
+        .unwrap();
+    assert_eq!(entry.namespace(), Some(NAMESPACE));
+    assert_eq!(entry.url(), Some(URL));
+    assert_eq!(entry.headers(), &headers);
+    assert!(!entry.in_ram());
+    assert!(entry.in_file());
+
+    CACHE_MANAGER.remove(task_id.clone());
+    assert!(FileCache::read_meta(&task_id, Some(NAMESPACE)).is_none());
+}
+
+// @tc.name: ut_cache_manager_pinned_restore
+// @tc.desc: Test restoring a pinned file cache already expired
+// @tc.precon: NA
+// @tc.step: 1. Create a file cache in a namespace and pin it
+//           2. Set a zero max age and wait for the index to be written
+//           3. Restore the files with another cache manager of the namespace
+// @tc.expect: The pinned entry is restored instead of being removed
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_cache_manager_pinned_restore() {
+    init();
+    crate::init_curr_store_dir();
+    const NAMESPACE: &str = "ut_pinned_restore";
+    let task_id = TaskId::new(fast_random().to_string());
+    static CACHE_MANAGER: LazyLock<CacheManager> =
+        LazyLock::new(|| CacheManager::with_namespace(NAMESPACE));
+    static RESTORED: LazyLock<CacheManager> =
+        LazyLock::new(|| CacheManager::with_namespace(NAMESPACE));
+
+    CACHE_MANAGER.set_pinned(&task_id, true);
+    let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+    cache.write_all(TEST_STRING.as_bytes()).unwrap();
+    cache.finish_write();
+    thread::sleep(Duration::from_millis(100));
+    CACHE_MANAGER.set_max_age(&task_id, Some(Duration::ZERO));
+    thread::sleep(Duration::from_millis(100));
+
+    RESTORED.build_cached_files_index();
+    assert!(RESTORED.is_pinned(&task_id));
+    assert!(RESTORED
+        .file_manager
+        .caches
+        .lock()
+        .unwrap()
+        .contains(&task_id));
+
+    CACHE_MANAGER.remove(task_id.clone());
+    assert!(FileCache::open(&task_id, Some(NAMESPACE)).is_err());
+}
+
+// @tc.name: ut_cache_manager_clear_pinned
+// @tc.desc: Test clearing the file caches keeps the pinned ones
+// @tc.precon: NA
+// @tc.step: 1. Create two file caches and pin the first one
+//           2. Call clear_file_cache method without running tasks
+// @tc.expect: Only the unpinned entry is removed
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_cache_manager_clear_pinned() {
+    init();
+    let task_ids = (0..2)
+        .map(|_| TaskId::new(fast_random().to_string()))
+        .collect::<Vec<_>>();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    CACHE_MANAGER.set_pinned(&task_ids[0], true);
+
+    for task_id in task_ids.iter() {
+        let mut cache = RamCache::new(task_id.clone(), &CACHE_MANAGER, Some(TEST_STRING_SIZE));
+        cache.write_all(TEST_STRING.as_bytes()).unwrap();
+        cache.finish_write();
+        thread::sleep(Duration::from_millis(100));
+    }
+
+    CACHE_MANAGER.clear_file_cache(&HashSet::new());
+    {
+        let caches = CACHE_MANAGER.file_manager.caches.lock().unwrap();
+        assert!(caches.contains(&task_ids[0]));
+        assert!(!caches.contains(&task_ids[1]));
+    }
+
+    CACHE_MANAGER.remove(task_ids[0].clone());
+    assert!(!CACHE_MANAGER.is_pinned(&task_ids[0]));
+}
@@ -0,0 +1,198 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Sun, 06 Nov 1994 08:49:37 GMT in seconds since the epoch.
+const DATE: u64 = 784_111_777;
+
+fn headers(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+// @tc.name: ut_policy_parse_http_date
+// @tc.desc: Test parsing HTTP dates
+// @tc.precon: NA
+// @tc.step: 1. Parse valid and invalid IMF-fixdate dates
+// @tc.expect: Valid dates are converted to seconds since the epoch, invalid
+//             dates are rejected
+// @tc.type: FUNC
+#[test]
+fn ut_policy_parse_http_date() {
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Some(DATE));
+    assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+    assert_eq!(
+        parse_http_date("Tue, 29 Feb 2028 12:00:00 GMT"),
+        Some(1_835_438_400)
+    );
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 UTC"), None);
+    assert_eq!(parse_http_date("Sun, 06 Foo 1994 08:49:37 GMT"), None);
+    assert_eq!(parse_http_date("Sun, 06 Nov 1994 25:49:37 GMT"), None);
+    assert_eq!(
+        parse_http_date("Sun, 06 Nov 18446744073709551615 08:49:37 GMT"),
+        None
+    );
+    assert_eq!(parse_http_date("0"), None);
+}
+
+// @tc.name: ut_policy_freshness
+// @tc.desc: Test the freshness lifetime of cached responses
+// @tc.precon: NA
+// @tc.step: 1. Build policies from Cache-Control, Expires, Age and
+//              Last-Modified headers
+// @tc.expect: Entries are fresh for their lifetime only
+// @tc.type: FUNC
+#[test]
+fn ut_policy_freshness() {
+    let now = DATE * 1000;
+
+    let policy =
+        CachePolicy::from_headers(&headers(&[("cache-control", "public, max-age=60")]), now);
+    assert!(policy.is_fresh(now + 59_999));
+    assert!(!policy.is_fresh(now + 60_000));
+
+    let policy = CachePolicy::from_headers(
+        &headers(&[("cache-control", "max-age=60"), ("age", "20")]),
+        now,
+    );
+    assert!(!policy.is_fresh(now + 40_000));
+
+    let policy = CachePolicy::from_headers(
+        &headers(&[
+            ("date", "Sun, 06 Nov 1994 08:49:37 GMT"),
+            ("expires", "Sun, 06 Nov 1994 08:50:37 GMT"),
+        ]),
+        now,
+    );
+    assert!(policy.is_fresh(now + 59_999));
+    assert!(!policy.is_fresh(now + 60_000));
+
+    let policy = CachePolicy::from_headers(&headers(&[("expires", "0")]), now);
+    assert!(!policy.is_fresh(now));
+
+    let policy = CachePolicy::from_headers(
+        &headers(&[
+            ("cache-control", "max-age=18446744073709551615"),
+            ("age", "18446744073709551615"),
+        ]),
+        now,
+    );
+    assert!(!policy.is_fresh(now));
+
+    let policy = CachePolicy::from_headers(
+        &headers(&[
+            ("cache-control", "no-cache, max-age=60"),
+            ("etag", "\"v1\""),
+        ]),
+        now,
+    );
+    assert!(!policy.is_fresh(now));
+
+    let policy = CachePolicy::from_headers(
+        &headers(&[
+            ("date", "Sun, 06 Nov 1994 08:49:37 GMT"),
+            ("last-modified", "Sun, 06 Nov 1994 08:32:57 GMT"),
+        ]),
+        now,
+    );
+    assert!(policy.is_fresh(now + 99_999));
+    assert!(!policy.is_fresh(now + 100_000));
+}
+
+// @tc.name: ut_policy_without_freshness
+// @tc.desc: Test responses without freshness information
+// @tc.precon: NA
+// @tc.step: 1. Build policies from responses with and without validators
+// @tc.expect: Responses with validators are always revalidated, responses
+//             without are always fresh
+// @tc.type: FUNC
+#[test]
+fn ut_policy_without_freshness() {
+    let policy = CachePolicy::from_headers(&headers(&[]), 0);
+    assert!(policy.is_fresh(u64::MAX - 1));
+    assert!(!policy.has_validators());
+    assert!(CachePolicy::default().is_fresh(0));
+
+    let policy = CachePolicy::from_headers(&headers(&[("etag", "\"v1\"")]), 0);
+    assert!(!policy.is_fresh(0));
+    assert_eq!(
+        policy.conditional_headers(),
+        vec![("If-None-Match".to_string(), "\"v1\"".to_string())]
+    );
+}
+
+// @tc.name: ut_policy_revalidated
+// @tc.desc: Test refreshing a policy with a 304 response
+// @tc.precon: NA
+// @tc.step: 1. Revalidate a stale entry with a 304 response carrying a new
+//              max-age but no validators
+// @tc.expect: The entry is fresh again and keeps its validators
+// @tc.type: FUNC
+#[test]
+fn ut_policy_revalidated() {
+    let stale = CachePolicy::from_headers(
+        &headers(&[
+            ("cache-control", "max-age=0"),
+            ("etag", "\"v1\""),
+            ("last-modified", "Sun, 06 Nov 1994 08:49:37 GMT"),
+        ]),
+        0,
+    );
+    assert!(!stale.is_fresh(0));
+
+    let policy = stale.revalidated(&headers(&[("cache-control", "max-age=60")]), 1000);
+    assert!(policy.is_fresh(1000));
+    assert_eq!(
+        policy.conditional_headers(),
+        vec![
+            ("If-None-Match".to_string(), "\"v1\"".to_string()),
+            (
+                "If-Modified-Since".to_string(),
+                "Sun, 06 Nov 1994 08:49:37 GMT".to_string()
+            ),
+        ]
+    );
+
+    let policy = stale.revalidated(&headers(&[("etag", "\"v2\"")]), 1000);
+    assert_eq!(
+        policy.conditional_headers()[0],
+        ("If-None-Match".to_string(), "\"v2\"".to_string())
+    );
+}
+
+// @tc.name: ut_policy_serialize
+// @tc.desc: Test persisting a policy
+// @tc.precon: NA
+// @tc.step: 1. Serialize and deserialize policies
+// @tc.expect: Policies round-trip, unknown lines are ignored
+// @tc.type: FUNC
+#[test]
+fn ut_policy_serialize() {
+    let policy = CachePolicy::from_headers(
+        &headers(&[
+            ("cache-control", "max-age=60"),
+            ("etag", "W/\"v1:2\""),
+            ("last-modified", "Sun, 06 Nov 1994 08:49:37 GMT"),
+        ]),
+        DATE * 1000,
+    );
+    assert_eq!(CachePolicy::deserialize(&policy.serialize()), policy);
+    assert_eq!(CachePolicy::deserialize(""), CachePolicy::default());
+    assert_eq!(
+        CachePolicy::deserialize("unknown:1\nexpires-at:abc\n"),
+        CachePolicy::default()
+    );
+}
@@ -0,0 +1,168 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::LazyLock;
+use std::thread;
+use std::time::Duration;
+
+use request_utils::fastrand::fast_random;
+use request_utils::task_id::TaskId;
+use request_utils::test::log::init;
+
+use super::*;
+use crate::{CacheManager, Updater};
+
+const TEST_STRING: &str = "你这猴子真让我欢喜";
+const TEST_STRING_SIZE: usize = TEST_STRING.len();
+
+/// Reads the whole content of a reader in chunks of 4 bytes.
+fn read_in_chunks(reader: &mut CacheReader) -> io::Result<Vec<u8>> {
+    let mut content = vec![];
+    let mut buf = [0u8; 4];
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            return Ok(content);
+        }
+        content.extend_from_slice(&buf[..n]);
+    }
+}
+
+// @tc.name: ut_cache_reader_none
+// @tc.desc: Test reader of an entry neither cached nor being received
+// @tc.precon: NA
+// @tc.step: 1. Open a reader of an unknown task
+// @tc.expect: No reader is returned
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_reader_none() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    let task_id = TaskId::new(fast_random().to_string());
+    assert!(CACHE_MANAGER.reader(&task_id).is_none());
+}
+
+// @tc.name: ut_cache_reader_ram
+// @tc.desc: Test reader of an entry cached in RAM
+// @tc.precon: NA
+// @tc.step: 1. Receive and finish the content of a task
+//           2. Read the content in chunks
+// @tc.expect: The content read matches the received content
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_reader_ram() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    let task_id = TaskId::new(fast_random().to_string());
+    let mut updater = Updater::new(task_id.clone(), &CACHE_MANAGER);
+    updater.cache_receive(TEST_STRING.as_bytes(), || Some(TEST_STRING_SIZE));
+    updater.cache_finish();
+
+    let mut reader = CACHE_MANAGER.reader(&task_id).unwrap();
+    assert!(!reader.is_receiving());
+    assert_eq!(read_in_chunks(&mut reader).unwrap(), TEST_STRING.as_bytes());
+}
+
+// @tc.name: ut_cache_reader_file
+// @tc.desc: Test reader of an entry cached in a file only
+// @tc.precon: NA
+// @tc.step: 1. Receive and finish the content of a task
+//           2. Wait for the file to be written and remove the RAM cache
+//           3. Read the content in chunks
+// @tc.expect: The content is read from the file without loading it into RAM
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_reader_file() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    let task_id = TaskId::new(fast_random().to_string());
+    let mut updater = Updater::new(task_id.clone(), &CACHE_MANAGER);
+    updater.cache_receive(TEST_STRING.as_bytes(), || Some(TEST_STRING_SIZE));
+    updater.cache_finish();
+    thread::sleep(Duration::from_millis(100));
+    CACHE_MANAGER.rams.lock().unwrap().remove(&task_id);
+
+    let mut reader = CACHE_MANAGER.reader(&task_id).unwrap();
+    assert_eq!(read_in_chunks(&mut reader).unwrap(), TEST_STRING.as_bytes());
+    assert!(!CACHE_MANAGER.rams.lock().unwrap().contains_key(&task_id));
+}
+
+// @tc.name: ut_cache_reader_receiving
+// @tc.desc: Test reader tailing an entry being received
+// @tc.precon: NA
+// @tc.step: 1. Receive the first part of the content of a task
+//           2. Read the content in another thread
+//           3. Receive the rest of the content and finish it
+// @tc.expect: The reader waits for the rest of the content and reads it all
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_reader_receiving() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    let task_id = TaskId::new(fast_random().to_string());
+    let (first, rest) = TEST_STRING.as_bytes().split_at(TEST_STRING_SIZE / 2);
+    let mut updater = Updater::new(task_id.clone(), &CACHE_MANAGER);
+    updater.cache_receive(first, || Some(TEST_STRING_SIZE));
+
+    let mut reader = CACHE_MANAGER.reader(&task_id).unwrap();
+    assert!(reader.is_receiving());
+    let handle = thread::spawn(move || {
+        let content = read_in_chunks(&mut reader).unwrap();
+        (content, reader.is_receiving())
+    });
+    thread::sleep(Duration::from_millis(50));
+    assert!(!handle.is_finished());
+
+    updater.cache_receive(rest, || Some(TEST_STRING_SIZE));
+    updater.cache_finish();
+    let (content, is_receiving) = handle.join().unwrap();
+    assert_eq!(content, TEST_STRING.as_bytes());
+    assert!(!is_receiving);
+    assert!(CACHE_MANAGER.receiving.lock().unwrap().is_empty());
+}
+
+// @tc.name: ut_cache_reader_receiving_abort
+// @tc.desc: Test reader tailing an entry whose receiving is aborted
+// @tc.precon: NA
+// @tc.step: 1. Receive the first part of the content of a task
+//           2. Read the content in another thread
+//           3. Drop the updater before finishing the content
+// @tc.expect: The reader fails with UnexpectedEof instead of waiting forever
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_reader_receiving_abort() {
+    init();
+    static CACHE_MANAGER: LazyLock<CacheManager> = LazyLock::new(CacheManager::new);
+    let task_id = TaskId::new(fast_random().to_string());
+    let mut updater = Updater::new(task_id.clone(), &CACHE_MANAGER);
+    updater.cache_receive(TEST_STRING.as_bytes(), || None);
+
+    let mut reader = CACHE_MANAGER.reader(&task_id).unwrap();
+    let handle = thread::spawn(move || read_in_chunks(&mut reader));
+    thread::sleep(Duration::from_millis(50));
+    drop(updater);
+
+    let err = handle.join().unwrap().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    assert!(CACHE_MANAGER.reader(&task_id).is_none());
+}
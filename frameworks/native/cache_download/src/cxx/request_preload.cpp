@@ -470,7 +470,7 @@ void Preload::Cancel(std::string const &url)
     if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(url.begin(), url.end()))) {
         return;
     }
-    agent_->cancel(rust::str(url));
+    agent_->ffi_cancel(rust::str(url));
 }
 
 /**
@@ -482,7 +482,7 @@ void Preload::Remove(std::string const &url)
     if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(url.begin(), url.end()))) {
         return;
     }
-    agent_->remove(rust::str(url));
+    agent_->ffi_remove(rust::str(url));
 }
 
 /**
@@ -508,7 +508,7 @@ bool Preload::Contains(const std::string &url)
     if (!Utf8Utils::RunUtf8Validation(std::vector<uint8_t>(url.begin(), url.end()))) {
         return false;
     }
-    return agent_->contains(rust::str(url));
+    return agent_->ffi_contains(rust::str(url));
 }
 
 void Preload::ClearMemoryCache()
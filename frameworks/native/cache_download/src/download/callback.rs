@@ -22,7 +22,7 @@ use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
-use cache_core::{CacheManager, Updater};
+use cache_core::{CacheManager, RamCache, Updater};
 use netstack_rs::info::DownloadInfo;
 use request_utils::task_id::TaskId;
 
@@ -30,6 +30,7 @@ use super::common::{CommonError, CommonResponse};
 use super::{CacheDownloadError, RUNNING};
 use crate::download::{CANCEL, FAIL, SUCCESS};
 use crate::info::RustDownloadInfo;
+use crate::key::{covers_vary, parse_vary};
 use crate::services::{CacheDownloadService, PreloadCallback};
 
 /// Interval for reporting progress updates.
@@ -46,6 +47,15 @@ pub(crate) struct TaskConfig {
     pub(crate) network_check_timeout: Option<u32>,
     /// HTTP total timeout in seconds (task override or global setting)
     pub(crate) http_total_timeout: Option<u32>,
+    /// Headers revalidating the cached content, empty if there is none
+    pub(crate) conditional_headers: Vec<(String, String)>,
+    /// Key recording the `Vary` header of the response, `None` if the
+    /// request does not honor it
+    pub(crate) vary_key: Option<TaskId>,
+    /// Lowercase names of the request headers that are part of the cache key
+    pub(crate) key_headers: Vec<String>,
+    /// URL of the content, recorded with the cache
+    pub(crate) url: String,
 }
 
 impl TaskConfig {
@@ -54,11 +64,19 @@ impl TaskConfig {
         max_retry: Option<usize>,
         network_check_timeout: Option<u32>,
         http_total_timeout: Option<u32>,
+        conditional_headers: Vec<(String, String)>,
+        vary_key: Option<TaskId>,
+        key_headers: Vec<String>,
+        url: String,
     ) -> Self {
         Self {
             max_retry,
             network_check_timeout,
             http_total_timeout,
+            conditional_headers,
+            vary_key,
+            key_headers,
+            url,
         }
     }
 }
@@ -151,6 +169,11 @@ impl PrimeCallback {
     pub(crate) fn http_total_timeout(&self) -> Option<u32> {
         self.config.http_total_timeout
     }
+
+    /// Returns the headers revalidating the cached content, if any.
+    pub(crate) fn conditional_headers(&self) -> &[(String, String)] {
+        &self.config.conditional_headers
+    }
 }
 
 impl PrimeCallback {
@@ -173,8 +196,63 @@ impl PrimeCallback {
         let code = response.code();
         info!("{} status {}", self.task_id.brief(), code);
 
-        // Finalize cache storage
+        let headers = response.headers();
+        let vary = headers.get("vary").map(String::as_str);
+        if let Some(vary_key) = &self.config.vary_key {
+            CacheDownloadService::get_instance().record_vary(vary_key, vary);
+            // A response varying on headers missing from its key would be
+            // served to the requests of the other variants.
+            if !covers_vary(&self.config.key_headers, &parse_vary(vary.unwrap_or(""))) {
+                info!("{} varies beyond its key, not cached", self.task_id.brief());
+                let cache = self.cache_handle.cache_discard();
+                self.notify_success(cache);
+                return;
+            }
+        }
+
+        // Finalize cache storage along with its freshness, validators and
+        // metadata
+        self.cache_handle.cache_policy(&headers);
+        self.cache_handle.cache_meta(&self.config.url, &headers);
         let cache = self.cache_handle.cache_finish();
+        self.notify_success(cache);
+    }
+
+    /// Handles a `304 Not Modified` answer to a conditional request.
+    ///
+    /// Refreshes the cached content with the response headers and notifies
+    /// all registered callbacks of the successful completion with it, as for a
+    /// completed download.
+    ///
+    /// # Type Parameters
+    /// * `R` - Type implementing `CommonResponse` containing the HTTP headers
+    ///
+    /// # Arguments
+    /// * `response` - The 304 response
+    ///
+    /// # Returns
+    /// `false` if the content is no longer cached, in which case nothing is
+    /// notified
+    pub(crate) fn common_not_modified<R>(&mut self, response: R) -> bool
+    where
+        R: CommonResponse,
+    {
+        self.cache_handle.reset_cache();
+        match self.cache_handle.cache_revalidated(&response.headers()) {
+            Some(cache) => {
+                self.notify_success(cache);
+                true
+            }
+            None => {
+                error!("{} not modified but not cached", self.task_id.brief());
+                false
+            }
+        }
+    }
+
+    /// Marks the download as successful and notifies all registered callbacks
+    /// with its content.
+    fn notify_success(&mut self, cache: Arc<RamCache>) {
         // Update task state to success
         self.state.store(SUCCESS, Ordering::Release);
         self.finish.store(true, Ordering::Release);
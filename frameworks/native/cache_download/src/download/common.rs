@@ -16,6 +16,8 @@
 //! This module defines common interfaces used across download implementations,
 //! including traits for responses, errors, and operation handles.
 
+use std::collections::HashMap;
+
 /// Common interface for response objects.
 ///
 /// Provides a consistent way to access status codes from different response
@@ -26,6 +28,14 @@ pub(crate) trait CommonResponse {
     /// # Returns
     /// The status code as a 32-bit unsigned integer.
     fn code(&self) -> u32;
+
+    /// Returns the response headers.
+    ///
+    /// # Returns
+    /// The headers, with lowercase names, empty by default.
+    fn headers(&self) -> HashMap<String, String> {
+        HashMap::new()
+    }
 }
 
 /// Common interface for error objects.
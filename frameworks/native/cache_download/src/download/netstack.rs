@@ -13,13 +13,14 @@
 
 //! Netstack client integration for cache download operations.
 
+use std::collections::HashMap;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
-use netstack_rs::error::HttpClientError;
+use netstack_rs::error::{HttpClientError, HttpErrorCode};
 use netstack_rs::info::{DownloadInfo, DownloadInfoMgr};
 use netstack_rs::request::{Request, RequestCallback};
-use netstack_rs::response::Response;
+use netstack_rs::response::{Response, ResponseCode};
 use netstack_rs::task::RequestTask;
 use netstack_rs::{DEFAULT_MAX_RETRY_COUNT, DEFAULT_NETWORK_CHECK_TIMEOUT};
 use request_utils::error;
@@ -33,6 +34,11 @@ impl<'a> CommonResponse for Response<'a> {
     fn code(&self) -> u32 {
         self.status() as u32
     }
+
+    /// Returns the HTTP response headers.
+    fn headers(&self) -> HashMap<String, String> {
+        Response::headers(self)
+    }
 }
 
 impl CommonError for HttpClientError {
@@ -63,6 +69,20 @@ impl RequestCallback for PrimeCallback {
         self.common_fail(error, info);
     }
 
+    /// Called when a conditional request is answered with 304 Not Modified.
+    ///
+    /// Delegates to `common_not_modified` to serve the cached content, and
+    /// falls back to `on_fail` if it is no longer cached.
+    fn on_not_modified(&mut self, response: Response, info: DownloadInfo) {
+        if !self.common_not_modified(response) {
+            let error = HttpClientError::new(
+                HttpErrorCode::HttpNoneErr,
+                (ResponseCode::NotModified as u32).to_string(),
+            );
+            self.common_fail(error, info);
+        }
+    }
+
     /// Called when the download request is canceled.
     ///
     /// Delegates to `common_cancel` to update state and notify callbacks.
@@ -145,8 +165,17 @@ impl DownloadTask {
         // Create and configure request
         let mut request: Request<PrimeCallback> = Request::new();
         request.url(input.url);
+        let mut user_conditional = false;
         if let Some(headers) = input.headers {
             for (key, value) in headers {
+                user_conditional |= key.eq_ignore_ascii_case("if-none-match")
+                    || key.eq_ignore_ascii_case("if-modified-since");
+                request.header(key, value);
+            }
+        }
+        // Revalidate the cached content, unless the caller did it already
+        if !user_conditional {
+            for (key, value) in callback.conditional_headers() {
                 request.header(key, value);
             }
         }
@@ -358,6 +358,18 @@ where
         .http_total_timeout
         .or(Some(service.get_global_http_total_timeout()));
 
+    // Revalidate the cached content, if any, instead of downloading it again
+    let conditional_headers = cache_manager
+        .conditional_headers(&task_id)
+        .unwrap_or_default();
+    let vary_key = crate::services::vary_key(&request);
+    let key_headers = match (&vary_key, &request.cache_key) {
+        (Some(vary_key), Some(cache_key)) => {
+            cache_key.header_names(&service.recorded_vary(vary_key))
+        }
+        _ => vec![],
+    };
+
     let callback = PrimeCallback::new(
         task_id,
         cache_manager,
@@ -365,7 +377,15 @@ where
         handle.state_flag(),
         handle.callbacks(),
         seq,
-        super::callback::TaskConfig::new(max_retry, network_check_timeout, http_total_timeout),
+        super::callback::TaskConfig::new(
+            max_retry,
+            network_check_timeout,
+            http_total_timeout,
+            conditional_headers,
+            vary_key,
+            key_headers,
+            request.url.to_string(),
+        ),
     );
     downloader(request, callback, info_mgr).map(move |command| {
         handle.set_handle(command);
@@ -58,6 +58,10 @@ impl CommonResponse for Response {
     fn code(&self) -> u32 {
         self.status().as_u16() as u32
     }
+
+    fn headers(&self) -> HashMap<String, String> {
+        self.headers.clone()
+    }
 }
 
 /// Download operator that processes download events and reports progress.
@@ -148,7 +152,7 @@ impl DownloadTask {
         let handle = Arc::new(CancelHandle::new(flag.clone()));
         
         // Process request headers if provided
-        let mut headers = None;
+        let mut headers: Option<Vec<(String, String)>> = None;
         if let Some(h) = request.headers {
             headers = Some(
                 h.iter()
@@ -156,6 +160,17 @@ impl DownloadTask {
                     .collect(),
             );
         }
+
+        // Revalidate the cached content, unless the caller did it already
+        let user_conditional = headers.iter().flatten().any(|(key, _)| {
+            key.eq_ignore_ascii_case("if-none-match")
+                || key.eq_ignore_ascii_case("if-modified-since")
+        });
+        if !user_conditional && !callback.conditional_headers().is_empty() {
+            headers
+                .get_or_insert_with(Vec::new)
+                .extend_from_slice(callback.conditional_headers());
+        }
         
         // Spawn an asynchronous task to perform the download
         ylong_runtime::spawn(async move {
@@ -209,16 +224,26 @@ pub async fn download(
     // Send the request using the configured client
     let response = client().request(request).await?;
     let status = response.status();
+    let headers: HashMap<String, String> = response
+        .headers()
+        .into_iter()
+        .map(|(key, value)| (key.to_string().to_lowercase(), value.to_string().unwrap()))
+        .collect();
+
+    // Serve the cached content the server reports as not modified
+    if status.as_u16() == 304 {
+        let response = Response { status, headers };
+        if !callback.common_not_modified(response) {
+            return Err(HttpClientError::other("not modified but not cached"));
+        }
+        return Ok(());
+    }
 
     // Create download operator with the callback and headers
     let operator = Operator {
         callback: callback,
         abort_flag: abort_flag,
-        headers: response
-            .headers()
-            .into_iter()
-            .map(|(key, value)| (key.to_string(), value.to_string().unwrap()))
-            .collect(),
+        headers: headers.clone(),
     };
     
     // Build and run the downloader
@@ -229,7 +254,7 @@ pub async fn download(
     downloader.download().await?;
 
     // Notify the callback of successful completion
-    let response = Response { status: status };
+    let response = Response { status, headers };
     callback.common_success(response);
     Ok(())
 }
@@ -239,6 +264,7 @@ pub async fn download(
 /// Provides a simplified view of the HTTP response for the download system.
 pub struct Response {
     status: StatusCode,
+    headers: HashMap<String, String>,
 }
 
 impl Response {
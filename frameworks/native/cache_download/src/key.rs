@@ -0,0 +1,221 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cache keys of the preloaded content.
+//!
+//! By default, preloaded content is cached under the hash of its full URL. A
+//! `CacheKey` customizes the string hashed into the task ID, so that URLs
+//! differing only by volatile query parameters share a cache entry, while
+//! responses depending on request headers get one entry per variant.
+
+/// Strategy building the cache key of a download request.
+///
+/// The key is made of a base, the URL without its ignored query parameters
+/// or an explicit key, followed by the values of the selected request
+/// headers.
+///
+/// # Examples
+///
+/// ```rust
+/// use cache_download::{CacheKey, DownloadRequest};
+///
+/// let mut key = CacheKey::new();
+/// key.ignore_query(vec!["token", "expires"])
+///     .headers(vec!["Accept-Language"])
+///     .vary(true);
+///
+/// let mut request = DownloadRequest::new("https://example.com/a.png?token=1");
+/// request.cache_key(key);
+/// ```
+#[derive(Clone, Default)]
+pub struct CacheKey<'a> {
+    /// Explicit key replacing the URL.
+    pub key: Option<&'a str>,
+    /// Names of the query parameters removed from the URL.
+    pub ignored_query: Vec<&'a str>,
+    /// Names of the request headers whose values are part of the key.
+    pub headers: Vec<&'a str>,
+    /// Whether the request headers listed in the `Vary` header of the
+    /// response are part of the key.
+    pub vary: bool,
+}
+
+impl<'a> CacheKey<'a> {
+    /// Creates a cache key made of the full URL.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets an explicit key replacing the URL.
+    ///
+    /// # Arguments
+    /// * `key` - The key, identifying the content whatever its URL
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    pub fn key(&mut self, key: &'a str) -> &mut Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Sets the query parameters removed from the URL, such as rotating
+    /// signatures of CDN URLs.
+    ///
+    /// # Arguments
+    /// * `params` - Names of the ignored query parameters
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    pub fn ignore_query(&mut self, params: Vec<&'a str>) -> &mut Self {
+        self.ignored_query = params;
+        self
+    }
+
+    /// Sets the request headers whose values are part of the key.
+    ///
+    /// # Arguments
+    /// * `names` - Names of the headers, case-insensitive
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    pub fn headers(&mut self, names: Vec<&'a str>) -> &mut Self {
+        self.headers = names;
+        self
+    }
+
+    /// Sets whether the request headers listed in the `Vary` header of the
+    /// response are part of the key.
+    ///
+    /// The `Vary` header is learned from the responses, the first one being
+    /// served but not cached since its key lacks the headers it lists. Only
+    /// the recorded `Vary` is kept in memory, so a response is downloaded
+    /// again after a restart before its variants are served from the cache.
+    ///
+    /// # Arguments
+    /// * `vary` - Whether to honor `Vary`
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    pub fn vary(&mut self, vary: bool) -> &mut Self {
+        self.vary = vary;
+        self
+    }
+
+    /// Returns the base of the key of a URL, before any request header.
+    pub(crate) fn base(&self, url: &str) -> String {
+        match self.key {
+            Some(key) => key.to_string(),
+            None => strip_query(url, &self.ignored_query),
+        }
+    }
+
+    /// Returns the key of a request.
+    ///
+    /// # Arguments
+    /// * `url` - URL of the request
+    /// * `headers` - Headers of the request
+    /// * `vary` - Lowercase header names of the last `Vary` of the responses
+    pub(crate) fn source(&self, url: &str, headers: &[(&str, &str)], vary: &[String]) -> String {
+        let mut names = self.header_names(vary);
+        // `Vary: *` makes every request header part of the key
+        if names.iter().any(|name| name == "*") {
+            names.extend(headers.iter().map(|(name, _)| name.to_ascii_lowercase()));
+            names.retain(|name| name != "*");
+        }
+        names.sort();
+        names.dedup();
+
+        let mut source = self.base(url);
+        for name in names {
+            let value = headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(&name))
+                .map_or("", |(_, v)| *v);
+            source.push_str(&format!("\n{}:{}", name, value));
+        }
+        source
+    }
+
+    /// Returns the lowercase names of the request headers that are part of
+    /// the key, `*` standing for every header.
+    ///
+    /// # Arguments
+    /// * `vary` - Lowercase header names of the last `Vary` of the responses
+    pub(crate) fn header_names(&self, vary: &[String]) -> Vec<String> {
+        let mut names = self
+            .headers
+            .iter()
+            .map(|name| name.to_ascii_lowercase())
+            .chain(vary.iter().cloned())
+            .collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        names
+    }
+}
+
+/// Checks if a key made of the given request headers distinguishes every
+/// variant of a response.
+///
+/// # Arguments
+/// * `names` - Lowercase names of the request headers of the key, as
+///   returned by `CacheKey::header_names`
+/// * `vary` - Lowercase header names of the `Vary` of the response
+pub(crate) fn covers_vary(names: &[String], vary: &[String]) -> bool {
+    names.iter().any(|name| name == "*") || vary.iter().all(|name| names.contains(name))
+}
+
+/// Parses the value of a `Vary` header into lowercase header names.
+pub(crate) fn parse_vary(vary: &str) -> Vec<String> {
+    let mut names = vary
+        .split(',')
+        .map(|name| name.trim().to_ascii_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Removes the given query parameters from a URL.
+///
+/// The query is dropped if no parameter remains, and the fragment is kept.
+fn strip_query(url: &str, ignored: &[&str]) -> String {
+    if ignored.is_empty() {
+        return url.to_string();
+    }
+    let (url, fragment) = match url.find('#') {
+        Some(i) => url.split_at(i),
+        None => (url, ""),
+    };
+    let Some((path, query)) = url.split_once('?') else {
+        return format!("{}{}", url, fragment);
+    };
+    let query = query
+        .split('&')
+        .filter(|param| {
+            let name = param.split('=').next().unwrap_or_default();
+            !param.is_empty() && !ignored.contains(&name)
+        })
+        .collect::<Vec<_>>();
+    if query.is_empty() {
+        format!("{}{}", path, fragment)
+    } else {
+        format!("{}?{}{}", path, query.join("&"), fragment)
+    }
+}
+
+#[cfg(test)]
+mod ut_key {
+    include!("../tests/ut/ut_key.rs");
+}
@@ -41,16 +41,20 @@ mod download;
 
 // Public modules exposing API interfaces
 pub mod info; // Download information and metrics
+pub mod key; // Cache keys of the preloaded content
 pub mod observe; // Observation and monitoring functionality
 pub mod services; // Service interfaces and types
 
 // Re-export downloader enum for public API use
-/// Re-export of the in-memory RAM cache type from `cache_core`.
-pub use cache_core::RamCache;
+/// Re-export of the cache entry, in-memory RAM cache and streaming reader
+/// types from `cache_core`.
+pub use cache_core::{CacheEntry, CacheReader, RamCache};
 /// Re-export of the downloader implementation.
 pub use download::task::Downloader;
 /// Re-export of the cache download error type and its error kind enum.
 pub use download::{CacheDownloadError, ErrorKind};
+/// Re-export of the cache key strategy of download requests.
+pub use key::CacheKey;
 /// Re-export of the cache download service, request, and preload callback types.
 pub use services::{CacheDownloadService, DownloadRequest, PreloadCallback};
 
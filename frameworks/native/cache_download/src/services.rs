@@ -22,9 +22,10 @@ use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, Once, OnceLock};
+use std::time::Duration;
 
 // External dependencies
-use cache_core::{CacheManager, RamCache};
+use cache_core::{CacheEntry, CacheManager, CacheReader, RamCache};
 use netstack_rs::info::{DownloadInfo, DownloadInfoMgr};
 use request_utils::observe::network::NetRegistrar;
 use request_utils::task_id::TaskId;
@@ -33,11 +34,30 @@ use request_utils::task_id::TaskId;
 use crate::download::task::{DownloadTask, Downloader, TaskHandle};
 use crate::download::CacheDownloadError;
 use crate::info::RustDownloadInfo;
+use crate::key::{parse_vary, CacheKey};
 use crate::observe::NetObserver;
 
 /// Max `http_total_timeout` (seconds) before the `* 1000` ms conversion overflows `u32`.
 const MAX_HTTP_TOTAL_TIMEOUT: u32 = u32::MAX / 1000;
 
+/// Max length of a cache namespace name.
+const MAX_NAMESPACE_LEN: usize = 64;
+
+/// Max number of cache namespaces, bounding the total cache quota.
+const MAX_NAMESPACES: usize = 8;
+
+/// RAM cache size of a cache namespace until it is set.
+const DEFAULT_NAMESPACE_RAM_CACHE_SIZE: u64 = 1024 * 1024 * 4;
+
+/// File cache size of a cache namespace until it is set.
+const DEFAULT_NAMESPACE_FILE_CACHE_SIZE: u64 = 1024 * 1024 * 20;
+
+/// Max RAM cache size of a cache namespace.
+const MAX_NAMESPACE_RAM_CACHE_SIZE: u64 = 1024 * 1024 * 20;
+
+/// Max file cache size of a cache namespace.
+const MAX_NAMESPACE_FILE_CACHE_SIZE: u64 = 1024 * 1024 * 100;
+
 /// Trait defining callback methods for preload operations.
 ///
 /// Implementations of this trait receive notifications about various download
@@ -79,6 +99,12 @@ pub struct CacheDownloadService {
     running_tasks: Mutex<HashMap<TaskId, Arc<Mutex<DownloadTask>>>>,
     /// Manager for handling cached content in memory and on disk.
     cache_manager: CacheManager,
+    /// Caches of the named namespaces, created on first use or restored at
+    /// startup, at most `MAX_NAMESPACES`.
+    namespaces: Mutex<HashMap<String, &'static NamespaceCache>>,
+    /// Lowercase header names of the last `Vary` of the responses, by the
+    /// base key of the requests honoring it.
+    varies: Mutex<HashMap<TaskId, Vec<String>>>,
     /// Manager for storing and retrieving download information metrics.
     info_mgr: Arc<DownloadInfoMgr>,
     /// Registrar for network state observation and notifications.
@@ -111,6 +137,10 @@ pub struct DownloadRequest<'a> {
     pub network_check_timeout: Option<u32>,
     /// Optional HTTP total timeout in seconds (overrides global setting)
     pub http_total_timeout: Option<u32>,
+    /// Optional cache namespace storing the downloaded content
+    pub namespace: Option<&'a str>,
+    /// Optional strategy building the cache key, the full URL by default
+    pub cache_key: Option<CacheKey<'a>>,
 }
 
 impl<'a> DownloadRequest<'a> {
@@ -133,6 +163,8 @@ impl<'a> DownloadRequest<'a> {
             max_retry: None,
             network_check_timeout: None,
             http_total_timeout: None,
+            namespace: None,
+            cache_key: None,
         }
     }
 
@@ -222,6 +254,37 @@ impl<'a> DownloadRequest<'a> {
         self.http_total_timeout = Some(timeout);
         self
     }
+
+    /// Sets the cache namespace storing the downloaded content.
+    ///
+    /// Each namespace has its own RAM and file quotas and eviction, so the
+    /// preloads of one namespace never evict the entries of another. Without
+    /// a namespace, the content is stored in the default one. At most
+    /// `MAX_NAMESPACES` namespaces can be used, the requests to further ones
+    /// failing.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace, made of ASCII letters, digits,
+    ///   `_`, `-` and `.`, not starting with `.`
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    pub fn namespace(&mut self, namespace: &'a str) -> &mut Self {
+        self.namespace = Some(namespace);
+        self
+    }
+
+    /// Sets the strategy building the cache key of the downloaded content.
+    ///
+    /// # Arguments
+    /// * `cache_key` - The cache key strategy
+    ///
+    /// # Returns
+    /// A mutable reference to self for method chaining
+    pub fn cache_key(&mut self, cache_key: CacheKey<'a>) -> &mut Self {
+        self.cache_key = Some(cache_key);
+        self
+    }
 }
 
 impl CacheDownloadService {
@@ -233,6 +296,8 @@ impl CacheDownloadService {
         Self {
             running_tasks: Mutex::new(HashMap::new()),
             cache_manager: CacheManager::new(),
+            namespaces: Mutex::new(HashMap::new()),
+            varies: Mutex::new(HashMap::new()),
             info_mgr: Arc::new(DownloadInfoMgr::new()),
             net_registrar: NetRegistrar::new(),
             restore_finished: Arc::new(AtomicBool::new(false)),
@@ -267,6 +332,10 @@ impl CacheDownloadService {
             crate::spawn(|| {
                 // Restore cached files from previous sessions
                 cache_download.cache_manager.build_cached_files_index();
+                cache_download.cache_manager.start_expiry_sweep();
+                for namespace in cache_core::stored_namespaces() {
+                    cache_download.namespace_cache(Some(&namespace));
+                }
                 cache_download
                     .restore_finished
                     .store(true, Ordering::SeqCst);
@@ -281,12 +350,12 @@ impl CacheDownloadService {
         cache_download
     }
 
-    /// Cancels a download task identified by URL.
+    /// Cancels the download task of a request.
     ///
     /// # Arguments
-    /// * `url` - URL of the download task to cancel
-    pub fn cancel(&self, url: &str) {
-        let task_id = TaskId::from_url(url);
+    /// * `request` - The request the task was preloaded with
+    pub fn cancel(&self, request: &DownloadRequest) {
+        let task_id = self.task_id(request);
         if let Some(updater) = self.running_tasks.lock().unwrap().get(&task_id).cloned() {
             updater.lock().unwrap().cancel();
         }
@@ -302,32 +371,35 @@ impl CacheDownloadService {
         }
     }
 
-    /// Removes a cached item identified by URL.
+    /// Removes the cached content of a request.
     ///
     /// # Arguments
-    /// * `url` - URL of the cached item to remove
-    pub fn remove(&self, url: &str) {
-        let task_id = TaskId::from_url(url);
-        self.cache_manager.remove(task_id);
+    /// * `request` - The request the content was preloaded with
+    pub fn remove(&'static self, request: &DownloadRequest) {
+        if let Some((cache_manager, task_id)) = self.request_cache(request) {
+            cache_manager.remove(task_id);
+        }
     }
 
-    /// Checks if a URL is already cached.
+    /// Checks if the content of a request is already cached.
     ///
     /// # Arguments
-    /// * `url` - URL to check in the cache
+    /// * `request` - The request the content was preloaded with
     ///
     /// # Returns
-    /// `true` if the URL is in the cache, `false` otherwise
-    pub fn contains(&self, url: &str) -> bool {
-        let task_id = TaskId::from_url(url);
-        self.cache_manager.contains(&task_id)
+    /// `true` if the content is in the cache, `false` otherwise
+    pub fn contains(&'static self, request: &DownloadRequest) -> bool {
+        self.request_cache(request)
+            .is_some_and(|(cache_manager, task_id)| cache_manager.contains(&task_id))
     }
 
     /// Preloads content from a URL into the cache.
     ///
     /// Initiates a download operation for the specified URL, optionally
     /// updating existing cached content, and using the provided callback
-    /// for progress notifications.
+    /// for progress notifications. Fresh cached content is served directly,
+    /// while stale cached content with validators is revalidated with a
+    /// conditional request, a `304 Not Modified` answer serving it again.
     ///
     /// # Arguments
     /// * `request` - Download request with URL and optional configuration
@@ -346,16 +418,32 @@ impl CacheDownloadService {
         downloader: Downloader,
     ) -> Option<TaskHandle> {
         let url = request.url;
-        let task_id = TaskId::from_url(url);
+        let Some((cache_manager, restore_finished)) = self.namespace_cache(request.namespace)
+        else {
+            error!("preload with invalid cache namespace");
+            return None;
+        };
+        let task_id = self.task_id(&request);
         info!("preload {}", task_id.brief());
 
-        // Try to fetch from cache first if not updating
+        // Try to fetch from cache first if not updating and still fresh, stale
+        // content is revalidated by the download task
         if !update {
-            match self.callback_from_local_file(&task_id, callback) {
-                CallbackResult::Completed(handle) => return Some(handle),
-                CallbackResult::Failed(cb) => {
-                    callback = cb;
+            if cache_manager.is_fresh(&task_id) {
+                let res = Self::callback_from_local_file(
+                    cache_manager,
+                    restore_finished,
+                    &task_id,
+                    callback,
+                );
+                match res {
+                    CallbackResult::Completed(handle) => return Some(handle),
+                    CallbackResult::Failed(cb) => {
+                        callback = cb;
+                    }
                 }
+            } else {
+                info!("{} cache stale", task_id.brief());
             }
         }
 
@@ -367,7 +455,7 @@ impl CacheDownloadService {
                     // Create new download task if none exists
                     let download_task = DownloadTask::new(
                         task_id.clone(),
-                        &self.cache_manager,
+                        cache_manager,
                         self.info_mgr.clone(),
                         request,
                         callback,
@@ -392,7 +480,9 @@ impl CacheDownloadService {
                 Err(mut cb) => {
                     if update {
                         info!("add callback failed, update task {}", task_id.brief());
-                    } else if let Err(callback) = self.fetch_with_callback(&task_id, cb) {
+                    } else if let Err(callback) =
+                        Self::fetch_with_callback(cache_manager, &task_id, cb)
+                    {
                         error!("{} fetch fail after update", task_id.brief());
                         cb = callback;
                     } else {
@@ -407,7 +497,7 @@ impl CacheDownloadService {
                         let seq = updater.seq + 1;
                         let download_task = DownloadTask::new(
                             task_id.clone(),
-                            &self.cache_manager,
+                            cache_manager,
                             self.info_mgr.clone(),
                             request,
                             cb,
@@ -429,16 +519,66 @@ impl CacheDownloadService {
         }
     }
 
-    /// Fetches cached content for a URL.
+    /// Fetches the cached content of a request.
     ///
     /// # Arguments
-    /// * `url` - URL of the content to fetch
+    /// * `request` - The request the content was preloaded with
     ///
     /// # Returns
     /// An optional Arc to the cached content if found
-    pub fn fetch(&'static self, url: &str) -> Option<Arc<RamCache>> {
-        let task_id = TaskId::from_url(url);
-        self.cache_manager.fetch(&task_id)
+    pub fn fetch(&'static self, request: &DownloadRequest) -> Option<Arc<RamCache>> {
+        let (cache_manager, task_id) = self.request_cache(request)?;
+        cache_manager.fetch(&task_id)
+    }
+
+    /// Opens a streaming reader of the cached content of a request.
+    ///
+    /// The content is read without being loaded whole into memory, and
+    /// content still being preloaded is read while it is downloaded.
+    ///
+    /// # Arguments
+    /// * `request` - The request the content was preloaded with
+    ///
+    /// # Returns
+    /// A reader of the content if it is cached or being preloaded
+    pub fn reader(&'static self, request: &DownloadRequest) -> Option<CacheReader> {
+        let (cache_manager, task_id) = self.request_cache(request)?;
+        cache_manager.reader(&task_id)
+    }
+
+    /// Lists the cached entries of the default cache and of the namespaces.
+    ///
+    /// # Returns
+    /// The description of each cached entry, with its URL, size, times,
+    /// storage tier and response headers
+    pub fn cache_entries(&self) -> Vec<CacheEntry> {
+        let mut entries = self.cache_manager.entries();
+        let namespaces = self
+            .namespaces
+            .lock()
+            .unwrap()
+            .values()
+            .copied()
+            .collect::<Vec<_>>();
+        for cache in namespaces {
+            entries.extend(cache.cache_manager.entries());
+        }
+        entries
+    }
+
+    /// Pins or unpins the cached content of a request.
+    ///
+    /// Pinned content is never evicted for space or expiry, keeping it
+    /// available offline until it is removed. Content may be pinned before it
+    /// is preloaded.
+    ///
+    /// # Arguments
+    /// * `request` - The request the content is preloaded with
+    /// * `pinned` - Whether to pin the content
+    pub fn set_pinned(&'static self, request: &DownloadRequest, pinned: bool) {
+        if let Some((cache_manager, task_id)) = self.request_cache(request) {
+            cache_manager.set_pinned(&task_id, pinned);
+        }
     }
 
     /// Handles task completion notification.
@@ -469,6 +609,31 @@ impl CacheDownloadService {
         self.cache_manager.set_file_cache_size(size);
     }
 
+    /// Sets the max age of the file cache entries.
+    ///
+    /// Applies to the entries cached afterwards without a max age of their
+    /// own.
+    ///
+    /// # Arguments
+    /// * `max_age` - Max age of the entries, `None` to keep them until
+    ///   evicted for space
+    pub fn set_file_cache_max_age(&self, max_age: Option<Duration>) {
+        info!("set file cache max age to {:?}", max_age);
+        self.cache_manager.set_file_cache_max_age(max_age);
+    }
+
+    /// Sets the max age of the file cache entry of a request.
+    ///
+    /// # Arguments
+    /// * `request` - The request the content is preloaded with
+    /// * `max_age` - Max age of the entry, `None` to keep it until evicted
+    ///   for space
+    pub fn set_cache_max_age(&'static self, request: &DownloadRequest, max_age: Option<Duration>) {
+        if let Some((cache_manager, task_id)) = self.request_cache(request) {
+            cache_manager.set_max_age(&task_id, max_age);
+        }
+    }
+
     /// Sets the maximum RAM cache size.
     ///
     /// # Arguments
@@ -478,6 +643,42 @@ impl CacheDownloadService {
         self.cache_manager.set_ram_cache_size(size);
     }
 
+    /// Sets the maximum RAM cache size of a cache namespace.
+    ///
+    /// The size is capped to `MAX_NAMESPACE_RAM_CACHE_SIZE`.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace
+    /// * `size` - Maximum size in bytes for the RAM cache of the namespace
+    pub fn set_namespace_ram_cache_size(&'static self, namespace: &str, size: u64) {
+        let size = size.min(MAX_NAMESPACE_RAM_CACHE_SIZE);
+        match self.namespace_cache(Some(namespace)) {
+            Some((cache_manager, _)) => {
+                info!("set ram cache size of {} to {}", namespace, size);
+                cache_manager.set_ram_cache_size(size);
+            }
+            None => error!("set ram cache size of invalid namespace {}", namespace),
+        }
+    }
+
+    /// Sets the maximum file cache size of a cache namespace.
+    ///
+    /// The size is capped to `MAX_NAMESPACE_FILE_CACHE_SIZE`.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace
+    /// * `size` - Maximum size in bytes for the file cache of the namespace
+    pub fn set_namespace_file_cache_size(&'static self, namespace: &str, size: u64) {
+        let size = size.min(MAX_NAMESPACE_FILE_CACHE_SIZE);
+        match self.namespace_cache(Some(namespace)) {
+            Some((cache_manager, _)) => {
+                info!("set file cache size of {} to {}", namespace, size);
+                cache_manager.set_file_cache_size(size);
+            }
+            None => error!("set file cache size of invalid namespace {}", namespace),
+        }
+    }
+
     /// Sets the maximum number of download info entries to keep.
     ///
     /// # Arguments
@@ -486,16 +687,15 @@ impl CacheDownloadService {
         self.info_mgr.update_info_list_size(size);
     }
 
-    /// Gets download information for a URL.
+    /// Gets download information of a request.
     ///
     /// # Arguments
-    /// * `url` - URL to get download information for
+    /// * `request` - The request the content was preloaded with
     ///
     /// # Returns
     /// Optional download information if available
-    pub fn get_download_info(&self, url: &str) -> Option<DownloadInfo> {
-        let task_id = TaskId::from_url(url);
-        self.info_mgr.get_download_info(task_id)
+    pub fn get_download_info(&self, request: &DownloadRequest) -> Option<DownloadInfo> {
+        self.info_mgr.get_download_info(self.task_id(request))
     }
 
     /// Clears all memory cache.
@@ -508,6 +708,9 @@ impl CacheDownloadService {
             .cloned()
             .collect::<HashSet<_>>();
         self.cache_manager.clear_memory_cache(&running_tasks);
+        for namespace in self.namespaces.lock().unwrap().values() {
+            namespace.cache_manager.clear_memory_cache(&running_tasks);
+        }
         info!("clear memory cache");
     }
 
@@ -521,6 +724,9 @@ impl CacheDownloadService {
             .cloned()
             .collect::<HashSet<_>>();
         self.cache_manager.clear_file_cache(&running_tasks);
+        for namespace in self.namespaces.lock().unwrap().values() {
+            namespace.cache_manager.clear_file_cache(&running_tasks);
+        }
         info!("clear file cache");
     }
 
@@ -594,12 +800,12 @@ impl CacheDownloadService {
     /// Ok(()) if content was found and callback notified, Err(callback)
     /// otherwise
     fn fetch_with_callback(
-        &'static self,
+        cache_manager: &'static CacheManager,
         task_id: &TaskId,
         mut callback: Box<dyn PreloadCallback>,
     ) -> Result<(), Box<dyn PreloadCallback>> {
         let task_id = task_id.clone();
-        if let Some(cache) = self.cache_manager.fetch(&task_id) {
+        if let Some(cache) = cache_manager.fetch(&task_id) {
             // Spawn callback in a separate thread to avoid blocking
             crate::spawn(move || callback.on_success(cache, task_id.brief()));
             Ok(())
@@ -609,12 +815,13 @@ impl CacheDownloadService {
     }
 
     fn callback_from_local_file(
-        &'static self,
+        cache_manager: &'static CacheManager,
+        restore_finished: &AtomicBool,
         task_id: &TaskId,
         mut callback: Box<dyn PreloadCallback>,
     ) -> CallbackResult {
-        if !self.restore_finished.load(Ordering::SeqCst) {
-            match self.fetch_with_callback(task_id, callback) {
+        if !restore_finished.load(Ordering::SeqCst) {
+            match Self::fetch_with_callback(cache_manager, task_id, callback) {
                 Ok(()) => {
                     info!("{} fetch success", task_id.brief());
                     return complete_callback(task_id);
@@ -623,7 +830,7 @@ impl CacheDownloadService {
                     callback = ret;
                 }
             }
-            match self.read_local_file_with_callback(task_id.clone(), callback) {
+            match Self::read_local_file_with_callback(cache_manager, task_id.clone(), callback) {
                 Ok(()) => {
                     info!("{} read local success", task_id.brief());
                     return complete_callback(task_id);
@@ -633,7 +840,7 @@ impl CacheDownloadService {
                 }
             }
         } else {
-            match self.fetch_with_callback(task_id, callback) {
+            match Self::fetch_with_callback(cache_manager, task_id, callback) {
                 Ok(()) => {
                     info!("{} fetch success", task_id.brief());
                     return complete_callback(task_id);
@@ -647,11 +854,11 @@ impl CacheDownloadService {
     }
 
     fn read_local_file_with_callback(
-        &'static self,
+        cache_manager: &'static CacheManager,
         task_id: TaskId,
         mut callback: Box<dyn PreloadCallback>,
     ) -> Result<(), Box<dyn PreloadCallback>> {
-        if let Some(cache) = self.cache_manager.read_task_local_file(&task_id) {
+        if let Some(cache) = cache_manager.read_task_local_file(&task_id) {
             // Spawn callback in a separate thread to avoid blocking
             crate::spawn(move || callback.on_success(Arc::new(cache), task_id.brief()));
             Ok(())
@@ -659,6 +866,161 @@ impl CacheDownloadService {
             Err(callback)
         }
     }
+
+    /// Gets the task ID of a download request from its namespace and cache
+    /// key.
+    fn task_id(&self, request: &DownloadRequest) -> TaskId {
+        let Some(cache_key) = &request.cache_key else {
+            return namespace_task_id(request.namespace, request.url);
+        };
+        let headers = request.headers.as_deref().unwrap_or_default();
+        let vary = match vary_key(request) {
+            Some(vary_key) => self.recorded_vary(&vary_key),
+            None => vec![],
+        };
+        let key = cache_key.source(request.url, headers, &vary);
+        namespace_task_id(request.namespace, &key)
+    }
+
+    /// Gets the lowercase header names of the last `Vary` recorded for the
+    /// base key of a request, empty if there is none.
+    ///
+    /// Only responses whose `Vary` is covered by the key of their request are
+    /// cached, so that after a restart, the first response of a base key
+    /// without any recorded `Vary` is downloaded again.
+    pub(crate) fn recorded_vary(&self, vary_key: &TaskId) -> Vec<String> {
+        self.varies
+            .lock()
+            .unwrap()
+            .get(vary_key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Gets the cache manager and the task ID of a download request.
+    ///
+    /// # Returns
+    /// `None` if the namespace of the request is invalid
+    fn request_cache(
+        &'static self,
+        request: &DownloadRequest,
+    ) -> Option<(&'static CacheManager, TaskId)> {
+        let Some((cache_manager, _)) = self.namespace_cache(request.namespace) else {
+            error!("invalid cache namespace");
+            return None;
+        };
+        Some((cache_manager, self.task_id(request)))
+    }
+
+    /// Records the `Vary` header of a response to a request honoring it.
+    ///
+    /// # Arguments
+    /// * `vary_key` - Base key of the request, as returned by `vary_key`
+    /// * `vary` - Value of the `Vary` header, `None` if the response has none
+    pub(crate) fn record_vary(&self, vary_key: &TaskId, vary: Option<&str>) {
+        let names = vary.map(parse_vary).unwrap_or_default();
+        let mut varies = self.varies.lock().unwrap();
+        if names.is_empty() {
+            varies.remove(vary_key);
+        } else {
+            varies.insert(vary_key.clone(), names);
+        }
+    }
+
+    /// Gets the cache manager of a namespace, creating the namespace and
+    /// restoring its files on first use.
+    ///
+    /// A namespace is created with the default namespace quotas. As the
+    /// caches of a namespace live as long as the service, at most
+    /// `MAX_NAMESPACES` are created.
+    ///
+    /// # Arguments
+    /// * `namespace` - Name of the namespace, `None` for the default one
+    ///
+    /// # Returns
+    /// The cache manager of the namespace and whether its files have been
+    /// restored, or `None` if the namespace name is invalid or no more
+    /// namespaces can be created
+    fn namespace_cache(
+        &'static self,
+        namespace: Option<&str>,
+    ) -> Option<(&'static CacheManager, &'static AtomicBool)> {
+        let Some(namespace) = namespace else {
+            return Some((&self.cache_manager, self.restore_finished.as_ref()));
+        };
+        if !is_valid_namespace(namespace) {
+            return None;
+        }
+        let mut namespaces = self.namespaces.lock().unwrap();
+        if let Some(cache) = namespaces.get(namespace) {
+            return Some((&cache.cache_manager, &cache.restore_finished));
+        }
+        if namespaces.len() >= MAX_NAMESPACES {
+            error!("too many cache namespaces, {} rejected", namespace);
+            return None;
+        }
+        info!("create cache namespace {}", namespace);
+        let cache: &'static NamespaceCache = Box::leak(Box::new(NamespaceCache {
+            cache_manager: CacheManager::with_namespace(namespace),
+            restore_finished: AtomicBool::new(false),
+        }));
+        cache
+            .cache_manager
+            .set_ram_cache_size(DEFAULT_NAMESPACE_RAM_CACHE_SIZE);
+        cache
+            .cache_manager
+            .set_file_cache_size(DEFAULT_NAMESPACE_FILE_CACHE_SIZE);
+        namespaces.insert(namespace.to_string(), cache);
+        crate::spawn(move || {
+            // Restore cached files of the namespace from previous sessions
+            cache.cache_manager.build_cached_files_index();
+            cache.cache_manager.start_expiry_sweep();
+            cache.restore_finished.store(true, Ordering::SeqCst);
+        });
+        Some((&cache.cache_manager, &cache.restore_finished))
+    }
+}
+
+/// Caches of a named namespace.
+struct NamespaceCache {
+    /// Manager for the cached content of the namespace.
+    cache_manager: CacheManager,
+    /// Whether the cached files of the namespace have been restored.
+    restore_finished: AtomicBool,
+}
+
+/// Checks if a namespace name can be used as the name of its cache directory.
+fn is_valid_namespace(namespace: &str) -> bool {
+    !namespace.is_empty()
+        && namespace.len() <= MAX_NAMESPACE_LEN
+        && !namespace.starts_with('.')
+        && namespace
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+/// Gets the task ID of a cache key in a namespace.
+///
+/// The namespace is part of the ID, so that the same key preloaded in
+/// different namespaces runs and is cached separately.
+fn namespace_task_id(namespace: Option<&str>, key: &str) -> TaskId {
+    match namespace {
+        Some(namespace) => TaskId::from_url(&format!("{}:{}", namespace, key)),
+        None => TaskId::from_url(key),
+    }
+}
+
+/// Gets the ID under which the `Vary` header of the responses to a request is
+/// recorded, the task ID of its key without request headers.
+///
+/// # Returns
+/// `None` if the request does not honor `Vary`
+pub(crate) fn vary_key(request: &DownloadRequest) -> Option<TaskId> {
+    let cache_key = request.cache_key.as_ref().filter(|key| key.vary)?;
+    Some(namespace_task_id(
+        request.namespace,
+        &cache_key.base(request.url),
+    ))
 }
 
 fn complete_callback(task_id: &TaskId) -> CallbackResult {
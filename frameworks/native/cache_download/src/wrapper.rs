@@ -268,18 +268,30 @@ impl CacheDownloadService {
     }
 
     fn ffi_fetch(&'static self, url: &str) -> UniquePtr<ffi::Data> {
-        match self.fetch(url).map(RustData::new) {
+        match self.fetch(&DownloadRequest::new(url)).map(RustData::new) {
             Some(data) => ffi::UniqueData(Box::new(data)),
             _ => UniquePtr::null(),
         }
     }
 
     fn ffi_get_download_info(&'static self, url: &str) -> UniquePtr<ffi::CppDownloadInfo> {
-        match self.get_download_info(url) {
+        match self.get_download_info(&DownloadRequest::new(url)) {
             Some(info) => ffi::UniqueInfo(Box::new(RustDownloadInfo::from_download_info(info))),
             None => UniquePtr::null(),
         }
     }
+
+    fn ffi_cancel(&self, url: &str) {
+        self.cancel(&DownloadRequest::new(url));
+    }
+
+    fn ffi_remove(&'static self, url: &str) {
+        self.remove(&DownloadRequest::new(url));
+    }
+
+    fn ffi_contains(&'static self, url: &str) -> bool {
+        self.contains(&DownloadRequest::new(url))
+    }
 }
 
 /// Gets a raw pointer to the cache download service singleton for C++.
@@ -368,9 +380,9 @@ pub(crate) mod ffi {
 
         fn cache_download_service() -> *const CacheDownloadService;
         fn set_file_cache_path(path: String);
-        fn cancel(self: &CacheDownloadService, url: &str);
-        fn remove(self: &CacheDownloadService, url: &str);
-        fn contains(self: &CacheDownloadService, url: &str) -> bool;
+        fn ffi_cancel(self: &CacheDownloadService, url: &str);
+        fn ffi_remove(self: &'static CacheDownloadService, url: &str);
+        fn ffi_contains(self: &'static CacheDownloadService, url: &str) -> bool;
         fn clear_memory_cache(self: &CacheDownloadService);
         fn clear_file_cache(self: &CacheDownloadService);
 
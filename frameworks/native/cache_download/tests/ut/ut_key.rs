@@ -0,0 +1,168 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+const URL: &str = "https://example.com/a.png?w=100&token=abc&expires=1#top";
+
+// @tc.name: ut_cache_key_default
+// @tc.desc: Test the default cache key
+// @tc.precon: NA
+// @tc.step: 1. Create a default CacheKey
+//           2. Build the key of a URL with request headers
+// @tc.expect: The key is the full URL
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_key_default() {
+    let key = CacheKey::new();
+    assert_eq!(key.base(URL), URL);
+    assert_eq!(key.source(URL, &[("Accept-Language", "en")], &[]), URL);
+}
+
+// @tc.name: ut_cache_key_ignore_query
+// @tc.desc: Test removal of ignored query parameters
+// @tc.precon: NA
+// @tc.step: 1. Ignore some of the query parameters of a URL
+//           2. Ignore all of the query parameters of a URL
+// @tc.expect: Ignored parameters are removed, the fragment is kept and an
+// empty query is dropped
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_key_ignore_query() {
+    let mut key = CacheKey::new();
+    key.ignore_query(vec!["token", "expires"]);
+    assert_eq!(key.base(URL), "https://example.com/a.png?w=100#top");
+    assert_eq!(
+        key.base("https://example.com/a.png?token=abc&expires=1"),
+        "https://example.com/a.png"
+    );
+    assert_eq!(
+        key.base("https://example.com/a.png#top"),
+        "https://example.com/a.png#top"
+    );
+    assert_eq!(
+        key.base("https://example.com/a.png?tokens=1&token"),
+        "https://example.com/a.png?tokens=1"
+    );
+}
+
+// @tc.name: ut_cache_key_explicit
+// @tc.desc: Test an explicit cache key
+// @tc.precon: NA
+// @tc.step: 1. Set an explicit key and ignored query parameters
+//           2. Build the keys of different URLs
+// @tc.expect: The key replaces the URL
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_key_explicit() {
+    let mut key = CacheKey::new();
+    key.key("avatar:42").ignore_query(vec!["token"]);
+    assert_eq!(key.base(URL), "avatar:42");
+    assert_eq!(key.base("https://cdn.example.com/42.png"), "avatar:42");
+}
+
+// @tc.name: ut_cache_key_headers
+// @tc.desc: Test selected request headers in the cache key
+// @tc.precon: NA
+// @tc.step: 1. Select request headers with different cases
+//           2. Build the key of requests with and without the headers
+// @tc.expect: Header names are matched case-insensitively, sorted, and
+// missing headers have an empty value
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_key_headers() {
+    let mut key = CacheKey::new();
+    key.key("k").headers(vec!["Accept-Language", "accept"]);
+    let headers = [("accept-language", "en"), ("ACCEPT", "image/webp")];
+    assert_eq!(
+        key.source(URL, &headers, &[]),
+        "k\naccept:image/webp\naccept-language:en"
+    );
+    assert_eq!(key.source(URL, &[], &[]), "k\naccept:\naccept-language:");
+}
+
+// @tc.name: ut_cache_key_vary
+// @tc.desc: Test request headers listed in Vary in the cache key
+// @tc.precon: NA
+// @tc.step: 1. Build the key with Vary names overlapping selected headers
+//           2. Build the key with a Vary of `*`
+// @tc.expect: Names are deduplicated, and `*` selects every request header
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_key_vary() {
+    let mut key = CacheKey::new();
+    key.key("k").headers(vec!["Accept-Language"]);
+    let headers = [("Accept-Language", "en"), ("User-Agent", "ua")];
+    let vary = parse_vary("accept-language, User-Agent");
+    assert_eq!(
+        key.source(URL, &headers, &vary),
+        "k\naccept-language:en\nuser-agent:ua"
+    );
+    let vary = parse_vary("*");
+    assert_eq!(
+        key.source(URL, &headers, &vary),
+        "k\naccept-language:en\nuser-agent:ua"
+    );
+}
+
+// @tc.name: ut_cache_key_covers_vary
+// @tc.desc: Test checking if a key distinguishes the variants of a response
+// @tc.precon: NA
+// @tc.step: 1. Check Vary names selected by the key or not
+//           2. Check a key selecting every header
+// @tc.expect: A key covers a Vary only if it selects each of its names
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_key_covers_vary() {
+    let mut key = CacheKey::new();
+    key.headers(vec!["Accept-Language"]);
+    let names = key.header_names(&[]);
+    assert_eq!(names, vec!["accept-language"]);
+    assert!(covers_vary(&names, &[]));
+    assert!(covers_vary(&names, &parse_vary("Accept-Language")));
+    let both = parse_vary("Accept-Language, User-Agent");
+    assert!(!covers_vary(&names, &both));
+    assert!(!covers_vary(&[], &parse_vary("*")));
+
+    let names = key.header_names(&parse_vary("*"));
+    assert!(covers_vary(&names, &both));
+}
+
+// @tc.name: ut_parse_vary
+// @tc.desc: Test parsing of the Vary header
+// @tc.precon: NA
+// @tc.step: 1. Parse Vary headers with spaces, cases and duplicates
+// @tc.expect: Names are lowercase, sorted and deduplicated
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_parse_vary() {
+    assert_eq!(
+        parse_vary(" User-Agent ,Accept-Language,, user-agent"),
+        vec!["accept-language", "user-agent"]
+    );
+    assert!(parse_vary("").is_empty());
+}
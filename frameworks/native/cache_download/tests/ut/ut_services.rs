@@ -257,7 +257,7 @@ fn ut_preload_cancel_1() {
         flag: cancel_flag.clone(),
     });
     let handle = SERVICE.preload(DownloadRequest::new(TEST_URL), callback, true, DOWNLOADER);
-    SERVICE.cancel(TEST_URL);
+    SERVICE.cancel(&DownloadRequest::new(TEST_URL));
     assert!(handle.is_some());
     let handle = handle.unwrap();
     while handle.state() != CANCEL {
@@ -430,7 +430,7 @@ fn ut_preload_fetch() {
     while !handle.is_finish() {
         thread::sleep(Duration::from_millis(500));
     }
-    let cache = SERVICE.fetch(TEST_URL);
+    let cache = SERVICE.fetch(&DownloadRequest::new(TEST_URL));
     assert!(cache.is_some());
     assert_eq!(success_flag.load(Ordering::SeqCst), 1);
 }
@@ -525,6 +525,197 @@ fn ut_download_request_all_options() {
     assert_eq!(request.http_total_timeout, Some(120));
 }
 
+// @tc.name: ut_download_request_namespace
+// @tc.desc: Test DownloadRequest set namespace
+// @tc.precon: NA
+// @tc.step: 1. Create a DownloadRequest object.
+//           2. Call the namespace function to set the namespace
+//           3. Check whether namespace is set
+// @tc.expect: The namespace is set successfully
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_download_request_namespace() {
+    let mut request = DownloadRequest::new(TEST_URL);
+    assert_eq!(request.namespace, None);
+    request.namespace("images");
+    assert_eq!(request.namespace, Some("images"));
+}
+
+// @tc.name: ut_cache_namespace_name
+// @tc.desc: Test validation of cache namespace names
+// @tc.precon: NA
+// @tc.step: 1. Check valid namespace names
+//           2. Check namespace names unusable as directory names
+// @tc.expect: Only names made of allowed characters are valid
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_namespace_name() {
+    assert!(is_valid_namespace("images"));
+    assert!(is_valid_namespace("video-segments_1.0"));
+    assert!(!is_valid_namespace(""));
+    assert!(!is_valid_namespace("."));
+    assert!(!is_valid_namespace(".."));
+    assert!(!is_valid_namespace("a/b"));
+    assert!(!is_valid_namespace(&"a".repeat(MAX_NAMESPACE_LEN + 1)));
+}
+
+// @tc.name: ut_cache_namespace_task_id
+// @tc.desc: Test task IDs of a URL preloaded in different namespaces
+// @tc.precon: NA
+// @tc.step: 1. Get the task ID of a URL without namespace
+//           2. Get the task IDs of the URL in two namespaces
+// @tc.expect: The default task ID is the URL one, and each namespace has its
+// own task ID
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_namespace_task_id() {
+    let task_id = namespace_task_id(None, TEST_URL);
+    assert!(task_id == TaskId::from_url(TEST_URL));
+    let images = namespace_task_id(Some("images"), TEST_URL);
+    let config = namespace_task_id(Some("config"), TEST_URL);
+    assert!(images != task_id);
+    assert!(images != config);
+    assert!(images == namespace_task_id(Some("images"), TEST_URL));
+}
+
+// @tc.name: ut_cache_key_task_id
+// @tc.desc: Test task IDs of requests with a cache key strategy
+// @tc.precon: NA
+// @tc.step: 1. Get the task IDs of URLs differing by an ignored query param
+//           2. Get the task IDs of requests with different selected headers
+// @tc.expect: Ignored params share a task ID, selected headers do not
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_key_task_id() {
+    let service = CacheDownloadService::get_instance();
+    let mut key = CacheKey::new();
+    key.ignore_query(vec!["token"])
+        .headers(vec!["Accept-Language"]);
+
+    let mut first = DownloadRequest::new("https://example.com/a.png?token=1");
+    first.cache_key(key.clone());
+    let mut second = DownloadRequest::new("https://example.com/a.png?token=2");
+    second.cache_key(key.clone());
+    assert!(service.task_id(&first) == service.task_id(&second));
+    assert!(service.task_id(&first) != namespace_task_id(None, first.url));
+
+    let mut english = DownloadRequest::new("https://example.com/a.png?token=1");
+    english
+        .headers(vec![("accept-language", "en")])
+        .cache_key(key.clone());
+    let mut french = DownloadRequest::new("https://example.com/a.png?token=1");
+    french
+        .headers(vec![("Accept-Language", "fr")])
+        .cache_key(key);
+    assert!(service.task_id(&english) != service.task_id(&french));
+    assert!(service.task_id(&english) != service.task_id(&first));
+}
+
+// @tc.name: ut_cache_key_vary
+// @tc.desc: Test task IDs of requests honoring the recorded Vary header
+// @tc.precon: NA
+// @tc.step: 1. Get the task ID of a request before any Vary is recorded
+//           2. Record a Vary header and get the task IDs again
+//           3. Record a response without Vary
+// @tc.expect: Request headers listed in Vary are part of the task ID only
+// while a Vary is recorded
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_key_vary() {
+    const URL: &str = "https://example.com/ut_cache_key_vary";
+    let service = CacheDownloadService::get_instance();
+    let mut key = CacheKey::new();
+    key.vary(true);
+    let mut english = DownloadRequest::new(URL);
+    english
+        .headers(vec![("Accept-Language", "en")])
+        .cache_key(key.clone());
+    let mut french = DownloadRequest::new(URL);
+    french
+        .headers(vec![("Accept-Language", "fr")])
+        .cache_key(key);
+
+    let base = vary_key(&english).unwrap();
+    assert!(base == namespace_task_id(None, URL));
+    assert!(service.task_id(&english) == base);
+    assert!(service.task_id(&french) == base);
+
+    service.record_vary(&base, Some("Accept-Language"));
+    assert!(service.task_id(&english) != base);
+    assert!(service.task_id(&english) != service.task_id(&french));
+
+    service.record_vary(&base, None);
+    assert!(service.task_id(&english) == base);
+    assert!(vary_key(&DownloadRequest::new(URL)).is_none());
+}
+
+// @tc.name: ut_cache_pinned
+// @tc.desc: Test pinning the cached content of a URL
+// @tc.precon: NA
+// @tc.step: 1. Pin the content of a URL not cached yet
+//           2. Unpin it
+// @tc.expect: The pin is set on the task of the URL before it is cached, and
+// removed on unpin
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_pinned() {
+    const URL: &str = "https://example.com/ut_cache_pinned";
+    let service = CacheDownloadService::get_instance();
+    let task_id = TaskId::from_url(URL);
+    service.set_pinned(&DownloadRequest::new(URL), true);
+    assert!(service.cache_manager.is_pinned(&task_id));
+    assert!(!service
+        .cache_entries()
+        .iter()
+        .any(|entry| entry.task_id() == &task_id));
+    service.set_pinned(&DownloadRequest::new(URL), false);
+    assert!(!service.cache_manager.is_pinned(&task_id));
+}
+
+// @tc.name: ut_cache_request_pinned
+// @tc.desc: Test pinning the cached content of a request in a namespace
+// @tc.precon: NA
+// @tc.step: 1. Pin the content of a request in a namespace
+//           2. Unpin it
+// @tc.expect: The pin is set in the cache of the namespace only, and removed
+// on unpin
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cache_request_pinned() {
+    const URL: &str = "https://example.com/ut_cache_request_pinned";
+    let service = CacheDownloadService::get_instance();
+    let mut request = DownloadRequest::new(URL);
+    request.namespace("ut_request_pinned");
+    let (cache_manager, task_id) = service.request_cache(&request).unwrap();
+    assert!(task_id == namespace_task_id(Some("ut_request_pinned"), URL));
+
+    service.set_pinned(&request, true);
+    assert!(cache_manager.is_pinned(&task_id));
+    assert!(!service.cache_manager.is_pinned(&task_id));
+    assert!(!service.contains(&request));
+    service.set_pinned(&request, false);
+    assert!(!cache_manager.is_pinned(&task_id));
+
+    let mut invalid = DownloadRequest::new(URL);
+    invalid.namespace("../invalid");
+    assert!(service.request_cache(&invalid).is_none());
+    assert!(service.fetch(&invalid).is_none());
+}
+
 // @tc.name: ut_set_global_retry_options
 // @tc.desc: Test CacheDownloadService set_global_retry_options
 // @tc.precon: NA
@@ -641,7 +832,7 @@ fn ut_set_global_timeout_options_boundary() {
 #[test]
 fn ut_remove_file_cache() {
     let test_url = TEST_TEXT_URL.lock().unwrap();
-    CacheDownloadService::get_instance().remove(test_url.as_ref());
+    CacheDownloadService::get_instance().remove(&DownloadRequest::new(test_url.as_ref()));
     let success_flag = Arc::new(AtomicUsize::new(0));
     let callback = Box::new(TestCallbackS {
         flag: success_flag.clone(),
@@ -657,7 +848,8 @@ fn ut_remove_file_cache() {
     while !handle.is_finish() {
         thread::sleep(Duration::from_millis(500));
     }
-    let cache = CacheDownloadService::get_instance().fetch(test_url.as_ref());
+    let cache =
+        CacheDownloadService::get_instance().fetch(&DownloadRequest::new(test_url.as_ref()));
     assert!(cache.is_some());
     let path = get_curr_store_dir();
     let task_id = handle.task_id();
@@ -666,7 +858,8 @@ fn ut_remove_file_cache() {
     assert!(file_path.exists());
     CacheDownloadService::get_instance().clear_file_cache();
     assert!(!file_path.exists());
-    let cache = CacheDownloadService::get_instance().fetch(test_url.as_ref());
+    let cache =
+        CacheDownloadService::get_instance().fetch(&DownloadRequest::new(test_url.as_ref()));
     assert!(cache.is_some());
 }
 
@@ -686,7 +879,7 @@ fn ut_remove_file_cache() {
 #[test]
 fn ut_remove_ram_cache() {
     let test_url = TEST_TEXT_URL.lock().unwrap();
-    CacheDownloadService::get_instance().remove(test_url.as_ref());
+    CacheDownloadService::get_instance().remove(&DownloadRequest::new(test_url.as_ref()));
     let success_flag = Arc::new(AtomicUsize::new(0));
     let callback = Box::new(TestCallbackS {
         flag: success_flag.clone(),
@@ -702,16 +895,19 @@ fn ut_remove_ram_cache() {
     while !handle.is_finish() {
         thread::sleep(Duration::from_millis(500));
     }
-    let cache = CacheDownloadService::get_instance().fetch(test_url.as_ref());
+    let cache =
+        CacheDownloadService::get_instance().fetch(&DownloadRequest::new(test_url.as_ref()));
     assert!(cache.is_some());
     drop(cache);
     CacheDownloadService::get_instance().clear_memory_cache();
-    let cache = CacheDownloadService::get_instance().fetch(test_url.as_ref());
+    let cache =
+        CacheDownloadService::get_instance().fetch(&DownloadRequest::new(test_url.as_ref()));
     assert!(cache.is_some());
     drop(cache);
     CacheDownloadService::get_instance().clear_memory_cache();
     CacheDownloadService::get_instance().clear_file_cache();
-    let cache = CacheDownloadService::get_instance().fetch(test_url.as_ref());
+    let cache =
+        CacheDownloadService::get_instance().fetch(&DownloadRequest::new(test_url.as_ref()));
     assert!(cache.is_none());
 }
 
@@ -730,8 +926,8 @@ fn ut_remove_ram_cache() {
 #[test]
 fn ut_remove_finished_caches() {
     let test_url = TEST_TEXT_URL.lock().unwrap();
-    CacheDownloadService::get_instance().remove(test_url.as_ref());
-    CacheDownloadService::get_instance().remove(TEST_VIDEO_URL);
+    CacheDownloadService::get_instance().remove(&DownloadRequest::new(test_url.as_ref()));
+    CacheDownloadService::get_instance().remove(&DownloadRequest::new(TEST_VIDEO_URL));
     let success_flag = Arc::new(AtomicUsize::new(0));
     let callback = Box::new(TestCallbackS {
         flag: success_flag.clone(),
@@ -764,9 +960,10 @@ fn ut_remove_finished_caches() {
     while !handle2.is_finish() {
         thread::sleep(Duration::from_millis(500));
     }
-    let cache = CacheDownloadService::get_instance().fetch(test_url.as_ref());
+    let cache =
+        CacheDownloadService::get_instance().fetch(&DownloadRequest::new(test_url.as_ref()));
     assert!(cache.is_none());
-    let cache = CacheDownloadService::get_instance().fetch(TEST_VIDEO_URL);
+    let cache = CacheDownloadService::get_instance().fetch(&DownloadRequest::new(TEST_VIDEO_URL));
     assert!(cache.is_some());
 }
 
@@ -795,21 +992,23 @@ fn ut_download_fetch_after_init() {
     let res = fs::write(file_path, "hello");
     assert!(res.is_ok());
 
-    let first_ram = CacheDownloadService::get_instance().fetch(SELF_CREATE_URL);
+    let first_ram =
+        CacheDownloadService::get_instance().fetch(&DownloadRequest::new(SELF_CREATE_URL));
     if let Some(ram) = first_ram {
         let data = ram.cursor().into_inner();
         assert_eq!(data, b"hello");
     } else {
         // Wait for the file cache to be restored.
         thread::sleep(Duration::from_millis(100));
-        let ram = CacheDownloadService::get_instance().fetch(SELF_CREATE_URL);
+        let ram =
+            CacheDownloadService::get_instance().fetch(&DownloadRequest::new(SELF_CREATE_URL));
         assert!(ram.is_some());
 
         let ram = ram.unwrap();
         let data = ram.cursor().into_inner();
         assert_eq!(data, b"hello");
     }
-    CacheDownloadService::get_instance().remove(SELF_CREATE_URL);
+    CacheDownloadService::get_instance().remove(&DownloadRequest::new(SELF_CREATE_URL));
 }
 
 pub fn get_curr_store_dir() -> PathBuf {
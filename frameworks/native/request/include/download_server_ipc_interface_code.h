@@ -43,6 +43,10 @@ enum class RequestInterfaceCode {
     CMD_DELETE_GROUP,
     CMD_SET_MAX_SPEED,
     CMD_SHOW_PROGRESS,
+    CMD_SET_BANDWIDTH_BUDGET,
+    CMD_SET_COOKIES,
+    CMD_CLEAR_COOKIES,
+    CMD_SET_AUTH_TOKEN,
     CMD_SET_MODE = 100,
     CMD_DISABLE_TASK_NOTIFICATIONS,
 };
@@ -27,6 +27,9 @@ public:
     virtual void OnFaultsReceive(const std::shared_ptr<int32_t> &tid, const std::shared_ptr<SubscribeType> &type,
         const std::shared_ptr<Reason> &reason) = 0;
     virtual void OnWaitReceive(std::int32_t taskId, WaitingReason reason) = 0;
+    virtual void OnTokenRefresh(std::int32_t taskId)
+    {
+    }
 };
 
 } // namespace OHOS::Request
@@ -39,6 +39,7 @@ public:
     void OnFaultsReceive(const std::shared_ptr<int32_t> &tid, const std::shared_ptr<SubscribeType> &type,
         const std::shared_ptr<Reason> &reason);
     void OnWaitReceive(std::int32_t taskId, WaitingReason reason);
+    void OnTokenRefresh(std::int32_t taskId);
     bool NeedNotify(const std::shared_ptr<NotifyData> &notifyData);
 
 private:
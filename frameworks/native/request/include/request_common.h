@@ -90,6 +90,14 @@ enum Reason : uint32_t {
     APP_ACCOUNT,
     NETWORK_APP_ACCOUNT,
     LOW_SPEED,
+    INTEGRITY_MISMATCH,
+    PREREQUISITE_WAITING,
+    PREREQUISITE_FAILED,
+    START_TIME_WAITING,
+    STORAGE_SPACE_WAITING,
+    CLIENT_CERT_INVALID,
+    CLIENT_CERT_REJECTED,
+    RETRY_WAITING,
 };
 
 enum WaitingReason : uint32_t {
@@ -97,6 +105,10 @@ enum WaitingReason : uint32_t {
     NetworkNotMatch = 0x01,
     AppBackground = 0x02,
     UserInactivated = 0x03,
+    InsufficientSpace = 0x04,
+    PrerequisiteWaiting = 0x05,
+    StartTimeWaiting = 0x06,
+    RetryWaiting = 0x07,
 };
 
 enum class SubscribeType : uint32_t {
@@ -110,6 +122,7 @@ enum class SubscribeType : uint32_t {
     RESPONSE,
     FAULT_OCCUR,
     WAIT,
+    TOKEN_REFRESH,
     BUTT,
 };
 
@@ -158,6 +171,45 @@ struct Timeout {
                                     // Default: 7 days if task has notification, 10 minutes otherwise.
 };
 
+// Retry policy for failed download/upload attempts.
+struct RetryPolicy {
+    uint32_t maxAttempts = 0;    // Maximum attempts including the first one. 0 disables the policy.
+    uint64_t baseDelay = 0;      // Delay in milliseconds before the first retry.
+    uint32_t backoffFactor = 0;  // Factor the delay is multiplied by after each retry.
+    uint32_t jitter = 0;         // Percentage of the delay randomly added or subtracted, at most 100.
+    std::vector<uint32_t> reasons;  // Retryable reason codes. Empty means the service defaults.
+    std::vector<uint32_t> statuses; // Retryable HTTP status codes. Empty means the service defaults.
+};
+
+enum class RecurrenceKind : uint32_t {
+    ONCE = 0,
+    INTERVAL,
+    DAILY,
+};
+
+// Recurrence rule of a task, which runs again after each completion or failure.
+struct Recurrence {
+    RecurrenceKind kind = RecurrenceKind::ONCE;
+    uint64_t interval = 0;   // Interval in seconds between runs. At least 15 minutes.
+    uint32_t windowStart = 0; // Start of the daily window in minutes since midnight UTC.
+    uint32_t windowEnd = 0;   // End of the daily window in minutes since midnight UTC, exclusive.
+};
+
+enum class AuthScheme : uint32_t {
+    NONE = 0,
+    BASIC,
+    DIGEST,
+    BEARER,
+};
+
+// Authentication of the requests of a task, skipped when the task has its own Authorization header.
+struct Auth {
+    AuthScheme scheme = AuthScheme::NONE;
+    std::string username; // User name of the basic and digest schemes.
+    std::string secret;   // Password of the basic and digest schemes, or the bearer token.
+    bool refresh = false; // Whether to ask the app for a new bearer token once the server rejects it.
+};
+
 struct Config {
     Action action;
     std::string url;
@@ -198,6 +250,20 @@ struct Config {
     Notification notification;
     MinSpeed minSpeed;
     Timeout timeout;
+    uint32_t segments = 0;
+    std::string digest;
+    uint64_t chunkSize = 0;
+    RetryPolicy retryPolicy;
+    std::vector<std::string> prerequisites; // IDs of the tasks that must complete before this task starts.
+    uint64_t notBefore = 0; // Wall-clock time in milliseconds before which the task does not start.
+    Recurrence recurrence;
+    uint64_t expectedSize = 0; // Declared size in bytes of the downloaded file, 0 when unknown.
+    bool preallocate = false;  // Whether to reserve the space of the downloaded file before writing it.
+    bool compression = false;  // Whether to transfer the body compressed (gzip or deflate).
+    bool cookieJar = false;    // Whether to send and store cookies through the cookie jar of the app.
+    Auth auth;
+    std::string clientCertPath; // PEM client certificate chain in the app sandbox, empty for none.
+    std::string clientKeyPath;  // PEM private key of the client certificate in the app sandbox.
 };
 
 enum class State : uint32_t {
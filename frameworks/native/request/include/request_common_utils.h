@@ -66,6 +66,14 @@ private:
                                                             "app is"
                                                             "background or terminate";
     static constexpr const char *LOW_SPEED_INFO = "Below low speed limit";
+    static constexpr const char *INTEGRITY_MISMATCH_INFO = "Integrity check failed";
+    static constexpr const char *PREREQUISITE_WAITING_INFO = "Waiting for prerequisite tasks";
+    static constexpr const char *PREREQUISITE_FAILED_INFO = "A prerequisite task failed or was removed";
+    static constexpr const char *START_TIME_WAITING_INFO = "Waiting for the scheduled start time";
+    static constexpr const char *STORAGE_SPACE_WAITING_INFO = "Waiting for enough storage space";
+    static constexpr const char *CLIENT_CERT_INVALID_INFO = "Invalid client certificate or private key";
+    static constexpr const char *CLIENT_CERT_REJECTED_INFO = "Client certificate rejected by the server";
+    static constexpr const char *RETRY_WAITING_INFO = "Waiting to retry the failed request";
 
 public:
     REQUEST_API static Faults GetFaultByReason(Reason code);
@@ -61,6 +61,7 @@ public:
     REQUEST_API int32_t Remove(const std::string &tid, const Version version);
     REQUEST_API int32_t Resume(const std::string &tid);
     REQUEST_API int32_t SetMaxSpeed(const std::string &tid, const int64_t maxSpeed);
+    REQUEST_API int32_t SetAuthToken(const std::string &tid, const std::string &token);
 
     REQUEST_API int32_t Subscribe(const std::string &taskId);
     REQUEST_API int32_t Unsubscribe(const std::string &taskId);
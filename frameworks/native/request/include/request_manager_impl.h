@@ -72,6 +72,7 @@ public:
     int32_t Remove(const std::string &tid, const Version version);
     int32_t Resume(const std::string &tid);
     int32_t SetMaxSpeed(const std::string &tid, const int64_t maxSpeed);
+    int32_t SetAuthToken(const std::string &tid, const std::string &token);
 
     int32_t Subscribe(const std::string &taskId);
     int32_t Unsubscribe(const std::string &taskId);
@@ -116,6 +117,7 @@ private:
     void OnFaultsReceive(const std::shared_ptr<int32_t> &tid, const std::shared_ptr<SubscribeType> &type,
         const std::shared_ptr<Reason> &reason) override;
     void OnWaitReceive(std::int32_t taskId, WaitingReason reason) override;
+    void OnTokenRefresh(std::int32_t taskId) override;
 
 private:
     std::mutex serviceProxyMutex_;
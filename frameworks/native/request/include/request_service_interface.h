@@ -58,6 +58,7 @@ public:
     virtual int32_t Remove(const std::string &tid, const Version version) = 0;
     virtual int32_t Resume(const std::string &tid) = 0;
     virtual int32_t SetMaxSpeed(const std::string &tid, const int64_t maxSpeed) = 0;
+    virtual int32_t SetAuthToken(const std::string &tid, const std::string &token) = 0;
 
     virtual int32_t Stop(const std::string &tid) = 0;
     virtual int32_t Query(const std::string &tid, TaskInfo &info) = 0;
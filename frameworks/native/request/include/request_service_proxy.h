@@ -62,6 +62,7 @@ public:
     int32_t Remove(const std::string &tid, const Version version) override;
     int32_t Resume(const std::string &tid) override;
     int32_t SetMaxSpeed(const std::string &tid, const int64_t maxSpeed) override;
+    int32_t SetAuthToken(const std::string &tid, const std::string &token) override;
 
     int32_t Stop(const std::string &tid) override;
     int32_t Query(const std::string &tid, TaskInfo &info) override;
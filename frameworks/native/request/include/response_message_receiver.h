@@ -27,6 +27,7 @@ enum MessageType {
     NOTIFY_DATA,
     FAULTS,
     WAIT,
+    TOKEN_REFRESH,
 };
 
 class ResponseMessageReceiver
@@ -68,6 +69,7 @@ private:
     void HandNotifyData(char *&leftBuf, int32_t &leftLen);
     void HandFaultsData(char *&leftBuf, int32_t &leftLen);
     void HandWaitData(char *&leftBuf, int32_t &leftLen);
+    void HandTokenRefreshData(char *&leftBuf, int32_t &leftLen);
     void OnShutdown(int32_t fd) override;
     void OnException(int32_t fd) override;
     void ShutdownChannel();
@@ -127,4 +127,13 @@ void Request::OnWaitReceive(std::int32_t taskId, WaitingReason reason)
     }
 }
 
+void Request::OnTokenRefresh(std::int32_t taskId)
+{
+    std::lock_guard<std::mutex> lock(listenerMutex_);
+    auto listener = notifyDataListenerMap_.find(SubscribeType::TOKEN_REFRESH);
+    if (listener != notifyDataListenerMap_.end()) {
+        listener->second->OnTokenRefresh(taskId);
+    }
+}
+
 } // namespace OHOS::Request
\ No newline at end of file
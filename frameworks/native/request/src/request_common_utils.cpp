@@ -51,6 +51,14 @@ Faults CommonUtils::GetFaultByReason(Reason code)
         { APP_ACCOUNT, Faults::OTHERS },
         { NETWORK_APP_ACCOUNT, Faults::DISCONNECTED },
         { LOW_SPEED, Faults::LOW_SPEED },
+        { INTEGRITY_MISMATCH, Faults::FSIO },
+        { PREREQUISITE_WAITING, Faults::OTHERS },
+        { PREREQUISITE_FAILED, Faults::OTHERS },
+        { START_TIME_WAITING, Faults::OTHERS },
+        { STORAGE_SPACE_WAITING, Faults::FSIO },
+        { CLIENT_CERT_INVALID, Faults::PARAM },
+        { CLIENT_CERT_REJECTED, Faults::SSL },
+        { RETRY_WAITING, Faults::OTHERS },
     };
     static const std::unordered_set<Faults> downgradeFaults = { Faults::PARAM, Faults::DNS, Faults::TCP, Faults::SSL,
         Faults::REDIRECT };
@@ -104,6 +112,14 @@ std::string CommonUtils::GetMsgByReason(Reason code)
         { APP_ACCOUNT, APP_ACCOUNT_INFO },
         { NETWORK_APP_ACCOUNT, NETWORK_ACCOUNT_APP_INFO },
         { LOW_SPEED, LOW_SPEED_INFO },
+        { INTEGRITY_MISMATCH, INTEGRITY_MISMATCH_INFO },
+        { PREREQUISITE_WAITING, PREREQUISITE_WAITING_INFO },
+        { PREREQUISITE_FAILED, PREREQUISITE_FAILED_INFO },
+        { START_TIME_WAITING, START_TIME_WAITING_INFO },
+        { STORAGE_SPACE_WAITING, STORAGE_SPACE_WAITING_INFO },
+        { CLIENT_CERT_INVALID, CLIENT_CERT_INVALID_INFO },
+        { CLIENT_CERT_REJECTED, CLIENT_CERT_REJECTED_INFO },
+        { RETRY_WAITING, RETRY_WAITING_INFO },
     };
     auto iter = reasonMsg.find(code);
     if (iter == reasonMsg.end()) {
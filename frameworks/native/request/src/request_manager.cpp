@@ -154,6 +154,11 @@ int32_t RequestManager::SetMaxSpeed(const std::string &tid, const int64_t maxSpe
     return RequestManagerImpl::GetInstance()->SetMaxSpeed(tid, maxSpeed);
 }
 
+int32_t RequestManager::SetAuthToken(const std::string &tid, const std::string &token)
+{
+    return RequestManagerImpl::GetInstance()->SetAuthToken(tid, token);
+}
+
 int32_t RequestManager::Subscribe(const std::string &taskId)
 {
     return RequestManagerImpl::GetInstance()->Subscribe(taskId);
@@ -249,6 +249,11 @@ int32_t RequestManagerImpl::SetMaxSpeed(const std::string &tid, const int64_t ma
     return CallProxyMethod(&RequestServiceInterface::SetMaxSpeed, tid, maxSpeed);
 }
 
+int32_t RequestManagerImpl::SetAuthToken(const std::string &tid, const std::string &token)
+{
+    return CallProxyMethod(&RequestServiceInterface::SetAuthToken, tid, token);
+}
+
 int32_t RequestManagerImpl::AddListener(
     const std::string &taskId, const SubscribeType &type, const std::shared_ptr<IResponseListener> &listener)
 {
@@ -438,6 +443,16 @@ void RequestManagerImpl::OnWaitReceive(std::int32_t taskId, WaitingReason reason
     task->OnWaitReceive(taskId, reason);
 }
 
+void RequestManagerImpl::OnTokenRefresh(std::int32_t taskId)
+{
+    std::shared_ptr<Request> task = this->GetTask(std::to_string(taskId));
+    if (task.get() == nullptr) {
+        REQUEST_HILOGE("OnTokenRefresh task not found");
+        return;
+    }
+    task->OnTokenRefresh(taskId);
+}
+
 sptr<RequestServiceInterface> RequestManagerImpl::GetRequestServiceProxy(bool needLoadSA)
 {
     std::lock_guard<std::mutex> lock(serviceProxyMutex_);
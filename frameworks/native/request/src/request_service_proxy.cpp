@@ -463,6 +463,22 @@ void SerializeNotification(MessageParcel &data, const Notification &notification
     data.WriteUint32(static_cast<uint32_t>(notification.visibility));
 }
 
+void SerializeRetryPolicy(MessageParcel &data, const RetryPolicy &retryPolicy)
+{
+    data.WriteUint32(retryPolicy.maxAttempts);
+    data.WriteUint64(retryPolicy.baseDelay);
+    data.WriteUint32(retryPolicy.backoffFactor);
+    data.WriteUint32(retryPolicy.jitter);
+    data.WriteUint32(retryPolicy.reasons.size());
+    for (const auto &reason : retryPolicy.reasons) {
+        data.WriteUint32(reason);
+    }
+    data.WriteUint32(retryPolicy.statuses.size());
+    for (const auto &status : retryPolicy.statuses) {
+        data.WriteUint32(status);
+    }
+}
+
 int32_t RequestServiceProxy::Create(const Config &config, std::string &tid)
 {
     REQUEST_HILOGD("Request Create, tid: %{public}s", tid.c_str());
@@ -513,6 +529,29 @@ void RequestServiceProxy::WriteConfigData(const Config &config, MessageParcel &d
     data.WriteString(config.proxy);
     data.WriteString(config.certificatePins);
     GetVectorData(config, data);
+    data.WriteUint32(config.segments);
+    data.WriteString(config.digest);
+    data.WriteUint64(config.chunkSize);
+    SerializeRetryPolicy(data, config.retryPolicy);
+    data.WriteUint32(config.prerequisites.size());
+    for (const auto &taskId : config.prerequisites) {
+        data.WriteString(taskId);
+    }
+    data.WriteUint64(config.notBefore);
+    data.WriteUint32(static_cast<uint32_t>(config.recurrence.kind));
+    data.WriteUint64(config.recurrence.interval);
+    data.WriteUint32(config.recurrence.windowStart);
+    data.WriteUint32(config.recurrence.windowEnd);
+    data.WriteUint64(config.expectedSize);
+    data.WriteBool(config.preallocate);
+    data.WriteBool(config.compression);
+    data.WriteBool(config.cookieJar);
+    data.WriteUint32(static_cast<uint32_t>(config.auth.scheme));
+    data.WriteString(config.auth.username);
+    data.WriteString(config.auth.secret);
+    data.WriteBool(config.auth.refresh);
+    data.WriteString(config.clientCertPath);
+    data.WriteString(config.clientKeyPath);
     SerializeNotification(data, config.notification);
 }
 
@@ -802,6 +841,29 @@ int32_t RequestServiceProxy::SetMaxSpeed(const std::string &tid, const int64_t m
     return rets[0];
 }
 
+int32_t RequestServiceProxy::SetAuthToken(const std::string &tid, const std::string &token)
+{
+    REQUEST_HILOGD("Request SetAuthToken, tid: %{public}s", tid.c_str());
+    MessageParcel data;
+    MessageParcel reply;
+    MessageOption option;
+    data.WriteInterfaceToken(GetDescriptor());
+    data.WriteString(tid);
+    data.WriteString(token);
+    int32_t ret =
+        Remote()->SendRequest(static_cast<uint32_t>(RequestInterfaceCode::CMD_SET_AUTH_TOKEN), data, reply, option);
+    if (ret != ERR_NONE) {
+        REQUEST_HILOGE("End Request SetAuthToken, tid: %{public}s, failed: %{public}d", tid.c_str(), ret);
+        if (ret != REMOTE_DIED_ERROR) {
+            SysEventLog::SendSysEventLog(FAULT_EVENT, IPC_FAULT_00, std::to_string(ret));
+        }
+        return E_SERVICE_ERROR;
+    }
+    REQUEST_HILOGD("End Request SetAuthToken ok, tid: %{public}s", tid.c_str());
+    int32_t errCode = reply.ReadInt32();
+    return errCode;
+}
+
 int32_t RequestServiceProxy::OpenChannel(int32_t &sockFd)
 {
     REQUEST_HILOGD("Request OpenChannel");
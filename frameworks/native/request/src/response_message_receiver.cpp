@@ -456,6 +456,8 @@ void ResponseMessageReceiver::OnReadable(int32_t fd)
         HandFaultsData(leftBuf, leftLen);
     } else if (msgType == MessageType::WAIT) {
         HandWaitData(leftBuf, leftLen);
+    } else if (msgType == MessageType::TOKEN_REFRESH) {
+        HandTokenRefreshData(leftBuf, leftLen);
     }
 }
 
@@ -509,6 +511,16 @@ void ResponseMessageReceiver::HandWaitData(char *&leftBuf, int32_t &leftLen)
     this->handler_->OnWaitReceive(taskId, static_cast<WaitingReason>(reason));
 }
 
+void ResponseMessageReceiver::HandTokenRefreshData(char *&leftBuf, int32_t &leftLen)
+{
+    int32_t taskId;
+    if (Int32FromParcel(taskId, leftBuf, leftLen) != 0) {
+        REQUEST_HILOGE("Bad taskId");
+        return;
+    }
+    this->handler_->OnTokenRefresh(taskId);
+}
+
 void ResponseMessageReceiver::OnShutdown(int32_t fd)
 {
     ShutdownChannel();
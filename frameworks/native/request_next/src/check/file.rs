@@ -18,14 +18,13 @@
 //! downloaded content across different API versions.
 
 // Standard library imports
-use std::error::Error;
-use std::fmt::Display;
 use std::fs::{self, OpenOptions};
 use std::os::unix::fs::PermissionsExt;
 use std::path::PathBuf;
 
 // External dependencies
 use request_core::config::Version;
+pub use request_core::error::DownloadPathError;
 use request_utils::context::Context;
 use request_utils::storage;
 
@@ -283,33 +282,3 @@ pub fn set_file_permission(path: &PathBuf, context: &Context) -> Result<(), Down
 
     Ok(())
 }
-
-/// Error types for download path validation and permission operations.
-#[derive(Debug)]
-pub enum DownloadPathError {
-    /// The provided path is empty
-    EmptyPath,
-    /// The path exceeds the maximum allowed length
-    TooLongPath,
-    /// The path is not in an allowed storage area
-    InvalidPath,
-    /// The bundle name in the path doesn't match the application
-    BundleNameNotMap,
-    /// The file already exists and overwrite is disabled
-    AlreadyExists,
-    /// File creation failed with an IO error
-    CreateFile(std::io::Error),
-    /// Setting file permissions failed with an IO error
-    SetPermission(std::io::Error),
-    /// Setting ACL access permissions failed
-    AclAccess(i32),
-}
-
-impl Error for DownloadPathError {}
-
-impl Display for DownloadPathError {
-    /// Formats the error as a human-readable string via the `Display` trait.
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&format!("{}", self))
-    }
-}
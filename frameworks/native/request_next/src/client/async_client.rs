@@ -0,0 +1,207 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asynchronous client API for managing download tasks.
+//!
+//! `AsyncRequestClient` wraps the `RequestClient` singleton: its operations
+//! are futures resolving once the service has answered, and the events of a
+//! task are delivered as a `TaskEventStream` fed by the listener of the
+//! `Observer`, so that no thread is dedicated to waiting for them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use request_client::client::async_client::AsyncRequestClient;
+//! use request_client::{StateChange, TaskEvent};
+//! use request_core::config::{Mode, TaskConfig};
+//! use request_utils::context::Context;
+//!
+//! async fn download(context: Context, seq: u64, config: TaskConfig) -> Result<(), i32> {
+//!     let client = AsyncRequestClient::new();
+//!     let task_id = client
+//!         .create_task(context, seq, config)
+//!         .await
+//!         .map_err(|e| e.code())?;
+//!
+//!     // Subscribe before starting so that no event is missed
+//!     let mut events = client.subscribe(task_id);
+//!     client.start(task_id).await.map_err(|e| e.code())?;
+//!
+//!     while let Some(event) = events.next().await {
+//!         match event {
+//!             TaskEvent::Progress(progress) => {
+//!                 println!("processed {} bytes", progress.total_processed)
+//!             }
+//!             TaskEvent::StateChanged(StateChange::Failed(code), _) => return Err(code),
+//!             _ => {}
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use request_core::config::{Mode, TaskConfig};
+use request_core::error_code::OTHER;
+use request_core::filter::SearchFilter;
+use request_core::info::TaskInfo;
+use request_utils::context::Context;
+
+use crate::client::error::RequestError;
+use crate::client::RequestClient;
+use crate::listen::TaskEventStream;
+
+/// Asynchronous handle to the download service.
+///
+/// The calls to the service are made as tasks of the runtime that also runs
+/// the event listener, so awaiting them never blocks the calling task and
+/// needs no thread of its own.
+#[derive(Clone, Copy)]
+pub struct AsyncRequestClient {
+    /// Client the operations are delegated to.
+    client: &'static RequestClient<'static>,
+}
+
+impl AsyncRequestClient {
+    /// Creates a handle to the `RequestClient` singleton.
+    pub fn new() -> Self {
+        Self {
+            client: RequestClient::get_instance(),
+        }
+    }
+
+    /// Validates a task configuration and creates the task.
+    ///
+    /// The configuration is checked on the calling task, where the paths and
+    /// files it names are resolved against the context, so that only the
+    /// resolved configuration is handed to the runtime.
+    ///
+    /// # Arguments
+    /// * `context` - Application context used for path permission checks
+    /// * `seq` - Request sequence number used as the local registry key
+    /// * `config` - Task configuration to validate and create
+    ///
+    /// # Returns
+    /// The task ID on success, or a `RequestError` on failure
+    pub async fn create_task(
+        &self,
+        context: Context,
+        seq: u64,
+        config: TaskConfig,
+    ) -> Result<i64, RequestError> {
+        self.client.check_config(context, seq, config)?;
+        self.call(move |client| client.create_registered_task(seq))
+            .await
+    }
+
+    /// Retrieves the configuration of a task from the service.
+    pub async fn get_task(
+        &self,
+        task_id: i64,
+        token: Option<String>,
+    ) -> Result<TaskConfig, RequestError> {
+        self.call(move |client| client.get_task(task_id, token))
+            .await
+    }
+
+    /// Starts a task.
+    pub async fn start(&self, task_id: i64) -> Result<(), RequestError> {
+        self.call(move |client| client.start(task_id)).await
+    }
+
+    /// Pauses a running task.
+    pub async fn pause(&self, task_id: i64) -> Result<(), RequestError> {
+        self.call(move |client| client.pause(task_id)).await
+    }
+
+    /// Resumes a paused task.
+    pub async fn resume(&self, task_id: i64) -> Result<(), RequestError> {
+        self.call(move |client| client.resume(task_id)).await
+    }
+
+    /// Stops a running task without removing its files.
+    pub async fn stop(&self, task_id: i64) -> Result<(), RequestError> {
+        self.call(move |client| client.stop(task_id)).await
+    }
+
+    /// Removes a task and its associated files.
+    pub async fn remove(&self, task_id: i64) -> Result<(), RequestError> {
+        self.call(move |client| client.remove(task_id)).await
+    }
+
+    /// Sets the maximum speed of a task in bytes per second.
+    pub async fn set_max_speed(&self, task_id: i64, speed: i64) -> Result<(), RequestError> {
+        self.call(move |client| client.set_max_speed(task_id, speed))
+            .await
+    }
+
+    /// Switches a task between the foreground and background modes.
+    pub async fn set_mode(&self, task_id: i64, mode: Mode) -> Result<(), RequestError> {
+        self.call(move |client| client.set_mode(task_id, mode))
+            .await
+    }
+
+    /// Disables the notifications of tasks, returning the result for each.
+    pub async fn disable_task_notifications(
+        &self,
+        task_ids: Vec<i64>,
+    ) -> Result<Vec<Result<(), RequestError>>, RequestError> {
+        self.call(move |client| client.disable_task_notifications(&task_ids))
+            .await
+    }
+
+    /// Retrieves the information of a task created by the application.
+    pub async fn show_task(&self, task_id: i64) -> Result<TaskInfo, RequestError> {
+        self.call(move |client| client.show_task(task_id)).await
+    }
+
+    /// Queries the information of a task.
+    pub async fn query(&self, task_id: i64) -> Result<TaskInfo, RequestError> {
+        self.call(move |client| client.query(task_id)).await
+    }
+
+    /// Touches a task with its access token, returning its information.
+    pub async fn touch(&self, task_id: i64, token: String) -> Result<TaskInfo, RequestError> {
+        self.call(move |client| client.touch(task_id, token)).await
+    }
+
+    /// Searches for the tasks matching a filter.
+    pub async fn search(&self, filter: SearchFilter) -> Result<Vec<String>, RequestError> {
+        self.call(move |client| client.search(filter)).await
+    }
+
+    /// Subscribes a stream to the events of a task.
+    ///
+    /// Subscribing before starting the task ensures that none of its events
+    /// is missed.
+    pub fn subscribe(&self, task_id: i64) -> TaskEventStream {
+        self.client.subscribe(task_id)
+    }
+
+    /// Runs a call to the service as a task of the runtime.
+    async fn call<T, F>(&self, f: F) -> Result<T, RequestError>
+    where
+        F: FnOnce(&'static RequestClient<'static>) -> Result<T, RequestError> + Send + 'static,
+        T: Send + 'static,
+    {
+        let client = self.client;
+        ylong_runtime::spawn(async move { f(client) })
+            .await
+            .unwrap_or(Err(RequestError::Other(OTHER)))
+    }
+}
+
+impl Default for AsyncRequestClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
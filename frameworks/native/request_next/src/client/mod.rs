@@ -104,6 +104,7 @@
 //! ```
 
 // Public module exports
+pub mod async_client;
 pub mod error;
 mod native_task;
 use std::path::PathBuf;
@@ -111,21 +112,21 @@ use std::path::PathBuf;
 use std::sync::{Arc, OnceLock};
 
 // External dependencies
-use request_core::config::{Action, Notification, TaskConfig, Version};
-use request_core::error_code::{CHANNEL_NOT_OPEN, OTHER};
+use request_core::config::{Action, Mode, Notification, TaskConfig, Version};
+use request_core::error_code::OTHER;
 use request_core::file::FileSpec;
 use request_core::filter::SearchFilter;
 use request_core::info::TaskInfo;
 use request_utils::context::Context;
 
 // Internal dependencies
-use crate::client::error::CreateTaskError;
+use crate::client::error::RequestError;
 use crate::client::native_task::{NativeTask, NativeTaskManager};
 use crate::file::FileManager;
-use crate::listen::Observer;
+use crate::listen::{Observer, TaskEventStream};
 use crate::proxy::RequestProxy;
 use crate::verify::TaskConfigVerifier;
-use crate::{check, Callback};
+use crate::{check, Callback, RunCountCallback};
 
 /// Client for interacting with the download service.
 ///
@@ -191,14 +192,14 @@ impl<'a> RequestClient<'a> {
     /// * `config` - Task configuration to validate and register.
     ///
     /// # Returns
-    /// `Ok(())` if the config is valid and registered, or a `CreateTaskError`
-    /// on validation or permission failure.
+    /// `Ok(())` if the config is valid and registered, or a `RequestError` on
+    /// validation or permission failure.
     pub fn check_config(
         &self,
         context: Context,
         seq: u64,
         mut config: TaskConfig,
-    ) -> Result<(), CreateTaskError> {
+    ) -> Result<(), RequestError> {
         debug!("Creating task with config: {:?}", config);
         // todo: errcode and errmsg
         TaskConfigVerifier::get_instance().verify(&config)?;
@@ -223,11 +224,11 @@ impl<'a> RequestClient<'a> {
     /// * `overwrite` - Whether to overwrite existing files
     ///
     /// # Returns
-    /// A task ID on success, or a `CreateTaskError` on failure
+    /// A task ID on success, or a `RequestError` on failure
     ///
     /// # Errors
-    /// * `CreateTaskError::DownloadPath` - If path validation fails
-    /// * `CreateTaskError::Code` - If task creation fails for other reasons
+    /// * `RequestError::DownloadPath` - If path validation fails
+    /// * Any other `RequestError` - If task creation fails for other reasons
     ///
     /// # Notes
     /// The function name contains a typo (`crate_task` instead of
@@ -297,11 +298,23 @@ impl<'a> RequestClient<'a> {
     ///     }
     /// }
     /// ```
-    pub fn create_task(&self, context: Context, seq: u64) -> Result<i64, CreateTaskError> {
+    pub fn create_task(&self, context: Context, seq: u64) -> Result<i64, RequestError> {
+        self.create_registered_task(seq)
+    }
+
+    /// Sends the task registered under the given sequence number by
+    /// `check_config` to the service, reopening the channel if needed.
+    ///
+    /// # Arguments
+    /// * `seq` - Request sequence number the task was registered under.
+    ///
+    /// # Returns
+    /// The task ID on success, or a `RequestError` on failure.
+    pub(crate) fn create_registered_task(&self, seq: u64) -> Result<i64, RequestError> {
         let task = self
             .task_manager
             .get_by_seq(&seq)
-            .ok_or(CreateTaskError::Code(OTHER))?;
+            .ok_or(RequestError::Other(OTHER))?;
 
         // Retry loop for channel reconnection
         loop {
@@ -309,7 +322,7 @@ impl<'a> RequestClient<'a> {
                 Err(e) => {
                     error!("Failed to create task: {:?}", e);
                     // Attempt to reopen channel if it's closed
-                    if matches!(e, CreateTaskError::Code(CHANNEL_NOT_OPEN)) {
+                    if matches!(e, RequestError::ChannelNotOpen) {
                         self.open_channel();
                         continue;
                     }
@@ -333,9 +346,15 @@ impl<'a> RequestClient<'a> {
     /// * `token` - Optional access token for authorization.
     ///
     /// # Returns
-    /// The task configuration on success, or an error code on failure.
-    pub fn get_task(&self, task_id: i64, token: Option<String>) -> Result<TaskConfig, i32> {
-        self.proxy.get_task(task_id, token)
+    /// The task configuration on success, or a `RequestError` on failure.
+    pub fn get_task(
+        &self,
+        task_id: i64,
+        token: Option<String>,
+    ) -> Result<TaskConfig, RequestError> {
+        self.proxy
+            .get_task(task_id, token)
+            .map_err(RequestError::from)
     }
 
     /// Starts a download task with the specified ID.
@@ -344,9 +363,9 @@ impl<'a> RequestClient<'a> {
     /// * `task_id` - ID of the task to start
     ///
     /// # Returns
-    /// `Ok(())` on success, or an error code on failure
-    pub fn start(&self, task_id: i64) -> Result<(), i32> {
-        self.proxy.start(task_id)
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn start(&self, task_id: i64) -> Result<(), RequestError> {
+        self.proxy.start(task_id).map_err(RequestError::from)
     }
 
     /// Pauses a running download task.
@@ -355,9 +374,9 @@ impl<'a> RequestClient<'a> {
     /// * `task_id` - ID of the task to pause
     ///
     /// # Returns
-    /// `Ok(())` on success, or an error code on failure
-    pub fn pause(&self, task_id: i64) -> Result<(), i32> {
-        self.proxy.pause(task_id)
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn pause(&self, task_id: i64) -> Result<(), RequestError> {
+        self.proxy.pause(task_id).map_err(RequestError::from)
     }
 
     /// Resumes a paused download task.
@@ -366,9 +385,9 @@ impl<'a> RequestClient<'a> {
     /// * `task_id` - ID of the task to resume
     ///
     /// # Returns
-    /// `Ok(())` on success, or an error code on failure
-    pub fn resume(&self, task_id: i64) -> Result<(), i32> {
-        self.proxy.resume(task_id)
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn resume(&self, task_id: i64) -> Result<(), RequestError> {
+        self.proxy.resume(task_id).map_err(RequestError::from)
     }
 
     /// Removes a download task and its associated files.
@@ -377,10 +396,10 @@ impl<'a> RequestClient<'a> {
     /// * `task_id` - ID of the task to remove
     ///
     /// # Returns
-    /// `Ok(())` on success, or an error code on failure
-    pub fn remove(&self, task_id: i64) -> Result<(), i32> {
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn remove(&self, task_id: i64) -> Result<(), RequestError> {
         self.task_manager.remove_task(&task_id);
-        self.proxy.remove(task_id)
+        self.proxy.remove(task_id).map_err(RequestError::from)
     }
 
     /// Stops a running download task without removing files.
@@ -389,9 +408,9 @@ impl<'a> RequestClient<'a> {
     /// * `task_id` - ID of the task to stop
     ///
     /// # Returns
-    /// `Ok(())` on success, or an error code on failure
-    pub fn stop(&self, task_id: i64) -> Result<(), i32> {
-        self.proxy.stop(task_id)
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn stop(&self, task_id: i64) -> Result<(), RequestError> {
+        self.proxy.stop(task_id).map_err(RequestError::from)
     }
 
     /// Sets the maximum download speed for a task.
@@ -401,9 +420,168 @@ impl<'a> RequestClient<'a> {
     /// * `speed` - Maximum speed limit in bytes per second
     ///
     /// # Returns
-    /// `Ok(())` on success, or an error code on failure
-    pub fn set_max_speed(&self, task_id: i64, speed: i64) -> Result<(), i32> {
-        self.proxy.set_max_speed(task_id, speed)
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn set_max_speed(&self, task_id: i64, speed: i64) -> Result<(), RequestError> {
+        self.proxy
+            .set_max_speed(task_id, speed)
+            .map_err(RequestError::from)
+    }
+
+    /// Sets the bandwidth caps shared by all tasks of the device or of an
+    /// application.
+    ///
+    /// # Arguments
+    /// * `scope` - 0 for the device budget, 1 for an application budget
+    /// * `uid` - Application uid, ignored for the device budget
+    /// * `metered` - Cap on metered networks in bytes per second, 0 for
+    ///   unlimited
+    /// * `unmetered` - Cap on unmetered networks in bytes per second, 0 for
+    ///   unlimited
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn set_bandwidth_budget(
+        &self,
+        scope: u32,
+        uid: u64,
+        metered: i64,
+        unmetered: i64,
+    ) -> Result<(), RequestError> {
+        self.proxy
+            .set_bandwidth_budget(scope, uid, metered, unmetered)
+            .map_err(RequestError::from)
+    }
+
+    /// Seeds the cookie jar shared by the tasks of an application.
+    ///
+    /// # Arguments
+    /// * `uid` - Application uid, ignored unless the caller has the download
+    ///   permission
+    /// * `url` - The url the cookies are set for, which their `Domain` must
+    ///   cover
+    /// * `cookies` - The cookies in the `Set-Cookie` format
+    ///
+    /// # Returns
+    /// The number of cookies accepted on success, or a `RequestError` on
+    /// failure
+    pub fn set_cookies(
+        &self,
+        uid: u64,
+        url: &str,
+        cookies: &[String],
+    ) -> Result<u32, RequestError> {
+        self.proxy
+            .set_cookies(uid, url, cookies)
+            .map_err(RequestError::from)
+    }
+
+    /// Clears the cookie jar shared by the tasks of an application.
+    ///
+    /// # Arguments
+    /// * `uid` - Application uid, ignored unless the caller has the download
+    ///   permission
+    /// * `domain` - The domain whose cookies are removed, with its
+    ///   subdomains, or empty for all cookies
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn clear_cookies(&self, uid: u64, domain: &str) -> Result<(), RequestError> {
+        self.proxy
+            .clear_cookies(uid, domain)
+            .map_err(RequestError::from)
+    }
+
+    /// Hands over a refreshed bearer token of a task.
+    ///
+    /// Usually called by the listener with the result of
+    /// `Callback::on_token_refresh`.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task waiting for the token
+    /// * `token` - The new token, or empty to decline the refresh
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn set_auth_token(&self, task_id: i64, token: &str) -> Result<(), RequestError> {
+        self.proxy
+            .set_auth_token(task_id, token)
+            .map_err(RequestError::from)
+    }
+
+    /// Switches a task between the foreground and background modes.
+    ///
+    /// Requires the download session manager permission.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task to switch
+    /// * `mode` - Mode the task runs in from now on
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn set_mode(&self, task_id: i64, mode: Mode) -> Result<(), RequestError> {
+        self.proxy
+            .set_mode(task_id, mode)
+            .map_err(RequestError::from)
+    }
+
+    /// Disables the notifications of tasks.
+    ///
+    /// # Arguments
+    /// * `task_ids` - IDs of the tasks whose notifications are disabled
+    ///
+    /// # Returns
+    /// The result for each task, in the order of `task_ids`, or a
+    /// `RequestError` if the request could not be sent.
+    pub fn disable_task_notifications(
+        &self,
+        task_ids: &[i64],
+    ) -> Result<Vec<Result<(), RequestError>>, RequestError> {
+        let codes = self.proxy.disable_task_notifications(task_ids)?;
+        Ok(codes
+            .into_iter()
+            .map(|code| match code {
+                0 => Ok(()),
+                code => Err(RequestError::from(code)),
+            })
+            .collect())
+    }
+
+    /// Subscribes a callback to the number of running tasks.
+    ///
+    /// The process subscribes to the service on the first callback, and the
+    /// callback is called with the current number right away. Only system
+    /// processes may subscribe.
+    ///
+    /// # Arguments
+    /// * `callback` - Callback to receive the run count
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn subscribe_run_count(
+        &self,
+        callback: Arc<dyn RunCountCallback + Send + Sync + 'static>,
+    ) -> Result<(), RequestError> {
+        self.listener
+            .register_run_count_callback(callback, |obj| self.proxy.subscribe_run_count(obj))
+            .map_err(RequestError::from)
+    }
+
+    /// Unsubscribes a callback from the number of running tasks.
+    ///
+    /// The process unsubscribes from the service with its last callback.
+    ///
+    /// # Arguments
+    /// * `callback` - Callback previously passed to `subscribe_run_count`
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a `RequestError` on failure
+    pub fn unsubscribe_run_count(
+        &self,
+        callback: &Arc<dyn RunCountCallback + Send + Sync + 'static>,
+    ) -> Result<(), RequestError> {
+        self.listener
+            .unregister_run_count_callback(callback, || self.proxy.unsubscribe_run_count())
+            .map_err(RequestError::from)
     }
 
     /// Queries the MIME type of a task's downloaded content.
@@ -412,9 +590,11 @@ impl<'a> RequestClient<'a> {
     /// * `task_id` - ID of the task to query.
     ///
     /// # Returns
-    /// The MIME type string on success, or an error code on failure.
-    pub fn query_mime_type(&self, task_id: i64) -> Result<String, i32> {
-        self.proxy.query_mime_type(task_id)
+    /// The MIME type string on success, or a `RequestError` on failure.
+    pub fn query_mime_type(&self, task_id: i64) -> Result<String, RequestError> {
+        self.proxy
+            .query_mime_type(task_id)
+            .map_err(RequestError::from)
     }
 
     /// Registers a callback for task status updates.
@@ -430,6 +610,18 @@ impl<'a> RequestClient<'a> {
         self.listener.register_callback(task_id, callback);
     }
 
+    /// Subscribes a stream to the events of a task.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task to monitor
+    ///
+    /// # Returns
+    /// A stream of the task events, ending once the task completes, fails or
+    /// is removed.
+    pub fn subscribe(&self, task_id: i64) -> TaskEventStream {
+        self.listener.subscribe(task_id)
+    }
+
     /// Opens the communication channel with the download service.
     ///
     /// Initializes the listener with a file descriptor from the proxy.
@@ -445,9 +637,9 @@ impl<'a> RequestClient<'a> {
     /// * `task_id` - ID of the task to query
     ///
     /// # Returns
-    /// Task information on success, or an error code on failure
-    pub fn show_task(&self, task_id: i64) -> Result<TaskInfo, i32> {
-        self.proxy.show(task_id)
+    /// Task information on success, or a `RequestError` on failure
+    pub fn show_task(&self, task_id: i64) -> Result<TaskInfo, RequestError> {
+        self.proxy.show(task_id).map_err(RequestError::from)
     }
 
     /// Searches for tasks matching the specified filter.
@@ -456,9 +648,9 @@ impl<'a> RequestClient<'a> {
     /// * `keyword` - Search filter defining the search criteria
     ///
     /// # Returns
-    /// A list of matching task IDs on success, or an error code on failure
-    pub fn search(&self, keyword: SearchFilter) -> Result<Vec<String>, i32> {
-        self.proxy.search(keyword)
+    /// A list of matching task IDs on success, or a `RequestError` on failure
+    pub fn search(&self, keyword: SearchFilter) -> Result<Vec<String>, RequestError> {
+        self.proxy.search(keyword).map_err(RequestError::from)
     }
 
     /// Touches a task to keep it alive, returning its current information.
@@ -468,9 +660,9 @@ impl<'a> RequestClient<'a> {
     /// * `token` - Access token authorizing the operation.
     ///
     /// # Returns
-    /// The task information on success, or an error code on failure.
-    pub fn touch(&self, task_id: i64, token: String) -> Result<TaskInfo, i32> {
-        self.proxy.touch(task_id, token)
+    /// The task information on success, or a `RequestError` on failure.
+    pub fn touch(&self, task_id: i64, token: String) -> Result<TaskInfo, RequestError> {
+        self.proxy.touch(task_id, token).map_err(RequestError::from)
     }
 
     /// Queries the current information of a task.
@@ -479,9 +671,9 @@ impl<'a> RequestClient<'a> {
     /// * `task_id` - ID of the task to query.
     ///
     /// # Returns
-    /// The task information on success, or an error code on failure.
-    pub fn query(&self, task_id: i64) -> Result<TaskInfo, i32> {
-        self.proxy.query(task_id)
+    /// The task information on success, or a `RequestError` on failure.
+    pub fn query(&self, task_id: i64) -> Result<TaskInfo, RequestError> {
+        self.proxy.query(task_id).map_err(RequestError::from)
     }
 
     /// Creates a task group with the given gauge and notification settings.
@@ -491,13 +683,15 @@ impl<'a> RequestClient<'a> {
     /// * `notification` - Notification configuration for the group.
     ///
     /// # Returns
-    /// The created group ID on success, or an error code on failure.
+    /// The created group ID on success, or a `RequestError` on failure.
     pub fn create_group(
         &self,
         gauge: Option<bool>,
         notification: Notification,
-    ) -> Result<String, i32> {
-        self.proxy.create_group(gauge, notification)
+    ) -> Result<String, RequestError> {
+        self.proxy
+            .create_group(gauge, notification)
+            .map_err(RequestError::from)
     }
 
     /// Attaches a set of tasks to an existing task group.
@@ -507,9 +701,15 @@ impl<'a> RequestClient<'a> {
     /// * `task_ids` - IDs of the tasks to attach.
     ///
     /// # Returns
-    /// `Ok(())` on success, or an error code on failure.
-    pub fn attach_group(&self, group_id: String, task_ids: Vec<String>) -> Result<(), i32> {
-        self.proxy.attach_group(group_id, task_ids)
+    /// `Ok(())` on success, or a `RequestError` on failure.
+    pub fn attach_group(
+        &self,
+        group_id: String,
+        task_ids: Vec<String>,
+    ) -> Result<(), RequestError> {
+        self.proxy
+            .attach_group(group_id, task_ids)
+            .map_err(RequestError::from)
     }
 
     /// Deletes a task group.
@@ -518,8 +718,10 @@ impl<'a> RequestClient<'a> {
     /// * `group_id` - ID of the group to delete.
     ///
     /// # Returns
-    /// `Ok(())` on success, or an error code on failure.
-    pub fn delete_group(&self, group_id: String) -> Result<(), i32> {
-        self.proxy.delete_group(group_id)
+    /// `Ok(())` on success, or a `RequestError` on failure.
+    pub fn delete_group(&self, group_id: String) -> Result<(), RequestError> {
+        self.proxy
+            .delete_group(group_id)
+            .map_err(RequestError::from)
     }
 }
@@ -40,6 +40,12 @@ mod listen;
 /// Re-export of the callback trait for request state monitoring.
 pub use listen::Callback;
 
+/// Re-exports of the typed task events delivered by `TaskEventStream`.
+pub use listen::{StateChange, TaskEvent, TaskEventStream};
+
+/// Re-export of the callback trait for the number of running tasks.
+pub use listen::RunCountCallback;
+
 // Import utility macros
 #[macro_use]
 extern crate request_utils;
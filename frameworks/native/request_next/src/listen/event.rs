@@ -0,0 +1,192 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed task events delivered as asynchronous streams.
+//!
+//! The messages received by the `Observer` from the download service are
+//! converted into `TaskEvent`s and sent to the `TaskEventStream`s subscribed
+//! to their task, from the same listener task that dispatches callbacks.
+//! Each stream buffers a bounded number of events: a stream that does not
+//! keep up misses progress events, which the next one supersedes, and is
+//! ended if it would miss any other event.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use request_core::info::{Faults, NotifyData, Progress, Response, SubscribeType, WaitingReason};
+use ylong_runtime::sync::error::TrySendError;
+use ylong_runtime::sync::mpsc::{bounded_channel, BoundedReceiver, BoundedSender};
+
+/// Number of events buffered by a stream.
+const STREAM_CAPACITY: usize = 64;
+
+/// Senders of the event streams subscribed to each task.
+pub(crate) type EventSenders = Arc<Mutex<HashMap<i64, Vec<BoundedSender<TaskEvent>>>>>;
+
+/// State change of a task reported by the download service.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StateChange {
+    /// The task has completed successfully.
+    Completed,
+    /// The task has failed, with the response code of its file.
+    Failed(i32),
+    /// The task has been paused.
+    Paused,
+    /// The task has been resumed.
+    Resumed,
+    /// The task has been removed.
+    Removed,
+    /// The response headers of an upload have been received.
+    HeaderReceived,
+}
+
+impl StateChange {
+    /// Returns whether the change ends the current run of the task.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            StateChange::Completed | StateChange::Failed(_) | StateChange::Removed
+        )
+    }
+}
+
+/// Event of a task received from the download service.
+#[derive(Clone, Debug)]
+pub enum TaskEvent {
+    /// The progress of the task has been updated.
+    Progress(Progress),
+    /// The task changed state, with its progress at that time.
+    StateChanged(StateChange, Progress),
+    /// An HTTP response has been received.
+    Response(Response),
+    /// A fault has occurred on the task.
+    Fault(Faults),
+    /// The task is waiting to run.
+    Wait(WaitingReason),
+}
+
+impl TaskEvent {
+    /// Converts a task notification into an event, or `None` if the
+    /// notification carries no event of interest.
+    pub(crate) fn from_notify_data(data: NotifyData) -> Option<Self> {
+        let change = match data.subscribe_type {
+            SubscribeType::Progress => return Some(TaskEvent::Progress(data.progress)),
+            SubscribeType::Completed => StateChange::Completed,
+            SubscribeType::Failed => StateChange::Failed(
+                data.task_states
+                    .first()
+                    .map_or(0, |state| state.response_code as i32),
+            ),
+            SubscribeType::Pause => StateChange::Paused,
+            SubscribeType::Resume => StateChange::Resumed,
+            SubscribeType::Remove => StateChange::Removed,
+            SubscribeType::HeaderReceive => StateChange::HeaderReceived,
+            _ => return None,
+        };
+        Some(TaskEvent::StateChanged(change, data.progress))
+    }
+
+    /// Returns whether the event ends the current run of the task.
+    pub fn is_final(&self) -> bool {
+        match self {
+            TaskEvent::StateChanged(change, _) => change.is_final(),
+            _ => false,
+        }
+    }
+}
+
+/// Asynchronous stream of the events of a task.
+///
+/// The stream ends after the event completing, failing or removing the task,
+/// so a task started again must be subscribed to again. Dropping the stream
+/// unsubscribes it.
+///
+/// # Examples
+///
+/// ```rust
+/// use request_client::client::async_client::AsyncRequestClient;
+/// use request_client::TaskEvent;
+///
+/// async fn wait_for_task(task_id: i64) {
+///     let client = AsyncRequestClient::new();
+///     let mut events = client.subscribe(task_id);
+///     while let Some(event) = events.next().await {
+///         if let TaskEvent::Progress(progress) = event {
+///             println!("processed {} bytes", progress.total_processed);
+///         }
+///     }
+/// }
+/// ```
+pub struct TaskEventStream {
+    /// ID of the task the events belong to.
+    task_id: i64,
+    /// Receiving end of the events of the task.
+    rx: BoundedReceiver<TaskEvent>,
+}
+
+impl TaskEventStream {
+    /// Subscribes a new stream to the events of a task.
+    pub(crate) fn subscribe(senders: &EventSenders, task_id: i64) -> Self {
+        let (tx, rx) = bounded_channel(STREAM_CAPACITY);
+        senders.lock().unwrap().entry(task_id).or_default().push(tx);
+        Self { task_id, rx }
+    }
+
+    /// Returns the ID of the task the events belong to.
+    pub fn task_id(&self) -> i64 {
+        self.task_id
+    }
+
+    /// Waits for the next event, or `None` once the stream has ended.
+    pub async fn next(&mut self) -> Option<TaskEvent> {
+        self.rx.recv().await.ok()
+    }
+}
+
+impl Stream for TaskEventStream {
+    type Item = TaskEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<TaskEvent>> {
+        match self.get_mut().rx.poll_recv(cx) {
+            Poll::Ready(res) => Poll::Ready(res.ok()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Sends an event to the streams subscribed to its task.
+///
+/// Streams that have been dropped are unsubscribed, and all the streams of
+/// the task end after a final event. A progress event is skipped for a
+/// stream whose buffer is full, which is unsubscribed on any other event.
+pub(crate) fn publish(senders: &EventSenders, task_id: i64, event: TaskEvent) {
+    let mut senders = senders.lock().unwrap();
+    let Some(task_senders) = senders.get_mut(&task_id) else {
+        return;
+    };
+    task_senders.retain(|tx| match tx.try_send(event.clone()) {
+        Ok(()) => true,
+        Err(TrySendError::Full(_)) if matches!(event, TaskEvent::Progress(_)) => true,
+        Err(TrySendError::Full(_)) => {
+            error!("task {} event stream overflowed", task_id);
+            false
+        }
+        Err(TrySendError::Closed(_)) => false,
+    });
+    if task_senders.is_empty() || event.is_final() {
+        senders.remove(&task_id);
+    }
+}
@@ -14,11 +14,19 @@
 //! Task event listening and observation.
 //!
 //! Aggregates the `Callback` trait and its `Observer` implementation that
-//! dispatch task lifecycle events to subscribers.
+//! dispatch task lifecycle events to subscribers, the `TaskEventStream`
+//! delivering them asynchronously, and the `RunCountCallback` receiving the
+//! number of running tasks.
 
+mod event;
 mod observe;
+mod run_count;
 mod ser;
 mod uds;
 
+/// Re-exports of the typed task events and their stream.
+pub use event::{StateChange, TaskEvent, TaskEventStream};
 /// Re-exports of the task event callback trait and its observer.
 pub use observe::{Callback, Observer};
+/// Re-export of the run count callback trait.
+pub use run_count::RunCountCallback;
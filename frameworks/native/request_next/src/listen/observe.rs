@@ -16,7 +16,8 @@
 //! This module provides the infrastructure for monitoring and responding to
 //! download task events through a callback mechanism. It includes the
 //! `Observer` struct that manages callbacks and dispatches events, and the
-//! `Callback` trait that defines the interface for handling these events.
+//! `Callback` trait that defines the interface for handling these events. The
+//! same events are also published to the `TaskEventStream`s of their task.
 
 // Standard library imports
 use std::collections::HashMap;
@@ -24,7 +25,9 @@ use std::fs::File;
 use std::sync::{Arc, Mutex};
 
 // External dependencies
+use ipc::remote::RemoteObj;
 use request_core::config::{Action, Version};
+use request_core::error_code::OTHER;
 use request_core::info::{
     Faults, NotifyData, Progress, Response, SubscribeType, TaskState, WaitingReason,
 };
@@ -33,6 +36,8 @@ use ylong_runtime::task::JoinHandle;
 use crate::client::RequestClient;
 use crate::file::FileManager;
 // Internal dependencies
+use crate::listen::event::{self, EventSenders, TaskEvent, TaskEventStream};
+use crate::listen::run_count::{RunCountCallback, RunCountCallbacks, RunCountStub};
 use crate::listen::uds::{Message, UdsListener};
 
 /// Manages callbacks and dispatches task events to registered observers.
@@ -45,6 +50,10 @@ pub struct Observer {
     /// Registry mapping task IDs to their corresponding callback
     /// implementations
     callbacks: Arc<Mutex<HashMap<i64, Arc<dyn Callback + Send + Sync + 'static>>>>,
+    /// Event streams subscribed to each task
+    streams: EventSenders,
+    /// Callbacks registered for the number of running tasks
+    run_count: RunCountCallbacks,
     /// Handle to the background task listening for events
     listener: Mutex<Option<JoinHandle<()>>>,
 }
@@ -162,6 +171,16 @@ pub trait Callback {
     /// # Parameters
     /// * `waiting_reason` - Reason the task is waiting.
     fn on_wait(&self, waiting_reason: WaitingReason) {}
+    /// Called when the server rejects the bearer token of a task configured
+    /// to refresh it.
+    ///
+    /// Runs on a blocking thread, so it may fetch the token synchronously.
+    ///
+    /// # Returns
+    /// The new token, or `None` to let the task fail with the rejection.
+    fn on_token_refresh(&self) -> Option<String> {
+        None
+    }
 }
 
 impl Observer {
@@ -179,6 +198,8 @@ impl Observer {
     pub fn new() -> Self {
         Observer {
             callbacks: Arc::new(Mutex::new(HashMap::new())),
+            streams: Arc::new(Mutex::new(HashMap::new())),
+            run_count: Arc::new(Mutex::new(Vec::new())),
             listener: Mutex::new(None),
         }
     }
@@ -213,6 +234,7 @@ impl Observer {
     pub fn set_listenr(&self, file: File) {
         let mut listener = UdsListener::new(file);
         let callbacks = self.callbacks.clone();
+        let streams = self.streams.clone();
 
         // Spawn background task to process incoming messages
         let handle = ylong_runtime::spawn(async move {
@@ -225,6 +247,8 @@ impl Observer {
                             if let Some(callback) = callbacks.lock().unwrap().get(&task_id) {
                                 callback.on_response(&response);
                             }
+                            let event = TaskEvent::Response(response.clone());
+                            event::publish(&streams, task_id, event);
                         }
                         Message::NotifyData(data) => {
                             let task_id = data.task_id as i64;
@@ -304,18 +328,40 @@ impl Observer {
                                     },
                                 }
                             }
+                            if let Some(event) = TaskEvent::from_notify_data(data.clone()) {
+                                event::publish(&streams, task_id, event);
+                            }
                         }
                         Message::Faults(faultOccur) => {
                             let task_id = faultOccur.task_id as i64;
                             if let Some(callback) = callbacks.lock().unwrap().get(&task_id) {
                                 callback.on_fault(faultOccur.faults);
                             }
+                            let event = TaskEvent::Fault(faultOccur.faults);
+                            event::publish(&streams, task_id, event);
                         }
                         Message::WAIT(wait) => {
                             let task_id = wait.task_id as i64;
                             if let Some(callback) = callbacks.lock().unwrap().get(&task_id) {
                                 callback.on_wait(wait.waiting_reason);
                             }
+                            let event = TaskEvent::Wait(wait.waiting_reason);
+                            event::publish(&streams, task_id, event);
+                        }
+                        Message::TokenRefresh(task_id) => {
+                            let task_id = *task_id as i64;
+                            let callback = callbacks.lock().unwrap().get(&task_id).cloned();
+                            // Always answer, so the task does not wait for the timeout
+                            ylong_runtime::spawn_blocking(move || {
+                                let token = callback
+                                    .and_then(|callback| callback.on_token_refresh())
+                                    .unwrap_or_default();
+                                if let Err(e) =
+                                    RequestClient::get_instance().set_auth_token(task_id, &token)
+                                {
+                                    error!("set auth token of {} failed: {:?}", task_id, e);
+                                }
+                            });
                         }
                     },
                     Err(e) => error!("Error receiving message: {}", e),
@@ -395,6 +441,108 @@ impl Observer {
         self.callbacks.lock().unwrap().remove(&task_id);
     }
 
+    /// Subscribes a stream to the events of a specific task.
+    ///
+    /// Unlike callbacks, any number of streams can be subscribed to the same
+    /// task. Each stream ends after the task completes, fails or is removed.
+    ///
+    /// # Parameters
+    /// - `task_id`: ID of the task to monitor
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use request_client::listen::Observer;
+    ///
+    /// let observer = Observer::new();
+    /// let mut events = observer.subscribe(12345);
+    /// assert_eq!(events.task_id(), 12345);
+    /// ```
+    pub fn subscribe(&self, task_id: i64) -> TaskEventStream {
+        TaskEventStream::subscribe(&self.streams, task_id)
+    }
+
+    /// Registers a callback for the number of running tasks.
+    ///
+    /// The run count is not sent over the channel of the observer but to a
+    /// remote object the process subscribes with. `subscribe` is called with
+    /// that object when the first callback is registered, and the callback is
+    /// unregistered again if it fails.
+    ///
+    /// The callback is registered before `subscribe` is called and the lock
+    /// on the callbacks is released during the call, since the service sends
+    /// the current run count before replying to the subscription.
+    ///
+    /// # Parameters
+    /// - `callback`: Callback to receive the run count
+    /// - `subscribe`: Subscribes the process to the run count with the given
+    ///   remote object
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or the error code of `subscribe` on failure
+    pub fn register_run_count_callback<F>(
+        &self,
+        callback: Arc<dyn RunCountCallback + Send + Sync + 'static>,
+        subscribe: F,
+    ) -> Result<(), i32>
+    where
+        F: FnOnce(RemoteObj) -> Result<(), i32>,
+    {
+        let first = {
+            let mut callbacks = self.run_count.lock().unwrap();
+            if callbacks.iter().any(|cb| Arc::ptr_eq(cb, &callback)) {
+                return Ok(());
+            }
+            callbacks.push(callback.clone());
+            callbacks.len() == 1
+        };
+        if !first {
+            return Ok(());
+        }
+        let stub = RunCountStub::new(self.run_count.clone());
+        let res = match RemoteObj::from_stub(stub) {
+            Some(obj) => subscribe(obj),
+            None => {
+                error!("Failed to create run count stub");
+                Err(OTHER)
+            }
+        };
+        if res.is_err() {
+            self.run_count
+                .lock()
+                .unwrap()
+                .retain(|cb| !Arc::ptr_eq(cb, &callback));
+        }
+        res
+    }
+
+    /// Unregisters a callback for the number of running tasks.
+    ///
+    /// `unsubscribe` is called once the last callback is unregistered.
+    ///
+    /// # Parameters
+    /// - `callback`: Callback previously registered
+    /// - `unsubscribe`: Unsubscribes the process from the run count
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or the error code of `unsubscribe` on failure
+    pub fn unregister_run_count_callback<F>(
+        &self,
+        callback: &Arc<dyn RunCountCallback + Send + Sync + 'static>,
+        unsubscribe: F,
+    ) -> Result<(), i32>
+    where
+        F: FnOnce() -> Result<(), i32>,
+    {
+        let mut callbacks = self.run_count.lock().unwrap();
+        let len = callbacks.len();
+        callbacks.retain(|cb| !Arc::ptr_eq(cb, callback));
+        if callbacks.len() != len && callbacks.is_empty() {
+            unsubscribe()?;
+        }
+        Ok(())
+    }
+
     /// Resolves the file path for an incoming header notification based on the
     /// task's multipart configuration and the notification index.
     ///
@@ -0,0 +1,105 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Notifications of the number of running tasks.
+//!
+//! Unlike task events, which are read from the channel of the `Observer`, the
+//! run count is sent by the download service to a remote object registered
+//! by the process. `RunCountStub` is that object: it dispatches the run count
+//! to the `RunCountCallback`s registered with the `Observer`.
+
+use std::sync::{Arc, Mutex};
+
+use ipc::parcel::MsgParcel;
+use ipc::remote::RemoteStub;
+use ipc::IpcStatusCode;
+use request_core::interface;
+
+/// Interface token of the run count notifications.
+const NOTIFY_TOKEN: &str = "OHOS.Download.NotifyInterface";
+
+/// Callbacks registered for the run count.
+pub(crate) type RunCountCallbacks =
+    Arc<Mutex<Vec<Arc<dyn RunCountCallback + Send + Sync + 'static>>>>;
+
+/// Trait for receiving the number of running tasks of the download service.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use request_client::{RequestClient, RunCountCallback};
+///
+/// struct RunCountLogger;
+///
+/// impl RunCountCallback for RunCountLogger {
+///     fn on_run_count(&self, run_count: i64) {
+///         println!("{} tasks running", run_count);
+///     }
+/// }
+///
+/// let callback: Arc<dyn RunCountCallback + Send + Sync> = Arc::new(RunCountLogger);
+/// RequestClient::get_instance().subscribe_run_count(callback.clone());
+/// // ...
+/// RequestClient::get_instance().unsubscribe_run_count(&callback);
+/// ```
+pub trait RunCountCallback {
+    /// Called when the number of running tasks changes, and once with the
+    /// current number on subscription.
+    ///
+    /// # Parameters
+    /// - `run_count`: Number of tasks running in the download service
+    fn on_run_count(&self, run_count: i64);
+}
+
+/// Remote object receiving the run count from the download service.
+pub(crate) struct RunCountStub {
+    /// Callbacks the run count is dispatched to
+    callbacks: RunCountCallbacks,
+}
+
+impl RunCountStub {
+    /// Creates a stub dispatching to the given callbacks.
+    pub(crate) fn new(callbacks: RunCountCallbacks) -> Self {
+        Self { callbacks }
+    }
+}
+
+impl RemoteStub for RunCountStub {
+    fn on_remote_request(&self, code: u32, data: &mut MsgParcel, _reply: &mut MsgParcel) -> i32 {
+        match data.read_interface_token() {
+            Ok(token) if token == NOTIFY_TOKEN => {}
+            _ => {
+                error!("Run count notification gets invalid token");
+                return IpcStatusCode::Failed as i32;
+            }
+        }
+        if code != interface::NOTIFY_RUN_COUNT {
+            error!("Run count notification gets invalid code {}", code);
+            return IpcStatusCode::Failed as i32;
+        }
+        let Ok(run_count) = data.read::<i64>() else {
+            error!("Run count notification gets no run count");
+            return IpcStatusCode::Failed as i32;
+        };
+        debug!("Run count notified: {}", run_count);
+
+        // Dispatch outside the lock so that callbacks may unsubscribe
+        let callbacks = self.callbacks.lock().unwrap().clone();
+        for callback in callbacks {
+            callback.on_run_count(run_count);
+        }
+        0
+    }
+}
@@ -49,6 +49,11 @@ const NOTIFY_DATA: i16 = 1;
 const FAULTS: i16 = 2;
 const WAIT: i16 = 3;
 
+/// Message type identifier for bearer token refresh requests.
+///
+/// Indicates that the service needs a new bearer token for a task.
+const TOKEN_REFRESH: i16 = 4;
+
 /// Listener for Unix Domain Socket messages.
 ///
 /// Provides methods to receive and process messages from the download service.
@@ -174,6 +179,9 @@ impl UdsListener {
         } else if msg_type == WAIT {
             let wait: Wait = uds.read();
             Ok(Message::WAIT(wait))
+        } else if msg_type == TOKEN_REFRESH {
+            let task_id: u32 = uds.read();
+            Ok(Message::TokenRefresh(task_id))
         } else {
             Err(io::Error::new(
                 io::ErrorKind::InvalidData,
@@ -194,6 +202,8 @@ pub enum Message {
     NotifyData(NotifyData),
     Faults(FaultOccur),
     WAIT(Wait),
+    /// Request for a new bearer token of the task with the given ID
+    TokenRefresh(u32),
 }
 
 /// Validates the header of a received message.
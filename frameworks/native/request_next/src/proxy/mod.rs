@@ -21,6 +21,7 @@
 // Submodules
 mod notification; // Handles notification-related functionality
 mod query; // Provides task query capabilities
+mod run_count; // Subscribes to the number of running tasks
 mod state; // Manages service state tracking
 mod task; // Implements task management operations
 mod uds; // Handles Unix Domain Socket communication
@@ -160,4 +160,36 @@ impl RequestProxy {
         }
         Ok(())
     }
+
+    /// Disables the notifications of download tasks.
+    ///
+    /// # Arguments
+    /// * `task_ids` - IDs of the tasks whose notifications are disabled
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` with the result code of each task, in the order of
+    ///   `task_ids`, if the request was sent
+    /// - `Err(i32)` with an error code if the request failed
+    pub(crate) fn disable_task_notifications(&self, task_ids: &[i64]) -> Result<Vec<i32>, i32> {
+        let remote = self.remote()?;
+        let mut data = MsgParcel::new();
+
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+        let task_ids = task_ids
+            .iter()
+            .map(|task_id| task_id.to_string())
+            .collect::<Vec<_>>();
+        data.write(&task_ids).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::DISABLE_TASK_NOTIFICATION, &mut data)
+            .map_err(|_| 13400003)?;
+
+        // One result code is replied for each task
+        let codes = task_ids
+            .iter()
+            .map(|_| reply.read::<i32>().unwrap())
+            .collect();
+        Ok(codes)
+    }
 }
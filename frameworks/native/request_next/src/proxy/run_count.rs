@@ -0,0 +1,74 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Run count subscription for the RequestProxy.
+//!
+//! The download service notifies the number of running tasks to a remote
+//! object registered by the subscribing process. A process has at most one
+//! subscription, identified by its pid on the service side.
+
+// IPC and download core dependencies
+use ipc::parcel::MsgParcel;
+use ipc::remote::RemoteObj;
+use request_core::interface;
+
+// Local dependencies
+use super::{RequestProxy, SERVICE_TOKEN};
+
+impl RequestProxy {
+    /// Subscribes the process to the number of running tasks.
+    ///
+    /// # Arguments
+    /// * `obj` - Remote object the service sends the run count to
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub(crate) fn subscribe_run_count(&self, obj: RemoteObj) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+        data.write_remote(obj).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::SUB_RUN_COUNT, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+
+    /// Unsubscribes the process from the number of running tasks.
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub(crate) fn unsubscribe_run_count(&self) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::UNSUB_RUN_COUNT, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+}
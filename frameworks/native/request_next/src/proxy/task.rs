@@ -15,17 +15,17 @@
 //!
 //! This module implements methods for managing download tasks through the
 //! RequestProxy, including creating, starting, pausing, resuming, removing,
-//! stopping, and setting speed limits for tasks.
+//! stopping, setting speed limits and switching the mode of tasks.
 
 // IPC and parcel dependencies
 use ipc::parcel::MsgParcel;
 // Download core dependencies
-use request_core::config::TaskConfig;
+use request_core::config::{Mode, TaskConfig};
 use request_core::interface;
 
 // Local dependencies
 use super::{RequestProxy, SERVICE_TOKEN};
-use crate::client::error::CreateTaskError;
+use crate::client::error::RequestError;
 
 impl RequestProxy {
     /// Creates a new download task with the provided configuration.
@@ -38,7 +38,7 @@ impl RequestProxy {
     ///
     /// # Returns
     /// - `Ok(i64)` with the task ID if the task was created successfully
-    /// - `Err(CreateTaskError)` if an error occurred during task creation
+    /// - `Err(RequestError)` if an error occurred during task creation
     ///
     /// # Examples
     ///
@@ -61,7 +61,7 @@ impl RequestProxy {
     ///
     /// # Panics
     /// - Panics if parcel operations fail due to IPC errors
-    pub(crate) fn create(&self, config: &TaskConfig) -> Result<i64, CreateTaskError> {
+    pub(crate) fn create(&self, config: &TaskConfig) -> Result<i64, RequestError> {
         let remote = self.remote()?;
         let mut data = MsgParcel::new();
         // Write interface token to identify the service
@@ -79,13 +79,13 @@ impl RequestProxy {
         // Check first error code
         let code = reply.read::<i32>().unwrap();
         if code != 0 {
-            return Err(CreateTaskError::Code(code));
+            return Err(RequestError::from(code));
         }
 
         // Check second error code
         let code = reply.read::<i32>().unwrap();
         if code != 0 {
-            return Err(CreateTaskError::Code(code));
+            return Err(RequestError::from(code));
         }
         // Read and return the task ID
         let task_id = reply.read::<u32>().unwrap();
@@ -430,4 +430,164 @@ impl RequestProxy {
         }
         Ok(())
     }
+
+    /// Sets the bandwidth caps of the device or of an application.
+    ///
+    /// # Arguments
+    /// * `scope` - 0 for the device budget, 1 for an application budget
+    /// * `uid` - Application uid, ignored for the device budget
+    /// * `metered` - Cap on metered networks in bytes per second, 0 for
+    ///   unlimited
+    /// * `unmetered` - Cap on unmetered networks in bytes per second, 0 for
+    ///   unlimited
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub(crate) fn set_bandwidth_budget(
+        &self,
+        scope: u32,
+        uid: u64,
+        metered: i64,
+        unmetered: i64,
+    ) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&scope).unwrap();
+        data.write(&uid).unwrap();
+        data.write(&metered).unwrap();
+        data.write(&unmetered).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::SET_BANDWIDTH_BUDGET, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+
+    /// Seeds the cookie jar of an application.
+    ///
+    /// # Arguments
+    /// * `uid` - Application uid, only used by callers with the download
+    ///   permission
+    /// * `url` - The url the cookies are set for
+    /// * `cookies` - The cookies in the `Set-Cookie` format
+    ///
+    /// # Returns
+    /// The number of cookies accepted on success, or an error code on failure
+    pub(crate) fn set_cookies(&self, uid: u64, url: &str, cookies: &[String]) -> Result<u32, i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&uid).unwrap();
+        data.write(&url.to_string()).unwrap();
+        data.write(&(cookies.len() as u32)).unwrap();
+        for cookie in cookies {
+            data.write(cookie).unwrap();
+        }
+
+        let mut reply = remote
+            .send_request(interface::SET_COOKIES, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(reply.read::<u32>().unwrap())
+    }
+
+    /// Clears the cookie jar of an application.
+    ///
+    /// # Arguments
+    /// * `uid` - Application uid, only used by callers with the download
+    ///   permission
+    /// * `domain` - The domain whose cookies are removed, with its
+    ///   subdomains, or empty for all cookies
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub(crate) fn clear_cookies(&self, uid: u64, domain: &str) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&uid).unwrap();
+        data.write(&domain.to_string()).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::CLEAR_COOKIES, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+
+    /// Hands over a refreshed bearer token of a task.
+    ///
+    /// # Arguments
+    /// * `task_id` - The unique identifier of the task
+    /// * `token` - The new token, or empty to decline the refresh
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub(crate) fn set_auth_token(&self, task_id: i64, token: &str) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&task_id.to_string()).unwrap();
+        data.write(&token.to_string()).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::SET_AUTH_TOKEN, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
+
+    /// Switches a task between the foreground and background modes.
+    ///
+    /// # Arguments
+    /// * `task_id` - The unique identifier of the task
+    /// * `mode` - The mode the task runs in from now on
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or an error code on failure
+    pub(crate) fn set_mode(&self, task_id: i64, mode: Mode) -> Result<(), i32> {
+        let remote = self.remote()?;
+
+        let mut data = MsgParcel::new();
+        data.write_interface_token(SERVICE_TOKEN).unwrap();
+
+        data.write(&task_id.to_string()).unwrap();
+        data.write(&(mode as u32)).unwrap();
+
+        let mut reply = remote
+            .send_request(interface::SET_MODE, &mut data)
+            .map_err(|_| 13400003)?;
+
+        let code = reply.read::<i32>().unwrap(); // error code
+        if code != 0 {
+            return Err(code);
+        }
+        Ok(())
+    }
 }
@@ -26,6 +26,10 @@ pub struct DataVerifier {}
 
 impl ConfigVerifier for DataVerifier {
     // todo: check
+    fn field(&self) -> &'static str {
+        "data"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         if matches!(config.version, Version::API10) {
             if matches!(config.common_data.action, Action::Upload) && !config.data.is_empty() {
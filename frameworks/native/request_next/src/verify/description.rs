@@ -25,6 +25,10 @@ use crate::verify::ConfigVerifier;
 pub struct DescriptionVerifier {}
 
 impl ConfigVerifier for DescriptionVerifier {
+    fn field(&self) -> &'static str {
+        "description"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         const DESCRIPTION_MAX_LEN: usize = 1024;
         if matches!(config.version, Version::API9) {
@@ -25,6 +25,10 @@ use crate::verify::ConfigVerifier;
 pub struct FileSpecVerifier {}
 
 impl ConfigVerifier for FileSpecVerifier {
+    fn field(&self) -> &'static str {
+        "files"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         if matches!(config.common_data.action, Action::Upload) {
             if config.file_specs.is_empty() {
@@ -25,6 +25,10 @@ use crate::verify::ConfigVerifier;
 pub struct FormItemVerifier {}
 
 impl ConfigVerifier for FormItemVerifier {
+    fn field(&self) -> &'static str {
+        "data"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         if matches!(config.version, Version::API9)
             && matches!(config.common_data.action, Action::Upload)
@@ -26,6 +26,10 @@ use crate::verify::ConfigVerifier;
 pub struct IndexVerifier {}
 
 impl ConfigVerifier for IndexVerifier {
+    fn field(&self) -> &'static str {
+        "index"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         if matches!(config.common_data.action, Action::Download) {
             if config.common_data.index != 0 {
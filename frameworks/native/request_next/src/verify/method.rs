@@ -13,27 +13,39 @@
 
 //! HTTP method validation for task configuration.
 //!
-//! Downloads accept only `GET` or `POST`; uploads accept only `PUT` or `POST`.
+//! Downloads accept `GET`, `POST`, `PATCH`, `DELETE`, `HEAD` or `OPTIONS`;
+//! uploads accept `PUT`, `POST` or `PATCH`. Any other method is rejected.
 
 use request_core::config::{Action, TaskConfig};
+use request_utils::method::{DOWNLOAD_METHODS, UPLOAD_METHODS};
 
 use crate::verify::ConfigVerifier;
 
 /// Verifier for the HTTP method of a task config.
 ///
-/// Downloads accept only `GET` or `POST`; uploads accept only `PUT` or `POST`.
+/// Methods must be uppercase and supported by the task action.
 pub struct MethodVerifier {}
 
 impl ConfigVerifier for MethodVerifier {
+    fn field(&self) -> &'static str {
+        "method"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         if matches!(config.common_data.action, Action::Download) {
-            if config.method != "GET" && config.method != "POST" {
-                error!("method must be GET or POST for download action");
+            if !DOWNLOAD_METHODS.contains(&config.method.as_str()) {
+                error!(
+                    "method must be one of {:?} for download action",
+                    DOWNLOAD_METHODS
+                );
                 return Err(401);
             }
         } else {
-            if config.method != "PUT" && config.method != "POST" {
-                error!("method must be PUT or POST for upload action");
+            if !UPLOAD_METHODS.contains(&config.method.as_str()) {
+                error!(
+                    "method must be one of {:?} for upload action",
+                    UPLOAD_METHODS
+                );
                 return Err(401);
             }
         }
@@ -25,6 +25,10 @@ use crate::verify::ConfigVerifier;
 pub struct MinSpeedVerifier {}
 
 impl ConfigVerifier for MinSpeedVerifier {
+    fn field(&self) -> &'static str {
+        "minSpeed"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         if config.min_speed.speed < 0 {
             error!(
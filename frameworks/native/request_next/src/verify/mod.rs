@@ -37,6 +37,8 @@ use std::sync::OnceLock;
 
 use request_core::config::TaskConfig;
 
+use crate::client::error::RequestError;
+
 /// Aggregator that runs all registered config verifiers against a task config.
 pub struct TaskConfigVerifier {
     verifiers: Vec<Box<dyn ConfigVerifier>>,
@@ -46,10 +48,16 @@ impl TaskConfigVerifier {
     /// Runs every registered verifier against the given config.
     ///
     /// # Returns
-    /// `Ok(())` if all verifiers pass, or the first error code on failure.
-    pub fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
+    /// `Ok(())` if all verifiers pass, or `RequestError::InvalidField` naming
+    /// the field of the first verifier that fails, with the code it reported.
+    pub fn verify(&self, config: &TaskConfig) -> Result<(), RequestError> {
         for verifier in &self.verifiers {
-            verifier.verify(config)?;
+            verifier
+                .verify(config)
+                .map_err(|code| RequestError::InvalidField {
+                    field: verifier.field(),
+                    code,
+                })?;
         }
         Ok(())
     }
@@ -80,5 +88,8 @@ impl TaskConfigVerifier {
 }
 
 pub(crate) trait ConfigVerifier: Send + Sync {
+    /// Name of the config field checked, as exposed to applications.
+    fn field(&self) -> &'static str;
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32>;
 }
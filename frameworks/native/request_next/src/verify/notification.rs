@@ -26,6 +26,10 @@ use crate::verify::ConfigVerifier;
 pub struct NotificationVerifier {}
 
 impl ConfigVerifier for NotificationVerifier {
+    fn field(&self) -> &'static str {
+        "notification"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         const NOTIFICATION_TITLE_MAX_LEN: usize = 1024;
         const NOTIFICATION_TEXT_MAX_LEN: usize = 3072;
@@ -25,6 +25,10 @@ use crate::verify::ConfigVerifier;
 pub struct ProxyVerifier {}
 
 impl ConfigVerifier for ProxyVerifier {
+    fn field(&self) -> &'static str {
+        "proxy"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         const PROXY_MAX_LEN: usize = 512;
         if matches!(config.version, Version::API9) {
@@ -29,6 +29,10 @@ const MAX_TIMEOUT: u64 = 604800;
 pub struct TimeoutVerifier {}
 
 impl ConfigVerifier for TimeoutVerifier {
+    fn field(&self) -> &'static str {
+        "timeout"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         if config.timeout.connection_timeout < MIN_TIMEOUT {
             error!("Parameter verification failed, the connectionTimeout is less than minimum");
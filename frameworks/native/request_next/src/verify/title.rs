@@ -25,6 +25,10 @@ use crate::verify::ConfigVerifier;
 pub struct TitleVerifier {}
 
 impl ConfigVerifier for TitleVerifier {
+    fn field(&self) -> &'static str {
+        "title"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         const TITLE_MAX_LEN: usize = 256;
         if matches!(config.version, Version::API10) && config.title.len() > TITLE_MAX_LEN {
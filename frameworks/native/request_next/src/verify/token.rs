@@ -27,6 +27,10 @@ use crate::verify::ConfigVerifier;
 pub struct TokenVerifier {}
 
 impl ConfigVerifier for TokenVerifier {
+    fn field(&self) -> &'static str {
+        "token"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         const TOKEN_MAX_LEN: usize = 2048;
         const TOKEN_MIN_LEN: usize = 8;
@@ -27,6 +27,10 @@ use crate::verify::ConfigVerifier;
 pub struct UrlVerifier {}
 
 impl ConfigVerifier for UrlVerifier {
+    fn field(&self) -> &'static str {
+        "url"
+    }
+
     fn verify(&self, config: &TaskConfig) -> Result<(), i32> {
         const URL_MAX_SIZE: usize = 8192;
         if config.url.len() > URL_MAX_SIZE {
@@ -11,25 +11,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use request_client::client::error::CreateTaskError;
+use request_client::client::error::RequestError;
 use request_client::check::file::DownloadPathError;
 
-// @tc.name: ut_create_task_error_from_download_path_error
-// @tc.desc: Test CreateTaskError conversion from DownloadPathError
+// @tc.name: ut_request_error_from_download_path_error
+// @tc.desc: Test RequestError conversion from DownloadPathError
 // @tc.precon: NA
 // @tc.step: 1. Create DownloadPathError::EmptyPath
-//           2. Convert to CreateTaskError using From trait
+//           2. Convert to RequestError using From trait
 //           3. Verify conversion result
-// @tc.expect: CreateTaskError::DownloadPath variant is created
+// @tc.expect: RequestError::DownloadPath variant is created
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
-fn ut_create_task_error_from_download_path_error() {
+fn ut_request_error_from_download_path_error() {
     let path_error = DownloadPathError::EmptyPath;
-    let task_error: CreateTaskError = path_error.into();
+    let task_error: RequestError = path_error.into();
     
     match task_error {
-        CreateTaskError::DownloadPath(err) => {
+        RequestError::DownloadPath(err) => {
             match err {
                 DownloadPathError::EmptyPath => {}
                 _ => panic!("Expected EmptyPath variant"),
@@ -39,172 +39,172 @@ fn ut_create_task_error_from_download_path_error() {
     }
 }
 
-// @tc.name: ut_create_task_error_from_i32
-// @tc.desc: Test CreateTaskError conversion from i32
+// @tc.name: ut_request_error_from_i32
+// @tc.desc: Test RequestError conversion from i32
 // @tc.precon: NA
 // @tc.step: 1. Create i32 error code
-//           2. Convert to CreateTaskError using From trait
+//           2. Convert to RequestError using From trait
 //           3. Verify conversion result
-// @tc.expect: CreateTaskError::Code variant is created with correct code
+// @tc.expect: RequestError::Other variant is created with correct code
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
-fn ut_create_task_error_from_i32() {
+fn ut_request_error_from_i32() {
     let error_code: i32 = -1;
-    let task_error: CreateTaskError = error_code.into();
+    let task_error: RequestError = error_code.into();
     
     match task_error {
-        CreateTaskError::Code(code) => assert_eq!(code, -1),
-        _ => panic!("Expected Code variant"),
+        RequestError::Other(code) => assert_eq!(code, -1),
+        _ => panic!("Expected Other variant"),
     }
 }
 
-// @tc.name: ut_create_task_error_debug_code
-// @tc.desc: Test Debug trait for CreateTaskError::Code
+// @tc.name: ut_request_error_debug_code
+// @tc.desc: Test Debug trait for RequestError::Other
 // @tc.precon: NA
-// @tc.step: 1. Create CreateTaskError::Code variant
+// @tc.step: 1. Create RequestError::Other variant
 //           2. Format with Debug trait
 //           3. Verify output contains expected content
-// @tc.expect: Debug output contains "Code"
+// @tc.expect: Debug output contains "Other"
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
-fn ut_create_task_error_debug_code() {
-    let error = CreateTaskError::Code(123);
+fn ut_request_error_debug_code() {
+    let error = RequestError::Other(123);
     let debug_str = format!("{:?}", error);
-    assert!(debug_str.contains("Code"));
+    assert!(debug_str.contains("Other"));
     assert!(debug_str.contains("123"));
 }
 
-// @tc.name: ut_create_task_error_debug_download_path
-// @tc.desc: Test Debug trait for CreateTaskError::DownloadPath
+// @tc.name: ut_request_error_debug_download_path
+// @tc.desc: Test Debug trait for RequestError::DownloadPath
 // @tc.precon: NA
-// @tc.step: 1. Create CreateTaskError::DownloadPath variant
+// @tc.step: 1. Create RequestError::DownloadPath variant
 //           2. Format with Debug trait
 //           3. Verify output contains expected content
 // @tc.expect: Debug output contains "DownloadPath"
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
-fn ut_create_task_error_debug_download_path() {
-    let error = CreateTaskError::DownloadPath(DownloadPathError::TooLongPath);
+fn ut_request_error_debug_download_path() {
+    let error = RequestError::DownloadPath(DownloadPathError::TooLongPath);
     let debug_str = format!("{:?}", error);
     assert!(debug_str.contains("DownloadPath"));
     assert!(debug_str.contains("TooLongPath"));
 }
 
-// @tc.name: ut_create_task_error_from_download_path_invalid
-// @tc.desc: Test CreateTaskError conversion from DownloadPathError::InvalidPath
+// @tc.name: ut_request_error_from_download_path_invalid
+// @tc.desc: Test RequestError conversion from DownloadPathError::InvalidPath
 // @tc.precon: NA
 // @tc.step: 1. Create DownloadPathError::InvalidPath
-//           2. Convert to CreateTaskError
+//           2. Convert to RequestError
 //           3. Verify correct variant
-// @tc.expect: CreateTaskError::DownloadPath contains InvalidPath
+// @tc.expect: RequestError::DownloadPath contains InvalidPath
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
-fn ut_create_task_error_from_download_path_invalid() {
+fn ut_request_error_from_download_path_invalid() {
     let path_error = DownloadPathError::InvalidPath;
-    let task_error: CreateTaskError = path_error.into();
+    let task_error: RequestError = path_error.into();
     
     match task_error {
-        CreateTaskError::DownloadPath(DownloadPathError::InvalidPath) => {}
+        RequestError::DownloadPath(DownloadPathError::InvalidPath) => {}
         _ => panic!("Expected DownloadPath(InvalidPath) variant"),
     }
 }
 
-// @tc.name: ut_create_task_error_from_download_path_already_exists
-// @tc.desc: Test CreateTaskError conversion from DownloadPathError::AlreadyExists
+// @tc.name: ut_request_error_from_download_path_already_exists
+// @tc.desc: Test RequestError conversion from DownloadPathError::AlreadyExists
 // @tc.precon: NA
 // @tc.step: 1. Create DownloadPathError::AlreadyExists
-//           2. Convert to CreateTaskError
+//           2. Convert to RequestError
 //           3. Verify correct variant
-// @tc.expect: CreateTaskError::DownloadPath contains AlreadyExists
+// @tc.expect: RequestError::DownloadPath contains AlreadyExists
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
-fn ut_create_task_error_from_download_path_already_exists() {
+fn ut_request_error_from_download_path_already_exists() {
     let path_error = DownloadPathError::AlreadyExists;
-    let task_error: CreateTaskError = path_error.into();
+    let task_error: RequestError = path_error.into();
     
     match task_error {
-        CreateTaskError::DownloadPath(DownloadPathError::AlreadyExists) => {}
+        RequestError::DownloadPath(DownloadPathError::AlreadyExists) => {}
         _ => panic!("Expected DownloadPath(AlreadyExists) variant"),
     }
 }
 
-// @tc.name: ut_create_task_error_from_download_path_bundle_name
-// @tc.desc: Test CreateTaskError conversion from DownloadPathError::BundleNameNotMap
+// @tc.name: ut_request_error_from_download_path_bundle_name
+// @tc.desc: Test RequestError conversion from DownloadPathError::BundleNameNotMap
 // @tc.precon: NA
 // @tc.step: 1. Create DownloadPathError::BundleNameNotMap
-//           2. Convert to CreateTaskError
+//           2. Convert to RequestError
 //           3. Verify correct variant
-// @tc.expect: CreateTaskError::DownloadPath contains BundleNameNotMap
+// @tc.expect: RequestError::DownloadPath contains BundleNameNotMap
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
-fn ut_create_task_error_from_download_path_bundle_name() {
+fn ut_request_error_from_download_path_bundle_name() {
     let path_error = DownloadPathError::BundleNameNotMap;
-    let task_error: CreateTaskError = path_error.into();
+    let task_error: RequestError = path_error.into();
     
     match task_error {
-        CreateTaskError::DownloadPath(DownloadPathError::BundleNameNotMap) => {}
+        RequestError::DownloadPath(DownloadPathError::BundleNameNotMap) => {}
         _ => panic!("Expected DownloadPath(BundleNameNotMap) variant"),
     }
 }
 
-// @tc.name: ut_create_task_error_from_i32_zero
-// @tc.desc: Test CreateTaskError conversion from i32 zero
+// @tc.name: ut_request_error_from_i32_zero
+// @tc.desc: Test RequestError conversion from i32 zero
 // @tc.precon: NA
 // @tc.step: 1. Create i32 zero error code
-//           2. Convert to CreateTaskError
+//           2. Convert to RequestError
 //           3. Verify correct code
-// @tc.expect: CreateTaskError::Code contains 0
+// @tc.expect: RequestError::Other contains 0
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
-fn ut_create_task_error_from_i32_zero() {
+fn ut_request_error_from_i32_zero() {
     let error_code: i32 = 0;
-    let task_error: CreateTaskError = error_code.into();
+    let task_error: RequestError = error_code.into();
     
     match task_error {
-        CreateTaskError::Code(code) => assert_eq!(code, 0),
-        _ => panic!("Expected Code variant"),
+        RequestError::Other(code) => assert_eq!(code, 0),
+        _ => panic!("Expected Other variant"),
     }
 }
 
-// @tc.name: ut_create_task_error_from_i32_positive
-// @tc.desc: Test CreateTaskError conversion from positive i32
+// @tc.name: ut_request_error_from_i32_positive
+// @tc.desc: Test RequestError conversion from positive i32
 // @tc.precon: NA
 // @tc.step: 1. Create positive i32 error code
-//           2. Convert to CreateTaskError
+//           2. Convert to RequestError
 //           3. Verify correct code
-// @tc.expect: CreateTaskError::Code contains positive value
+// @tc.expect: RequestError::Other contains positive value
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
-fn ut_create_task_error_from_i32_positive() {
+fn ut_request_error_from_i32_positive() {
     let error_code: i32 = 100;
-    let task_error: CreateTaskError = error_code.into();
+    let task_error: RequestError = error_code.into();
     
     match task_error {
-        CreateTaskError::Code(code) => assert_eq!(code, 100),
-        _ => panic!("Expected Code variant"),
+        RequestError::Other(code) => assert_eq!(code, 100),
+        _ => panic!("Expected Other variant"),
     }
 }
 
-// @tc.name: ut_create_task_error_multiple_conversions
+// @tc.name: ut_request_error_multiple_conversions
 // @tc.desc: Test multiple error conversions in sequence
 // @tc.precon: NA
 // @tc.step: 1. Create multiple different errors
-//           2. Convert each to CreateTaskError
+//           2. Convert each to RequestError
 //           3. Verify each conversion
 // @tc.expect: All conversions produce correct variants
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
-fn ut_create_task_error_multiple_conversions() {
-    let errors: Vec<CreateTaskError> = vec![
+fn ut_request_error_multiple_conversions() {
+    let errors: Vec<RequestError> = vec![
         DownloadPathError::EmptyPath.into(),
         DownloadPathError::TooLongPath.into(),
         DownloadPathError::InvalidPath.into(),
@@ -216,12 +216,90 @@ fn ut_create_task_error_multiple_conversions() {
     assert_eq!(errors.len(), 6);
     
     match &errors[0] {
-        CreateTaskError::DownloadPath(DownloadPathError::EmptyPath) => {}
+        RequestError::DownloadPath(DownloadPathError::EmptyPath) => {}
         _ => panic!("Expected EmptyPath"),
     }
     
     match &errors[3] {
-        CreateTaskError::Code(-1) => {}
-        _ => panic!("Expected Code(-1)"),
+        RequestError::Other(-1) => {}
+        _ => panic!("Expected Other(-1)"),
     }
 }
+
+// @tc.name: ut_request_error_from_service_codes
+// @tc.desc: Test RequestError conversion from the error codes of the service
+// @tc.precon: NA
+// @tc.step: 1. Convert each known error code to RequestError
+//           2. Verify the variant and its code
+// @tc.expect: Known codes map to named variants keeping their code
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_request_error_from_service_codes() {
+    assert!(matches!(RequestError::from(201), RequestError::Permission));
+    assert!(matches!(RequestError::from(202), RequestError::SystemApi));
+    assert!(matches!(RequestError::from(401), RequestError::Parameter));
+    assert!(matches!(RequestError::from(13400001), RequestError::FileOperation));
+    assert!(matches!(RequestError::from(13400003), RequestError::ServiceUnavailable));
+    assert!(matches!(RequestError::from(21900004), RequestError::QueueFull));
+    assert!(matches!(RequestError::from(21900005), RequestError::TaskMode));
+    assert!(matches!(RequestError::from(21900006), RequestError::TaskNotFound));
+    assert!(matches!(RequestError::from(21900007), RequestError::TaskState));
+    assert!(matches!(RequestError::from(21900008), RequestError::GroupNotFound));
+    assert!(matches!(RequestError::from(21900009), RequestError::ClientCertInvalid));
+
+    for code in [
+        201, 202, 401, 13400001, 13400003, 21900004, 21900005, 21900006, 21900007, 21900008,
+        21900009,
+    ] {
+        assert_eq!(RequestError::from(code).code(), code);
+        assert_eq!(RequestError::from(code), code);
+    }
+}
+
+// @tc.name: ut_request_error_invalid_field
+// @tc.desc: Test RequestError for a config field failing verification
+// @tc.precon: NA
+// @tc.step: 1. Create RequestError::InvalidField
+//           2. Check its code and Display output
+// @tc.expect: The code is the one of the verifier and the message names the
+//             field
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_request_error_invalid_field() {
+    let error = RequestError::InvalidField {
+        field: "url",
+        code: 401,
+    };
+    assert_eq!(error.code(), 401);
+    assert!(error.to_string().contains("url"));
+
+    let error = RequestError::InvalidField {
+        field: "files",
+        code: 13400001,
+    };
+    assert_eq!(error.code(), 13400001);
+}
+
+// @tc.name: ut_request_error_download_path_code
+// @tc.desc: Test the code of RequestError::DownloadPath
+// @tc.precon: NA
+// @tc.step: 1. Convert DownloadPathError variants to RequestError
+//           2. Check their codes and error source
+// @tc.expect: Invalid paths are parameter errors, others file errors, and
+//             the path error is the source
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_request_error_download_path_code() {
+    use std::error::Error;
+
+    let error = RequestError::from(DownloadPathError::InvalidPath);
+    assert_eq!(error.code(), 401);
+    assert!(error.source().is_some());
+
+    let error = RequestError::from(DownloadPathError::AlreadyExists);
+    assert_eq!(error.code(), 13400001);
+    assert!(error.to_string().contains("already exists"));
+}
@@ -0,0 +1,83 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use request_client::listen::Observer;
+use request_client::{StateChange, TaskEvent};
+use request_core::info::{Faults, Progress, State, WaitingReason};
+
+fn progress() -> Progress {
+    Progress {
+        state: State::Running,
+        index: 0,
+        processed: 0,
+        total_processed: 0,
+        sizes: vec![],
+        extras: HashMap::new(),
+        body_bytes: vec![],
+    }
+}
+
+// @tc.name: ut_state_change_is_final
+// @tc.desc: Test which state changes end the run of a task
+// @tc.precon: NA
+// @tc.step: 1. Check is_final for every state change
+// @tc.expect: Only completion, failure and removal are final
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_state_change_is_final() {
+    assert!(StateChange::Completed.is_final());
+    assert!(StateChange::Failed(404).is_final());
+    assert!(StateChange::Removed.is_final());
+    assert!(!StateChange::Paused.is_final());
+    assert!(!StateChange::Resumed.is_final());
+    assert!(!StateChange::HeaderReceived.is_final());
+}
+
+// @tc.name: ut_task_event_is_final
+// @tc.desc: Test which task events end an event stream
+// @tc.precon: NA
+// @tc.step: 1. Check is_final for events of every kind
+// @tc.expect: Only final state changes end the stream
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_task_event_is_final() {
+    assert!(TaskEvent::StateChanged(StateChange::Completed, progress()).is_final());
+    assert!(!TaskEvent::StateChanged(StateChange::Paused, progress()).is_final());
+    assert!(!TaskEvent::Progress(progress()).is_final());
+    assert!(!TaskEvent::Fault(Faults::Others).is_final());
+    assert!(!TaskEvent::Wait(WaitingReason::NETWORK_NOT_MATCH).is_final());
+}
+
+// @tc.name: ut_observer_subscribe
+// @tc.desc: Test subscribing several streams to the same task
+// @tc.precon: NA
+// @tc.step: 1. Create Observer
+//           2. Subscribe two streams to a task and one to another task
+// @tc.expect: Each stream reports the task it was subscribed to
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_observer_subscribe() {
+    let observer = Observer::new();
+    let first = observer.subscribe(12345);
+    let second = observer.subscribe(12345);
+    let other = observer.subscribe(54321);
+
+    assert_eq!(first.task_id(), 12345);
+    assert_eq!(second.task_id(), 12345);
+    assert_eq!(other.task_id(), 54321);
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+use std::sync::Arc;
+
+use request_client::listen::Observer;
+use request_client::RunCountCallback;
+
+struct TestRunCount;
+
+impl RunCountCallback for TestRunCount {
+    fn on_run_count(&self, _run_count: i64) {}
+}
+
+fn callback() -> Arc<dyn RunCountCallback + Send + Sync> {
+    Arc::new(TestRunCount)
+}
+
+// @tc.name: ut_run_count_subscribe_once
+// @tc.desc: Test that the process subscribes to the run count once
+// @tc.precon: NA
+// @tc.step: 1. Register two run count callbacks, one of them twice
+//           2. Unregister them one by one
+// @tc.expect: The process subscribes with the first callback and
+//             unsubscribes with the last one
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_run_count_subscribe_once() {
+    let observer = Observer::new();
+    let first = callback();
+    let second = callback();
+    let subscribed = Cell::new(0);
+    let unsubscribed = Cell::new(0);
+
+    for cb in [&first, &first, &second] {
+        observer
+            .register_run_count_callback(cb.clone(), |_| {
+                subscribed.set(subscribed.get() + 1);
+                Ok(())
+            })
+            .unwrap();
+    }
+    assert_eq!(subscribed.get(), 1);
+
+    observer
+        .unregister_run_count_callback(&first, || {
+            unsubscribed.set(unsubscribed.get() + 1);
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(unsubscribed.get(), 0);
+    observer
+        .unregister_run_count_callback(&second, || {
+            unsubscribed.set(unsubscribed.get() + 1);
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(unsubscribed.get(), 1);
+}
+
+// @tc.name: ut_run_count_subscribe_failed
+// @tc.desc: Test that a callback is not registered if subscribing fails
+// @tc.precon: NA
+// @tc.step: 1. Register a run count callback with a failing subscription
+//           2. Register it again with a successful subscription
+// @tc.expect: The error is returned and the process subscribes again
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_run_count_subscribe_failed() {
+    let observer = Observer::new();
+    let cb = callback();
+
+    let res = observer.register_run_count_callback(cb.clone(), |_| Err(201));
+    assert_eq!(res, Err(201));
+
+    let subscribed = Cell::new(false);
+    observer
+        .register_run_count_callback(cb.clone(), |_| {
+            subscribed.set(true);
+            Ok(())
+        })
+        .unwrap();
+    assert!(subscribed.get());
+}
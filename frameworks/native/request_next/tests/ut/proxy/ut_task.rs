@@ -20,7 +20,7 @@
 use std::collections::HashMap;
 
 use request_core::config::{Action, TaskConfig};
-use request_next::client::error::CreateTaskError;
+use request_next::client::error::RequestError;
 use request_next::proxy::RequestProxy;
 
 // @tc.name: ut_task_config_default_values
@@ -57,11 +57,11 @@ fn ut_task_config_with_url() {
 }
 
 // @tc.name: ut_task_create_task_error_code_variant
-// @tc.desc: Test CreateTaskError::Code variant for IPC error handling
+// @tc.desc: Test RequestError code for IPC error handling
 // @tc.precon: NA
-// @tc.step: 1. Create CreateTaskError::Code with various error codes
+// @tc.step: 1. Convert various error codes replied by the service
 //           2. Verify error code is preserved
-// @tc.expect: CreateTaskError::Code correctly stores error codes from IPC
+// @tc.expect: RequestError::code returns the code replied over IPC
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
@@ -69,47 +69,44 @@ fn ut_task_create_task_error_code_variant() {
     let error_codes = vec![0, -1, 13400001, 13400002, 13400003];
     
     for code in error_codes {
-        let error = CreateTaskError::Code(code);
-        match error {
-            CreateTaskError::Code(c) => assert_eq!(c, code),
-            _ => panic!("Expected Code variant"),
-        }
+        let error = RequestError::from(code);
+        assert_eq!(error.code(), code);
     }
 }
 
 // @tc.name: ut_task_create_task_error_from_i32
-// @tc.desc: Test CreateTaskError From<i32> trait for error conversion
+// @tc.desc: Test RequestError From<i32> trait for error conversion
 // @tc.precon: NA
-// @tc.step: 1. Convert i32 error codes to CreateTaskError
+// @tc.step: 1. Convert i32 error codes to RequestError
 //           2. Verify conversion works correctly
-// @tc.expect: i32 error codes are correctly converted to CreateTaskError::Code
+// @tc.expect: The service exception code converts to ServiceUnavailable
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
 fn ut_task_create_task_error_from_i32() {
-    let error: CreateTaskError = 13400003.into();
+    let error: RequestError = 13400003.into();
     
     match error {
-        CreateTaskError::Code(code) => assert_eq!(code, 13400003),
-        _ => panic!("Expected Code variant"),
+        RequestError::ServiceUnavailable => {}
+        _ => panic!("Expected ServiceUnavailable variant"),
     }
 }
 
 // @tc.name: ut_task_create_task_error_debug
-// @tc.desc: Test CreateTaskError Debug implementation for logging
+// @tc.desc: Test RequestError Debug implementation for logging
 // @tc.precon: NA
-// @tc.step: 1. Create CreateTaskError and format with Debug
+// @tc.step: 1. Create RequestError and format with Debug
 //           2. Verify output contains error information
 // @tc.expect: Debug output is useful for error logging
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
 fn ut_task_create_task_error_debug() {
-    let error = CreateTaskError::Code(13400001);
+    let error = RequestError::Other(13400002);
     let debug_str = format!("{:?}", error);
     
-    assert!(debug_str.contains("Code"), "Debug output should contain 'Code'");
-    assert!(debug_str.contains("13400001"), "Debug output should contain error code");
+    assert!(debug_str.contains("Other"), "Debug output should contain 'Other'");
+    assert!(debug_str.contains("13400002"), "Debug output should contain error code");
 }
 
 // @tc.name: ut_task_id_string_conversion
@@ -245,26 +242,21 @@ fn ut_task_config_clone_independence() {
 }
 
 // @tc.name: ut_task_error_equality
-// @tc.desc: Test CreateTaskError equality comparison
+// @tc.desc: Test RequestError comparison with error codes
 // @tc.precon: NA
-// @tc.step: 1. Create two CreateTaskError with same code
-//           2. Verify they are considered equal in matching
-// @tc.expect: CreateTaskError with same code can be matched
+// @tc.step: 1. Create two RequestError from the same code and one from another
+//           2. Compare them with the codes
+// @tc.expect: RequestError equals its own code only
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
 fn ut_task_error_equality() {
-    let error1 = CreateTaskError::Code(13400001);
-    let error2 = CreateTaskError::Code(13400001);
-    let error3 = CreateTaskError::Code(13400002);
+    let error1 = RequestError::from(13400001);
+    let error2 = RequestError::from(13400001);
+    let error3 = RequestError::from(13400002);
     
-    match (error1, error2, error3) {
-        (CreateTaskError::Code(c1), CreateTaskError::Code(c2), CreateTaskError::Code(c3)) => {
-            assert_eq!(c1, c2, "Same error codes should match");
-            assert_ne!(c1, c3, "Different error codes should not match");
-        }
-        _ => panic!("Expected all Code variants"),
-    }
+    assert!(error1 == error2.code(), "Same error codes should match");
+    assert!(error1 != error3.code(), "Different error codes should not match");
 }
 
 // @tc.name: ut_task_config_version_field
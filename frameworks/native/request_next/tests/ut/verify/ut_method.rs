@@ -80,8 +80,8 @@ fn ut_method_verifier_download_invalid_method() {
 // @tc.precon: NA
 // @tc.step: 1. Create MethodVerifier
 //           2. Create TaskConfig with download action and DELETE method
-//           3. Verify config fails validation
-// @tc.expect: Verification fails with error code 401
+//           3. Verify config passes validation
+// @tc.expect: Verification passes for download with DELETE
 // @tc.type: FUNC
 // @tc.require: issueNumber
 #[test]
@@ -89,8 +89,44 @@ fn ut_method_verifier_download_delete_method() {
     let verifier = MethodVerifier {};
     let config = create_config_with_method(Action::Download, "DELETE");
     let result = verifier.verify(&config);
-    assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), 401);
+    assert!(result.is_ok());
+}
+
+// @tc.name: ut_method_verifier_download_extended_methods
+// @tc.desc: Test MethodVerifier with download action and PATCH, HEAD and
+//           OPTIONS methods
+// @tc.precon: NA
+// @tc.step: 1. Create MethodVerifier
+//           2. Create TaskConfigs with download action and each method
+//           3. Verify every config passes validation
+// @tc.expect: Verification passes for all three methods
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_method_verifier_download_extended_methods() {
+    let verifier = MethodVerifier {};
+    for method in ["PATCH", "HEAD", "OPTIONS"] {
+        let config = create_config_with_method(Action::Download, method);
+        assert!(verifier.verify(&config).is_ok());
+    }
+}
+
+// @tc.name: ut_method_verifier_unknown_method
+// @tc.desc: Test MethodVerifier with an unknown method
+// @tc.precon: NA
+// @tc.step: 1. Create MethodVerifier
+//           2. Create TaskConfigs with an unknown method for both actions
+//           3. Verify every config fails validation
+// @tc.expect: Verification fails with error code 401
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_method_verifier_unknown_method() {
+    let verifier = MethodVerifier {};
+    for action in [Action::Download, Action::Upload] {
+        let config = create_config_with_method(action, "FETCH");
+        assert_eq!(verifier.verify(&config).unwrap_err(), 401);
+    }
 }
 
 // @tc.name: ut_method_verifier_upload_put
@@ -127,6 +163,23 @@ fn ut_method_verifier_upload_post() {
     assert!(result.is_ok());
 }
 
+// @tc.name: ut_method_verifier_upload_patch
+// @tc.desc: Test MethodVerifier with upload action and PATCH method
+// @tc.precon: NA
+// @tc.step: 1. Create MethodVerifier
+//           2. Create TaskConfig with upload action and PATCH method
+//           3. Verify config passes validation
+// @tc.expect: Verification passes for upload with PATCH
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_method_verifier_upload_patch() {
+    let verifier = MethodVerifier {};
+    let config = create_config_with_method(Action::Upload, "PATCH");
+    let result = verifier.verify(&config);
+    assert!(result.is_ok());
+}
+
 // @tc.name: ut_method_verifier_upload_invalid_method
 // @tc.desc: Test MethodVerifier with upload action and invalid method
 // @tc.precon: NA
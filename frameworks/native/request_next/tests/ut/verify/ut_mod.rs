@@ -13,6 +13,7 @@
 
 use request_core::config::{Action, TaskConfig, TaskConfigBuilder, Timeout, Version};
 use request_core::file::FileSpec;
+use request_client::client::error::RequestError;
 use request_client::verify::{TaskConfigVerifier, ConfigVerifier};
 
 fn create_valid_download_config() -> TaskConfig {
@@ -210,3 +211,35 @@ fn ut_task_config_verifier_api9_config() {
         .build();
     assert!(verifier.verify(&config).is_ok());
 }
+
+// @tc.name: ut_task_config_verifier_invalid_field_name
+// @tc.desc: Test that TaskConfigVerifier reports the failing field
+// @tc.precon: NA
+// @tc.step: 1. Get TaskConfigVerifier instance
+//           2. Verify configs with an invalid url and an invalid title
+// @tc.expect: The errors name the url and title fields
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_task_config_verifier_invalid_field_name() {
+    let verifier = TaskConfigVerifier::get_instance();
+    let mut config = create_valid_download_config();
+    config.url = "invalid_url".to_string();
+    assert!(matches!(
+        verifier.verify(&config),
+        Err(RequestError::InvalidField {
+            field: "url",
+            code: 401
+        })
+    ));
+
+    let mut config = create_valid_download_config();
+    config.title = "a".repeat(257);
+    assert!(matches!(
+        verifier.verify(&config),
+        Err(RequestError::InvalidField {
+            field: "title",
+            code: 401
+        })
+    ));
+}
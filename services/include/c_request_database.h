@@ -124,6 +124,10 @@ constexpr const char *REQUEST_TASK_TABLE_ADD_TOTAL_TIMEOUT = "ALTER TABLE reques
                                                              "INTEGER";
 constexpr const char *REQUEST_TASK_TABLE_ADD_TASK_TIME = "ALTER TABLE request_task ADD COLUMN task_time "
                                                          "INTEGER";
+constexpr const char *REQUEST_TASK_TABLE_ADD_UPLOAD_COMMITTED = "ALTER TABLE request_task ADD COLUMN "
+                                                                "upload_committed TEXT";
+constexpr const char *REQUEST_TASK_TABLE_ADD_CONDITIONS = "ALTER TABLE request_task ADD COLUMN conditions "
+                                                          "INTEGER DEFAULT 0";
 
 constexpr const char *REQUEST_TASK_TABLE_COL_PROXY = "proxy";
 constexpr const char *REQUEST_TASK_TABLE_COL_CERTIFICATE_PINS = "certificate_pins";
@@ -136,6 +140,8 @@ constexpr const char *REQUEST_TASK_TABLE_COL_MIN_SPEED_DURATION = "min_speed_dur
 constexpr const char *REQUEST_TASK_TABLE_COL_CONNECTION_TIMEOUT = "connection_timeout";
 constexpr const char *REQUEST_TASK_TABLE_COL_TOTAL_TIMEOUT = "total_timeout";
 constexpr const char *REQUEST_TASK_TABLE_COL_TASK_TIME = "task_time";
+constexpr const char *REQUEST_TASK_TABLE_COL_UPLOAD_COMMITTED = "upload_committed";
+constexpr const char *REQUEST_TASK_TABLE_COL_CONDITIONS = "conditions";
 
 struct TaskFilter;
 struct NetworkInfo;
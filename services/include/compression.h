@@ -0,0 +1,64 @@
+/*
+* Copyright (c) 2026 Huawei Device Co., Ltd.
+* Licensed under the Apache License, Version 2.0 (the "License");
+* you may not use this file except in compliance with the License.
+* You may obtain a copy of the License at
+*
+*     http://www.apache.org/licenses/LICENSE-2.0
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific language governing permissions and
+* limitations under the License.
+*/
+
+#ifndef REQUEST_COMPRESSION_H
+#define REQUEST_COMPRESSION_H
+
+#include <cstdint>
+#include <memory>
+
+#include "brotli/decode.h"
+#include "cxx.h"
+#include "zlib.h"
+
+namespace OHOS::Request {
+class ZStream {
+public:
+    ZStream(bool inflating, bool rawFallback);
+    ~ZStream();
+    ZStream(const ZStream &) = delete;
+    ZStream &operator=(const ZStream &) = delete;
+
+    int32_t Inflate(rust::Slice<const uint8_t> input, rust::Vec<uint8_t> &output);
+    int32_t Deflate(rust::Slice<const uint8_t> input, bool finish, rust::Vec<uint8_t> &output);
+
+private:
+    z_stream stream_ {};
+    bool inflating_;
+    bool rawFallback_;
+    bool ready_ = false;
+};
+
+class BrotliStream {
+public:
+    BrotliStream();
+    ~BrotliStream();
+    BrotliStream(const BrotliStream &) = delete;
+    BrotliStream &operator=(const BrotliStream &) = delete;
+
+    int32_t Decode(rust::Slice<const uint8_t> input, rust::Vec<uint8_t> &output);
+
+private:
+    BrotliDecoderState *state_ = nullptr;
+};
+
+std::unique_ptr<ZStream> NewInflateStream(bool rawFallback);
+std::unique_ptr<ZStream> NewGzipStream();
+std::unique_ptr<BrotliStream> NewBrotliStream();
+int32_t Inflate(ZStream &stream, rust::Slice<const uint8_t> input, rust::Vec<uint8_t> &output);
+int32_t Deflate(ZStream &stream, rust::Slice<const uint8_t> input, bool finish, rust::Vec<uint8_t> &output);
+int32_t BrotliDecode(BrotliStream &stream, rust::Slice<const uint8_t> input, rust::Vec<uint8_t> &output);
+} // namespace OHOS::Request
+#endif // REQUEST_COMPRESSION_H
@@ -28,6 +28,8 @@ bool CheckPermission(uint64_t tokenId, rust::str permission);
 bool PublishStateChangeEvent(rust::str bundleName, uint32_t taskId, int32_t state, int32_t uid);
 int32_t UpdatePolicy(bool result);
 bool IsCalledByHAP(uint32_t tokenId);
+int64_t GetAvailableSpace(rust::str path);
+int32_t PreallocateFile(int32_t fd, int64_t offset, int64_t len);
 
 } // namespace OHOS::Request
 
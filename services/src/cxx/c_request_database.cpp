@@ -590,6 +590,12 @@ void RequestDBUpgradeFrom60(OHOS::NativeRdb::RdbStore &store)
     if (!ColumnExists(store, REQUEST_TASK_TABLE_COL_TASK_TIME)) {
         store.ExecuteSql(REQUEST_TASK_TABLE_ADD_TASK_TIME);
     }
+    if (!ColumnExists(store, REQUEST_TASK_TABLE_COL_UPLOAD_COMMITTED)) {
+        store.ExecuteSql(REQUEST_TASK_TABLE_ADD_UPLOAD_COMMITTED);
+    }
+    if (!ColumnExists(store, REQUEST_TASK_TABLE_COL_CONDITIONS)) {
+        store.ExecuteSql(REQUEST_TASK_TABLE_ADD_CONDITIONS);
+    }
 }
 
 int RequestDBUpgrade(OHOS::NativeRdb::RdbStore &store)
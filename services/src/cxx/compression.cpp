@@ -0,0 +1,201 @@
+/*
+* Copyright (c) 2026 Huawei Device Co., Ltd.
+* Licensed under the Apache License, Version 2.0 (the "License");
+* you may not use this file except in compliance with the License.
+* You may obtain a copy of the License at
+*
+*     http://www.apache.org/licenses/LICENSE-2.0
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific language governing permissions and
+* limitations under the License.
+*/
+
+#include "compression.h"
+
+#include "log.h"
+
+namespace OHOS::Request {
+namespace {
+constexpr size_t CHUNK_SIZE = 16 * 1024;
+// Adding 32 to the window bits detects both the zlib and the gzip header.
+constexpr int INFLATE_AUTO_WINDOW_BITS = MAX_WBITS + 32;
+// Adding 16 to the window bits writes a gzip header and trailer.
+constexpr int GZIP_WINDOW_BITS = MAX_WBITS + 16;
+constexpr int GZIP_MEM_LEVEL = 8;
+constexpr int32_t STREAM_CONTINUE = 0;
+constexpr int32_t STREAM_END = 1;
+constexpr int32_t STREAM_ERROR = -1;
+} // namespace
+
+ZStream::ZStream(bool inflating, bool rawFallback) : inflating_(inflating), rawFallback_(rawFallback)
+{
+    int ret = inflating_ ? inflateInit2(&stream_, INFLATE_AUTO_WINDOW_BITS)
+                         : deflateInit2(&stream_, Z_DEFAULT_COMPRESSION, Z_DEFLATED, GZIP_WINDOW_BITS,
+                               GZIP_MEM_LEVEL, Z_DEFAULT_STRATEGY);
+    if (ret != Z_OK) {
+        REQUEST_HILOGE("zlib stream init failed, ret: %{public}d", ret);
+        return;
+    }
+    ready_ = true;
+}
+
+ZStream::~ZStream()
+{
+    if (!ready_) {
+        return;
+    }
+    if (inflating_) {
+        inflateEnd(&stream_);
+    } else {
+        deflateEnd(&stream_);
+    }
+}
+
+int32_t ZStream::Inflate(rust::Slice<const uint8_t> input, rust::Vec<uint8_t> &output)
+{
+    if (!ready_ || !inflating_) {
+        return STREAM_ERROR;
+    }
+    uint8_t buffer[CHUNK_SIZE];
+    stream_.next_in = const_cast<Bytef *>(input.data());
+    stream_.avail_in = static_cast<uInt>(input.size());
+    while (true) {
+        stream_.next_out = buffer;
+        stream_.avail_out = CHUNK_SIZE;
+        int ret = inflate(&stream_, Z_NO_FLUSH);
+        // Some servers send raw deflate data for the `deflate` coding instead
+        // of the zlib format, which fails on the header.
+        if (ret == Z_DATA_ERROR && rawFallback_ && stream_.total_out == 0) {
+            rawFallback_ = false;
+            if (inflateReset2(&stream_, -MAX_WBITS) != Z_OK) {
+                return STREAM_ERROR;
+            }
+            stream_.next_in = const_cast<Bytef *>(input.data());
+            stream_.avail_in = static_cast<uInt>(input.size());
+            continue;
+        }
+        size_t produced = CHUNK_SIZE - stream_.avail_out;
+        for (size_t i = 0; i < produced; i++) {
+            output.push_back(buffer[i]);
+        }
+        if (ret == Z_STREAM_END) {
+            return STREAM_END;
+        }
+        if (ret == Z_BUF_ERROR || (ret == Z_OK && stream_.avail_out != 0)) {
+            return STREAM_CONTINUE;
+        }
+        if (ret != Z_OK) {
+            REQUEST_HILOGE("inflate failed, ret: %{public}d", ret);
+            return STREAM_ERROR;
+        }
+    }
+}
+
+int32_t ZStream::Deflate(rust::Slice<const uint8_t> input, bool finish, rust::Vec<uint8_t> &output)
+{
+    if (!ready_ || inflating_) {
+        return STREAM_ERROR;
+    }
+    uint8_t buffer[CHUNK_SIZE];
+    stream_.next_in = const_cast<Bytef *>(input.data());
+    stream_.avail_in = static_cast<uInt>(input.size());
+    int flush = finish ? Z_FINISH : Z_NO_FLUSH;
+    while (true) {
+        stream_.next_out = buffer;
+        stream_.avail_out = CHUNK_SIZE;
+        int ret = deflate(&stream_, flush);
+        if (ret == Z_STREAM_ERROR) {
+            REQUEST_HILOGE("deflate failed, ret: %{public}d", ret);
+            return STREAM_ERROR;
+        }
+        size_t produced = CHUNK_SIZE - stream_.avail_out;
+        for (size_t i = 0; i < produced; i++) {
+            output.push_back(buffer[i]);
+        }
+        if (ret == Z_STREAM_END) {
+            return STREAM_END;
+        }
+        if (!finish && stream_.avail_out != 0) {
+            return STREAM_CONTINUE;
+        }
+    }
+}
+
+BrotliStream::BrotliStream()
+{
+    state_ = BrotliDecoderCreateInstance(nullptr, nullptr, nullptr);
+    if (state_ == nullptr) {
+        REQUEST_HILOGE("brotli stream init failed");
+    }
+}
+
+BrotliStream::~BrotliStream()
+{
+    if (state_ != nullptr) {
+        BrotliDecoderDestroyInstance(state_);
+    }
+}
+
+int32_t BrotliStream::Decode(rust::Slice<const uint8_t> input, rust::Vec<uint8_t> &output)
+{
+    if (state_ == nullptr) {
+        return STREAM_ERROR;
+    }
+    uint8_t buffer[CHUNK_SIZE];
+    const uint8_t *nextIn = input.data();
+    size_t availIn = input.size();
+    while (true) {
+        uint8_t *nextOut = buffer;
+        size_t availOut = CHUNK_SIZE;
+        BrotliDecoderResult ret =
+            BrotliDecoderDecompressStream(state_, &availIn, &nextIn, &availOut, &nextOut, nullptr);
+        size_t produced = CHUNK_SIZE - availOut;
+        for (size_t i = 0; i < produced; i++) {
+            output.push_back(buffer[i]);
+        }
+        if (ret == BROTLI_DECODER_RESULT_SUCCESS) {
+            return STREAM_END;
+        }
+        if (ret == BROTLI_DECODER_RESULT_NEEDS_MORE_INPUT) {
+            return STREAM_CONTINUE;
+        }
+        if (ret != BROTLI_DECODER_RESULT_NEEDS_MORE_OUTPUT) {
+            REQUEST_HILOGE("brotli decode failed, err: %{public}d", BrotliDecoderGetErrorCode(state_));
+            return STREAM_ERROR;
+        }
+    }
+}
+
+std::unique_ptr<ZStream> NewInflateStream(bool rawFallback)
+{
+    return std::make_unique<ZStream>(true, rawFallback);
+}
+
+std::unique_ptr<ZStream> NewGzipStream()
+{
+    return std::make_unique<ZStream>(false, false);
+}
+
+std::unique_ptr<BrotliStream> NewBrotliStream()
+{
+    return std::make_unique<BrotliStream>();
+}
+
+int32_t Inflate(ZStream &stream, rust::Slice<const uint8_t> input, rust::Vec<uint8_t> &output)
+{
+    return stream.Inflate(input, output);
+}
+
+int32_t Deflate(ZStream &stream, rust::Slice<const uint8_t> input, bool finish, rust::Vec<uint8_t> &output)
+{
+    return stream.Deflate(input, finish, output);
+}
+
+int32_t BrotliDecode(BrotliStream &stream, rust::Slice<const uint8_t> input, rust::Vec<uint8_t> &output)
+{
+    return stream.Decode(input, output);
+}
+} // namespace OHOS::Request
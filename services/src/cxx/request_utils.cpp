@@ -15,6 +15,10 @@
 
 #include "request_utils.h"
 
+#include <cerrno>
+#include <fcntl.h>
+#include <linux/falloc.h>
+#include <sys/statvfs.h>
 #include <want.h>
 
 #include "ability_manager_client.h"
@@ -180,4 +184,22 @@ bool IsCalledByHAP(uint32_t tokenId)
     return AccessTokenKit::GetTokenTypeFlag(tokenId) == ATokenTypeEnum::TOKEN_HAP;
 }
 
+int64_t GetAvailableSpace(rust::str path)
+{
+    struct statvfs stat;
+    if (statvfs(std::string(path).c_str(), &stat) != 0) {
+        REQUEST_HILOGE("GetAvailableSpace failed, errno: %{public}d", errno);
+        return -1;
+    }
+    return static_cast<int64_t>(stat.f_bavail) * static_cast<int64_t>(stat.f_frsize);
+}
+
+int32_t PreallocateFile(int32_t fd, int64_t offset, int64_t len)
+{
+    if (fallocate(fd, FALLOC_FL_KEEP_SIZE, offset, len) != 0) {
+        return errno;
+    }
+    return 0;
+}
+
 } // namespace OHOS::Request
\ No newline at end of file
@@ -0,0 +1,315 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Persistence for task configuration fields outside the C schema.
+//!
+//! The `request_task` table is written through the C++ `CTaskConfig` layout.
+//! Configuration fields that are not part of that layout are kept in the
+//! `task_config_ext` side table, keyed by task id, and merged back into the
+//! `TaskConfig` loaded from `request_task`.
+
+use std::sync::Once;
+
+use super::REQUEST_DB;
+use crate::task::config::{Auth, Recurrence, TaskConfig};
+use crate::utils::split_string;
+
+const CREATE_TASK_CONFIG_EXT_TABLE: &str =
+    "CREATE TABLE IF NOT EXISTS task_config_ext (task_id INTEGER PRIMARY KEY, segments INTEGER)";
+
+/// Columns added to `task_config_ext` after it was first created.
+const TASK_CONFIG_EXT_COLUMNS: [&str; 24] = [
+    "ALTER TABLE task_config_ext ADD COLUMN digest TEXT DEFAULT ''",
+    "ALTER TABLE task_config_ext ADD COLUMN chunk_size INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN retry_attempts INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN retry_base_delay INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN retry_factor INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN retry_jitter INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN retry_reasons TEXT DEFAULT ''",
+    "ALTER TABLE task_config_ext ADD COLUMN retry_statuses TEXT DEFAULT ''",
+    "ALTER TABLE task_config_ext ADD COLUMN not_before INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN recurrence INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN recurrence_interval INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN recurrence_start INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN recurrence_end INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN expected_size INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN preallocate INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN compression INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN cookie_jar INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN auth_scheme INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN auth_username TEXT DEFAULT ''",
+    "ALTER TABLE task_config_ext ADD COLUMN auth_secret TEXT DEFAULT ''",
+    "ALTER TABLE task_config_ext ADD COLUMN auth_refresh INTEGER DEFAULT 0",
+    "ALTER TABLE task_config_ext ADD COLUMN client_cert_path TEXT DEFAULT ''",
+    "ALTER TABLE task_config_ext ADD COLUMN client_key_path TEXT DEFAULT ''",
+    "ALTER TABLE task_config_ext ADD COLUMN retries_made INTEGER DEFAULT 0",
+];
+
+/// Creates the `task_config_ext` table on first use.
+fn init() {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        if let Err(e) = REQUEST_DB.execute(CREATE_TASK_CONFIG_EXT_TABLE, ()) {
+            error!("Failed to create task_config_ext table: {}", e);
+            sys_event!(
+                ExecFault,
+                DfxCode::RDB_FAULT_04,
+                &format!("Failed to create task_config_ext table: {}", e)
+            );
+        }
+        // Fails harmlessly when the column already exists.
+        for sql in TASK_CONFIG_EXT_COLUMNS {
+            let _ = REQUEST_DB.execute(sql, ());
+        }
+    });
+}
+
+/// Stores the extended configuration fields of a newly created task.
+pub(crate) fn store(config: &TaskConfig) {
+    init();
+    let task_id = config.common_data.task_id;
+    let policy = &config.retry_policy;
+    let (recurrence, interval, start, end) = config.recurrence.to_parts();
+    if let Err(e) = REQUEST_DB.execute(
+        "INSERT OR REPLACE INTO task_config_ext (task_id, segments, digest, chunk_size, \
+         retry_attempts, retry_base_delay, retry_factor, retry_jitter, retry_reasons, \
+         retry_statuses, not_before, recurrence, recurrence_interval, recurrence_start, \
+         recurrence_end) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        (
+            task_id,
+            config.segments,
+            config.digest.as_str(),
+            config.chunk_size,
+            policy.max_attempts,
+            policy.base_delay,
+            policy.backoff_factor,
+            policy.jitter,
+            format!("{:?}", policy.reasons),
+            format!("{:?}", policy.statuses),
+            config.not_before,
+            recurrence,
+            interval,
+            start,
+            end,
+        ),
+    ) {
+        error!("Failed to store task {} config ext: {}", task_id, e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to store task {} config ext: {}", task_id, e)
+        );
+        return;
+    }
+    // Bound parameters are limited to 16 per statement.
+    let (auth_scheme, auth_username, auth_secret, auth_refresh) = config.auth.to_parts();
+    if let Err(e) = REQUEST_DB.execute(
+        "UPDATE task_config_ext SET expected_size = ?, preallocate = ?, compression = ?, \
+         cookie_jar = ?, auth_scheme = ?, auth_username = ?, auth_secret = ?, auth_refresh = ?, \
+         client_cert_path = ?, client_key_path = ? WHERE task_id = ?",
+        (
+            config.expected_size,
+            config.preallocate,
+            config.compression,
+            config.cookie_jar,
+            auth_scheme,
+            auth_username,
+            auth_secret,
+            auth_refresh,
+            config.client_cert_path.as_str(),
+            config.client_key_path.as_str(),
+            task_id,
+        ),
+    ) {
+        error!("Failed to store task {} storage config: {}", task_id, e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to store task {} storage config: {}", task_id, e)
+        );
+    }
+}
+
+/// Fills the extended configuration fields of a task loaded from
+/// `request_task`.
+///
+/// Fields are left untouched when the task has no `task_config_ext` row,
+/// which is the case for tasks created before the table existed.
+pub(crate) fn load(config: &mut TaskConfig) {
+    init();
+    let task_id = config.common_data.task_id;
+    #[allow(clippy::type_complexity)]
+    let mut rows = match REQUEST_DB.query::<(
+        u32,
+        String,
+        u64,
+        u32,
+        u64,
+        u32,
+        u32,
+        String,
+        String,
+        u64,
+        u32,
+        u64,
+        u32,
+        u32,
+        u64,
+        bool,
+    )>(
+        "SELECT segments, digest, chunk_size, retry_attempts, retry_base_delay, retry_factor, \
+         retry_jitter, retry_reasons, retry_statuses, not_before, recurrence, \
+         recurrence_interval, recurrence_start, recurrence_end, expected_size, preallocate \
+         FROM task_config_ext WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(rows) => rows,
+        Err(e) => {
+            error!("Failed to query task {} config ext: {}", task_id, e);
+            return;
+        }
+    };
+    if let Some((
+        segments,
+        digest,
+        chunk_size,
+        max_attempts,
+        base_delay,
+        backoff_factor,
+        jitter,
+        mut reasons,
+        mut statuses,
+        not_before,
+        recurrence,
+        interval,
+        start,
+        end,
+        expected_size,
+        preallocate,
+    )) = rows.next()
+    {
+        config.segments = segments;
+        config.digest = digest;
+        config.chunk_size = chunk_size;
+        let policy = &mut config.retry_policy;
+        policy.max_attempts = max_attempts;
+        policy.base_delay = base_delay;
+        policy.backoff_factor = backoff_factor;
+        policy.jitter = jitter;
+        policy.reasons = split_string(&mut reasons)
+            .filter_map(|reason| reason.parse().ok())
+            .collect();
+        policy.statuses = split_string(&mut statuses)
+            .filter_map(|status| status.parse().ok())
+            .collect();
+        config.not_before = not_before;
+        config.recurrence =
+            Recurrence::from_parts(recurrence, interval, start, end).unwrap_or_default();
+        config.expected_size = expected_size;
+        config.preallocate = preallocate;
+    }
+    // Results are limited to 16 columns per query.
+    match REQUEST_DB.query::<(bool, bool, u32, String, String, bool, String, String)>(
+        "SELECT compression, cookie_jar, auth_scheme, auth_username, auth_secret, auth_refresh, \
+         client_cert_path, client_key_path FROM task_config_ext WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(mut rows) => {
+            if let Some(row) = rows.next() {
+                let (compression, cookie_jar, scheme, username, secret, refresh, cert, key) = row;
+                config.compression = compression;
+                config.cookie_jar = cookie_jar;
+                config.auth =
+                    Auth::from_parts(scheme, username, secret, refresh).unwrap_or_default();
+                config.client_cert_path = cert;
+                config.client_key_path = key;
+            }
+        }
+        Err(e) => error!("Failed to query task {} transfer config: {}", task_id, e),
+    }
+}
+
+/// Replaces the stored secret of the authentication of a task, such as a
+/// refreshed bearer token.
+pub(crate) fn store_auth_secret(task_id: u32, secret: &str) {
+    init();
+    if let Err(e) = REQUEST_DB.execute(
+        "UPDATE task_config_ext SET auth_secret = ? WHERE task_id = ?",
+        (secret, task_id),
+    ) {
+        error!("Failed to store task {} auth secret: {}", task_id, e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to store task {} auth secret: {}", task_id, e)
+        );
+    }
+}
+
+/// Replaces the stored `not_before` time of a task, such as the start of the
+/// next occurrence of a recurring task.
+pub(crate) fn store_not_before(task_id: u32, not_before: u64) {
+    init();
+    if let Err(e) = REQUEST_DB.execute(
+        "UPDATE task_config_ext SET not_before = ? WHERE task_id = ?",
+        (not_before, task_id),
+    ) {
+        error!("Failed to store task {} not before: {}", task_id, e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to store task {} not before: {}", task_id, e)
+        );
+    }
+}
+
+/// Replaces the stored number of retry attempts a task made under its retry
+/// policy, so that they survive a restart during the retry delay.
+pub(crate) fn store_retries_made(task_id: u32, retries: u32) {
+    init();
+    if let Err(e) = REQUEST_DB.execute(
+        "UPDATE task_config_ext SET retries_made = ? WHERE task_id = ?",
+        (retries, task_id),
+    ) {
+        error!("Failed to store task {} retries made: {}", task_id, e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to store task {} retries made: {}", task_id, e)
+        );
+    }
+}
+
+/// Queries the stored number of retry attempts a task made under its retry
+/// policy, 0 if none is stored.
+pub(crate) fn query_retries_made(task_id: u32) -> u32 {
+    init();
+    match REQUEST_DB.query::<u32>(
+        "SELECT retries_made FROM task_config_ext WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(mut rows) => rows.next().unwrap_or(0),
+        Err(e) => {
+            error!("Failed to query task {} retries made: {}", task_id, e);
+            0
+        }
+    }
+}
+
+/// Removes the extended configuration of a task.
+pub(crate) fn remove(task_id: u32) {
+    init();
+    if let Err(e) = REQUEST_DB.execute("DELETE FROM task_config_ext WHERE task_id = ?", task_id) {
+        error!("Failed to remove task {} config ext: {}", task_id, e);
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Persistence of the cookie jars.
+//!
+//! Each cookie is a row of the `request_cookie` table, keyed by the uid of
+//! the jar and by the name, domain and path of the cookie. Session cookies,
+//! stored with an expiry of 0, only last as long as the service process: they
+//! are removed together with the expired cookies when the table is first
+//! used.
+
+use std::sync::Once;
+
+use super::REQUEST_DB;
+use crate::task::cookie::Cookie;
+use crate::utils::get_current_timestamp;
+
+/// Name, value, domain, path, host-only, secure, http-only, expiry and
+/// creation of a stored cookie.
+type CookieRow = (String, String, String, String, bool, bool, bool, u64, u64);
+
+const CREATE_REQUEST_COOKIE_TABLE: &str = "CREATE TABLE IF NOT EXISTS request_cookie (uid INTEGER, name TEXT, value TEXT, domain TEXT, path TEXT, host_only INTEGER, secure INTEGER, http_only INTEGER, expiry INTEGER, creation INTEGER, PRIMARY KEY (uid, name, domain, path))";
+
+/// Creates the `request_cookie` table and drops the cookies of the previous
+/// service process that are no longer valid.
+fn init() {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        if let Err(e) = REQUEST_DB.execute(CREATE_REQUEST_COOKIE_TABLE, ()) {
+            error!("Failed to create request_cookie table: {}", e);
+            sys_event!(
+                ExecFault,
+                DfxCode::RDB_FAULT_04,
+                &format!("Failed to create request_cookie table: {}", e)
+            );
+            return;
+        }
+        if let Err(e) = REQUEST_DB.execute(
+            "DELETE FROM request_cookie WHERE expiry <= ?",
+            get_current_timestamp(),
+        ) {
+            error!("Failed to remove stale cookies: {}", e);
+        }
+    });
+}
+
+/// Stores `cookie` in the jar of `uid`, replacing the cookie with the same
+/// name, domain and path but keeping its creation time.
+pub(crate) fn store(uid: u64, cookie: &Cookie) {
+    init();
+    if let Err(e) = REQUEST_DB.execute(
+        "INSERT OR REPLACE INTO request_cookie (uid, name, value, domain, path, host_only, \
+         secure, http_only, expiry, creation) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, \
+         COALESCE((SELECT creation FROM request_cookie WHERE uid = ? AND name = ? AND \
+         domain = ? AND path = ?), ?))",
+        (
+            uid,
+            cookie.name.as_str(),
+            cookie.value.as_str(),
+            cookie.domain.as_str(),
+            cookie.path.as_str(),
+            cookie.host_only,
+            cookie.secure,
+            cookie.http_only,
+            cookie.expiry,
+            uid,
+            cookie.name.as_str(),
+            cookie.domain.as_str(),
+            cookie.path.as_str(),
+            cookie.creation,
+        ),
+    ) {
+        error!("Failed to store cookie of uid {}: {}", uid, e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to store cookie of uid {}: {}", uid, e)
+        );
+    }
+}
+
+/// Removes the cookie with the name, domain and path of `cookie` from the
+/// jar of `uid`.
+pub(crate) fn remove(uid: u64, cookie: &Cookie) {
+    init();
+    if let Err(e) = REQUEST_DB.execute(
+        "DELETE FROM request_cookie WHERE uid = ? AND name = ? AND domain = ? AND path = ?",
+        (
+            uid,
+            cookie.name.as_str(),
+            cookie.domain.as_str(),
+            cookie.path.as_str(),
+        ),
+    ) {
+        error!("Failed to remove cookie of uid {}: {}", uid, e);
+    }
+}
+
+/// Returns the cookies of the jar of `uid` that have not expired.
+pub(crate) fn query(uid: u64) -> Vec<Cookie> {
+    init();
+    let rows = match REQUEST_DB.query::<CookieRow>(
+        "SELECT name, value, domain, path, host_only, secure, http_only, expiry, creation \
+         FROM request_cookie WHERE uid = ? AND (expiry = 0 OR expiry > ?)",
+        (uid, get_current_timestamp()),
+    ) {
+        Ok(rows) => rows,
+        Err(e) => {
+            error!("Failed to query cookies of uid {}: {}", uid, e);
+            return vec![];
+        }
+    };
+    rows.map(
+        |(name, value, domain, path, host_only, secure, http_only, expiry, creation)| Cookie {
+            name,
+            value,
+            domain,
+            path,
+            host_only,
+            secure,
+            http_only,
+            expiry,
+            creation,
+        },
+    )
+    .collect()
+}
+
+/// Removes the cookies of the jar of `uid` whose domain is `domain` or one of
+/// its subdomains, or all of them if `domain` is empty.
+pub(crate) fn clear(uid: u64, domain: &str) {
+    init();
+    let res = if domain.is_empty() {
+        REQUEST_DB.execute("DELETE FROM request_cookie WHERE uid = ?", uid)
+    } else {
+        REQUEST_DB.execute(
+            "DELETE FROM request_cookie WHERE uid = ? AND (domain = ? OR domain LIKE ?)",
+            (uid, domain, format!("%.{}", domain)),
+        )
+    };
+    if let Err(e) = res {
+        error!("Failed to clear cookies of uid {}: {}", uid, e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to clear cookies of uid {}: {}", uid, e)
+        );
+    }
+}
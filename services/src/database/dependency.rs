@@ -0,0 +1,144 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Persistence of the task dependency graph.
+//!
+//! Each edge of the graph is a row of the `task_dependency` table, stating
+//! that `task_id` may only start once `prerequisite` has completed. An edge is
+//! marked satisfied when its prerequisite completes, so that removing a
+//! completed prerequisite later does not affect its dependents. Edges are
+//! written when a task is constructed and removed together with the task, so
+//! the graph survives service restarts.
+
+use std::sync::Once;
+
+use super::REQUEST_DB;
+
+const CREATE_TASK_DEPENDENCY_TABLE: &str = "CREATE TABLE IF NOT EXISTS task_dependency (task_id INTEGER, prerequisite INTEGER, satisfied INTEGER DEFAULT 0, PRIMARY KEY (task_id, prerequisite))";
+
+/// Creates the `task_dependency` table on first use.
+fn init() {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        if let Err(e) = REQUEST_DB.execute(CREATE_TASK_DEPENDENCY_TABLE, ()) {
+            error!("Failed to create task_dependency table: {}", e);
+            sys_event!(
+                ExecFault,
+                DfxCode::RDB_FAULT_04,
+                &format!("Failed to create task_dependency table: {}", e)
+            );
+        }
+    });
+}
+
+/// Stores the prerequisites of a newly created task.
+pub(crate) fn store(task_id: u32, prerequisites: &[u32]) {
+    if prerequisites.is_empty() {
+        return;
+    }
+    init();
+    for prerequisite in prerequisites {
+        if let Err(e) = REQUEST_DB.execute(
+            "INSERT OR REPLACE INTO task_dependency (task_id, prerequisite) VALUES (?, ?)",
+            (task_id, *prerequisite),
+        ) {
+            error!("Failed to store task {} prerequisite: {}", task_id, e);
+            sys_event!(
+                ExecFault,
+                DfxCode::RDB_FAULT_04,
+                &format!("Failed to store task {} prerequisite: {}", task_id, e)
+            );
+        }
+    }
+}
+
+/// Returns the tasks that must complete before `task_id` starts.
+pub(crate) fn prerequisites(task_id: u32) -> Vec<u32> {
+    init();
+    match REQUEST_DB.query::<u32>(
+        "SELECT prerequisite FROM task_dependency WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(rows) => rows.collect(),
+        Err(e) => {
+            error!("Failed to query task {} prerequisites: {}", task_id, e);
+            vec![]
+        }
+    }
+}
+
+/// Returns the prerequisites of `task_id` that have not completed yet.
+pub(crate) fn pending_prerequisites(task_id: u32) -> Vec<u32> {
+    init();
+    match REQUEST_DB.query::<u32>(
+        "SELECT prerequisite FROM task_dependency WHERE task_id = ? AND satisfied = 0",
+        task_id,
+    ) {
+        Ok(rows) => rows.collect(),
+        Err(e) => {
+            error!("Failed to query task {} prerequisites: {}", task_id, e);
+            vec![]
+        }
+    }
+}
+
+/// Returns the tasks that wait for `task_id` to complete.
+pub(crate) fn dependents(task_id: u32) -> Vec<u32> {
+    init();
+    match REQUEST_DB.query::<u32>(
+        "SELECT task_id FROM task_dependency WHERE prerequisite = ? AND satisfied = 0",
+        task_id,
+    ) {
+        Ok(rows) => rows.collect(),
+        Err(e) => {
+            error!("Failed to query task {} dependents: {}", task_id, e);
+            vec![]
+        }
+    }
+}
+
+/// Marks the edges from `task_id` to its dependents satisfied once it has
+/// completed.
+pub(crate) fn satisfy(task_id: u32) {
+    init();
+    if let Err(e) = REQUEST_DB.execute(
+        "UPDATE task_dependency SET satisfied = 1 WHERE prerequisite = ?",
+        task_id,
+    ) {
+        error!("Failed to satisfy task {} dependents: {}", task_id, e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to satisfy task {} dependents: {}", task_id, e)
+        );
+    }
+}
+
+/// Removes the edges of a task deleted from `request_task`.
+///
+/// By then its dependents either started after it completed or have already
+/// failed, so the edges pointing to it are removed as well.
+pub(crate) fn remove(task_id: u32) {
+    init();
+    if let Err(e) = REQUEST_DB.execute(
+        "DELETE FROM task_dependency WHERE task_id = ? OR prerequisite = ?",
+        (task_id, task_id),
+    ) {
+        error!("Failed to remove task {} dependencies: {}", task_id, e);
+    }
+}
+
+#[cfg(test)]
+mod ut_dependency_store {
+    include!("../../tests/ut/manage/scheduler/ut_dependency_store.rs");
+}
@@ -15,6 +15,10 @@
 //!
 //! This module provides database operations and monitoring functionality.
 
+pub(crate) mod config_ext;
+pub(crate) mod cookie;
+pub(crate) mod dependency;
+pub(crate) mod temp_file;
 mod db_monitor;
 
 pub(crate) use db_monitor::monitor_database;
@@ -26,6 +30,7 @@ use rdb::{OpenConfig, RdbStore, SecurityLevel};
 
 use crate::service::notification_bar::NotificationDispatcher;
 use crate::task::info::State;
+use crate::task::segment;
 
 const DB_PATH: &str = if cfg!(test) {
     "/data/test/notification.db"
@@ -82,7 +87,7 @@ pub(crate) fn clear_database_by_state(pre_count: usize) -> Result<bool, ()> {
         if let Err(e) = REQUEST_DB.execute("DELETE from request_task WHERE task_id = ?", task_id) {
             error!("Failed to clear removed task {} info: {}", task_id, e);
         }
-        NotificationDispatcher::get_instance().clear_task_info(task_id);
+        clear_task_side_info(task_id);
     }
 
     // Clear Completed tasks older than 1 day
@@ -106,7 +111,7 @@ pub(crate) fn clear_database_by_state(pre_count: usize) -> Result<bool, ()> {
         if let Err(e) = REQUEST_DB.execute("DELETE from request_task WHERE task_id = ?", task_id) {
             error!("Failed to clear completed task {} info: {}", task_id, e);
         }
-        NotificationDispatcher::get_instance().clear_task_info(task_id);
+        clear_task_side_info(task_id);
     }
 
     // Clear other states (except Completed and Removed) older than 7 days
@@ -130,12 +135,21 @@ pub(crate) fn clear_database_by_state(pre_count: usize) -> Result<bool, ()> {
         if let Err(e) = REQUEST_DB.execute("DELETE from request_task WHERE task_id = ?", task_id) {
             error!("Failed to clear other task {} info: {}", task_id, e);
         }
-        NotificationDispatcher::get_instance().clear_task_info(task_id);
+        clear_task_side_info(task_id);
     }
 
     Ok(any_remain)
 }
 
+/// Clears the rows that other tables keep for a task deleted from
+/// `request_task`.
+fn clear_task_side_info(task_id: u32) {
+    NotificationDispatcher::get_instance().clear_task_info(task_id);
+    config_ext::remove(task_id);
+    dependency::remove(task_id);
+    segment::clear_segments(task_id);
+}
+
 /// Checkpoints the WAL back into the main database file.
 ///
 /// Runs `PRAGMA wal_checkpoint(RESTART)`: waits for in-flight readers to finish, merges as
@@ -0,0 +1,139 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bookkeeping of the temporary files of downloads.
+//!
+//! A download is written to a temporary file next to its destination file
+//! and renamed over it once complete. Each temporary file is recorded in the
+//! `task_temp_file` table until it is renamed, so that the temporary files of
+//! tasks that failed, were removed or were deleted from `request_task` can be
+//! found and deleted by the database maintenance.
+
+use std::sync::Once;
+
+use super::REQUEST_DB;
+use crate::task::files::TempFile;
+use crate::task::info::State;
+
+const CREATE_TASK_TEMP_FILE_TABLE: &str = "CREATE TABLE IF NOT EXISTS task_temp_file (task_id INTEGER, idx INTEGER, base TEXT, path TEXT, PRIMARY KEY (task_id, idx))";
+
+/// Creates the `task_temp_file` table on first use.
+fn init() {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        if let Err(e) = REQUEST_DB.execute(CREATE_TASK_TEMP_FILE_TABLE, ()) {
+            error!("Failed to create task_temp_file table: {}", e);
+            sys_event!(
+                ExecFault,
+                DfxCode::RDB_FAULT_04,
+                &format!("Failed to create task_temp_file table: {}", e)
+            );
+        }
+    });
+}
+
+/// Records the temporary file of the `idx`th file of a task.
+///
+/// # Arguments
+///
+/// * `base` - App base directory the file lies under.
+/// * `path` - Real path of the destination file.
+pub(crate) fn store(task_id: u32, idx: u32, base: &str, path: &str) {
+    init();
+    if let Err(e) = REQUEST_DB.execute(
+        "INSERT OR REPLACE INTO task_temp_file (task_id, idx, base, path) VALUES (?, ?, ?, ?)",
+        (task_id, idx, base, path),
+    ) {
+        error!("Failed to store task {} temp file: {}", task_id, e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to store task {} temp file: {}", task_id, e)
+        );
+    }
+}
+
+/// Forgets the temporary file of the `idx`th file of a task, once it has been
+/// renamed or deleted.
+pub(crate) fn remove(task_id: u32, idx: u32) {
+    init();
+    if let Err(e) = REQUEST_DB.execute(
+        "DELETE FROM task_temp_file WHERE task_id = ? AND idx = ?",
+        (task_id, idx),
+    ) {
+        error!("Failed to remove task {} temp file: {}", task_id, e);
+    }
+}
+
+/// Deletes the temporary files of a task, so that its next run downloads its
+/// files from scratch.
+pub(crate) fn discard(task_id: u32) {
+    init();
+    let rows = match REQUEST_DB.query::<(u32, String, String)>(
+        "SELECT idx, base, path FROM task_temp_file WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(rows) => rows.collect::<Vec<_>>(),
+        Err(e) => {
+            error!("Failed to query task {} temp files: {}", task_id, e);
+            return;
+        }
+    };
+    for (idx, base, path) in rows {
+        let temp_file = TempFile::new(base, path, task_id, idx as usize);
+        if let Err(e) = temp_file.discard() {
+            error!(
+                "Failed to discard task {} temp file {}: {}",
+                task_id, idx, e
+            );
+        }
+        remove(task_id, idx);
+    }
+}
+
+/// Returns the temporary files left behind by tasks that failed, were removed
+/// or no longer exist, as `(task_id, idx, base, path)` rows.
+pub(crate) fn stale() -> Vec<(u32, u32, String, String)> {
+    init();
+    match REQUEST_DB.query::<(u32, u32, String, String)>(
+        "SELECT task_id, idx, base, path FROM task_temp_file WHERE task_id NOT IN (SELECT task_id FROM request_task WHERE state != ? AND state != ?)",
+        (State::Failed.repr as u32, State::Removed.repr as u32),
+    ) {
+        Ok(rows) => rows.collect(),
+        Err(e) => {
+            error!("Failed to query stale temp files: {}", e);
+            vec![]
+        }
+    }
+}
+
+/// Deletes the temporary files left behind by tasks that failed, were
+/// removed or no longer exist.
+///
+/// A file that cannot be deleted, such as one whose app has been uninstalled,
+/// is forgotten as well, so that it is not retried forever.
+pub(crate) fn clear_stale() {
+    for (task_id, idx, base, path) in stale() {
+        let temp_file = TempFile::new(base, path, task_id, idx as usize);
+        match temp_file.discard() {
+            Ok(()) => debug!("clear task {} temp file {}", task_id, idx),
+            Err(e) => error!("Failed to clear task {} temp file {}: {}", task_id, idx, e),
+        }
+        remove(task_id, idx);
+    }
+}
+
+#[cfg(test)]
+mod ut_temp_file {
+    include!("../../tests/ut/ut_temp_file.rs");
+}
@@ -36,6 +36,7 @@ pub(crate) enum ErrorCode {
     TaskNotFound = 21900006,
     TaskStateErr = 21900007,
     GroupNotFound = 21900008,
+    ClientCertInvalid = 21900009,
 }
 
 impl From<ServiceError> for ErrorCode {
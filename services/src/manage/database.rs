@@ -27,19 +27,22 @@ use std::sync::{Arc, Mutex, Once};
 pub(crate) use ffi::*;
 
 cfg_oh! {
+    use crate::database::{config_ext, dependency};
     use crate::manage::SystemConfig;
 }
 
 cfg_not_oh! {
     use rusqlite::Connection;
-    const CREATE_TABLE: &'static str = "CREATE TABLE IF NOT EXISTS request_task (task_id INTEGER PRIMARY KEY, uid INTEGER, token_id INTEGER, action INTEGER, mode INTEGER, cover INTEGER, network INTEGER, metered INTEGER, roaming INTEGER, ctime INTEGER, mtime INTEGER, reason INTEGER, gauge INTEGER, retry INTEGER, redirect INTEGER, tries INTEGER, version INTEGER, config_idx INTEGER, begins INTEGER, ends INTEGER, precise INTEGER, priority INTEGER, background INTEGER, bundle TEXT, url TEXT, data TEXT, token TEXT, title TEXT, description TEXT, method TEXT, headers TEXT, config_extras TEXT, mime_type TEXT, state INTEGER, idx INTEGER, total_processed INTEGER, sizes TEXT, processed TEXT, extras TEXT, form_items BLOB, file_specs BLOB, each_file_status BLOB, body_file_names BLOB, certs_paths BLOB)";
+    const CREATE_TABLE: &'static str = "CREATE TABLE IF NOT EXISTS request_task (task_id INTEGER PRIMARY KEY, uid INTEGER, token_id INTEGER, action INTEGER, mode INTEGER, cover INTEGER, network INTEGER, metered INTEGER, roaming INTEGER, ctime INTEGER, mtime INTEGER, reason INTEGER, gauge INTEGER, retry INTEGER, redirect INTEGER, tries INTEGER, version INTEGER, config_idx INTEGER, begins INTEGER, ends INTEGER, precise INTEGER, priority INTEGER, background INTEGER, bundle TEXT, url TEXT, data TEXT, token TEXT, title TEXT, description TEXT, method TEXT, headers TEXT, config_extras TEXT, mime_type TEXT, state INTEGER, idx INTEGER, total_processed INTEGER, sizes TEXT, processed TEXT, extras TEXT, form_items BLOB, file_specs BLOB, each_file_status BLOB, body_file_names BLOB, certs_paths BLOB, conditions INTEGER DEFAULT 0)";
 }
 use crate::config::Action;
 use crate::error::ErrorCode;
 use crate::service::client::ClientManagerEntry;
+use crate::task::condition::Conditions;
 use crate::task::config::TaskConfig;
 use crate::task::ffi::{CTaskConfig, CTaskInfo, CUpdateInfo};
 use crate::task::info::{State, TaskInfo, UpdateInfo};
+use crate::task::notify::WaitingCause;
 use crate::task::reason::Reason;
 use crate::task::request_task::RequestTask;
 use crate::utils::{call_once, get_current_timestamp, hashmap_to_string};
@@ -328,6 +331,8 @@ impl RequestDb {
         if !unsafe { RecordRequestTask(&c_task_info, &c_task_config) } {
             info!("task {} insert database fail", task_id);
         }
+        config_ext::store(task_config);
+        dependency::store(task_id, &task_config.prerequisites);
 
         // For some tasks contains user_file, we must save it to map first.
         if task.conf.contains_user_file() {
@@ -466,6 +471,23 @@ impl RequestDb {
         let _ = self.execute(&sql);
     }
 
+    /// Lists the tasks in the `Waiting` state on any of some conditions.
+    ///
+    /// # Arguments
+    /// * `conditions` - The conditions the tasks are waiting on.
+    ///
+    /// # Returns
+    /// The ids of the matching tasks, empty on query failure.
+    pub(crate) fn query_waiting_tasks(&self, conditions: Conditions) -> Vec<u32> {
+        let sql = format!(
+            "SELECT task_id FROM request_task WHERE state = {} AND (({}) & {}) != 0",
+            State::Waiting.repr,
+            Conditions::column_sql(),
+            conditions.bits(),
+        );
+        self.query_integer(&sql)
+    }
+
     /// Queries the owning uid of a task.
     ///
     /// # Arguments
@@ -514,21 +536,61 @@ impl RequestDb {
 
     /// Updates a task's state and reason and refreshes its modification time.
     ///
+    /// The waiting conditions of the task are cleared, tasks are put in the
+    /// waiting state with [`update_task_waiting`](Self::update_task_waiting).
+    ///
     /// # Arguments
     /// * `task_id` - The task to update.
     /// * `state` - The new task state.
     /// * `reason` - The reason for the state transition.
     pub(crate) fn update_task_state(&self, task_id: u32, state: State, reason: Reason) {
         let sql = format!(
-            "UPDATE request_task SET state = {}, mtime = {}, reason = {} WHERE task_id = {}",
+            "UPDATE request_task SET state = {}, mtime = {}, reason = {}, conditions = {} WHERE task_id = {}",
             state.repr,
             get_current_timestamp(),
             reason.repr,
+            Conditions::NONE.bits(),
             task_id
         );
         let _ = self.execute(&sql);
     }
 
+    /// Puts a task in the waiting state, adding and removing some of the
+    /// conditions it waits on, and refreshes its modification time.
+    ///
+    /// A task not waiting yet starts from no condition.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task to update.
+    /// * `set` - The conditions the task does not meet anymore.
+    /// * `clear` - The conditions the task meets again.
+    ///
+    /// # Returns
+    /// The conditions the task waits on afterwards.
+    pub(crate) fn update_task_waiting(
+        &self,
+        task_id: u32,
+        set: Conditions,
+        clear: Conditions,
+    ) -> Conditions {
+        let waiting = State::Waiting.repr;
+        let sql = format!(
+            "UPDATE request_task SET mtime = {}, conditions = CASE WHEN state = {} THEN {} ELSE {} END, reason = CASE WHEN state = {} THEN {} ELSE {} END, state = {} WHERE task_id = {}",
+            get_current_timestamp(),
+            waiting,
+            Conditions::updated_sql(set, clear),
+            set.difference(clear).bits(),
+            waiting,
+            Reason::RunningTaskMeetLimits.repr,
+            set.difference(clear).reason().repr,
+            waiting,
+            task_id
+        );
+        let _ = self.execute(&sql);
+        self.query_task_conditions(task_id)
+            .unwrap_or(set.difference(clear))
+    }
+
     /// Updates the max speed limit of a task.
     ///
     /// # Arguments
@@ -542,6 +604,23 @@ impl RequestDb {
         let _ = self.execute(&sql);
     }
 
+    /// Puts a finished task back in the `Initialized` state without progress,
+    /// so that it runs again from scratch once started.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task to reset.
+    /// * `files` - The number of files of the task.
+    pub(crate) fn reset_task(&self, task_id: u32, files: usize) {
+        let sql = format!(
+            "UPDATE request_task SET state = {}, reason = {}, conditions = 0, idx = 0, total_processed = 0, processed = '{:?}' WHERE task_id = {}",
+            State::Initialized.repr,
+            Reason::Default.repr,
+            vec![0usize; files],
+            task_id
+        );
+        let _ = self.execute(&sql);
+    }
+
     /// Persists the per-file sizes vector of a task.
     ///
     /// # Arguments
@@ -575,11 +654,24 @@ impl RequestDb {
             return None;
         }
         let c_task_info = unsafe { &*c_task_info };
-        let task_info = TaskInfo::from_c_struct(c_task_info);
+        let mut task_info = TaskInfo::from_c_struct(c_task_info);
         unsafe { DeleteCTaskInfo(c_task_info) };
+        self.derive_waiting_reason(&mut task_info);
         Some(task_info)
     }
 
+    /// Sets the reason of a waiting task to the one of its conditions, its
+    /// `reason` column being reset once they are updated.
+    fn derive_waiting_reason(&self, task_info: &mut TaskInfo) {
+        let task_id = task_info.common_data.task_id;
+        if self.query_task_state(task_id) != Some(State::Waiting.repr) {
+            return;
+        }
+        if let Some(conditions) = self.query_task_conditions(task_id) {
+            task_info.common_data.reason = conditions.reason().repr;
+        }
+    }
+
     /// Queries the total processed bytes of a task.
     ///
     /// # Arguments
@@ -609,6 +701,50 @@ impl RequestDb {
             .map(|state: &i32| *state as u8)
     }
 
+    /// Queries the conditions a waiting task does not meet.
+    ///
+    /// Tasks persisted before their conditions have them read from their
+    /// reason.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task id to look up.
+    ///
+    /// # Returns
+    /// `Some(conditions)` when the task exists, `None` otherwise.
+    pub(crate) fn query_task_conditions(&self, task_id: u32) -> Option<Conditions> {
+        let sql = format!(
+            "SELECT conditions FROM request_task WHERE task_id = {}",
+            task_id
+        );
+        let conditions = self.query_integer::<u32>(&sql).first().copied()?;
+        if conditions != 0 {
+            return Some(Conditions::from_bits(conditions));
+        }
+        let sql = format!(
+            "SELECT reason FROM request_task WHERE task_id = {}",
+            task_id
+        );
+        let reason = self.query_integer::<u8>(&sql).first().copied()?;
+        Some(Conditions::from_reason(Reason::from(reason)))
+    }
+
+    /// Queries every cause a task is waiting for, its notifications only
+    /// carrying the one of the reason it reports.
+    ///
+    /// # Arguments
+    /// * `task_id` - The task id to look up.
+    ///
+    /// # Returns
+    /// The causes of the task, empty when it does not exist or is not
+    /// waiting.
+    pub(crate) fn query_task_causes(&self, task_id: u32) -> Vec<WaitingCause> {
+        if self.query_task_state(task_id) != Some(State::Waiting.repr) {
+            return vec![];
+        }
+        self.query_task_conditions(task_id)
+            .map_or_else(Vec::new, Conditions::causes)
+    }
+
     /// Off-device (rusqlite) counterpart of [`get_task_info`](Self::get_task_info).
     ///
     /// Reads the relevant columns directly via SQL rather than through the C
@@ -653,7 +789,9 @@ impl RequestDb {
                 })
             })
             .unwrap();
-        row.next().map(|info| info.unwrap())
+        let mut task_info = row.next().map(|info| info.unwrap())?;
+        self.derive_waiting_reason(&mut task_info);
+        Some(task_info)
     }
 
     /// Loads a task's configuration from the database.
@@ -680,8 +818,10 @@ impl RequestDb {
             );
             None
         } else {
-            let task_config = TaskConfig::from_c_struct(unsafe { &*c_task_config });
+            let mut task_config = TaskConfig::from_c_struct(unsafe { &*c_task_config });
             unsafe { DeleteCTaskConfig(c_task_config) };
+            config_ext::load(&mut task_config);
+            task_config.prerequisites = dependency::prerequisites(task_id);
             Some(task_config)
         }
     }
@@ -740,6 +880,20 @@ impl RequestDb {
                     proxy: Default::default(),
                     certificate_pins: Default::default(),
                     atomic_account: Default::default(),
+                    segments: 0,
+                    digest: "".to_string(),
+                    chunk_size: 0,
+                    retry_policy: Default::default(),
+                    prerequisites: vec![],
+                    not_before: 0,
+                    recurrence: Default::default(),
+                    expected_size: 0,
+                    preallocate: false,
+                    compression: false,
+                    cookie_jar: false,
+                    auth: Default::default(),
+                    client_cert_path: "".to_string(),
+                    client_key_path: "".to_string(),
                 })
             })
             .unwrap();
@@ -854,7 +1008,7 @@ impl RequestDb {
     /// QoS info for each matching task; empty when there are none.
     pub(crate) fn get_app_task_qos_infos(&self, uid: u64) -> Vec<TaskQosInfo> {
         let sql = format!(
-            "SELECT task_id, action, mode, state, priority FROM request_task WHERE uid = {} AND ((state = {} AND reason = {}) OR state = {} OR state = {})",
+            "SELECT task_id, action, mode, state, priority FROM request_task WHERE uid = {} AND ((state = {} AND conditions = 0 AND reason = {}) OR state = {} OR state = {})",
             uid,
             State::Waiting.repr,
             Reason::RunningTaskMeetLimits.repr,
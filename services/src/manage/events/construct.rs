@@ -26,6 +26,7 @@ use crate::error::ErrorCode;
 use crate::manage::database::RequestDb;
 use crate::manage::TaskManager;
 use crate::task::config::TaskConfig;
+use crate::task::info::State;
 use crate::task::request_task::{check_config, get_rest_time, RequestTask};
 use crate::utils::task_id_generator::TaskIdGenerator;
 
@@ -76,6 +77,28 @@ impl TaskManager {
             uid, task_id, version
         );
 
+        // Prerequisites must be tasks of the same application that can still
+        // complete
+        let database = RequestDb::get_instance();
+        for prerequisite in config.prerequisites.iter() {
+            if database.query_task_uid(*prerequisite) != Some(uid)
+                || database.query_task_state(*prerequisite) == Some(State::Removed.repr)
+            {
+                error!(
+                    "{} task {} prerequisite {} not valid",
+                    uid, task_id, prerequisite
+                );
+                return Err(ErrorCode::ParameterCheck);
+            }
+        }
+
+        // Each run of a recurring task reopens its files, which the file
+        // descriptors of user files do not allow
+        if config.recurrence.is_recurring() && config.contains_user_file() {
+            error!("{} task {} recurs with user files", uid, task_id);
+            return Err(ErrorCode::ParameterCheck);
+        }
+
         // Get or initialize task counters for this user ID
         let (frontend, background) = self
             .task_count
@@ -15,10 +15,22 @@
 //!
 //! This module implements methods for querying task information from the
 //! `TaskManager`, providing functionality to retrieve details about single
-//! tasks or all active tasks in the system.
+//! tasks or all active tasks in the system. Active tasks are the tasks of the
+//! running queue, together with the waiting tasks that are kept out of it.
 
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use crate::config::Mode;
+use crate::info::TaskInfo;
+use crate::manage::database::RequestDb;
+use crate::manage::scheduler::Scheduler;
 use crate::manage::TaskManager;
-use crate::task::info::{DumpAllEachInfo, DumpAllInfo, DumpOneInfo};
+use crate::task::config::Action;
+use crate::task::http_error_registry::peek_http_status_code;
+use crate::task::info::{url_host, DumpAllEachInfo, DumpAllInfo, State};
+use crate::task::notify::WaitingCause;
+use crate::task::request_task::RequestTask;
 
 impl TaskManager {
     /// Queries information for a single task by its task ID.
@@ -29,29 +41,27 @@ impl TaskManager {
     ///
     /// # Returns
     ///
-    /// * `Some(DumpOneInfo)` - Task information if a task with the given ID
-    ///   exists.
-    /// * `None` - If no task with the given ID is found.
+    /// * `Some(DumpAllEachInfo)` - Task information if an active task with the
+    ///   given ID exists.
+    /// * `None` - If no active task with the given ID is found.
     ///
     /// # Notes
     ///
     /// This method locks the task's status to ensure thread-safe access to its
     /// current state and reason.
-    pub(crate) fn query_one_task(&self, task_id: u32) -> Option<DumpOneInfo> {
+    pub(crate) fn query_one_task(&self, task_id: u32) -> Option<DumpAllEachInfo> {
         // Search for the task with matching ID in the scheduler
-        self.scheduler
+        if let Some(task) = self
+            .scheduler
             .tasks()
             .find(|task| task.task_id() == task_id)
-            .map(|task| {
-                // Lock the task status to ensure thread-safe read
-                let status = task.status.lock().unwrap();
-                DumpOneInfo {
-                    task_id: task.conf.common_data.task_id,
-                    action: task.conf.common_data.action,
-                    state: status.state,
-                    reason: status.reason,
-                }
-            })
+        {
+            return Some(running_task_info(task));
+        }
+        RequestDb::get_instance()
+            .get_task_info(task_id)
+            .filter(|info| info.progress.common_data.state == State::Waiting.repr)
+            .map(|info| stored_task_info(&info))
     }
 
     /// Queries information for all currently active tasks.
@@ -65,22 +75,93 @@ impl TaskManager {
     /// This method locks each task's status individually to ensure thread-safe
     /// access to their current state and reason information.
     pub(crate) fn query_all_task(&self) -> DumpAllInfo {
-        DumpAllInfo {
-            // Map each task to its dump information
-            vec: self
-                .scheduler
-                .tasks()
-                .map(|task| {
-                    // Lock each task's status individually for thread-safe read
-                    let status = task.status.lock().unwrap();
-                    DumpAllEachInfo {
-                        task_id: task.conf.common_data.task_id,
-                        action: task.conf.common_data.action,
-                        state: status.state,
-                        reason: status.reason,
-                    }
-                })
-                .collect(),
+        // Map each task of the running queue to its dump information
+        let mut vec: Vec<DumpAllEachInfo> = self.scheduler.tasks().map(running_task_info).collect();
+
+        // Waiting tasks kept out of the running queue are only in the database
+        let database = RequestDb::get_instance();
+        let sql = format!(
+            "SELECT task_id FROM request_task WHERE state = {}",
+            State::Waiting.repr
+        );
+        for task_id in database.query_integer::<u32>(&sql) {
+            if vec.iter().any(|info| info.task_id == task_id) {
+                continue;
+            }
+            if let Some(info) = database.get_task_info(task_id) {
+                vec.push(stored_task_info(&info));
+            }
         }
+        DumpAllInfo { vec }
+    }
+}
+
+/// Builds the dump information of a task in the running queue.
+fn running_task_info(task: &Arc<RequestTask>) -> DumpAllEachInfo {
+    // Lock the task status to ensure thread-safe read
+    let status = task.status.lock().unwrap();
+    let progress = task.progress.lock().unwrap();
+    let task_id = task.task_id();
+    DumpAllEachInfo {
+        task_id,
+        action: task.conf.common_data.action,
+        state: status.state,
+        reason: status.reason,
+        uid: task.uid(),
+        bundle: task.conf.bundle.clone(),
+        host: url_host(&task.conf.url).to_string(),
+        mode: Mode::from(task.mode.load(Ordering::Acquire)),
+        priority: task.conf.common_data.priority,
+        qos: Scheduler::qos_level(task),
+        processed: progress.common_data.total_processed as u64,
+        total: total_size(&progress.sizes),
+        speed: task.speed.load(Ordering::SeqCst),
+        retries: task.tries.load(Ordering::SeqCst) + task.retry_attempts.load(Ordering::SeqCst),
+        http_status: peek_http_status_code(task_id),
+        waiting: waiting_cause(status.state, status.reason.repr),
+        causes: RequestDb::get_instance().query_task_causes(task_id),
+    }
+}
+
+/// Builds the dump information of a task stored in the database.
+fn stored_task_info(info: &TaskInfo) -> DumpAllEachInfo {
+    let common = &info.common_data;
+    let state = State::from(info.progress.common_data.state);
+    DumpAllEachInfo {
+        task_id: common.task_id,
+        action: Action::from(common.action),
+        state,
+        reason: common.reason.into(),
+        uid: common.uid,
+        bundle: info.bundle.clone(),
+        host: url_host(&info.url).to_string(),
+        mode: Mode::from(common.mode),
+        priority: common.priority,
+        qos: None,
+        processed: info.progress.common_data.total_processed as u64,
+        total: total_size(&info.progress.sizes),
+        speed: 0,
+        retries: common.tries,
+        http_status: peek_http_status_code(common.task_id),
+        waiting: waiting_cause(state, common.reason),
+        causes: RequestDb::get_instance().query_task_causes(common.task_id),
+    }
+}
+
+/// Returns the total size of the task files, -1 if any size is unknown.
+fn total_size(sizes: &[i64]) -> i64 {
+    if sizes.iter().any(|size| *size < 0) {
+        -1
+    } else {
+        sizes.iter().sum()
+    }
+}
+
+/// Returns the cause a task in `state` is waiting for.
+fn waiting_cause(state: State, reason: u8) -> Option<WaitingCause> {
+    if state == State::Waiting {
+        WaitingCause::from_reason(reason)
+    } else {
+        None
     }
 }
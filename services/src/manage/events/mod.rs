@@ -19,6 +19,7 @@
 //! operations.
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 use ylong_runtime::sync::oneshot::{channel, Sender};
 
@@ -27,7 +28,7 @@ use crate::config::{Action, Mode};
 use crate::error::ErrorCode;
 use crate::info::TaskInfo;
 use crate::task::config::TaskConfig;
-use crate::task::info::{DumpAllInfo, DumpOneInfo};
+use crate::task::info::{DumpAllEachInfo, DumpAllInfo};
 use crate::task::reason::Reason;
 use crate::utils::Recv;
 
@@ -35,6 +36,7 @@ use crate::utils::Recv;
 mod construct;
 mod dump;
 mod pause;
+mod recur;
 mod remove;
 mod resume;
 mod set_max_speed;
@@ -198,8 +200,8 @@ impl TaskManagerEvent {
     /// # Returns
     ///
     /// A tuple containing the event and a receiver for the task information.
-    pub(crate) fn dump_one(task_id: u32) -> (Self, Recv<Option<DumpOneInfo>>) {
-        let (tx, rx) = channel::<Option<DumpOneInfo>>();
+    pub(crate) fn dump_one(task_id: u32) -> (Self, Recv<Option<DumpAllEachInfo>>) {
+        let (tx, rx) = channel::<Option<DumpAllEachInfo>>();
         (
             Self::Service(ServiceEvent::DumpOne(task_id, tx)),
             Recv::new(rx),
@@ -322,7 +324,7 @@ pub(crate) enum ServiceEvent {
     /// Resume a specific task.
     Resume(u64, u32, Sender<ErrorCode>),
     /// Dump information for a specific task.
-    DumpOne(u32, Sender<Option<DumpOneInfo>>),
+    DumpOne(u32, Sender<Option<DumpAllEachInfo>>),
     /// Dump information for all tasks.
     DumpAll(Sender<DumpAllInfo>),
     /// Attach multiple tasks to a group.
@@ -342,6 +344,10 @@ pub(crate) enum TaskEvent {
     Failed(u32, u64, Reason, Mode),
     /// Task has gone offline.
     Offline(u32, u64, Mode),
+    /// Task has stopped for lack of storage space.
+    StorageSpace(u32, u64, Mode),
+    /// Task waits for the delay of its retry policy, with its retry attempts.
+    RetryWaiting(u32, u64, Mode, u32, Duration),
     /// Task is currently running.
     Running(u32, u64, Mode),
     /// Subscribe to updates for a specific task.
@@ -400,6 +406,12 @@ pub(crate) enum ScheduleEvent {
     ClearTimeoutTasks,
     /// Restore all tasks from persistence.
     RestoreAllTasks,
+    /// Start the deferred tasks whose start time has been reached.
+    PromoteDeferredTasks,
+    /// Start the tasks waiting for storage space that is now available.
+    RecheckStorageSpace,
+    /// Start a task whose retry delay is over.
+    RetryDue(u32),
     /// Unload resources but keep the service running.
     Unload,
     /// Shutdown the service completely.
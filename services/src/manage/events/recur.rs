@@ -0,0 +1,90 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Recurring task implementation for the task manager.
+//!
+//! This module provides the next run of a recurring task once a run has
+//! completed or failed. The task keeps its ID, so that the application keeps
+//! tracking it across runs, and is deferred to the next occurrence of its
+//! recurrence rule.
+
+use crate::config::Mode;
+use crate::database::{config_ext, temp_file};
+use crate::manage::database::RequestDb;
+use crate::manage::TaskManager;
+use crate::task::info::State;
+use crate::task::{chunk_upload, segment};
+use crate::utils::get_current_timestamp;
+
+impl TaskManager {
+    /// Schedules the next run of a finished recurring task.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the completed or failed task.
+    ///
+    /// # Notes
+    ///
+    /// The task is reset to run again from scratch, with its `not_before`
+    /// time set to the next occurrence, and is started right away so that it
+    /// waits for that time. Tasks stopped or removed by the application do
+    /// not recur.
+    pub(crate) fn recur(&mut self, task_id: u32) {
+        let database = RequestDb::get_instance();
+        let Some(config) = database.get_task_config(task_id) else {
+            return;
+        };
+        if !config.recurrence.is_recurring() {
+            return;
+        }
+        match database.query_task_state(task_id) {
+            Some(state) if state == State::Completed.repr || state == State::Failed.repr => {}
+            _ => return,
+        }
+        let Some(info) = database.get_task_info(task_id) else {
+            return;
+        };
+
+        // Keep the occurrences aligned to the first scheduled start
+        let anchor = match config.not_before {
+            0 => info.common_data.ctime,
+            not_before => not_before,
+        };
+        let Some(next_start) = config
+            .recurrence
+            .next_start(anchor, get_current_timestamp())
+        else {
+            return;
+        };
+
+        // The next run transfers the files again from scratch
+        config_ext::store_not_before(task_id, next_start);
+        temp_file::discard(task_id);
+        segment::clear_segments(task_id);
+        chunk_upload::clear_committed(task_id);
+        database.reset_task(task_id, config.file_specs.len());
+
+        // The task is counted again, as the finished run was uncounted
+        let uid = config.common_data.uid;
+        let (frontend, background) = self.task_count.entry(uid).or_insert((0, 0));
+        match config.common_data.mode {
+            Mode::FrontEnd => *frontend += 1,
+            _ => *background += 1,
+        }
+
+        info!("task {} recurs at {}", task_id, next_start);
+        if let Err(e) = self.scheduler.start_task(uid, task_id) {
+            error!("task {} recur failed, start error {:?}", task_id, e);
+        }
+    }
+}
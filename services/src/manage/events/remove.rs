@@ -71,7 +71,12 @@ impl TaskManager {
 
         // Delegate to the scheduler to remove the task
         match self.scheduler.remove_task(uid, task_id) {
-            Ok(_) => ErrorCode::ErrOk,
+            Ok(_) => {
+                // Tasks waiting for the removed task can never run
+                self.scheduler
+                    .fail_dependents(task_id, &mut self.task_count);
+                ErrorCode::ErrOk
+            }
             Err(e) => e,
         }
     }
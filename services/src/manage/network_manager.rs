@@ -90,6 +90,17 @@ impl NetworkManager {
         matches!(network_manager.network.state(), NetworkState::Online(_))
     }
 
+    /// Checks if the device is currently on a metered network.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the network is online and metered, otherwise
+    /// `false`.
+    pub(crate) fn is_metered() -> bool {
+        let network_manager = NetworkManager::get_instance().lock().unwrap();
+        matches!(network_manager.network.state(), NetworkState::Online(info) if info.is_metered)
+    }
+
     /// Queries the current network state.
     ///
     /// # Returns
@@ -162,7 +162,9 @@ impl Notifier {
 
     /// Sends a waiting notification for a task.
     ///
-    /// Notifies clients that a task is waiting due to a specific cause.
+    /// A task waiting on several conditions is notified once, with the cause
+    /// of the reason it reports; the other ones are queried through
+    /// `RequestDb::query_task_causes`.
     ///
     /// # Arguments
     ///
@@ -0,0 +1,85 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scheduling of deferred tasks.
+//!
+//! A started task that is not due yet, because its `not_before` time has not
+//! been reached or the current time is outside its daily window, is kept in
+//! the `Waiting` state on `Conditions::START_TIME`, which keeps it out of
+//! the QoS queue. The `TaskManager` timer periodically promotes the deferred
+//! tasks that have become due, which then go through the same checks as a
+//! task being started.
+
+use super::Scheduler;
+use crate::manage::database::RequestDb;
+use crate::manage::notifier::Notifier;
+use crate::task::condition::Conditions;
+use crate::utils::get_current_timestamp;
+
+impl Scheduler {
+    /// Checks whether a started task is due, and puts it in the waiting
+    /// state if it is not.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the task may start now.
+    pub(crate) fn check_start_time(&self, task_id: u32) -> bool {
+        let Some(config) = RequestDb::get_instance().get_task_config(task_id) else {
+            return true;
+        };
+        if config.is_due(get_current_timestamp()) {
+            return true;
+        }
+        info!(
+            "task {} started, deferred until {}",
+            task_id, config.not_before
+        );
+        let conditions = RequestDb::get_instance().update_task_waiting(
+            task_id,
+            Conditions::START_TIME,
+            Conditions::NONE,
+        );
+        Notifier::waiting(&self.client_manager, task_id, conditions.cause());
+        false
+    }
+
+    /// Schedules the deferred tasks that have become due.
+    pub(crate) fn promote_deferred_tasks(&mut self) {
+        let database = RequestDb::get_instance();
+        let now = get_current_timestamp();
+        for task_id in database.query_waiting_tasks(Conditions::START_TIME) {
+            let Some(config) = database.get_task_config(task_id) else {
+                continue;
+            };
+            if !config.is_due(now) {
+                continue;
+            }
+            info!("task {} deferred start time reached", task_id);
+            let conditions =
+                database.update_task_waiting(task_id, Conditions::NONE, Conditions::START_TIME);
+            if !conditions.difference(Conditions::SYSTEM).is_empty() {
+                continue;
+            }
+            if !self.check_prerequisites(task_id) {
+                continue;
+            }
+            if !matches!(self.check_config_satisfy(task_id), Ok(true)) {
+                continue;
+            }
+            if let Some(qos_info) = database.get_task_qos_info(task_id) {
+                self.qos.start_task(config.common_data.uid, qos_info);
+                self.schedule_if_not_scheduled();
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scheduling of tasks with prerequisites.
+//!
+//! A started task whose prerequisites have not all completed is kept in the
+//! `Waiting` state on `Conditions::PREREQUISITE`, which keeps it out of
+//! the QoS queue. When its last prerequisite completes it is handed to the QoS
+//! queue like any other started task. When a prerequisite fails or is
+//! removed, the tasks depending on it fail with `Reason::PrerequisiteFailed`,
+//! and so do their own dependents.
+
+use std::collections::HashMap;
+
+use super::Scheduler;
+use crate::config::Mode;
+use crate::database::dependency;
+use crate::manage::database::RequestDb;
+use crate::manage::notifier::Notifier;
+use crate::task::condition::Conditions;
+use crate::task::info::State;
+use crate::task::reason::Reason;
+
+/// Combined state of the prerequisites of a task.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Prerequisites {
+    /// All prerequisites have completed.
+    Completed,
+    /// Some prerequisites have not completed yet.
+    Pending,
+    /// A prerequisite was removed and can never complete.
+    Failed,
+}
+
+impl Prerequisites {
+    /// Combines the states of the prerequisites of a task, `None` for a
+    /// prerequisite that no longer exists.
+    pub(crate) fn from_states(states: impl IntoIterator<Item = Option<u8>>) -> Self {
+        let mut prerequisites = Prerequisites::Completed;
+        for state in states {
+            match state {
+                Some(state) if state == State::Completed.repr => {}
+                Some(state) if state != State::Removed.repr => {
+                    prerequisites = Prerequisites::Pending;
+                }
+                _ => return Prerequisites::Failed,
+            }
+        }
+        prerequisites
+    }
+
+    /// Queries the state of the prerequisites of `task_id`.
+    pub(crate) fn query(task_id: u32) -> Self {
+        let database = RequestDb::get_instance();
+        Self::from_states(
+            dependency::pending_prerequisites(task_id)
+                .into_iter()
+                .map(|prerequisite| database.query_task_state(prerequisite)),
+        )
+    }
+}
+
+impl Scheduler {
+    /// Checks whether a started task may be scheduled as far as its
+    /// prerequisites are concerned, and puts it in the waiting state if some
+    /// of them have not completed yet.
+    ///
+    /// # Returns
+    ///
+    /// `true` if all prerequisites of the task have completed.
+    pub(crate) fn check_prerequisites(&self, task_id: u32) -> bool {
+        if Prerequisites::query(task_id) == Prerequisites::Completed {
+            return true;
+        }
+        info!("task {} started, waiting for prerequisites", task_id);
+        let conditions = RequestDb::get_instance().update_task_waiting(
+            task_id,
+            Conditions::PREREQUISITE,
+            Conditions::NONE,
+        );
+        Notifier::waiting(&self.client_manager, task_id, conditions.cause());
+        false
+    }
+
+    /// Schedules the tasks waiting for the completed task `task_id` whose
+    /// prerequisites have now all completed.
+    pub(crate) fn release_dependents(&mut self, task_id: u32) {
+        let database = RequestDb::get_instance();
+        let dependents = dependency::dependents(task_id);
+        dependency::satisfy(task_id);
+        for dependent in dependents {
+            let Some(info) = database.get_task_info(dependent) else {
+                continue;
+            };
+            let waiting = database
+                .query_task_conditions(dependent)
+                .unwrap_or_default();
+            if info.progress.common_data.state != State::Waiting.repr
+                || !waiting.intersects(Conditions::PREREQUISITE)
+                || Prerequisites::query(dependent) != Prerequisites::Completed
+            {
+                continue;
+            }
+            info!("task {} prerequisites completed", dependent);
+            let conditions =
+                database.update_task_waiting(dependent, Conditions::NONE, Conditions::PREREQUISITE);
+            if !conditions.difference(Conditions::SYSTEM).is_empty() {
+                continue;
+            }
+            if !matches!(self.check_config_satisfy(dependent), Ok(true)) {
+                continue;
+            }
+            if let Some(qos_info) = database.get_task_qos_info(dependent) {
+                self.qos.start_task(info.uid(), qos_info);
+                self.schedule_if_not_scheduled();
+            }
+        }
+    }
+
+    /// Fails the tasks depending on `task_id` if it failed or was removed
+    /// before completing.
+    ///
+    /// Only dependents that have not run yet are failed, the failure cascades
+    /// to their own dependents.
+    pub(crate) fn fail_dependents(
+        &mut self,
+        task_id: u32,
+        task_count: &mut HashMap<u64, (usize, usize)>,
+    ) {
+        let database = RequestDb::get_instance();
+        match database.query_task_state(task_id) {
+            Some(state) if state == State::Failed.repr || state == State::Removed.repr => {}
+            _ => return,
+        }
+        for dependent in dependency::dependents(task_id) {
+            let Some(info) = database.get_task_info(dependent) else {
+                continue;
+            };
+            let state = info.progress.common_data.state;
+            if state == State::Initialized.repr
+                || state == State::Waiting.repr
+                || state == State::Paused.repr
+            {
+                self.fail_by_prerequisite(info.uid(), dependent, task_count);
+            }
+        }
+    }
+
+    /// Fails a task whose prerequisite failed or was removed.
+    fn fail_by_prerequisite(
+        &mut self,
+        uid: u64,
+        task_id: u32,
+        task_count: &mut HashMap<u64, (usize, usize)>,
+    ) {
+        info!("task {} failed, prerequisite failed", task_id);
+        let database = RequestDb::get_instance();
+        self.qos.remove_task(uid, task_id);
+        database.update_task_state(task_id, State::Failed, Reason::PrerequisiteFailed);
+        if let Some(info) = database.get_task_info(task_id) {
+            Scheduler::reduce_task_count(uid, Mode::from(info.common_data.mode), task_count);
+            Scheduler::notify_fail(info, &self.client_manager, Reason::PrerequisiteFailed);
+        }
+        self.fail_dependents(task_id, task_count);
+    }
+}
+
+#[cfg(test)]
+mod ut_dependency {
+    include!("../../../tests/ut/manage/scheduler/ut_dependency.rs");
+}
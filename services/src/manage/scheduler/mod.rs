@@ -19,14 +19,19 @@
 //! across multiple applications while respecting network conditions, account
 //! states, and application foreground/background transitions.
 
+mod deferred;
+mod dependency;
 mod qos;
 mod queue;
+mod retry;
+mod space;
 pub(crate) mod state;
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 mod sql;
+use dependency::Prerequisites;
 use qos::Qos;
 use queue::RunningQueue;
 use state::sql::SqlList;
@@ -42,6 +47,7 @@ use crate::service::active_counter::ActiveCounter;
 use crate::service::client::ClientManagerEntry;
 use crate::service::notification_bar::NotificationDispatcher;
 use crate::service::run_count::RunCountManagerEntry;
+use crate::task::condition::Conditions;
 use crate::task::config::Action;
 use crate::task::info::State;
 use crate::task::notify::WaitingCause;
@@ -84,6 +90,8 @@ pub(crate) struct Scheduler {
     pub(crate) resort_scheduled: bool,
     /// Transmitter for sending events to the task manager.
     task_manager: TaskManagerTx,
+    /// Retry attempts of the tasks waiting for their retry delay.
+    retry_attempts: HashMap<u32, u32>,
 }
 
 impl Scheduler {
@@ -127,6 +135,7 @@ impl Scheduler {
             state_handler,
             resort_scheduled: false,
             task_manager: tx,
+            retry_attempts: HashMap::new(),
         }
     }
 
@@ -154,6 +163,12 @@ impl Scheduler {
         self.running_queue.tasks()
     }
 
+    /// Returns the name of the QoS level currently applied to a task in the
+    /// running queue, `None` if its speed limit matches no level.
+    pub(crate) fn qos_level(task: &RequestTask) -> Option<&'static str> {
+        qos::QosLevel::name_of(task.rate_limiting.load(Ordering::SeqCst))
+    }
+
     /// Returns the number of currently running tasks.
     ///
     /// # Returns
@@ -227,6 +242,11 @@ impl Scheduler {
         {
             return Err(ErrorCode::TaskStateErr);
         }
+        // A task with a removed prerequisite can never run
+        if Prerequisites::query(task_id) == Prerequisites::Failed {
+            error!("task {} start failed, prerequisite removed", task_id);
+            return Err(ErrorCode::TaskStateErr);
+        }
         // Change to Waiting state so the task can be scheduled
         database.change_status(task_id, State::Waiting)?;
 
@@ -246,6 +266,17 @@ impl Scheduler {
             if let Some(info) = database.get_task_info(task_id) {
                 Notifier::complete(&self.client_manager, info.build_notify_data());
             }
+            self.release_dependents(task_id);
+        }
+
+        // Check if the scheduled start time of the task has been reached
+        if !self.check_start_time(task_id) {
+            return Ok(());
+        }
+
+        // Check if the prerequisites of the task have completed
+        if !self.check_prerequisites(task_id) {
+            return Ok(());
         }
 
         // Check if task configuration requirements are satisfied
@@ -463,6 +494,9 @@ impl Scheduler {
             Notifier::complete(&self.client_manager, info.build_notify_data());
             NotificationDispatcher::get_instance().publish_success_notification(&info);
         }
+
+        // Schedule the tasks waiting for this one
+        self.release_dependents(task_id);
     }
 
     /// Handles task cancellation.
@@ -501,10 +535,10 @@ impl Scheduler {
             // If running, move to waiting state due to task limits
             State::Running | State::Retrying => {
                 info!("task {} waiting for task limits", task_id);
-                RequestDb::get_instance().update_task_state(
+                RequestDb::get_instance().update_task_waiting(
                     task_id,
-                    State::Waiting,
-                    Reason::RunningTaskMeetLimits,
+                    Conditions::NONE,
+                    Conditions::NONE,
                 );
                 Notifier::waiting(&self.client_manager, task_id, WaitingCause::TaskQueue);
             }
@@ -514,6 +548,7 @@ impl Scheduler {
                 Scheduler::reduce_task_count(uid, mode, task_count);
                 let reason = info.common_data.reason;
                 Scheduler::notify_fail(info, &self.client_manager, Reason::from(reason));
+                self.fail_dependents(task_id, task_count);
             }
             // If stopped or removed, clean up and try restart
             State::Stopped | State::Removed => {
@@ -521,29 +556,14 @@ impl Scheduler {
                 NotificationDispatcher::get_instance().unregister_task(uid, task_id, true);
                 self.running_queue.try_restart(uid, task_id);
             }
-            // For waiting tasks, determine waiting cause based on reason
+            // For waiting tasks, report the condition the task waits on
             State::Waiting => {
                 info!("task {} cancel with state Waiting", task_id);
-                let reason = match info.common_data.reason {
-                    reason if reason == Reason::AppBackgroundOrTerminate.repr => {
-                        WaitingCause::AppState
-                    }
-                    reason
-                        if reason == Reason::NetworkOffline.repr
-                            || reason == Reason::UnsupportedNetworkType.repr =>
-                    {
-                        WaitingCause::Network
-                    }
-                    reason if reason == Reason::RunningTaskMeetLimits.repr => {
-                        WaitingCause::TaskQueue
-                    }
-                    reason if reason == Reason::AccountStopped.repr => WaitingCause::UserState,
-                    reason => {
-                        error!("task {} cancel with other reason {}", task_id, reason);
-                        WaitingCause::TaskQueue
-                    }
-                };
-                Notifier::waiting(&self.client_manager, task_id, reason);
+                let cause = database
+                    .query_task_conditions(task_id)
+                    .unwrap_or_default()
+                    .cause();
+                Notifier::waiting(&self.client_manager, task_id, cause);
             }
             // Log other states for debugging
             state => {
@@ -577,7 +597,11 @@ impl Scheduler {
 
         // Check if task state needs updating
         let should_update = match database.get_task_qos_info(task_id) {
-            Some(info) if info.state == State::Running.repr || info.state == State::Waiting.repr => true,
+            Some(info)
+                if info.state == State::Running.repr || info.state == State::Waiting.repr =>
+            {
+                true
+            }
             Some(info) if info.state == State::Failed.repr => false,
             Some(_) => return,
             // Still update if task not found in QoS system
@@ -785,28 +809,30 @@ impl Scheduler {
             .get_task_config(task_id)
             .ok_or(ErrorCode::TaskNotFound)?;
 
-        // Check if network requirements are satisfied
+        // Collect every requirement the task does not satisfy
+        let mut conditions = Conditions::NONE;
         if let Err(reason) = config.satisfy_network(self.state_handler.network()) {
             info!(
                 "task {} started, waiting for network {:?}",
                 task_id,
                 self.state_handler.network()
             );
-            // Put task in waiting state due to network
-            database.update_task_state(task_id, State::Waiting, reason);
-            Notifier::waiting(&self.client_manager, task_id, WaitingCause::Network);
-            return Ok(false);
+            conditions = conditions.union(Conditions::from_reason(reason));
         }
-
-        // Check if foreground requirements are satisfied
         if !config.satisfy_foreground(self.state_handler.foreground_abilities()) {
             info!(
                 "task {} started, waiting for app {}",
                 task_id, config.common_data.uid
             );
-            // Put task in waiting state due to app state
-            database.update_task_state(task_id, State::Waiting, Reason::AppBackgroundOrTerminate);
-            Notifier::waiting(&self.client_manager, task_id, WaitingCause::AppState);
+            conditions = conditions.union(Conditions::APP_STATE);
+        }
+
+        // Put task in waiting state on all of them, in place of the ones
+        // found previously
+        if !conditions.is_empty() {
+            let clear = Conditions::NETWORK.union(Conditions::APP_STATE);
+            let conditions = database.update_task_waiting(task_id, conditions, clear);
+            Notifier::waiting(&self.client_manager, task_id, conditions.cause());
             return Ok(false);
         }
 
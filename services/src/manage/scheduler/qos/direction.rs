@@ -100,3 +100,16 @@ pub(crate) enum QosLevel {
     /// Medium priority with a maximum speed of 800 KB/s.
     Middle = 800 * 1024,
 }
+
+impl QosLevel {
+    /// Returns the name of the QoS level applied with the given speed limit,
+    /// as shown in dumps, or `None` if the limit matches no level.
+    pub(crate) fn name_of(speed_limit: u64) -> Option<&'static str> {
+        match speed_limit {
+            x if x == QosLevel::High as u64 => Some("high"),
+            x if x == QosLevel::Middle as u64 => Some("middle"),
+            x if x == QosLevel::Low as u64 => Some("low"),
+            _ => None,
+        }
+    }
+}
@@ -40,6 +40,7 @@ use crate::manage::task_manager::TaskManagerTx;
 use crate::service::active_counter::ActiveCounter;
 use crate::service::client::ClientManagerEntry;
 use crate::service::run_count::RunCountManagerEntry;
+use crate::task::client::build_task_identity;
 use crate::task::config::Action;
 use crate::task::info::State;
 use crate::task::reason::Reason;
@@ -68,6 +69,8 @@ pub(crate) struct RunningQueue {
     client_manager: ClientManagerEntry,
     /// Set of task IDs that need to resume uploads from breakpoints.
     pub(crate) upload_resume: HashSet<u32>,
+    /// Retry attempts carried over by the tasks retried under their policy.
+    pub(crate) retry_attempts: HashMap<u32, u32>,
 }
 
 impl RunningQueue {
@@ -98,6 +101,7 @@ impl RunningQueue {
             run_count_manager,
             client_manager,
             upload_resume: HashSet::new(),
+            retry_attempts: HashMap::new(),
         }
     }
 
@@ -167,6 +171,7 @@ impl RunningQueue {
                 return false;
             }
             info!("{} restart running", task_id);
+            task.retry_attempts.store(0, Ordering::SeqCst);
             let running_task = RunningTask::new(task.clone(), self.tx.clone(), self.keeper.clone());
             let abort_flag = Arc::new(AtomicBool::new(false));
             let abort_flag_clone = abort_flag.clone();
@@ -262,6 +267,7 @@ impl RunningQueue {
             #[cfg(feature = "oh")]
             let system_config = unsafe { SYSTEM_CONFIG_MANAGER.assume_init_ref().system_config() };
             let upload_resume = self.upload_resume.remove(&task_id);
+            let retry_attempts = self.retry_attempts.remove(&task_id);
 
             let task = match RequestDb::get_instance().get_task(
                 task_id,
@@ -276,11 +282,19 @@ impl RunningQueue {
                 Err(e) => {
                     // Handle other errors by marking task as failed
                     info!("get task {} error:{:?}", task_id, e);
+                    // The key material of a client certificate may have changed
+                    // since the task was created
+                    let reason = match RequestDb::get_instance().get_task_config(task_id) {
+                        Some(config) if build_task_identity(&config).is_err() => {
+                            Reason::ClientCertInvalid
+                        }
+                        _ => Reason::OthersError,
+                    };
                     if let Some(info) = RequestDb::get_instance().get_task_qos_info(task_id) {
                         self.tx.send_event(TaskManagerEvent::Task(TaskEvent::Failed(
                             task_id,
                             uid,
-                            Reason::OthersError,
+                            reason,
                             Mode::from(info.mode),
                         )));
                     }
@@ -289,6 +303,10 @@ impl RunningQueue {
                     continue;
                 }
             };
+            // Carry over the retry attempts of a task retried under its policy
+            if let Some(attempts) = retry_attempts {
+                task.retry_attempts.store(attempts, Ordering::SeqCst);
+            }
             // Apply the new QoS speed limit
             task.speed_limit(qos_direction.direction() as u64);
 
@@ -341,15 +359,22 @@ impl RunningQueue {
             .notify_run_count(self.download_queue.len() + self.upload_queue.len());
     }
 
-    /// Cancels all currently running tasks.
+    /// Cancels all currently running tasks so that they restart.
     ///
     /// This method cancels all tasks managed by this queue, clearing all abort
-    /// handles.
+    /// handles. Each task waits for its retry policy's recovery delay before
+    /// it runs again, so that the restarts are spread out.
     pub(crate) fn retry_all_tasks(&mut self) {
-        for task in self.running_tasks.iter_mut() {
-            if let Some(handle) = task.1.take() {
-                handle.cancel();
+        for (key, handle) in self.running_tasks.iter_mut() {
+            let Some(handle) = handle.take() else {
+                continue;
+            };
+            if let Some(task) = self.download_queue.get(key).or(self.upload_queue.get(key)) {
+                let delay = task.conf.retry_policy.recovery_delay();
+                task.restart_delay
+                    .store(delay.as_millis() as u64, Ordering::SeqCst);
             }
+            handle.cancel();
         }
     }
 
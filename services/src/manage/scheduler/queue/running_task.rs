@@ -20,6 +20,7 @@
 use std::ops::Deref;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::config::Mode;
 use crate::manage::database::RequestDb;
@@ -81,7 +82,13 @@ impl RunningTask {
     ///
     /// This method dispatches to either the download or upload implementation
     /// based on the task's action type. It consumes the `RunningTask` instance.
+    /// A restart delay set when the network recovered is waited out first.
     pub(crate) async fn run(self, abort_flag: Arc<AtomicBool>) {
+        let delay = self.task.restart_delay.swap(0, Ordering::SeqCst);
+        if delay > 0 {
+            info!("task {} restarts in {}ms", self.task.task_id(), delay);
+            ylong_runtime::time::sleep(Duration::from_millis(delay)).await;
+        }
         match self.conf.common_data.action {
             Action::Download => {
                 download(self.task.clone(), abort_flag).await;
@@ -206,6 +213,26 @@ impl Drop for RunningTask {
                             task_id, uid, mode,
                         )));
                 }
+                // Storage space shortage keeps the task waiting
+                Err(e) if e == Reason::StorageSpaceWaiting => {
+                    self.tx
+                        .send_event(TaskManagerEvent::Task(TaskEvent::StorageSpace(
+                            task_id, uid, mode,
+                        )));
+                }
+                // A retry under the retry policy waits for its delay
+                Err(e) if e == Reason::RetryWaiting => {
+                    let attempts = self.task.retry_attempts.load(Ordering::SeqCst);
+                    let delay = self.task.retry_delay.lock().unwrap().take();
+                    self.tx
+                        .send_event(TaskManagerEvent::Task(TaskEvent::RetryWaiting(
+                            task_id,
+                            uid,
+                            mode,
+                            attempts,
+                            delay.unwrap_or_default(),
+                        )));
+                }
                 // Report other failures
                 Err(e) => {
                     self.tx.send_event(TaskManagerEvent::Task(TaskEvent::Failed(
@@ -215,7 +242,9 @@ impl Drop for RunningTask {
             },
             // No explicit result - check if download completed successfully
             None => {
-                if self.check_download_complete() {
+                // A download interrupted before its file was moved into
+                // place runs again to commit it
+                if self.check_download_complete() && self.task.temp_files_committed() {
                     self.send_complete(task_id, uid, mode);
                 } else {
                     // Task was possibly cancelled or interrupted
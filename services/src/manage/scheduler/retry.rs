@@ -0,0 +1,141 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scheduling of tasks waiting for the delay of their retry policy.
+//!
+//! A task whose failed attempt is retried under its retry policy is taken out
+//! of the QoS queue and kept in the `Waiting` state on `Conditions::RETRY`,
+//! so that it does not hold a running slot during the delay. A timer sends
+//! `ScheduleEvent::RetryDue` when the delay is over, and the task then goes
+//! through the same checks as a task being started, keeping its retry
+//! attempts. The tasks whose timer was lost with the service are released
+//! by the `TaskManager` timer, with the retry attempts stored along with
+//! them.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use ylong_runtime::time::sleep;
+
+use super::Scheduler;
+use crate::config::Mode;
+use crate::database::config_ext;
+use crate::manage::database::RequestDb;
+use crate::manage::events::{ScheduleEvent, TaskManagerEvent};
+use crate::manage::notifier::Notifier;
+use crate::task::condition::Conditions;
+use crate::task::info::State;
+use crate::utils::runtime_spawn;
+
+impl Scheduler {
+    /// Handles a running task that waits for the delay of its retry policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application that owns the task.
+    /// * `task_id` - The unique identifier of the task.
+    /// * `mode` - The execution mode of the task.
+    /// * `attempts` - The retry attempts made by the task so far.
+    /// * `delay` - The delay before the task is retried.
+    /// * `task_count` - Map tracking task counts by UID and mode.
+    pub(crate) fn task_retry_waiting(
+        &mut self,
+        uid: u64,
+        task_id: u32,
+        mode: Mode,
+        attempts: u32,
+        delay: Duration,
+        task_count: &mut HashMap<u64, (usize, usize)>,
+    ) {
+        let database = RequestDb::get_instance();
+        // A task paused, stopped or removed meanwhile is handled as canceled
+        match database.query_task_state(task_id) {
+            Some(state) if state == State::Running.repr || state == State::Retrying.repr => {}
+            _ => return self.task_cancel(uid, task_id, mode, task_count),
+        }
+
+        info!("task {} waiting {}ms to retry", task_id, delay.as_millis());
+        self.running_queue.task_finish(uid, task_id);
+        if self.qos.remove_task(uid, task_id) {
+            self.schedule_if_not_scheduled();
+        }
+        let conditions = database.update_task_waiting(task_id, Conditions::RETRY, Conditions::NONE);
+        Notifier::waiting(&self.client_manager, task_id, conditions.cause());
+
+        config_ext::store_retries_made(task_id, attempts);
+        self.retry_attempts.insert(task_id, attempts);
+        let tx = self.task_manager.clone();
+        runtime_spawn(async move {
+            sleep(delay).await;
+            tx.send_event(TaskManagerEvent::Schedule(ScheduleEvent::RetryDue(task_id)));
+        });
+    }
+
+    /// Schedules a task whose retry delay is over.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The unique identifier of the task.
+    pub(crate) fn retry_due(&mut self, task_id: u32) {
+        let Some(attempts) = self.retry_attempts.remove(&task_id) else {
+            return;
+        };
+        // The task may have been resumed, stopped or removed meanwhile
+        let database = RequestDb::get_instance();
+        if database.query_task_state(task_id) != Some(State::Waiting.repr) {
+            return;
+        }
+        match database.query_task_conditions(task_id) {
+            Some(conditions) if conditions.intersects(Conditions::RETRY) => {}
+            _ => return,
+        }
+        info!("task {} retry delay over", task_id);
+        self.running_queue.retry_attempts.insert(task_id, attempts);
+        self.release_retry(task_id);
+    }
+
+    /// Schedules the tasks waiting for a retry delay whose timer was lost,
+    /// as the service restarted during the delay.
+    pub(crate) fn release_orphan_retries(&mut self) {
+        let database = RequestDb::get_instance();
+        for task_id in database.query_waiting_tasks(Conditions::RETRY) {
+            if self.retry_attempts.contains_key(&task_id) {
+                continue;
+            }
+            let attempts = config_ext::query_retries_made(task_id);
+            info!("task {} retry timer lost after {} attempts", task_id, attempts);
+            self.running_queue.retry_attempts.insert(task_id, attempts);
+            self.release_retry(task_id);
+        }
+    }
+
+    /// Clears the retry condition of a task and starts it if nothing else
+    /// keeps it waiting.
+    fn release_retry(&mut self, task_id: u32) {
+        let database = RequestDb::get_instance();
+        let conditions = database.update_task_waiting(task_id, Conditions::NONE, Conditions::RETRY);
+        if !conditions.difference(Conditions::SYSTEM).is_empty() {
+            return;
+        }
+        if !matches!(self.check_config_satisfy(task_id), Ok(true)) {
+            return;
+        }
+        let Some(uid) = database.query_task_uid(task_id) else {
+            return;
+        };
+        if let Some(qos_info) = database.get_task_qos_info(task_id) {
+            self.qos.start_task(uid, qos_info);
+            self.schedule_if_not_scheduled();
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scheduling of downloads waiting for storage space.
+//!
+//! A download whose preflight finds the storage space short is taken out of
+//! the QoS queue and kept in the `Waiting` state on
+//! `Conditions::STORAGE_SPACE`. The `TaskManager` timer periodically checks
+//! the free space of those tasks again, and the ones that fit go through the
+//! same checks as a task being started.
+
+use std::collections::HashMap;
+
+use super::Scheduler;
+use crate::config::Mode;
+use crate::manage::database::RequestDb;
+use crate::manage::notifier::Notifier;
+use crate::task::condition::Conditions;
+use crate::task::info::State;
+use crate::task::space;
+
+impl Scheduler {
+    /// Handles a running download that stopped for lack of storage space.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The user ID of the application that owns the task.
+    /// * `task_id` - The unique identifier of the task.
+    /// * `mode` - The execution mode of the task.
+    /// * `task_count` - Map tracking task counts by UID and mode.
+    pub(crate) fn task_space_waiting(
+        &mut self,
+        uid: u64,
+        task_id: u32,
+        mode: Mode,
+        task_count: &mut HashMap<u64, (usize, usize)>,
+    ) {
+        let database = RequestDb::get_instance();
+        // A task paused, stopped or removed meanwhile is handled as canceled
+        match database.query_task_state(task_id) {
+            Some(state) if state == State::Running.repr || state == State::Retrying.repr => {}
+            _ => return self.task_cancel(uid, task_id, mode, task_count),
+        }
+
+        info!("task {} waiting for storage space", task_id);
+        self.running_queue.task_finish(uid, task_id);
+        if self.qos.remove_task(uid, task_id) {
+            self.schedule_if_not_scheduled();
+        }
+        let conditions =
+            database.update_task_waiting(task_id, Conditions::STORAGE_SPACE, Conditions::NONE);
+        Notifier::waiting(&self.client_manager, task_id, conditions.cause());
+    }
+
+    /// Schedules the tasks waiting for storage space that now fits them.
+    pub(crate) fn recheck_storage_space(&mut self) {
+        let database = RequestDb::get_instance();
+        for task_id in database.query_waiting_tasks(Conditions::STORAGE_SPACE) {
+            let Some(config) = database.get_task_config(task_id) else {
+                continue;
+            };
+            let Some(info) = database.get_task_info(task_id) else {
+                continue;
+            };
+            if !space::has_enough_space(&config, &info.progress) {
+                continue;
+            }
+            info!("task {} storage space available", task_id);
+            let conditions =
+                database.update_task_waiting(task_id, Conditions::NONE, Conditions::STORAGE_SPACE);
+            if !conditions.difference(Conditions::SYSTEM).is_empty() {
+                continue;
+            }
+            if !matches!(self.check_config_satisfy(task_id), Ok(true)) {
+                continue;
+            }
+            if let Some(qos_info) = database.get_task_qos_info(task_id) {
+                self.qos.start_task(config.common_data.uid, qos_info);
+                self.schedule_if_not_scheduled();
+            }
+        }
+    }
+}
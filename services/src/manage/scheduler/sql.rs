@@ -29,7 +29,8 @@ use crate::task::reason::Reason;
 /// # Returns
 ///
 /// A SQL UPDATE statement that changes the task state to `Waiting` with
-/// appropriate reason code, but only if the task is in an applicable state.
+/// appropriate reason code and no waiting condition, but only if the task is
+/// in an applicable state.
 ///
 /// # Notes
 ///
@@ -41,7 +42,7 @@ use crate::task::reason::Reason;
 /// This conditional logic ensures tasks can only be started from valid states.
 pub(super) fn start_task(task_id: u32) -> String {
     format!(
-        "UPDATE request_task SET state = {}, reason = {} where task_id = {} AND (state = {} OR state = {} OR (action = {} AND (state = {} OR state = {} )))",
+        "UPDATE request_task SET state = {}, reason = {}, conditions = 0 where task_id = {} AND (state = {} OR state = {} OR (action = {} AND (state = {} OR state = {} )))",
         State::Waiting.repr,
         Reason::RunningTaskMeetLimits.repr,
         task_id,
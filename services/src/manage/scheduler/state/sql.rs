@@ -22,6 +22,7 @@ use std::collections::HashSet;
 use crate::config::{Action, Mode, Version};
 use crate::info::State;
 use crate::manage::network::{NetworkInfo, NetworkState, NetworkType};
+use crate::task::condition::Conditions;
 use crate::task::reason::Reason;
 
 // State constants for SQL statements
@@ -35,14 +36,16 @@ const FAILED: u8 = State::Failed.repr;
 
 // Reason constants for SQL statements
 const APP_BACKGROUND_OR_TERMINATE: u8 = Reason::AppBackgroundOrTerminate.repr;
-const RUNNING_TASK_MEET_LIMITS: u8 = Reason::RunningTaskMeetLimits.repr;
 const ACCOUNT_STOPPED: u8 = Reason::AccountStopped.repr;
 const NETWORK_OFFLINE: u8 = Reason::NetworkOffline.repr;
 const UNSUPPORTED_NETWORK_TYPE: u8 = Reason::UnsupportedNetworkType.repr;
-const NETWORK_APP: u8 = Reason::NetworkApp.repr;
-const NETWORK_ACCOUNT: u8 = Reason::NetworkAccount.repr;
-const APP_ACCOUNT: u8 = Reason::AppAccount.repr;
-const NETWORK_APP_ACCOUNT: u8 = Reason::NetworkAppAccount.repr;
+const RUNNING_TASK_MEET_LIMITS: u8 = Reason::RunningTaskMeetLimits.repr;
+
+// Condition constants for SQL statements
+const NETWORK_OFFLINE_CONDITION: u32 = Conditions::NETWORK_OFFLINE.bits();
+const NETWORK_TYPE_CONDITION: u32 = Conditions::NETWORK_TYPE.bits();
+const APP_STATE_CONDITION: u32 = Conditions::APP_STATE.bits();
+const ACCOUNT_CONDITION: u32 = Conditions::ACCOUNT.bits();
 
 // Action constants for SQL statements
 const DOWNLOAD: u8 = Action::Download.repr;
@@ -191,9 +194,9 @@ pub(crate) fn app_uninstall(uid: u64) -> String {
 /// unavailability.
 /// - Downloads are set to waiting state
 /// - Uploads are set to failed state
-/// - Existing waiting tasks have their reasons combined with app background
-///   reason
+/// - Existing waiting tasks have the app state condition added
 pub(crate) fn app_state_unavailable(uid: u64) -> String {
+    let conditions = Conditions::updated_sql(Conditions::APP_STATE, Conditions::NONE);
     format!(
         "UPDATE request_task SET 
             state = CASE
@@ -201,17 +204,14 @@ pub(crate) fn app_state_unavailable(uid: u64) -> String {
                 WHEN (state = {RUNNING} OR state = {RETRYING}) AND action = {UPLOAD} THEN {FAILED}
                 ELSE state
             END,
+            conditions = CASE
+                WHEN (state = {RUNNING} OR state = {RETRYING}) THEN {APP_STATE_CONDITION}
+                WHEN state = {WAITING} THEN {conditions}
+                ELSE conditions
+            END,
             reason = CASE 
                 WHEN (state = {RUNNING} OR state = {RETRYING}) THEN {APP_BACKGROUND_OR_TERMINATE} 
-                WHEN state = {WAITING} THEN
-                    CASE reason
-                        WHEN {RUNNING_TASK_MEET_LIMITS} THEN {APP_BACKGROUND_OR_TERMINATE}
-                        WHEN {NETWORK_OFFLINE} THEN {NETWORK_APP}
-                        WHEN {UNSUPPORTED_NETWORK_TYPE} THEN {NETWORK_APP}
-                        WHEN {ACCOUNT_STOPPED} THEN {APP_ACCOUNT}
-                        WHEN {NETWORK_ACCOUNT} THEN {NETWORK_APP_ACCOUNT}
-                        ELSE reason
-                    END
+                WHEN state = {WAITING} THEN {RUNNING_TASK_MEET_LIMITS}
                 ELSE reason 
             END
         WHERE 
@@ -227,18 +227,14 @@ pub(crate) fn app_state_unavailable(uid: u64) -> String {
 ///
 /// # Returns
 ///
-/// SQL statement to restore original task reasons when an application becomes
-/// available again.
+/// SQL statement to remove the app state condition of waiting tasks when an
+/// application becomes available again.
 pub(crate) fn app_state_available(uid: u64) -> String {
+    let conditions = Conditions::updated_sql(Conditions::NONE, Conditions::APP_STATE);
     format!(
         "UPDATE request_task SET 
-            reason = CASE
-                WHEN reason = {APP_BACKGROUND_OR_TERMINATE} THEN {RUNNING_TASK_MEET_LIMITS}
-                WHEN reason = {NETWORK_APP} THEN {NETWORK_OFFLINE}
-                WHEN reason = {APP_ACCOUNT} THEN {ACCOUNT_STOPPED}
-                WHEN reason = {NETWORK_APP_ACCOUNT} THEN {NETWORK_ACCOUNT}
-                ELSE reason
-            END
+            conditions = {conditions},
+            reason = {RUNNING_TASK_MEET_LIMITS}
         WHERE 
             state = {WAITING} AND uid = {uid}",
     )
@@ -255,23 +251,21 @@ pub(crate) fn app_state_available(uid: u64) -> String {
 /// SQL statement to update task states and reasons for tasks belonging to
 /// inactive accounts.
 pub(super) fn account_unavailable(active_accounts: &HashSet<u64>) -> String {
+    let conditions = Conditions::updated_sql(Conditions::ACCOUNT, Conditions::NONE);
     let mut sql = format!(
         "UPDATE request_task SET 
             state = CASE
                 WHEN state = {RUNNING} OR state = {RETRYING} THEN {WAITING}
                 ELSE state
             END,
+            conditions = CASE
+                WHEN (state = {RUNNING} OR state = {RETRYING}) THEN {ACCOUNT_CONDITION}
+                WHEN state = {WAITING} THEN {conditions}
+                ELSE conditions
+            END,
             reason = CASE
                 WHEN (state = {RUNNING} OR state = {RETRYING}) THEN {ACCOUNT_STOPPED}
-                WHEN state = {WAITING} THEN 
-                    CASE reason
-                        WHEN {RUNNING_TASK_MEET_LIMITS} THEN {ACCOUNT_STOPPED}
-                        WHEN {NETWORK_OFFLINE} THEN {NETWORK_ACCOUNT}
-                        WHEN {UNSUPPORTED_NETWORK_TYPE} THEN {NETWORK_ACCOUNT}
-                        WHEN {APP_BACKGROUND_OR_TERMINATE} THEN {APP_ACCOUNT}
-                        WHEN {NETWORK_APP} THEN {NETWORK_APP_ACCOUNT}
-                        ELSE reason
-                    END
+                WHEN state = {WAITING} THEN {RUNNING_TASK_MEET_LIMITS}
                 ELSE reason
             END  
         WHERE 
@@ -299,18 +293,14 @@ pub(super) fn account_unavailable(active_accounts: &HashSet<u64>) -> String {
 ///
 /// # Returns
 ///
-/// SQL statement to restore original task reasons for tasks belonging to active
-/// accounts.
+/// SQL statement to remove the account condition of waiting tasks belonging to
+/// active accounts.
 pub(super) fn account_available(active_accounts: &HashSet<u64>) -> String {
+    let conditions = Conditions::updated_sql(Conditions::NONE, Conditions::ACCOUNT);
     let mut sql = format!(
         "UPDATE request_task SET 
-            reason = CASE
-                WHEN reason= {ACCOUNT_STOPPED} THEN {RUNNING_TASK_MEET_LIMITS}
-                WHEN reason = {NETWORK_ACCOUNT} THEN {NETWORK_OFFLINE}
-                WHEN reason = {APP_ACCOUNT} THEN {APP_BACKGROUND_OR_TERMINATE}
-                WHEN reason = {NETWORK_APP_ACCOUNT} THEN {NETWORK_APP}
-                ELSE reason
-            END
+            conditions = {conditions},
+            reason = {RUNNING_TASK_MEET_LIMITS}
         WHERE 
             state = {WAITING} AND uid/200000 IN (",
     );
@@ -338,6 +328,7 @@ pub(super) fn account_available(active_accounts: &HashSet<u64>) -> String {
 /// - API9 uploads (fail)
 /// - API10 foreground downloads or no retry (fail)
 pub(super) fn network_offline() -> String {
+    let conditions = Conditions::updated_sql(Conditions::NETWORK_OFFLINE, Conditions::NETWORK);
     format!(
         "UPDATE request_task SET 
             state = CASE 
@@ -345,16 +336,14 @@ pub(super) fn network_offline() -> String {
                 WHEN (state = {RUNNING} OR state = {RETRYING}) AND ((version = {API9} AND action = {UPLOAD}) OR (version = {API10} AND (mode = {FRONTEND} OR retry = 0))) THEN {FAILED}
                 ELSE state
             END,
+            conditions = CASE
+                WHEN state = {RUNNING} OR state = {RETRYING} THEN {NETWORK_OFFLINE_CONDITION}
+                WHEN state = {WAITING} THEN {conditions}
+                ELSE conditions
+            END,
             reason = CASE 
                 WHEN state = {RUNNING} OR state = {RETRYING} THEN {NETWORK_OFFLINE}
-                WHEN state = {WAITING} THEN 
-                    CASE reason
-                        WHEN {RUNNING_TASK_MEET_LIMITS} THEN {NETWORK_OFFLINE}
-                        WHEN {ACCOUNT_STOPPED} THEN {NETWORK_ACCOUNT}
-                        WHEN {APP_BACKGROUND_OR_TERMINATE} THEN {NETWORK_APP}
-                        WHEN {APP_ACCOUNT} THEN {NETWORK_APP_ACCOUNT}
-                        ELSE reason
-                    END
+                WHEN state = {WAITING} THEN {RUNNING_TASK_MEET_LIMITS}
                 ELSE reason
             END"
     )
@@ -389,6 +378,7 @@ pub(super) fn network_unavailable(info: &NetworkInfo) -> Option<String> {
         unsupported_condition.push_str(" OR roaming = 0");
     }
 
+    let conditions = Conditions::updated_sql(Conditions::NETWORK_TYPE, Conditions::NETWORK);
     Some(format!(
         "UPDATE request_task SET 
             state = CASE 
@@ -396,16 +386,14 @@ pub(super) fn network_unavailable(info: &NetworkInfo) -> Option<String> {
                 WHEN (state = {RUNNING} OR state = {RETRYING}) AND ((version = {API9} AND action = {UPLOAD}) OR (version = {API10} AND (mode = {FRONTEND} OR retry = 0))) THEN {FAILED}
                 ELSE state
             END,
+            conditions = CASE
+                WHEN state = {RUNNING} OR state = {RETRYING} THEN {NETWORK_TYPE_CONDITION}
+                WHEN state = {WAITING} THEN {conditions}
+                ELSE conditions
+            END,
             reason = CASE 
                 WHEN state = {RUNNING} OR state = {RETRYING} THEN {UNSUPPORTED_NETWORK_TYPE}
-                WHEN state = {WAITING} THEN
-                    CASE reason
-                        WHEN {RUNNING_TASK_MEET_LIMITS} THEN {UNSUPPORTED_NETWORK_TYPE}
-                        WHEN {ACCOUNT_STOPPED} THEN {NETWORK_ACCOUNT}
-                        WHEN {APP_BACKGROUND_OR_TERMINATE} THEN {NETWORK_APP}
-                        WHEN {APP_ACCOUNT} THEN {NETWORK_APP_ACCOUNT}
-                        ELSE reason
-                    END
+                WHEN state = {WAITING} THEN {RUNNING_TASK_MEET_LIMITS}
                 ELSE reason
             END
         WHERE 
@@ -421,19 +409,14 @@ pub(super) fn network_unavailable(info: &NetworkInfo) -> Option<String> {
 ///
 /// # Returns
 ///
-/// SQL statement to restore original task reasons for tasks that can run on the
-/// current network.
+/// SQL statement to remove the network conditions of waiting tasks that can
+/// run on the current network.
 pub(super) fn network_available(info: &NetworkInfo) -> String {
+    let conditions = Conditions::updated_sql(Conditions::NONE, Conditions::NETWORK);
     let mut sql = format!(
         "UPDATE request_task SET 
-            reason = CASE 
-                WHEN reason = {NETWORK_OFFLINE} THEN {RUNNING_TASK_MEET_LIMITS}
-                WHEN reason = {UNSUPPORTED_NETWORK_TYPE} THEN {RUNNING_TASK_MEET_LIMITS}
-                WHEN reason = {NETWORK_ACCOUNT} THEN {ACCOUNT_STOPPED}
-                WHEN reason = {NETWORK_APP} THEN {APP_BACKGROUND_OR_TERMINATE}
-                WHEN reason = {NETWORK_APP_ACCOUNT} THEN {APP_ACCOUNT}
-                ELSE reason
-            END
+            conditions = {conditions},
+            reason = {RUNNING_TASK_MEET_LIMITS}
         WHERE 
             state = {WAITING}",
     );
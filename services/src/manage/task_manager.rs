@@ -52,6 +52,7 @@ use crate::service::active_counter::ActiveCounter;
 use crate::service::client::ClientManagerEntry;
 use crate::service::notification_bar::{subscribe_notification_bar, NotificationDispatcher};
 use crate::service::run_count::RunCountManagerEntry;
+use crate::task::cookie::clear_cookies;
 use crate::utils::task_event_count::{task_complete_add, task_fail_add, task_unload};
 use crate::utils::{get_current_timestamp, runtime_spawn, subscribe_common_event, update_policy};
 
@@ -62,6 +63,13 @@ const CLEAR_INTERVAL: u64 = 30 * 60;
 /// initialization.
 const RESTORE_ALL_TASKS_INTERVAL: u64 = 10;
 
+/// Interval (in seconds) for starting deferred tasks that have become due.
+const PROMOTE_DEFERRED_INTERVAL: u64 = 30;
+
+/// Interval (in seconds) for checking again the tasks waiting for storage
+/// space.
+const RECHECK_STORAGE_SPACE_INTERVAL: u64 = 60;
+
 // TaskManager initialization logic:
 //
 // First determine the source of tasks: 1) tasks from applications 2) unfinished
@@ -179,6 +187,8 @@ impl TaskManager {
         runtime_spawn(restore_all_tasks(tx.clone()));
 
         runtime_spawn(clear_timeout_tasks(tx.clone()));
+        runtime_spawn(promote_deferred_tasks(tx.clone()));
+        runtime_spawn(recheck_storage_space(tx.clone()));
         runtime_spawn(task_manager.run());
         tx
     }
@@ -347,6 +357,8 @@ impl TaskManager {
                 .on_state_change(Handler::update_background_timeout, uid),
             StateEvent::AppUninstall(uid) => {
                 self.scheduler.on_state_change(Handler::app_uninstall, uid);
+                // A reinstalled app may reuse the uid.
+                clear_cookies(uid, "");
             }
             StateEvent::SpecialTerminate(uid) => {
                 self.scheduler
@@ -374,6 +386,7 @@ impl TaskManager {
                 Scheduler::reduce_task_count(uid, mode, &mut self.task_count);
                 task_complete_add();
                 self.scheduler.task_completed(uid, task_id);
+                self.recur(task_id);
             }
             TaskEvent::Running(task_id, uid, mode) => {
                 self.scheduler
@@ -383,11 +396,28 @@ impl TaskManager {
                 Scheduler::reduce_task_count(uid, mode, &mut self.task_count);
                 task_fail_add();
                 self.scheduler.task_failed(uid, task_id, reason);
+                self.scheduler
+                    .fail_dependents(task_id, &mut self.task_count);
+                self.recur(task_id);
             }
             TaskEvent::Offline(task_id, uid, mode) => {
                 self.scheduler
                     .task_cancel(uid, task_id, mode, &mut self.task_count);
             }
+            TaskEvent::StorageSpace(task_id, uid, mode) => {
+                self.scheduler
+                    .task_space_waiting(uid, task_id, mode, &mut self.task_count);
+            }
+            TaskEvent::RetryWaiting(task_id, uid, mode, attempts, delay) => {
+                self.scheduler.task_retry_waiting(
+                    uid,
+                    task_id,
+                    mode,
+                    attempts,
+                    delay,
+                    &mut self.task_count,
+                );
+            }
         };
     }
 
@@ -410,6 +440,12 @@ impl TaskManager {
         match message {
             ScheduleEvent::ClearTimeoutTasks => self.clear_timeout_tasks(),
             ScheduleEvent::RestoreAllTasks => self.restore_all_tasks(),
+            ScheduleEvent::PromoteDeferredTasks => {
+                self.scheduler.promote_deferred_tasks();
+                self.scheduler.release_orphan_retries();
+            }
+            ScheduleEvent::RecheckStorageSpace => self.scheduler.recheck_storage_space(),
+            ScheduleEvent::RetryDue(task_id) => self.scheduler.retry_due(task_id),
             ScheduleEvent::Unload => return self.unload_sa(),
             ScheduleEvent::Shutdown => self.shutdown(),
             ScheduleEvent::RestartCountDown => {
@@ -491,10 +527,13 @@ impl TaskManager {
             }
         }
 
-        // Step 3: Clear group notification info
+        // Step 3: Delete the temporary files of failed, removed and cleared downloads
+        crate::database::temp_file::clear_stale();
+
+        // Step 4: Clear group notification info
         NotificationDispatcher::get_instance().clear_group_info();
 
-        // Step 4: Checkpoint the WAL after all cleanup so deleted pages are merged back into
+        // Step 5: Checkpoint the WAL after all cleanup so deleted pages are merged back into
         // the main DB and the -wal file is reset, rather than accumulating across cycles.
         crate::database::checkpoint_wal();
         true
@@ -820,3 +859,37 @@ async fn clear_timeout_tasks(tx: TaskManagerTx) {
         let _ = tx.send_event(TaskManagerEvent::Schedule(ScheduleEvent::ClearTimeoutTasks));
     }
 }
+
+/// Periodically starts deferred tasks.
+///
+/// Continuously runs at a specified interval, triggering the start of the
+/// deferred tasks whose start time has been reached each time.
+///
+/// # Arguments
+///
+/// * `tx` - The task manager event sender to use for triggering the start
+async fn promote_deferred_tasks(tx: TaskManagerTx) {
+    loop {
+        sleep(Duration::from_secs(PROMOTE_DEFERRED_INTERVAL)).await;
+        let _ = tx.send_event(TaskManagerEvent::Schedule(
+            ScheduleEvent::PromoteDeferredTasks,
+        ));
+    }
+}
+
+/// Periodically checks the tasks waiting for storage space.
+///
+/// Continuously runs at a specified interval, triggering the start of the
+/// tasks whose storage space has become available each time.
+///
+/// # Arguments
+///
+/// * `tx` - The task manager event sender to use for triggering the check
+async fn recheck_storage_space(tx: TaskManagerTx) {
+    loop {
+        sleep(Duration::from_secs(RECHECK_STORAGE_SPACE_INTERVAL)).await;
+        let _ = tx.send_event(TaskManagerEvent::Schedule(
+            ScheduleEvent::RecheckStorageSpace,
+        ));
+    }
+}
@@ -245,6 +245,30 @@ impl ClientManager {
                     }
                 }
 
+                // Token refresh routing
+                ClientEvent::SendTokenRefresh(tid, tx) => {
+                    let client = self
+                        .pid_map
+                        .get(&tid)
+                        .and_then(|pid| self.clients.get_mut(pid));
+                    match client {
+                        Some((client_tx, _fd)) => {
+                            if let Err(err) = client_tx.send(ClientEvent::SendTokenRefresh(tid, tx))
+                            {
+                                error!("send token refresh error, {}", err);
+                                sys_event!(
+                                    ExecFault,
+                                    DfxCode::UDS_FAULT_02,
+                                    &format!("send token refresh error, {}", err)
+                                );
+                            }
+                        }
+                        None => {
+                            let _ = tx.send(false);
+                        }
+                    }
+                }
+
                 // Ignore unhandled events
                 _ => {}
             }
@@ -128,6 +128,14 @@ pub(crate) enum ClientEvent {
     /// * `1` - Cause of waiting
     SendWaitNotify(u32, WaitingCause),
 
+    /// Asks a client for a new bearer token of a task.
+    ///
+    /// # Fields
+    ///
+    /// * `0` - Task ID
+    /// * `1` - Sender to confirm the request reached a subscribed client
+    SendTokenRefresh(u32, Sender<bool>),
+
     /// Signals to shutdown the client handler.
     Shutdown,
 }
@@ -143,6 +151,8 @@ pub(crate) enum MessageType {
     Faults,
     /// Waiting state notification message.
     Waiting,
+    /// Bearer token refresh request message.
+    TokenRefresh,
 }
 
 impl ClientManagerEntry {
@@ -330,6 +340,25 @@ impl ClientManagerEntry {
         let event = ClientEvent::SendWaitNotify(tid, reason);
         let _ = self.send_event(event);
     }
+
+    /// Asks the client subscribed to a task for a new bearer token.
+    ///
+    /// # Arguments
+    ///
+    /// * `tid` - Task ID
+    ///
+    /// # Returns
+    ///
+    /// `true` if the request was sent to a subscribed client, `false` if no
+    /// client is subscribed to the task
+    pub(crate) async fn send_token_refresh(&self, tid: u32) -> bool {
+        let (tx, rx) = channel::<bool>();
+        let event = ClientEvent::SendTokenRefresh(tid, tx);
+        if !self.send_event(event) {
+            return false;
+        }
+        rx.await.unwrap_or(false)
+    }
 }
 
 // uid and token_id will be used later
@@ -450,6 +479,10 @@ impl Client {
                         self.handle_send_waiting_notify(task_id, waiting_reason)
                             .await;
                     }
+                    ClientEvent::SendTokenRefresh(task_id, tx) => {
+                        self.handle_send_token_refresh(task_id).await;
+                        let _ = tx.send(true);
+                    }
                     _ => {}
                 }
             }
@@ -565,6 +598,46 @@ impl Client {
         self.send_message(message).await;
     }
 
+    /// Handles sending bearer token refresh requests to the client.
+    ///
+    /// The client answers with the new token through the service API, not
+    /// over the socket.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - Task ID
+    async fn handle_send_token_refresh(&mut self, task_id: u32) {
+        let mut message = Vec::<u8>::new();
+
+        // Message header with magic number
+        message.extend_from_slice(&REQUEST_MAGIC_NUM.to_le_bytes());
+
+        // Unique message identifier
+        message.extend_from_slice(&self.message_id.to_le_bytes());
+        self.message_id += 1;
+
+        // Message type for token refresh requests
+        let message_type = MessageType::TokenRefresh as u16;
+        message.extend_from_slice(&message_type.to_le_bytes());
+
+        // Message body size (initially 0, will be updated later)
+        let message_body_size: u16 = 0;
+        message.extend_from_slice(&message_body_size.to_le_bytes());
+
+        // Task ID
+        message.extend_from_slice(&task_id.to_le_bytes());
+
+        // Update the message size
+        let size = message.len() as u16;
+        info!("send token refresh, tid {}", task_id);
+        let size = size.to_le_bytes();
+        message[POSITION_OF_LENGTH as usize] = size[0];
+        message[(POSITION_OF_LENGTH + 1) as usize] = size[1];
+
+        // Send the constructed message
+        self.send_message(message).await;
+    }
+
     /// Handles sending HTTP responses to the client.
     ///
     /// This method constructs and sends an HTTP response message with the given
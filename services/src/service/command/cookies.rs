@@ -0,0 +1,146 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cookie jar management.
+//!
+//! This module provides the methods to seed the cookie jar of an application
+//! with cookies obtained outside of its tasks, and to clear it.
+
+use ipc::parcel::MsgParcel;
+use ipc::IpcResult;
+
+use crate::error::ErrorCode;
+use crate::service::permission::PermissionChecker;
+use crate::service::RequestServiceStub;
+use crate::task::cookie::{clear_cookies, store_cookies, CookieUrl};
+
+impl RequestServiceStub {
+    /// Seeds the cookie jar of an application.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the application uid, the url the
+    ///   cookies are set for, and the cookies in the `Set-Cookie` format
+    /// * `reply` - Message parcel to write the operation result and the number
+    ///   of cookies accepted to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error code in the reply parcel:
+    /// * `ErrOk` - Cookies stored successfully
+    /// * `ParameterCheck` - Url not http or https, or too many cookies
+    ///
+    /// # Notes
+    ///
+    /// * Cookies are accepted as if set by a response from the url, so those
+    ///   with a `Domain` not covering the url are ignored
+    /// * Callers without download permission may only seed the jar of their
+    ///   own application, and the uid in the parcel is ignored
+    pub(crate) fn set_cookies(&self, data: &mut MsgParcel, reply: &mut MsgParcel) -> IpcResult<()> {
+        info!("Service set_cookies");
+        // Maximum number of cookies seeded at once
+        const MAX_SEED_COOKIES: u32 = 256;
+
+        let permission = PermissionChecker::check_down_permission();
+
+        let uid: u64 = data.read()?;
+        let url: String = data.read()?;
+        let size: u32 = data.read()?;
+        if size > MAX_SEED_COOKIES {
+            error!("Service set_cookies, failed: too many cookies: {}", size);
+            sys_event!(
+                ExecError,
+                DfxCode::INVALID_IPC_MESSAGE_A42,
+                &format!("Service set_cookies, failed: too many cookies: {}", size)
+            );
+            reply.write(&(ErrorCode::ParameterCheck as i32))?;
+            return Ok(());
+        }
+        let mut cookies = Vec::with_capacity(size as usize);
+        for _ in 0..size {
+            let cookie: String = data.read()?;
+            cookies.push(cookie);
+        }
+
+        if CookieUrl::parse(&url).is_none() {
+            error!("Service set_cookies, failed: url not valid");
+            sys_event!(
+                ExecError,
+                DfxCode::INVALID_IPC_MESSAGE_A42,
+                "Service set_cookies, failed: url not valid"
+            );
+            reply.write(&(ErrorCode::ParameterCheck as i32))?;
+            return Ok(());
+        }
+
+        // Applications may only seed their own jar
+        let uid = if permission {
+            uid
+        } else {
+            ipc::Skeleton::calling_uid()
+        };
+
+        let accepted = cookies
+            .iter()
+            .map(|cookie| store_cookies(uid, &url, cookie))
+            .sum::<usize>();
+        debug!(
+            "Service set_cookies, uid {} accepted {}/{}",
+            uid, accepted, size
+        );
+        reply.write(&(ErrorCode::ErrOk as i32))?;
+        reply.write(&(accepted as u32))?;
+        Ok(())
+    }
+
+    /// Clears the cookie jar of an application.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the application uid and the
+    ///   domain whose cookies are removed, empty for all of them
+    /// * `reply` - Message parcel to write the operation result to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error code in the reply parcel:
+    /// * `ErrOk` - Cookies removed successfully
+    ///
+    /// # Notes
+    ///
+    /// * The cookies of the subdomains of the domain are removed as well
+    /// * Callers without download permission may only clear the jar of their
+    ///   own application, and the uid in the parcel is ignored
+    pub(crate) fn clear_cookies(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        info!("Service clear_cookies");
+        let permission = PermissionChecker::check_down_permission();
+
+        let uid: u64 = data.read()?;
+        let domain: String = data.read()?;
+
+        // Applications may only clear their own jar
+        let uid = if permission {
+            uid
+        } else {
+            ipc::Skeleton::calling_uid()
+        };
+
+        clear_cookies(uid, &domain);
+        reply.write(&(ErrorCode::ErrOk as i32))?;
+        Ok(())
+    }
+}
@@ -23,12 +23,105 @@ use ipc::IpcResult;
 
 use crate::manage::events::TaskManagerEvent;
 use crate::service::RequestServiceStub;
+use crate::task::config::Action;
+use crate::task::info::{DumpAllEachInfo, DumpFilter, State};
 
 /// Help message displayed when the dump command is used incorrectly or with `-h` flag.
 const HELP_MSG: &str = "usage:\n\
                          -h                    help text for the tool\n\
                          -t [taskid]           without taskid: display all task summary info; \
-                         taskid: display one task detail info\n";
+                         taskid: display one task detail info\n\
+                         --json                output the task info as JSON\n\
+                         --uid <uid>           only display the tasks of the uid\n\
+                         --state <state>       only display the tasks in the state, \
+                         by name or number\n\
+                         --action <action>     only display the tasks with the action, \
+                         download, upload or by number\n";
+
+/// States the dumped tasks can be filtered on.
+const STATES: [State; 9] = [
+    State::Initialized,
+    State::Waiting,
+    State::Running,
+    State::Retrying,
+    State::Paused,
+    State::Stopped,
+    State::Completed,
+    State::Failed,
+    State::Removed,
+];
+
+/// Arguments of the `-t` dump command.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct DumpArgs {
+    /// Task to dump, all tasks if `None`.
+    pub(crate) task_id: Option<u32>,
+    /// Whether to output JSON instead of a table.
+    pub(crate) json: bool,
+    /// Filter applied to the dumped tasks.
+    pub(crate) filter: DumpFilter,
+}
+
+impl DumpArgs {
+    /// Parses the arguments following `-t`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message describing the first invalid argument.
+    pub(crate) fn parse(args: &[String]) -> Result<Self, String> {
+        let mut dump_args = DumpArgs::default();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--json" => dump_args.json = true,
+                "--uid" | "--state" | "--action" => {
+                    let Some(value) = iter.next() else {
+                        return Err(format!("{} requires a value", arg));
+                    };
+                    match arg.as_str() {
+                        "--uid" => {
+                            let uid = value
+                                .parse::<u64>()
+                                .map_err(|_| "--uid accept a number".to_string())?;
+                            dump_args.filter.uid = Some(uid);
+                        }
+                        "--state" => dump_args.filter.state = Some(parse_state(value)?),
+                        _ => dump_args.filter.action = Some(parse_action(value)?),
+                    }
+                }
+                task_id if dump_args.task_id.is_none() => match task_id.parse::<u32>() {
+                    Ok(id) => dump_args.task_id = Some(id),
+                    Err(_) => return Err("-t accept a number".to_string()),
+                },
+                _ => return Err("too many args, -t accept no task id or one".to_string()),
+            }
+        }
+        Ok(dump_args)
+    }
+}
+
+/// Parses a task state from its name or number.
+fn parse_state(value: &str) -> Result<State, String> {
+    STATES
+        .into_iter()
+        .find(|state| match value.parse::<u8>() {
+            Ok(repr) => state.repr == repr,
+            Err(_) => format!("{:?}", state).eq_ignore_ascii_case(value),
+        })
+        .ok_or_else(|| format!("invalid state {}", value))
+}
+
+/// Parses a task action from its name or number.
+fn parse_action(value: &str) -> Result<Action, String> {
+    [Action::Download, Action::Upload]
+        .into_iter()
+        .find(|action| match value.parse::<u8>() {
+            Ok(repr) => action.repr == repr,
+            Err(_) => format!("{:?}", action).eq_ignore_ascii_case(value),
+        })
+        .ok_or_else(|| format!("invalid action {}", value))
+}
+
 impl RequestServiceStub {
     /// Dumps task information to a file based on provided arguments.
     ///
@@ -47,6 +140,8 @@ impl RequestServiceStub {
     /// * `-h` - Display help message
     /// * `-t` - Dump summary information for all tasks
     /// * `-t [taskid]` - Dump detailed information for a specific task
+    /// * `--json`, `--uid`, `--state`, `--action` - Output JSON, filter the
+    ///   dumped tasks
     pub(crate) fn dump(&self, mut file: File, args: Vec<String>) -> IpcResult<()> {
         info!("Service dump");
 
@@ -63,24 +158,18 @@ impl RequestServiceStub {
             return Ok(());
         }
 
-        // Process based on the number of arguments
-        match len {
-            // Dump all task information when `-t` is provided without a task ID
-            1 => self.dump_all_task_info(file),
-            // Dump specific task information when `-t` is followed by a task ID
-            2 => {
-                let task_id = args[1].parse::<u32>();
-                match task_id {
-                    Ok(id) => self.dump_one_task_info(file, id),
-                    Err(_) => {
-                        let _ = file.write("-t accept a number".as_bytes());
-                    }
-                }
-            }
-            // Handle too many arguments error
-            _ => {
-                let _ = file.write("too many args, -t accept no arg or one arg".as_bytes());
+        let dump_args = match DumpArgs::parse(&args[1..]) {
+            Ok(dump_args) => dump_args,
+            Err(msg) => {
+                let _ = file.write(msg.as_bytes());
+                return Ok(());
             }
+        };
+        match dump_args.task_id {
+            // Dump specific task information when `-t` is followed by a task ID
+            Some(task_id) => self.dump_one_task_info(file, task_id, dump_args.json),
+            // Dump all task information when `-t` is provided without a task ID
+            None => self.dump_all_task_info(file, &dump_args.filter, dump_args.json),
         }
         Ok(())
     }
@@ -90,11 +179,9 @@ impl RequestServiceStub {
     /// # Arguments
     ///
     /// * `file` - File to write the task summary information to.
-    ///
-    /// # Notes
-    ///
-    /// Writes a table with columns for task ID, action, state, and reason.
-    fn dump_all_task_info(&self, mut file: File) {
+    /// * `filter` - Filter applied to the tasks.
+    /// * `json` - Whether to output JSON instead of a table.
+    fn dump_all_task_info(&self, mut file: File, filter: &DumpFilter, json: bool) {
         info!("Service dump all task info");
 
         // Create event to request all task information
@@ -113,27 +200,31 @@ impl RequestServiceStub {
                 return;
             }
         };
-        // Write task count and formatted table of task information
-        let len = infos.vec.len();
-        let _ = file.write(format!("task num: {}\n", len).as_bytes());
-        if len > 0 {
-            // Write table header
+        let infos: Vec<&DumpAllEachInfo> = infos
+            .vec
+            .iter()
+            .filter(|info| filter.matches(info))
+            .collect();
+
+        if json {
+            let tasks: Vec<String> = infos.iter().map(|info| info.to_json()).collect();
             let _ = file.write(
                 format!(
-                    "{:<20}{:<12}{:<12}{:<12}\n",
-                    "id", "action", "state", "reason"
+                    "{{\"task_num\":{},\"tasks\":[{}]}}\n",
+                    infos.len(),
+                    tasks.join(",")
                 )
                 .as_bytes(),
             );
-            // Write each task's information in a formatted row
-            for info in infos.vec.iter() {
-                let _ = file.write(
-                    format!(
-                        "{:<20}{:<12}{:<12}{:<12}\n",
-                        info.task_id, info.action.repr, info.state.repr, info.reason.repr
-                    )
-                    .as_bytes(),
-                );
+            return;
+        }
+
+        // Write task count and formatted table of task information
+        let _ = file.write(format!("task num: {}\n", infos.len()).as_bytes());
+        if !infos.is_empty() {
+            let _ = file.write(DumpAllEachInfo::table_header().as_bytes());
+            for info in infos {
+                let _ = file.write(info.table_row().as_bytes());
             }
         }
     }
@@ -144,7 +235,8 @@ impl RequestServiceStub {
     ///
     /// * `file` - File to write the task information to.
     /// * `task_id` - ID of the task to retrieve information for.
-    fn dump_one_task_info(&self, mut file: File, task_id: u32) {
+    /// * `json` - Whether to output JSON instead of a table.
+    fn dump_one_task_info(&self, mut file: File, task_id: u32, json: bool) {
         info!("Service dump one task info");
 
         // Create event to request information for a specific task
@@ -164,26 +256,23 @@ impl RequestServiceStub {
         };
 
         // Write task information if found, otherwise show error message
-        if let Some(task) = task {
-            // Write table header
-            let _ = file.write(
-                format!(
-                    "{:<20}{:<12}{:<12}{:<12}\n",
-                    "id", "action", "state", "reason"
-                )
-                .as_bytes(),
-            );
-            // Write the task's information in a formatted row
-            let _ = file.write(
-                format!(
-                    "{:<20}{:<12}{:<12}{:<12}\n",
-                    task.task_id, task.action.repr, task.state.repr, task.reason.repr
-                )
-                .as_bytes(),
-            );
-        } else {
+        match task {
+            Some(task) if json => {
+                let _ = file.write(format!("{}\n", task.to_json()).as_bytes());
+            }
+            Some(task) => {
+                let _ = file.write(DumpAllEachInfo::table_header().as_bytes());
+                let _ = file.write(task.table_row().as_bytes());
+            }
             // Handle case where task ID is invalid
-            let _ = file.write(format!("invalid task id {}", task_id).as_bytes());
+            None => {
+                let _ = file.write(format!("invalid task id {}", task_id).as_bytes());
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod ut_dump {
+    include!("../../../tests/ut/service/command/ut_dump.rs");
+}
@@ -20,6 +20,7 @@
 use crate::error::ErrorCode;
 
 mod construct;      // Task creation and configuration
+mod cookies;        // Cookie jar seeding and clearing
 mod dump;           // Task information dumping utilities
 mod get_task;       // Task configuration retrieval
 mod notification_bar; // Notification system integration
@@ -30,6 +31,8 @@ mod query_mime_type; // MIME type detection for resources
 mod remove;         // Task deletion operations
 mod resume;         // Task resumption operations
 mod search;         // Task searching functionality
+mod set_auth_token; // Bearer token refresh of tasks
+mod set_bandwidth_budget; // Device and application bandwidth budgets
 mod set_max_speed;  // Bandwidth control for tasks
 mod set_mode;       // Task execution mode configuration
 mod show;           // Task visibility management
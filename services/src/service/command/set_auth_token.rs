@@ -0,0 +1,100 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bearer token refresh of tasks.
+//!
+//! This module provides the method applications use to answer the token
+//! refresh requests sent over their channel when a server rejects the bearer
+//! token of a task.
+
+use ipc::parcel::MsgParcel;
+use ipc::IpcResult;
+
+use crate::error::ErrorCode;
+use crate::service::permission::PermissionChecker;
+use crate::service::RequestServiceStub;
+use crate::task::auth::complete_token_refresh;
+
+impl RequestServiceStub {
+    /// Hands over a new bearer token of a task.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the task ID and the new token,
+    ///   empty to decline the refresh
+    /// * `reply` - Message parcel to write the operation result to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error code in the reply parcel:
+    /// * `ErrOk` - Token handed over to the task
+    /// * `TaskNotFound` - Task ID invalid or permission denied
+    /// * `TaskStateErr` - The task is not waiting for a token
+    ///
+    /// # Notes
+    ///
+    /// * Callers without download permission may only answer for their own
+    ///   tasks
+    pub(crate) fn set_auth_token(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        info!("Service set_auth_token");
+        let permission = PermissionChecker::check_down_permission();
+
+        let task_id: String = data.read()?;
+        let token: String = data.read()?;
+
+        let Ok(task_id) = task_id.parse::<u32>() else {
+            error!("Service set_auth_token, failed: tid not valid: {}", task_id);
+            sys_event!(
+                ExecError,
+                DfxCode::INVALID_IPC_MESSAGE_A42,
+                &format!("Service set_auth_token, failed: tid not valid: {}", task_id)
+            );
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Ok(());
+        };
+
+        // Applications may only answer for their own tasks
+        let uid = ipc::Skeleton::calling_uid();
+        if !permission && !self.check_task_uid(task_id, uid) {
+            error!(
+                "Service set_auth_token, failed: check task uid. tid: {}, uid: {}",
+                task_id, uid
+            );
+            sys_event!(
+                ExecError,
+                DfxCode::INVALID_IPC_MESSAGE_A42,
+                &format!(
+                    "Service set_auth_token, failed: check task uid. tid: {}, uid: {}",
+                    task_id, uid
+                )
+            );
+            reply.write(&(ErrorCode::TaskNotFound as i32))?;
+            return Ok(());
+        }
+
+        if !complete_token_refresh(task_id, token) {
+            info!(
+                "Service set_auth_token, tid {} not waiting for a token",
+                task_id
+            );
+            reply.write(&(ErrorCode::TaskStateErr as i32))?;
+            return Ok(());
+        }
+        reply.write(&(ErrorCode::ErrOk as i32))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Device and application bandwidth budget configuration.
+//!
+//! This module provides the method to set the bandwidth caps shared by all
+//! tasks of the device or of one application, with separate caps for metered
+//! and unmetered networks.
+
+use ipc::parcel::MsgParcel;
+use ipc::IpcResult;
+
+use crate::error::ErrorCode;
+use crate::service::permission::PermissionChecker;
+use crate::service::RequestServiceStub;
+use crate::task::bandwidth::{self, BandwidthCap, BudgetScope};
+
+impl RequestServiceStub {
+    /// Sets the bandwidth caps of the device or of an application.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Message parcel containing the budget scope, the application
+    ///   uid, and the metered and unmetered caps in bytes per second
+    /// * `reply` - Message parcel to write the operation result to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error code in the reply parcel:
+    /// * `ErrOk` - Caps set successfully
+    /// * `ParameterCheck` - Invalid scope, or a cap neither 0 nor >= 16KB/s
+    /// * `Permission` - The caller may not set the device budget
+    ///
+    /// # Notes
+    ///
+    /// * A cap of 0 removes the limit on that network type
+    /// * Callers without download permission may only set the budget of their
+    ///   own application, and the uid in the parcel is ignored
+    pub(crate) fn set_bandwidth_budget(
+        &self,
+        data: &mut MsgParcel,
+        reply: &mut MsgParcel,
+    ) -> IpcResult<()> {
+        info!("Service set_bandwidth_budget");
+        // Minimum bandwidth cap: 16KB/s
+        const MIN_BANDWIDTH_CAP: i64 = 16 * 1024;
+
+        let permission = PermissionChecker::check_down_permission();
+
+        let scope: u32 = data.read()?;
+        let uid: u64 = data.read()?;
+        let metered: i64 = data.read()?;
+        let unmetered: i64 = data.read()?;
+
+        let Some(scope) = BudgetScope::from_u32(scope) else {
+            error!(
+                "Service set_bandwidth_budget, failed: scope not valid: {}",
+                scope
+            );
+            sys_event!(
+                ExecError,
+                DfxCode::INVALID_IPC_MESSAGE_A42,
+                &format!(
+                    "Service set_bandwidth_budget, failed: scope not valid: {}",
+                    scope
+                )
+            );
+            reply.write(&(ErrorCode::ParameterCheck as i32))?;
+            return Ok(());
+        };
+
+        let valid = |cap: i64| cap == 0 || cap >= MIN_BANDWIDTH_CAP;
+        if !valid(metered) || !valid(unmetered) {
+            error!(
+                "Service set_bandwidth_budget, failed: cap not valid: {} {}",
+                metered, unmetered
+            );
+            sys_event!(
+                ExecError,
+                DfxCode::INVALID_IPC_MESSAGE_A42,
+                &format!(
+                    "Service set_bandwidth_budget, failed: cap not valid: {} {}",
+                    metered, unmetered
+                )
+            );
+            reply.write(&(ErrorCode::ParameterCheck as i32))?;
+            return Ok(());
+        }
+
+        let calling_uid = ipc::Skeleton::calling_uid();
+        let uid = match scope {
+            BudgetScope::Device if !permission => {
+                error!(
+                    "Service set_bandwidth_budget, failed: no permission, uid: {}",
+                    calling_uid
+                );
+                reply.write(&(ErrorCode::Permission as i32))?;
+                return Ok(());
+            }
+            BudgetScope::Device => 0,
+            BudgetScope::Application if permission => uid,
+            // Applications may only limit themselves
+            BudgetScope::Application => calling_uid,
+        };
+
+        bandwidth::set_budget(
+            scope,
+            uid,
+            BandwidthCap {
+                metered: metered as u64,
+                unmetered: unmetered as u64,
+            },
+        );
+        reply.write(&(ErrorCode::ErrOk as i32))?;
+        Ok(())
+    }
+}
@@ -63,6 +63,14 @@ pub const DELETE_GROUP: u32 = 20;
 pub const SET_MAX_SPEED: u32 = 21;
 /// Shows the progress of a task.
 pub const SHOW_PROGRESS: u32 = 22;
+/// Sets the device or application bandwidth budget.
+pub const SET_BANDWIDTH_BUDGET: u32 = 23;
+/// Seeds the cookie jar of an application.
+pub const SET_COOKIES: u32 = 24;
+/// Clears the cookie jar of an application.
+pub const CLEAR_COOKIES: u32 = 25;
+/// Hands over a refreshed bearer token of a task.
+pub const SET_AUTH_TOKEN: u32 = 26;
 /// Changes the mode of a task.
 pub const SET_MODE: u32 = 100;
 /// Disables notifications for a specific task.
@@ -98,6 +106,10 @@ mod test {
         assert_eq!(18, CREATE_GROUP);
         assert_eq!(19, ATTACH_GROUP);
         assert_eq!(20, DELETE_GROUP);
+        assert_eq!(23, SET_BANDWIDTH_BUDGET);
+        assert_eq!(24, SET_COOKIES);
+        assert_eq!(25, CLEAR_COOKIES);
+        assert_eq!(26, SET_AUTH_TOKEN);
         assert_eq!(100, SET_MODE);
         assert_eq!(101, DISABLE_TASK_NOTIFICATION);
     }
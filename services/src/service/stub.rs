@@ -183,6 +183,10 @@ impl RemoteStub for RequestServiceStub {
             interface::ATTACH_GROUP => self.attach_group(data, reply),
             interface::DELETE_GROUP => self.delete_group(data, reply),
             interface::SET_MAX_SPEED => self.set_max_speed(data, reply),
+            interface::SET_BANDWIDTH_BUDGET => self.set_bandwidth_budget(data, reply),
+            interface::SET_COOKIES => self.set_cookies(data, reply),
+            interface::CLEAR_COOKIES => self.clear_cookies(data, reply),
+            interface::SET_AUTH_TOKEN => self.set_auth_token(data, reply),
             interface::SET_MODE => self.set_mode(data, reply),
             interface::DISABLE_TASK_NOTIFICATION => self.disable_task_notifications(data, reply),
             _ => Err(IpcStatusCode::Failed),
@@ -0,0 +1,573 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Authentication of the requests of a task.
+//!
+//! A task configured with an `Auth` sends an `Authorization` header with its
+//! requests:
+//!
+//! * Basic credentials are sent with every request.
+//! * Digest credentials answer the last challenge of the server, following
+//!   RFC 7616 with the `auth` quality of protection. The first request is
+//!   sent without them, and is retried once the server challenges it.
+//! * A bearer token is sent with every request. When the server rejects a
+//!   token configured with `refresh`, the application owning the task is
+//!   asked for a new one over its channel, and hands it over with
+//!   `SET_AUTH_TOKEN` within `TOKEN_REFRESH_TIMEOUT`. The new token is used
+//!   for the rest of the task and stored with its configuration. Requests of
+//!   the task rejected while a refresh is pending wait for the same token
+//!   instead of asking again.
+//!
+//! A request rejected with `401` is retried once with the renewed
+//! credentials before the task fails with a `ProtocolError`. Nothing is
+//! added when the task headers already set an `Authorization` header.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use request_utils::fastrand::fast_random;
+use request_utils::hash::{md5, sha256};
+use ylong_http_client::async_impl::{RequestBuilder, Response};
+use ylong_runtime::sync::oneshot::{self, Sender};
+
+use super::config::Auth;
+use super::request_task::RequestTask;
+use crate::database::config_ext;
+
+/// Encoding of `Auth::None`.
+const NONE: u32 = 0;
+
+/// Encoding of `Auth::Basic`.
+const BASIC: u32 = 1;
+
+/// Encoding of `Auth::Digest`.
+const DIGEST: u32 = 2;
+
+/// Encoding of `Auth::Bearer`.
+const BEARER: u32 = 3;
+
+/// Time the application has to hand over a new bearer token.
+const TOKEN_REFRESH_TIMEOUT: Duration = Duration::from_secs(30);
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Requests waiting for the application to refresh the token, by task id.
+static PENDING_REFRESH: LazyLock<Mutex<HashMap<u32, Vec<Sender<String>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+impl Auth {
+    /// Decodes an authentication from its scheme, user name, secret and
+    /// refresh flag. The secret is the password of Basic and Digest, and the
+    /// token of Bearer.
+    ///
+    /// Returns `None` for an unknown scheme, or a Basic user name containing
+    /// a colon.
+    pub(crate) fn from_parts(
+        scheme: u32,
+        username: String,
+        secret: String,
+        refresh: bool,
+    ) -> Option<Self> {
+        match scheme {
+            NONE => Some(Auth::None),
+            BASIC if !username.contains(':') => Some(Auth::Basic {
+                username,
+                password: secret,
+            }),
+            DIGEST => Some(Auth::Digest {
+                username,
+                password: secret,
+            }),
+            BEARER => Some(Auth::Bearer {
+                token: secret,
+                refresh,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Encodes the authentication as its scheme, user name, secret and
+    /// refresh flag.
+    pub(crate) fn to_parts(&self) -> (u32, &str, &str, bool) {
+        match self {
+            Auth::None => (NONE, "", "", false),
+            Auth::Basic { username, password } => (BASIC, username, password, false),
+            Auth::Digest { username, password } => (DIGEST, username, password, false),
+            Auth::Bearer { token, refresh } => (BEARER, "", token, *refresh),
+        }
+    }
+}
+
+// Keeps the secrets out of the logs.
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Auth::None => write!(f, "None"),
+            Auth::Basic { username, .. } => write!(f, "Basic({})", username),
+            Auth::Digest { username, .. } => write!(f, "Digest({})", username),
+            Auth::Bearer { refresh, .. } => write!(f, "Bearer(refresh: {})", refresh),
+        }
+    }
+}
+
+/// Hash algorithm of a Digest challenge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DigestAlgorithm {
+    Md5,
+    Md5Sess,
+    Sha256,
+    Sha256Sess,
+}
+
+impl DigestAlgorithm {
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            DigestAlgorithm::Md5,
+            DigestAlgorithm::Md5Sess,
+            DigestAlgorithm::Sha256,
+            DigestAlgorithm::Sha256Sess,
+        ]
+        .into_iter()
+        .find(|algorithm| algorithm.name().eq_ignore_ascii_case(name))
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            DigestAlgorithm::Md5 => "MD5",
+            DigestAlgorithm::Md5Sess => "MD5-sess",
+            DigestAlgorithm::Sha256 => "SHA-256",
+            DigestAlgorithm::Sha256Sess => "SHA-256-sess",
+        }
+    }
+
+    fn hash(&self, input: &str) -> String {
+        match self {
+            DigestAlgorithm::Md5 | DigestAlgorithm::Md5Sess => md5(input),
+            DigestAlgorithm::Sha256 | DigestAlgorithm::Sha256Sess => sha256(input),
+        }
+    }
+
+    fn is_session(&self) -> bool {
+        matches!(self, DigestAlgorithm::Md5Sess | DigestAlgorithm::Sha256Sess)
+    }
+}
+
+/// A Digest challenge of a server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DigestChallenge {
+    pub(crate) realm: String,
+    pub(crate) nonce: String,
+    pub(crate) opaque: Option<String>,
+    pub(crate) algorithm: DigestAlgorithm,
+    /// Whether the server asks for the `auth` quality of protection, or
+    /// expects the RFC 2069 response otherwise.
+    pub(crate) qop: bool,
+}
+
+impl DigestChallenge {
+    /// Builds a challenge from its parameters.
+    ///
+    /// Returns `None` without a realm or a nonce, with an unsupported
+    /// algorithm, or when `auth-int` is the only quality of protection
+    /// offered.
+    fn from_params(params: Vec<(String, String)>) -> Option<Self> {
+        let mut realm = None;
+        let mut nonce = None;
+        let mut opaque = None;
+        let mut algorithm = DigestAlgorithm::Md5;
+        let mut qop = false;
+        for (name, value) in params {
+            match name.to_ascii_lowercase().as_str() {
+                "realm" => realm = Some(value),
+                "nonce" => nonce = Some(value),
+                "opaque" => opaque = Some(value),
+                "algorithm" => algorithm = DigestAlgorithm::from_name(&value)?,
+                "qop" => {
+                    if !value.split(',').any(|qop| qop.trim() == "auth") {
+                        return None;
+                    }
+                    qop = true;
+                }
+                _ => {}
+            }
+        }
+        Some(DigestChallenge {
+            realm: realm?,
+            nonce: nonce?,
+            opaque,
+            algorithm,
+            qop,
+        })
+    }
+}
+
+/// Credentials renewed while a task runs.
+#[derive(Default)]
+pub(crate) struct AuthState {
+    /// Bearer token sent with the requests.
+    token: String,
+    /// Last Digest challenge of the server.
+    challenge: Option<DigestChallenge>,
+    /// Number of requests sent with the nonce of the challenge.
+    nonce_count: u32,
+    /// Whether the credentials were renewed after the last response that did
+    /// not reject them.
+    renewed: bool,
+}
+
+impl AuthState {
+    /// Creates the state of a task configured with `auth`.
+    pub(crate) fn new(auth: &Auth) -> Self {
+        let token = match auth {
+            Auth::Bearer { token, .. } => token.clone(),
+            _ => String::new(),
+        };
+        AuthState {
+            token,
+            ..Default::default()
+        }
+    }
+}
+
+impl RequestTask {
+    /// Attaches the credentials of the task to a `method` request to `url`.
+    ///
+    /// Nothing is added unless the task is configured with an `Auth`, or if
+    /// an `Authorization` header is set in the task headers.
+    pub(crate) fn attach_auth(
+        &self,
+        method: &str,
+        url: &str,
+        request_builder: RequestBuilder,
+    ) -> RequestBuilder {
+        if self
+            .conf
+            .headers
+            .keys()
+            .any(|key| key.eq_ignore_ascii_case("authorization"))
+        {
+            return request_builder;
+        }
+        let credentials = match &self.conf.auth {
+            Auth::None => return request_builder,
+            Auth::Basic { username, password } => basic_credentials(username, password),
+            Auth::Digest { username, password } => {
+                let mut state = self.auth.lock().unwrap();
+                let Some(challenge) = state.challenge.clone() else {
+                    return request_builder;
+                };
+                state.nonce_count += 1;
+                let cnonce = format!("{:016x}", fast_random());
+                digest_credentials(
+                    &challenge,
+                    username,
+                    password,
+                    method,
+                    &request_target(url),
+                    state.nonce_count,
+                    &cnonce,
+                )
+            }
+            Auth::Bearer { .. } => {
+                let state = self.auth.lock().unwrap();
+                if state.token.is_empty() {
+                    return request_builder;
+                }
+                format!("Bearer {}", state.token)
+            }
+        };
+        request_builder.header("Authorization", credentials.as_str())
+    }
+
+    /// Renews the credentials of the task if `response` rejects them.
+    ///
+    /// Returns `true` if the request should be sent again. Credentials are
+    /// only renewed once until a response accepts them, so that a request
+    /// rejected again fails.
+    pub(crate) async fn renew_auth(&self, response: &Response) -> bool {
+        if self.conf.auth == Auth::None {
+            return false;
+        }
+        {
+            let mut state = self.auth.lock().unwrap();
+            let renewed = std::mem::take(&mut state.renewed);
+            if response.status().as_u16() != 401 || renewed {
+                return false;
+            }
+        }
+        let renewed = match &self.conf.auth {
+            Auth::Digest { .. } => {
+                let challenge = response
+                    .headers()
+                    .get("www-authenticate")
+                    .and_then(|value| value.to_string().ok())
+                    .and_then(|value| parse_digest_challenge(&value));
+                match challenge {
+                    Some(challenge) => {
+                        let mut state = self.auth.lock().unwrap();
+                        state.challenge = Some(challenge);
+                        state.nonce_count = 0;
+                        true
+                    }
+                    None => {
+                        error!("task {} no supported Digest challenge", self.task_id());
+                        false
+                    }
+                }
+            }
+            Auth::Bearer { refresh: true, .. } => match self.refresh_token().await {
+                Some(token) => {
+                    config_ext::store_auth_secret(self.task_id(), &token);
+                    self.auth.lock().unwrap().token = token;
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        };
+        if renewed {
+            info!("task {} credentials renewed", self.task_id());
+            self.auth.lock().unwrap().renewed = true;
+        }
+        renewed
+    }
+
+    /// Asks the application owning the task for a new bearer token.
+    ///
+    /// Only the first request asking sends the refresh to the application,
+    /// the others wait for its answer.
+    ///
+    /// Returns `None` if the application cannot be reached, declines, or
+    /// does not answer within `TOKEN_REFRESH_TIMEOUT`.
+    async fn refresh_token(&self) -> Option<String> {
+        let task_id = self.task_id();
+        let (tx, rx) = oneshot::channel();
+        let first = {
+            let mut pending = PENDING_REFRESH.lock().unwrap();
+            let waiters = pending.entry(task_id).or_default();
+            waiters.push(tx);
+            waiters.len() == 1
+        };
+        if first && !self.client_manager.send_token_refresh(task_id).await {
+            info!("task {} token refresh, app not subscribed", task_id);
+            // Drops the senders, so that the other waiters give up as well.
+            PENDING_REFRESH.lock().unwrap().remove(&task_id);
+            return None;
+        }
+        let token = ylong_runtime::time::timeout(TOKEN_REFRESH_TIMEOUT, rx)
+            .await
+            .ok()
+            .and_then(|token| token.ok());
+        if first {
+            PENDING_REFRESH.lock().unwrap().remove(&task_id);
+        }
+        token.filter(|token| !token.is_empty())
+    }
+}
+
+/// Hands over the token the application was asked for by task `task_id`, an
+/// empty token declining the refresh.
+///
+/// Returns `false` if the task is not waiting for a token.
+pub(crate) fn complete_token_refresh(task_id: u32, token: String) -> bool {
+    let Some(waiters) = PENDING_REFRESH.lock().unwrap().remove(&task_id) else {
+        return false;
+    };
+    waiters
+        .into_iter()
+        .fold(false, |sent, tx| tx.send(token.clone()).is_ok() || sent)
+}
+
+/// Returns the credentials of the Basic scheme.
+fn basic_credentials(username: &str, password: &str) -> String {
+    format!(
+        "Basic {}",
+        base64(format!("{}:{}", username, password).as_bytes())
+    )
+}
+
+/// Returns the credentials answering a Digest `challenge` for a `method`
+/// request to `uri`, the `nonce_count`-th one with its nonce.
+fn digest_credentials(
+    challenge: &DigestChallenge,
+    username: &str,
+    password: &str,
+    method: &str,
+    uri: &str,
+    nonce_count: u32,
+    cnonce: &str,
+) -> String {
+    let algorithm = challenge.algorithm;
+    let mut ha1 = algorithm.hash(&format!("{}:{}:{}", username, challenge.realm, password));
+    if algorithm.is_session() {
+        ha1 = algorithm.hash(&format!("{}:{}:{}", ha1, challenge.nonce, cnonce));
+    }
+    let ha2 = algorithm.hash(&format!("{}:{}", method, uri));
+    let nc = format!("{:08x}", nonce_count);
+    let response = if challenge.qop {
+        algorithm.hash(&format!(
+            "{}:{}:{}:{}:auth:{}",
+            ha1, challenge.nonce, nc, cnonce, ha2
+        ))
+    } else {
+        algorithm.hash(&format!("{}:{}:{}", ha1, challenge.nonce, ha2))
+    };
+
+    let mut credentials = format!(
+        "Digest username={}, realm={}, nonce={}, uri={}, algorithm={}, response={}",
+        quote(username),
+        quote(&challenge.realm),
+        quote(&challenge.nonce),
+        quote(uri),
+        algorithm.name(),
+        quote(&response)
+    );
+    if challenge.qop {
+        credentials.push_str(&format!(", qop=auth, nc={}, cnonce={}", nc, quote(cnonce)));
+    }
+    if let Some(opaque) = &challenge.opaque {
+        credentials.push_str(&format!(", opaque={}", quote(opaque)));
+    }
+    credentials
+}
+
+/// Returns the first supported Digest challenge of a `WWW-Authenticate`
+/// header, which may hold challenges of several schemes.
+pub(crate) fn parse_digest_challenge(value: &str) -> Option<DigestChallenge> {
+    let mut challenges = vec![];
+    // Parameters of the Digest challenge being parsed.
+    let mut current: Option<Vec<(String, String)>> = None;
+    for item in split_auth_items(value) {
+        let (scheme, param) = match item.split_once(char::is_whitespace) {
+            Some((scheme, rest))
+                if !scheme.contains('=') && !rest.trim_start().starts_with('=') =>
+            {
+                (Some(scheme), rest.trim())
+            }
+            None if !item.contains('=') => (Some(item), ""),
+            _ => (None, item),
+        };
+        if let Some(scheme) = scheme {
+            challenges.extend(current.take());
+            if scheme.eq_ignore_ascii_case("digest") {
+                current = Some(vec![]);
+            }
+        }
+        if let (Some(params), Some(param)) = (current.as_mut(), parse_auth_param(param)) {
+            params.push(param);
+        }
+    }
+    challenges.extend(current);
+    challenges
+        .into_iter()
+        .find_map(DigestChallenge::from_params)
+}
+
+/// Splits a `WWW-Authenticate` header at the commas outside of quoted
+/// strings.
+fn split_auth_items(value: &str) -> Vec<&str> {
+    let mut items = vec![];
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                items.push(value[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(value[start..].trim());
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+/// Parses a `name=value` parameter, unquoting the value.
+fn parse_auth_param(param: &str) -> Option<(String, String)> {
+    let (name, value) = param.split_once('=')?;
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+    let value = value.trim();
+    let Some(quoted) = value.strip_prefix('"') else {
+        return Some((name.to_string(), value.to_string()));
+    };
+    let mut unquoted = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unquoted.extend(chars.next()),
+            '"' => break,
+            c => unquoted.push(c),
+        }
+    }
+    Some((name.to_string(), unquoted))
+}
+
+/// Returns `value` as a quoted string.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Returns the path and query of `url`, the `uri` of Digest credentials.
+fn request_target(url: &str) -> String {
+    let url = url.split('#').next().unwrap_or_default();
+    let rest = match url.split_once("://") {
+        Some((_, rest)) => rest,
+        None => url,
+    };
+    match rest.find(['/', '?']) {
+        Some(i) if rest[i..].starts_with('?') => format!("/{}", &rest[i..]),
+        Some(i) => rest[i..].to_string(),
+        None => "/".to_string(),
+    }
+}
+
+/// Encodes `input` in base64 with padding.
+fn base64(input: &[u8]) -> String {
+    let mut output = String::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (bits >> (18 - 6 * i)) & 0x3f;
+                output.push(BASE64_ALPHABET[index as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod ut_auth {
+    include!("../../tests/ut/task/ut_auth.rs");
+}
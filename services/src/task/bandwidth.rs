@@ -0,0 +1,279 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Device-wide and per-application bandwidth budgets.
+//!
+//! Every transferred byte is charged to a device-wide token bucket and to the
+//! token bucket of the application that owns the task. A transfer that
+//! overdraws either bucket waits until the bucket has refilled. Per-task caps
+//! are enforced separately by `SpeedLimiter`.
+//!
+//! Each budget has separate caps for metered and unmetered networks. The caps
+//! are persisted in the `bandwidth_budget` table so that they survive service
+//! restarts.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use crate::database::REQUEST_DB;
+use crate::manage::network_manager::NetworkManager;
+
+const CREATE_BANDWIDTH_BUDGET_TABLE: &str = "CREATE TABLE IF NOT EXISTS bandwidth_budget (scope INTEGER, uid INTEGER, metered INTEGER, unmetered INTEGER, PRIMARY KEY (scope, uid))";
+
+/// Duration of transfer at the capped rate a bucket may hold, in
+/// milliseconds.
+const BURST_INTERVAL: u64 = 1000;
+
+/// Scope of a bandwidth budget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BudgetScope {
+    /// The budget shared by all tasks of the device.
+    Device = 0,
+    /// The budget shared by the tasks of one application.
+    Application = 1,
+}
+
+impl BudgetScope {
+    /// Converts an IPC scope value.
+    pub(crate) fn from_u32(scope: u32) -> Option<Self> {
+        match scope {
+            0 => Some(BudgetScope::Device),
+            1 => Some(BudgetScope::Application),
+            _ => None,
+        }
+    }
+}
+
+/// Caps of a bandwidth budget in bytes per second, 0 for unlimited.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct BandwidthCap {
+    /// Cap on metered networks.
+    pub(crate) metered: u64,
+    /// Cap on unmetered networks.
+    pub(crate) unmetered: u64,
+}
+
+impl BandwidthCap {
+    /// Returns the cap applying to the current network.
+    pub(crate) fn rate(&self, metered: bool) -> u64 {
+        if metered {
+            self.metered
+        } else {
+            self.unmetered
+        }
+    }
+
+    /// Returns whether neither cap is set.
+    pub(crate) fn is_unlimited(&self) -> bool {
+        self.metered == 0 && self.unmetered == 0
+    }
+}
+
+/// A token bucket refilled at a fixed rate.
+///
+/// Tokens are counted in thousandths of a byte so that frequent refills do not
+/// lose precision. The balance goes negative when a transfer overdraws the
+/// bucket.
+#[derive(Default)]
+pub(crate) struct TokenBucket {
+    /// Refill rate in bytes per second, 0 for unlimited.
+    rate: u64,
+    /// Available tokens in thousandths of a byte.
+    tokens: i64,
+    /// Timestamp of the last refill in milliseconds, 0 before the first one.
+    last_time: u64,
+}
+
+impl TokenBucket {
+    /// Updates the refill rate, restarting the bucket if it changed.
+    pub(crate) fn set_rate(&mut self, rate: u64) {
+        if self.rate != rate {
+            self.rate = rate;
+            self.tokens = 0;
+            self.last_time = 0;
+        }
+    }
+
+    /// Charges `size` bytes transferred at `current_time` to the bucket.
+    ///
+    /// Returns the time in milliseconds to wait until the bucket is no longer
+    /// overdrawn, 0 if the transfer may go on.
+    pub(crate) fn consume(&mut self, size: u64, current_time: u64) -> u64 {
+        if self.rate == 0 {
+            return 0;
+        }
+        let capacity = self.rate.saturating_mul(BURST_INTERVAL) as i64;
+        if self.last_time == 0 {
+            // A new bucket starts full
+            self.tokens = capacity;
+        } else if current_time > self.last_time {
+            let refill = (current_time - self.last_time).saturating_mul(self.rate) as i64;
+            self.tokens = self.tokens.saturating_add(refill).min(capacity);
+        }
+        self.last_time = self.last_time.max(current_time);
+
+        self.tokens = self.tokens.saturating_sub(size.saturating_mul(1000) as i64);
+        if self.tokens >= 0 {
+            0
+        } else {
+            (self.tokens.unsigned_abs() + self.rate - 1) / self.rate
+        }
+    }
+}
+
+/// Bandwidth budgets of the device and of the applications.
+#[derive(Default)]
+pub(crate) struct BandwidthBudget {
+    device_cap: BandwidthCap,
+    device: TokenBucket,
+    uid_caps: HashMap<u64, BandwidthCap>,
+    uids: HashMap<u64, TokenBucket>,
+}
+
+static BANDWIDTH_BUDGET: LazyLock<Mutex<BandwidthBudget>> =
+    LazyLock::new(|| Mutex::new(BandwidthBudget::load()));
+
+impl BandwidthBudget {
+    /// Returns the budgets of the service, loading the persisted caps on first
+    /// use.
+    pub(crate) fn get_instance() -> &'static Mutex<BandwidthBudget> {
+        &BANDWIDTH_BUDGET
+    }
+
+    /// Returns whether no cap is set on any budget.
+    pub(crate) fn is_unlimited(&self) -> bool {
+        self.device_cap.is_unlimited() && self.uid_caps.is_empty()
+    }
+
+    /// Returns the caps of a budget.
+    pub(crate) fn cap(&self, scope: BudgetScope, uid: u64) -> BandwidthCap {
+        match scope {
+            BudgetScope::Device => self.device_cap,
+            BudgetScope::Application => self.uid_caps.get(&uid).copied().unwrap_or_default(),
+        }
+    }
+
+    /// Sets the caps of a budget. For the device budget `uid` is ignored.
+    pub(crate) fn set_cap(&mut self, scope: BudgetScope, uid: u64, cap: BandwidthCap) {
+        match scope {
+            BudgetScope::Device => self.device_cap = cap,
+            BudgetScope::Application if cap.is_unlimited() => {
+                self.uid_caps.remove(&uid);
+                self.uids.remove(&uid);
+            }
+            BudgetScope::Application => {
+                self.uid_caps.insert(uid, cap);
+            }
+        }
+    }
+
+    /// Charges `size` bytes transferred by a task of `uid` to the device and
+    /// application budgets.
+    ///
+    /// Returns the time in milliseconds to wait until neither budget is
+    /// overdrawn.
+    pub(crate) fn consume(&mut self, uid: u64, size: u64, metered: bool, current_time: u64) -> u64 {
+        self.device.set_rate(self.device_cap.rate(metered));
+        let mut wait = self.device.consume(size, current_time);
+        if let Some(cap) = self.uid_caps.get(&uid) {
+            let bucket = self.uids.entry(uid).or_default();
+            bucket.set_rate(cap.rate(metered));
+            wait = wait.max(bucket.consume(size, current_time));
+        }
+        wait
+    }
+
+    /// Loads the persisted caps.
+    fn load() -> Self {
+        let mut budget = BandwidthBudget::default();
+        init();
+        match REQUEST_DB.query::<(u32, u64, u64, u64)>(
+            "SELECT scope, uid, metered, unmetered FROM bandwidth_budget",
+            (),
+        ) {
+            Ok(rows) => {
+                for (scope, uid, metered, unmetered) in rows {
+                    if let Some(scope) = BudgetScope::from_u32(scope) {
+                        budget.set_cap(scope, uid, BandwidthCap { metered, unmetered });
+                    }
+                }
+            }
+            Err(e) => error!("Failed to query bandwidth budgets: {}", e),
+        }
+        budget
+    }
+}
+
+/// Charges `size` bytes transferred by a task of `uid` to the bandwidth
+/// budgets, and returns the time in milliseconds to wait before the transfer
+/// goes on.
+pub(crate) fn consume_budget(uid: u64, size: u64, current_time: u64) -> u64 {
+    let mut budget = BandwidthBudget::get_instance().lock().unwrap();
+    if budget.is_unlimited() {
+        return 0;
+    }
+    budget.consume(uid, size, NetworkManager::is_metered(), current_time)
+}
+
+/// Sets and persists the caps of a bandwidth budget.
+pub(crate) fn set_budget(scope: BudgetScope, uid: u64, cap: BandwidthCap) {
+    info!(
+        "set bandwidth budget {:?} uid {}, metered {}, unmetered {}",
+        scope, uid, cap.metered, cap.unmetered
+    );
+    let uid = match scope {
+        BudgetScope::Device => 0,
+        BudgetScope::Application => uid,
+    };
+    BandwidthBudget::get_instance()
+        .lock()
+        .unwrap()
+        .set_cap(scope, uid, cap);
+
+    let res = if cap.is_unlimited() {
+        REQUEST_DB.execute(
+            "DELETE FROM bandwidth_budget WHERE scope = ? AND uid = ?",
+            (scope as u32, uid),
+        )
+    } else {
+        REQUEST_DB.execute(
+            "INSERT OR REPLACE INTO bandwidth_budget (scope, uid, metered, unmetered) VALUES (?, ?, ?, ?)",
+            (scope as u32, uid, cap.metered, cap.unmetered),
+        )
+    };
+    if let Err(e) = res {
+        error!("Failed to store bandwidth budget: {}", e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to store bandwidth budget: {}", e)
+        );
+    }
+}
+
+/// Creates the `bandwidth_budget` table.
+fn init() {
+    if let Err(e) = REQUEST_DB.execute(CREATE_BANDWIDTH_BUDGET_TABLE, ()) {
+        error!("Failed to create bandwidth_budget table: {}", e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to create bandwidth_budget table: {}", e)
+        );
+    }
+}
+
+#[cfg(test)]
+mod ut_bandwidth {
+    include!("../../tests/ut/task/ut_bandwidth.rs");
+}
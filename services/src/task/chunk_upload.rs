@@ -0,0 +1,390 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resumable chunked uploads.
+//!
+//! An upload task configured with a chunk size sends each raw file body as a
+//! series of ranged requests carrying `Content-Range: bytes a-b/total`. The
+//! server acknowledges an intermediate chunk with `308 Resume Incomplete` or a
+//! `2xx` status, optionally reporting the bytes it holds in a `Range` header,
+//! and answers the last chunk with the final response. A `308` without a
+//! `Range` header means the server holds none of the file. An empty file is
+//! sent as a single empty chunk carrying `Content-Range: bytes */0`.
+//!
+//! The committed offset of every file is kept in the `upload_committed`
+//! column of `request_task`, as comma separated decimals. When an interrupted upload is resumed, the
+//! server is asked for its committed offset with an empty request carrying
+//! `Content-Range: bytes */total`, and the upload continues from there.
+
+use std::fs::File;
+use std::future::Future;
+use std::io;
+use std::os::unix::fs::FileExt;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use ylong_http_client::async_impl::{Body, Request, Response, Uploader};
+use ylong_http_client::ReusableReader;
+use ylong_runtime::io::{AsyncRead, ReadBuf};
+
+use super::operator::TaskOperator;
+use super::reason::Reason;
+use super::request_task::{RequestTask, TaskError};
+use super::task_control;
+use super::upload::{check_upload_status, handle_upload_error};
+use crate::database::REQUEST_DB;
+
+/// Upper bound of the chunk size, which is buffered in memory.
+pub(crate) const MAX_CHUNK_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Status code of an acknowledged intermediate chunk.
+const RESUME_INCOMPLETE: u16 = 308;
+
+/// Uploads file `index` of a task in chunks, continuing from the offset the
+/// server has committed.
+pub(crate) async fn upload_file(
+    task: Arc<RequestTask>,
+    index: usize,
+    abort_flag: Arc<AtomicBool>,
+) -> Result<(), TaskError> {
+    let Some(file) = task.files.get(index) else {
+        error!("task {} file {} not found", task.task_id(), index);
+        return Err(TaskError::Failed(Reason::OthersError));
+    };
+    let total = task.progress.lock().unwrap().sizes[index] as u64;
+    let chunk_size = task.conf.chunk_size.min(MAX_CHUNK_SIZE);
+
+    // The indexed file may be uploaded from the configured beginning
+    let file_begins = if task.conf.common_data.index == index as u32 {
+        let metadata = task_control::file_metadata(file.clone()).await?;
+        if metadata.len() > total {
+            task.conf.common_data.begins
+        } else {
+            0
+        }
+    } else {
+        0
+    };
+
+    let mut offsets = query_committed(task.task_id());
+    offsets.resize(task.conf.file_specs.len(), 0);
+    let mut committed = offsets[index].min(total);
+    if committed > 0 {
+        committed = query_server_committed(&task, total).await?;
+        info!(
+            "task {} file {} resumes upload at {}",
+            task.task_id(),
+            index,
+            committed
+        );
+    }
+
+    task.upload_resume.store(false, Ordering::SeqCst);
+    set_progress(&task, index, committed);
+
+    let mut last_response = None;
+    // An empty file still takes one request to get the final response
+    let mut send_empty = total == 0;
+    while committed < total || send_empty {
+        send_empty = false;
+        let len = chunk_size.min(total - committed);
+        let data = read_chunk(file.clone(), file_begins + committed, len as usize).await?;
+
+        let request = build_chunk_request(&task, index, &abort_flag, data, committed, total)?;
+        let response = {
+            let client = task.client.lock().await;
+            client.request(request).await
+        };
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => return Err(handle_upload_error(&task, &e).await),
+        };
+        let status_code = response.status().as_u16();
+        info!(
+            "task {} file {} chunk {}+{} response {}",
+            task.task_id(),
+            index,
+            committed,
+            len,
+            status_code
+        );
+        // Send the chunk again once with renewed credentials when they are
+        // rejected
+        if task.renew_auth(&response).await {
+            send_empty = total == 0;
+            continue;
+        }
+        check_upload_status(&task, &response, true)?;
+
+        committed = if status_code == RESUME_INCOMPLETE {
+            response_committed(&response).unwrap_or(0).min(total)
+        } else {
+            committed + len
+        };
+        offsets[index] = committed;
+        store_committed(task.task_id(), &offsets);
+        set_progress(&task, index, committed);
+        last_response = Some(response);
+    }
+
+    if let Some(response) = last_response {
+        #[cfg(feature = "oh")]
+        task.notify_response(&response);
+        task.record_upload_response(index, Ok(response)).await;
+    }
+    Ok(())
+}
+
+/// Builds the request sending one chunk of a file.
+fn build_chunk_request(
+    task: &Arc<RequestTask>,
+    index: usize,
+    abort_flag: &Arc<AtomicBool>,
+    data: Vec<u8>,
+    begins: u64,
+    total: u64,
+) -> Result<Request, TaskError> {
+    let len = data.len() as u64;
+    let mut request_builder = task.build_request_builder()?;
+    if !task.conf.headers.contains_key("Content-Type") {
+        request_builder = request_builder.header("Content-Type", "application/octet-stream");
+    }
+    let range = if len == 0 {
+        format!("bytes */{}", total)
+    } else {
+        content_range(begins, begins + len - 1, total)
+    };
+    request_builder = request_builder
+        .header("Content-Length", len.to_string().as_str())
+        .header("Content-Range", range.as_str());
+
+    let uploader = Uploader::builder()
+        .reader(ChunkReader::new(task.clone(), index, data, begins as usize))
+        .operator(TaskOperator::new(task.clone(), abort_flag.clone()))
+        .total_bytes(Some(len))
+        .build();
+    Ok(request_builder.body(Body::stream(uploader))?)
+}
+
+/// Asks the server how many bytes of the file it has committed.
+///
+/// A `308` response reports the committed bytes in its `Range` header, or
+/// none when the header is absent. A `2xx` response means the file is already
+/// complete. Any other status restarts the file from the beginning. The query
+/// is sent again once with renewed credentials when they are rejected.
+async fn query_server_committed(task: &Arc<RequestTask>, total: u64) -> Result<u64, TaskError> {
+    let response = loop {
+        let request = task
+            .build_request_builder()?
+            .header("Content-Length", "0")
+            .header("Content-Range", format!("bytes */{}", total).as_str())
+            .body(Body::slice(Vec::new()))?;
+        let response = {
+            let client = task.client.lock().await;
+            client.request(request).await
+        };
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => return Err(handle_upload_error(task, &e).await),
+        };
+        if !task.renew_auth(&response).await {
+            break response;
+        }
+    };
+    let status = response.status();
+    let committed = if status.as_u16() == RESUME_INCOMPLETE {
+        response_committed(&response).unwrap_or(0).min(total)
+    } else if status.is_success() {
+        total
+    } else {
+        info!(
+            "task {} committed offset query answered {}, restart upload",
+            task.task_id(),
+            status.as_u16()
+        );
+        0
+    };
+    Ok(committed)
+}
+
+/// Returns the committed bytes reported in the `Range` header of a response.
+fn response_committed(response: &Response) -> Option<u64> {
+    let value = response.headers().get("range")?.to_string().ok()?;
+    parse_committed(&value)
+}
+
+/// Parses a `Range` header value such as `bytes=0-1023` into the number of
+/// committed bytes.
+pub(crate) fn parse_committed(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let range = value.strip_prefix("bytes=").unwrap_or(value);
+    let (begins, ends) = range.split_once('-')?;
+    if begins.trim().parse::<u64>().ok()? != 0 {
+        return None;
+    }
+    ends.trim().parse::<u64>().ok().map(|ends| ends + 1)
+}
+
+/// Formats the `Content-Range` value of a chunk.
+pub(crate) fn content_range(begins: u64, ends: u64, total: u64) -> String {
+    format!("bytes {}-{}/{}", begins, ends, total)
+}
+
+/// Sets the upload progress of file `index` to its committed bytes.
+fn set_progress(task: &RequestTask, index: usize, committed: u64) {
+    let mut progress = task.progress.lock().unwrap();
+    progress.processed[index] = committed as usize;
+    progress.common_data.index = index;
+    progress.common_data.total_processed = progress.processed.iter().take(index + 1).sum();
+}
+
+/// Reads `len` bytes of a file starting at `offset`.
+async fn read_chunk(file: Arc<Mutex<File>>, offset: u64, len: usize) -> io::Result<Vec<u8>> {
+    task_control::runtime_spawn_blocking(move || {
+        let mut buf = vec![0u8; len];
+        file.lock().unwrap().read_exact_at(&mut buf, offset)?;
+        Ok(buf)
+    })
+    .await
+    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+}
+
+/// A reader over one in-memory chunk that reports upload progress.
+struct ChunkReader {
+    task: Arc<RequestTask>,
+    index: usize,
+    data: Vec<u8>,
+    /// Bytes of the file committed before this chunk.
+    base: usize,
+    /// Bytes of the chunk read so far.
+    pos: usize,
+}
+
+impl ChunkReader {
+    fn new(task: Arc<RequestTask>, index: usize, data: Vec<u8>, base: usize) -> Self {
+        Self {
+            task,
+            index,
+            data,
+            base,
+            pos: 0,
+        }
+    }
+}
+
+impl AsyncRead for ChunkReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let pos = self.pos;
+        let unfilled = buf.initialize_unfilled();
+        let size = unfilled.len().min(self.data.len() - pos);
+        unfilled[..size].copy_from_slice(&self.data[pos..pos + size]);
+        let filled = buf.filled().len() + size;
+        buf.set_filled(filled);
+        self.pos += size;
+
+        let mut progress = self.task.progress.lock().unwrap();
+        progress.processed[self.index] = self.base + self.pos;
+        progress.common_data.total_processed += size;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl ReusableReader for ChunkReader {
+    fn reuse<'a>(&'a mut self) -> Pin<Box<dyn Future<Output = io::Result<()>> + Send + Sync + 'a>>
+    where
+        Self: 'a,
+    {
+        {
+            let mut progress = self.task.progress.lock().unwrap();
+            progress.processed[self.index] = self.base;
+            progress.common_data.total_processed -= self.pos;
+        }
+        self.pos = 0;
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Loads the committed offsets of the files of a task.
+fn query_committed(task_id: u32) -> Vec<u64> {
+    let mut rows = match REQUEST_DB.query::<Option<String>>(
+        "SELECT upload_committed FROM request_task WHERE task_id = ?",
+        task_id,
+    ) {
+        Ok(rows) => rows,
+        Err(e) => {
+            error!("Failed to query task {} upload offsets: {}", task_id, e);
+            return Vec::new();
+        }
+    };
+    rows.next()
+        .flatten()
+        .map(|offsets| decode_committed(&offsets))
+        .unwrap_or_default()
+}
+
+/// Encodes the committed offsets of the files of a task for the database.
+pub(crate) fn encode_committed(offsets: &[u64]) -> String {
+    offsets
+        .iter()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Decodes the committed offsets of the files of a task from the database,
+/// an unreadable offset being read as none.
+pub(crate) fn decode_committed(offsets: &str) -> Vec<u64> {
+    if offsets.is_empty() {
+        return Vec::new();
+    }
+    offsets
+        .split(',')
+        .map(|offset| offset.trim().parse::<u64>().unwrap_or(0))
+        .collect()
+}
+
+/// Records the committed offsets of the files of a task.
+fn store_committed(task_id: u32, offsets: &[u64]) {
+    if let Err(e) = REQUEST_DB.execute(
+        "UPDATE request_task SET upload_committed = ? WHERE task_id = ?",
+        (encode_committed(offsets), task_id),
+    ) {
+        error!("Failed to store task {} upload offsets: {}", task_id, e);
+        sys_event!(
+            ExecFault,
+            DfxCode::RDB_FAULT_04,
+            &format!("Failed to store task {} upload offsets: {}", task_id, e)
+        );
+    }
+}
+
+/// Removes the committed offsets of a completed upload.
+pub(crate) fn clear_committed(task_id: u32) {
+    if let Err(e) = REQUEST_DB.execute(
+        "UPDATE request_task SET upload_committed = NULL WHERE task_id = ?",
+        task_id,
+    ) {
+        error!("Failed to clear task {} upload offsets: {}", task_id, e);
+    }
+}
+
+#[cfg(test)]
+mod ut_chunk_upload {
+    include!("../../tests/ut/task/ut_chunk_upload.rs");
+}
@@ -22,14 +22,19 @@
 //! - Proxy settings support with task-specific and system-wide options
 //! - Domain policy enforcement for atomic services
 //! - Redirect handling with domain validation
+//! - Cookie storage for tasks using the cookie jar of their app
 //! - Public key pinning for enhanced security
+//! - Client certificates for servers requiring mutual TLS
 //! - Connection timeout and speed monitoring
 
 use std::error::Error;
+use std::fs;
+use std::sync::Mutex;
 
-use ylong_http_client::async_impl::{Client, Request};
+use ylong_http_client::async_impl::{Client, Request, Response};
 use ylong_http_client::{
-    Certificate, HttpClientError, Interceptor, Proxy, PubKeyPins, Redirect, Timeout, TlsVersion,
+    Certificate, HttpClientError, Identity, Interceptor, Proxy, PubKeyPins, Redirect, Timeout,
+    TlsVersion,
 };
 
 cfg_oh! {
@@ -38,9 +43,11 @@ cfg_oh! {
     use crate::utils::url_policy::check_url_domain;
 }
 
+use super::cookie::store_response_cookies;
 use super::files::BundleCache;
 use crate::task::config::{Action, TaskConfig};
 use crate::task::files::convert_path;
+use crate::task::reason::Reason;
 
 /// Builds an HTTP client with configuration based on the provided task
 /// settings.
@@ -163,6 +170,14 @@ pub(crate) fn build_client(
         client = client.add_public_key_pins(pinned_key);
     }
 
+    // Present the client certificate of the task to servers requiring mutual
+    // TLS
+    if let Some(identity) = build_task_identity(config)? {
+        client = client.tls_identity(identity);
+    }
+
+    let mut interceptors = TaskInterceptors::default();
+
     // Apply domain policy checks for atomic services (system-specific security
     // check)
     const ATOMIC_SERVICE: u32 = 1;
@@ -208,8 +223,7 @@ pub(crate) fn build_client(
         // the domain access policies, providing comprehensive security coverage
         #[cfg(feature = "oh")]
         {
-            let interceptors = DomainInterceptor::new(config.bundle.clone(), domain_type);
-            client = client.interceptor(interceptors);
+            interceptors.domain = Some(DomainInterceptor::new(config.bundle.clone(), domain_type));
         }
 
         info!(
@@ -218,6 +232,18 @@ pub(crate) fn build_client(
         );
     }
 
+    // Store the cookies set by every response, including the redirects, in the
+    // cookie jar of the app
+    if config.cookie_jar {
+        interceptors.cookie = Some(CookieInterceptor::new(
+            config.common_data.uid,
+            config.url.clone(),
+        ));
+    }
+    if interceptors.is_active() {
+        client = client.interceptor(interceptors);
+    }
+
     // Finalize client construction
     // All configuration steps are complete including timeouts, redirect policy,
     // proxy settings, certificates, public key pinning, and domain policy
@@ -428,6 +454,89 @@ fn build_task_certs(config: &TaskConfig) -> Result<Vec<Certificate>, Box<dyn Err
     Ok(certs)
 }
 
+/// Loads the client certificate chain and private key of the task.
+///
+/// # Arguments
+///
+/// * `config` - The task configuration containing the client certificate
+///   paths.
+///
+/// # Returns
+///
+/// Returns `Ok(Some(Identity))` with the loaded key material, `Ok(None)` if
+/// the task presents no client certificate, or an error if a file cannot be
+/// read, is not PEM encoded, or the private key does not match the
+/// certificate.
+pub(crate) fn build_task_identity(
+    config: &TaskConfig,
+) -> Result<Option<Identity>, Box<dyn Error + Send + Sync>> {
+    if config.client_cert_path.is_empty() {
+        return Ok(None);
+    }
+    let uid = config.common_data.uid;
+    let bundle_name = BundleCache::new(config).get_value()?;
+
+    // Both files live in the sandbox of the app, like the task certificates
+    let cert_path = convert_path(uid, &bundle_name, &config.client_cert_path);
+    let key_path = convert_path(uid, &bundle_name, &config.client_key_path);
+    let cert_chain = cvt_res_error!(
+        fs::read(cert_path).map_err(Box::new),
+        "Read task client cert failed - tid: {}",
+        config.common_data.task_id,
+    );
+    let key = cvt_res_error!(
+        fs::read(key_path).map_err(Box::new),
+        "Read task client key failed - tid: {}",
+        config.common_data.task_id,
+    );
+    Ok(Some(cvt_res_error!(
+        Identity::from_pem(&cert_chain, &key).map_err(Box::new),
+        "Parse task client cert failed - tid: {}",
+        config.common_data.task_id,
+    )))
+}
+
+/// Checks whether a request of a task failed as the server rejected its
+/// client certificate.
+///
+/// The rejection is looked for in the causes of the error, whatever its kind:
+/// with TLS 1.3, the server checks the client certificate after the
+/// handshake, so that its alert surfaces as a failure to read the response
+/// rather than as a TLS error of the connection.
+pub(crate) fn client_cert_rejected(config: &TaskConfig, error: &HttpClientError) -> bool {
+    if config.client_cert_path.is_empty() {
+        return false;
+    }
+    let mut source = error.source();
+    while let Some(cause) = source {
+        if is_client_cert_rejection(&cause.to_string()) {
+            return true;
+        }
+        source = cause.source();
+    }
+    false
+}
+
+/// Checks whether the reason of a TLS library error is one of the alerts a
+/// server sends when it does not accept the client certificate.
+///
+/// Failures to verify the server certificate are reported without an alert
+/// received from the peer, so they are not mistaken for a rejection.
+fn is_client_cert_rejection(reason: &str) -> bool {
+    const ALERTS: [&str; 8] = [
+        "alert bad certificate",
+        "alert certificate required",
+        "alert certificate unknown",
+        "alert certificate expired",
+        "alert certificate revoked",
+        "alert unsupported certificate",
+        "alert unknown ca",
+        "alert access denied",
+    ];
+    let reason = reason.to_ascii_lowercase();
+    ALERTS.iter().any(|alert| reason.contains(alert))
+}
+
 /// Converts an Action enum value to a domain type string used for policy
 /// checks.
 ///
@@ -534,3 +643,96 @@ impl Interceptor for DomainInterceptor {
         }
     }
 }
+
+/// Interceptor that stores the cookies set by responses in a cookie jar.
+///
+/// Responses do not carry their url, so the url of the last request sent is
+/// kept to check the cookies against. The requests of a task, including its
+/// parallel segments, all target the url of the task until redirected.
+struct CookieInterceptor {
+    /// The uid owning the cookie jar.
+    uid: u64,
+    /// The url of the last request sent.
+    url: Mutex<String>,
+}
+
+impl CookieInterceptor {
+    /// Creates a new CookieInterceptor storing into the cookie jar of `uid`,
+    /// starting with the requests to `url`.
+    fn new(uid: u64, url: String) -> Self {
+        CookieInterceptor {
+            uid,
+            url: Mutex::new(url),
+        }
+    }
+
+    /// Records the url of a request being sent.
+    fn record_request(&self, request: &Request) {
+        *self.url.lock().unwrap() = request.uri().to_string();
+    }
+
+    /// Stores the cookies set by a response to the last request sent.
+    fn store_response(&self, response: &Response) {
+        let url = self.url.lock().unwrap().clone();
+        store_response_cookies(self.uid, &url, response);
+    }
+}
+
+/// Interceptors installed on the client of a task.
+///
+/// The client holds a single interceptor, which dispatches to the enabled
+/// ones.
+#[derive(Default)]
+struct TaskInterceptors {
+    /// Domain policy check of atomic services.
+    domain: Option<DomainInterceptor>,
+    /// Cookie storage of tasks using the cookie jar.
+    cookie: Option<CookieInterceptor>,
+}
+
+impl TaskInterceptors {
+    /// Checks if any interceptor is enabled.
+    fn is_active(&self) -> bool {
+        self.domain.is_some() || self.cookie.is_some()
+    }
+}
+
+impl Interceptor for TaskInterceptors {
+    fn intercept_request(&self, request: &Request) -> Result<(), HttpClientError> {
+        if let Some(cookie) = &self.cookie {
+            cookie.record_request(request);
+        }
+        Ok(())
+    }
+
+    fn intercept_response(&self, response: &Response) -> Result<(), HttpClientError> {
+        if let Some(cookie) = &self.cookie {
+            cookie.store_response(response);
+        }
+        Ok(())
+    }
+
+    fn intercept_redirect_request(&self, request: &Request) -> Result<(), HttpClientError> {
+        #[cfg(feature = "oh")]
+        if let Some(domain) = &self.domain {
+            domain.intercept_redirect_request(request)?;
+        }
+        if let Some(cookie) = &self.cookie {
+            cookie.record_request(request);
+        }
+        Ok(())
+    }
+
+    fn intercept_redirect_response(&self, response: &Response) -> Result<(), HttpClientError> {
+        // Cookies set along a redirect chain are stored before it is followed.
+        if let Some(cookie) = &self.cookie {
+            cookie.store_response(response);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod ut_client_cert {
+    include!("../../tests/ut/task/ut_client_cert.rs");
+}
@@ -0,0 +1,377 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compressed transfer of request bodies.
+//!
+//! A task configured with `compression` advertises the gzip, deflate and
+//! brotli content codings when it downloads, and decodes an encoded response body as
+//! it is written, so that the file holds the decoded content. An upload of a
+//! raw file body sends it gzip encoded with `Content-Encoding`, while
+//! multipart and chunked uploads are sent as is. The codings are provided by
+//! zlib and brotli through the C++ bridge.
+//!
+//! Ranges apply to the encoded representation, so resumed and ranged
+//! downloads do not negotiate a coding. The bytes transferred on the wire are
+//! reported alongside the decoded ones in the progress extras.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use cxx::UniquePtr;
+use ylong_http_client::async_impl::{RequestBuilder, Response};
+use ylong_http_client::ReusableReader;
+use ylong_runtime::io::{AsyncRead, ReadBuf};
+
+use super::request_task::RequestTask;
+
+/// Content codings advertised in `Accept-Encoding`.
+pub(crate) const ACCEPT_ENCODING: &str = "gzip, deflate, br";
+
+/// Content coding of compressed uploads.
+pub(crate) const UPLOAD_ENCODING: &str = "gzip";
+
+/// Progress extras key of the bytes transferred on the wire.
+pub(crate) const WIRE_PROCESSED: &str = "wire-processed";
+
+/// Size of the plain chunks read from an upload before encoding.
+const ENCODE_BUFFER_SIZE: usize = 16 * 1024;
+
+/// Returned by the bridge when the stream expects more data.
+const STREAM_CONTINUE: i32 = 0;
+
+/// Returned by the bridge at the end of the stream.
+const STREAM_END: i32 = 1;
+
+#[cxx::bridge(namespace = "OHOS::Request")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("compression.h");
+
+        type ZStream;
+        type BrotliStream;
+
+        fn NewInflateStream(raw_fallback: bool) -> UniquePtr<ZStream>;
+        fn NewGzipStream() -> UniquePtr<ZStream>;
+        fn NewBrotliStream() -> UniquePtr<BrotliStream>;
+        fn Inflate(stream: Pin<&mut ZStream>, input: &[u8], output: &mut Vec<u8>) -> i32;
+        fn Deflate(
+            stream: Pin<&mut ZStream>,
+            input: &[u8],
+            finish: bool,
+            output: &mut Vec<u8>,
+        ) -> i32;
+        fn BrotliDecode(stream: Pin<&mut BrotliStream>, input: &[u8], output: &mut Vec<u8>) -> i32;
+    }
+}
+
+// A stream is only used through exclusive references.
+unsafe impl Send for ffi::ZStream {}
+unsafe impl Sync for ffi::ZStream {}
+unsafe impl Send for ffi::BrotliStream {}
+unsafe impl Sync for ffi::BrotliStream {}
+
+/// Content coding of a response body.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ContentCoding {
+    /// The body is not encoded.
+    Identity,
+    /// The body is gzip encoded.
+    Gzip,
+    /// The body is deflate encoded.
+    Deflate,
+    /// The body is brotli encoded.
+    Brotli,
+    /// The body is encoded with a coding, or a chain of codings, that cannot
+    /// be decoded.
+    Unsupported,
+}
+
+impl ContentCoding {
+    /// Parses the value of a `Content-Encoding` header.
+    pub(crate) fn parse(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "identity" => Self::Identity,
+            "gzip" | "x-gzip" => Self::Gzip,
+            "deflate" => Self::Deflate,
+            "br" => Self::Brotli,
+            _ => Self::Unsupported,
+        }
+    }
+
+    /// Returns the content coding of a response body.
+    pub(crate) fn of_response(response: &Response) -> Self {
+        match response
+            .headers()
+            .get("content-encoding")
+            .and_then(|v| v.to_string().ok())
+        {
+            Some(value) => Self::parse(&value),
+            None => Self::Identity,
+        }
+    }
+}
+
+/// Decoding stream of a content coding.
+enum DecodeStream {
+    Zlib(UniquePtr<ffi::ZStream>),
+    Brotli(UniquePtr<ffi::BrotliStream>),
+}
+
+/// Decoder of an encoded response body.
+pub(crate) struct Decoder {
+    stream: DecodeStream,
+    finished: bool,
+}
+
+impl Decoder {
+    /// Creates a decoder for `coding`, or `None` if the body needs no decoding
+    /// or cannot be decoded.
+    pub(crate) fn new(coding: ContentCoding) -> Option<Self> {
+        let stream = match coding {
+            ContentCoding::Gzip => DecodeStream::Zlib(ffi::NewInflateStream(false)),
+            // The `deflate` coding is the zlib format, though some servers
+            // send raw deflate data.
+            ContentCoding::Deflate => DecodeStream::Zlib(ffi::NewInflateStream(true)),
+            ContentCoding::Brotli => DecodeStream::Brotli(ffi::NewBrotliStream()),
+            ContentCoding::Identity | ContentCoding::Unsupported => return None,
+        };
+        Some(Self {
+            stream,
+            finished: false,
+        })
+    }
+
+    /// Decodes `input`, appending the decoded bytes to `output`.
+    ///
+    /// Data following the end of the encoded stream is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error if `input` is not validly encoded.
+    pub(crate) fn decode(&mut self, input: &[u8], output: &mut Vec<u8>) -> io::Result<()> {
+        if self.finished || input.is_empty() {
+            return Ok(());
+        }
+        let ret = match &mut self.stream {
+            DecodeStream::Zlib(stream) => ffi::Inflate(stream.pin_mut(), input, output),
+            DecodeStream::Brotli(stream) => ffi::BrotliDecode(stream.pin_mut(), input, output),
+        };
+        match ret {
+            STREAM_CONTINUE => Ok(()),
+            STREAM_END => {
+                self.finished = true;
+                Ok(())
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid encoded body",
+            )),
+        }
+    }
+
+    /// Checks if the end of the encoded stream has been decoded.
+    pub(crate) fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+/// Gzip encoder of an upload body.
+pub(crate) struct Encoder {
+    stream: UniquePtr<ffi::ZStream>,
+    finished: bool,
+}
+
+impl Encoder {
+    /// Creates a gzip encoder.
+    pub(crate) fn new() -> Self {
+        Self {
+            stream: ffi::NewGzipStream(),
+            finished: false,
+        }
+    }
+
+    /// Encodes `input`, appending the encoded bytes to `output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream is finished or cannot encode.
+    pub(crate) fn encode(&mut self, input: &[u8], output: &mut Vec<u8>) -> io::Result<()> {
+        self.deflate(input, false, output)
+    }
+
+    /// Ends the stream, appending the remaining encoded bytes and the gzip
+    /// trailer to `output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream is finished or cannot encode.
+    pub(crate) fn finish(&mut self, output: &mut Vec<u8>) -> io::Result<()> {
+        self.deflate(&[], true, output)
+    }
+
+    /// Checks if the stream has been ended.
+    pub(crate) fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn deflate(&mut self, input: &[u8], finish: bool, output: &mut Vec<u8>) -> io::Result<()> {
+        if self.finished {
+            return Err(io::Error::new(io::ErrorKind::Other, "encoder finished"));
+        }
+        match ffi::Deflate(self.stream.pin_mut(), input, finish, output) {
+            STREAM_CONTINUE if !finish => Ok(()),
+            STREAM_END if finish => {
+                self.finished = true;
+                Ok(())
+            }
+            _ => Err(io::Error::new(io::ErrorKind::Other, "gzip encoding failed")),
+        }
+    }
+}
+
+/// Reader gzip encoding the body read from another reader.
+pub(crate) struct GzipReader<R> {
+    task: Arc<RequestTask>,
+    inner: R,
+    encoder: Encoder,
+    plain: Vec<u8>,
+    encoded: Vec<u8>,
+    offset: usize,
+}
+
+impl<R> GzipReader<R> {
+    /// Creates a reader encoding the body of `task` read from `inner`.
+    pub(crate) fn new(task: Arc<RequestTask>, inner: R) -> Self {
+        Self {
+            task,
+            inner,
+            encoder: Encoder::new(),
+            plain: vec![0; ENCODE_BUFFER_SIZE],
+            encoded: vec![],
+            offset: 0,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for GzipReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            // Hand out the bytes already encoded first.
+            if this.offset < this.encoded.len() {
+                let size = buf.remaining().min(this.encoded.len() - this.offset);
+                buf.put_slice(&this.encoded[this.offset..this.offset + size]);
+                this.offset += size;
+                this.task
+                    .wire_processed
+                    .fetch_add(size as u64, Ordering::SeqCst);
+                return Poll::Ready(Ok(()));
+            }
+            if this.encoder.is_finished() {
+                return Poll::Ready(Ok(()));
+            }
+            this.encoded.clear();
+            this.offset = 0;
+
+            let mut plain = ReadBuf::new(&mut this.plain);
+            match Pin::new(&mut this.inner).poll_read(cx, &mut plain) {
+                Poll::Ready(Ok(())) => {}
+                other => return other,
+            }
+            let read = plain.filled().len();
+            let result = if read == 0 {
+                this.encoder.finish(&mut this.encoded)
+            } else {
+                this.encoder.encode(&this.plain[..read], &mut this.encoded)
+            };
+            if let Err(e) = result {
+                error!("task {} {}", this.task.task_id(), e);
+                return Poll::Ready(Err(e));
+            }
+        }
+    }
+}
+
+impl<R: ReusableReader> ReusableReader for GzipReader<R> {
+    fn reuse<'a>(&'a mut self) -> Pin<Box<dyn Future<Output = io::Result<()>> + Send + Sync + 'a>>
+    where
+        Self: 'a,
+    {
+        // The body is sent again from the start of a new gzip stream.
+        self.encoder = Encoder::new();
+        self.encoded.clear();
+        self.offset = 0;
+        self.inner.reuse()
+    }
+}
+
+impl RequestTask {
+    /// Advertises the supported content codings on a download request.
+    ///
+    /// Nothing is added unless the task is configured with `compression`. A
+    /// precise task needs the size of the file before downloading it, and a
+    /// coding requested in the task headers is left to the caller.
+    pub(crate) fn accept_encoding(&self, request_builder: RequestBuilder) -> RequestBuilder {
+        if !self.conf.compression
+            || self.conf.common_data.precise
+            || self
+                .conf
+                .headers
+                .keys()
+                .any(|key| key.eq_ignore_ascii_case("accept-encoding"))
+        {
+            return request_builder;
+        }
+        request_builder.header("Accept-Encoding", ACCEPT_ENCODING)
+    }
+
+    /// Checks if the body of `response` is decoded before it is written.
+    pub(crate) fn decodes(&self, response: &Response) -> bool {
+        self.conf.compression
+            && matches!(
+                ContentCoding::of_response(response),
+                ContentCoding::Gzip | ContentCoding::Deflate | ContentCoding::Brotli
+            )
+    }
+
+    /// Creates the decoder of the body of `response`, if it is decoded.
+    ///
+    /// A coding that cannot be decoded, only received when the task headers
+    /// request it, is written as is.
+    pub(crate) fn response_decoder(&self, response: &Response) -> Option<Decoder> {
+        if !self.conf.compression {
+            return None;
+        }
+        let coding = ContentCoding::of_response(response);
+        if coding == ContentCoding::Unsupported {
+            info!(
+                "task {} keeps an unsupported content coding",
+                self.task_id()
+            );
+        }
+        Decoder::new(coding)
+    }
+}
+
+#[cfg(test)]
+mod ut_compression {
+    include!("../../tests/ut/task/ut_compression.rs");
+}
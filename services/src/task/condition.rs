@@ -0,0 +1,264 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conditions a waiting task is blocked on.
+//!
+//! A task waiting for the system state carries the set of conditions it does
+//! not meet yet, persisted as a bitset in the `conditions` column of
+//! `request_task`. The state handlers set and clear each condition on its
+//! own, and the combined `Reason` reported through the API is derived from
+//! the set, so that a new blocking condition only takes a new bit instead of
+//! doubling the reasons.
+//!
+//! The `reason` column of a waiting task is only read for tasks persisted
+//! before their conditions: once the conditions of a waiting task are
+//! updated, its reason is reset to `RunningTaskMeetLimits`.
+
+use super::notify::WaitingCause;
+use super::reason::Reason;
+
+/// Reasons derived from a set of conditions, and persisted before the
+/// conditions were.
+pub(crate) const LEGACY_REASONS: [Reason; 12] = [
+    Reason::RunningTaskMeetLimits,
+    Reason::NetworkOffline,
+    Reason::UnsupportedNetworkType,
+    Reason::AppBackgroundOrTerminate,
+    Reason::AccountStopped,
+    Reason::NetworkApp,
+    Reason::NetworkAccount,
+    Reason::AppAccount,
+    Reason::NetworkAppAccount,
+    Reason::StorageSpaceWaiting,
+    Reason::StartTimeWaiting,
+    Reason::PrerequisiteWaiting,
+];
+
+/// Set of conditions a waiting task does not meet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Conditions(u32);
+
+impl Conditions {
+    /// No condition, the task only waits for its turn.
+    pub(crate) const NONE: Self = Self(0);
+    /// The network is offline.
+    pub(crate) const NETWORK_OFFLINE: Self = Self(1 << 0);
+    /// The current network is not one the task may use.
+    pub(crate) const NETWORK_TYPE: Self = Self(1 << 1);
+    /// The application is in the background or terminated.
+    pub(crate) const APP_STATE: Self = Self(1 << 2);
+    /// The account of the task is stopped.
+    pub(crate) const ACCOUNT: Self = Self(1 << 3);
+    /// The storage space is not enough for the task.
+    pub(crate) const STORAGE_SPACE: Self = Self(1 << 4);
+    /// The start time of the task is not reached.
+    pub(crate) const START_TIME: Self = Self(1 << 5);
+    /// The prerequisites of the task are not completed.
+    pub(crate) const PREREQUISITE: Self = Self(1 << 6);
+    /// The retry delay of the task is not elapsed.
+    pub(crate) const RETRY: Self = Self(1 << 7);
+    /// Any of the network conditions.
+    pub(crate) const NETWORK: Self = Self(Self::NETWORK_OFFLINE.0 | Self::NETWORK_TYPE.0);
+    /// The conditions checked again each time the task is scheduled.
+    pub(crate) const SYSTEM: Self = Self(Self::NETWORK.0 | Self::APP_STATE.0);
+    /// All the conditions known so far.
+    pub(crate) const ALL: Self = Self(
+        Self::NETWORK.0
+            | Self::APP_STATE.0
+            | Self::ACCOUNT.0
+            | Self::STORAGE_SPACE.0
+            | Self::START_TIME.0
+            | Self::PREREQUISITE.0
+            | Self::RETRY.0,
+    );
+
+    /// Creates a set from its persisted bits.
+    pub(crate) const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the bits of the set, as persisted.
+    pub(crate) const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Checks if the set contains no condition.
+    pub(crate) const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Checks if the set contains any condition of `other`.
+    pub(crate) const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Returns the set with the conditions of `other` added.
+    pub(crate) const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the set with the conditions of `other` removed.
+    pub(crate) const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns the conditions of a task persisted with `reason` only.
+    ///
+    /// The combined reasons do not tell an offline network from an
+    /// unsupported one, they are read as offline.
+    pub(crate) fn from_reason(reason: Reason) -> Self {
+        match reason {
+            Reason::NetworkOffline => Self::NETWORK_OFFLINE,
+            Reason::UnsupportedNetworkType => Self::NETWORK_TYPE,
+            Reason::AppBackgroundOrTerminate => Self::APP_STATE,
+            Reason::AccountStopped => Self::ACCOUNT,
+            Reason::NetworkApp => Self::NETWORK_OFFLINE.union(Self::APP_STATE),
+            Reason::NetworkAccount => Self::NETWORK_OFFLINE.union(Self::ACCOUNT),
+            Reason::AppAccount => Self::APP_STATE.union(Self::ACCOUNT),
+            Reason::NetworkAppAccount => Self::NETWORK_OFFLINE
+                .union(Self::APP_STATE)
+                .union(Self::ACCOUNT),
+            Reason::StorageSpaceWaiting => Self::STORAGE_SPACE,
+            Reason::StartTimeWaiting => Self::START_TIME,
+            Reason::PrerequisiteWaiting => Self::PREREQUISITE,
+            Reason::RetryWaiting => Self::RETRY,
+            _ => Self::NONE,
+        }
+    }
+
+    /// Returns the reason reported for a task waiting on the set.
+    ///
+    /// A task waiting for its prerequisites, its start time, its retry delay
+    /// or storage space reports that first, as it would not start on the
+    /// system state alone.
+    pub(crate) fn reason(self) -> Reason {
+        if self.intersects(Self::PREREQUISITE) {
+            return Reason::PrerequisiteWaiting;
+        }
+        if self.intersects(Self::START_TIME) {
+            return Reason::StartTimeWaiting;
+        }
+        if self.intersects(Self::RETRY) {
+            return Reason::RetryWaiting;
+        }
+        if self.intersects(Self::STORAGE_SPACE) {
+            return Reason::StorageSpaceWaiting;
+        }
+        let network = self.intersects(Self::NETWORK);
+        let app = self.intersects(Self::APP_STATE);
+        let account = self.intersects(Self::ACCOUNT);
+        match (network, app, account) {
+            (false, false, false) => Reason::RunningTaskMeetLimits,
+            (true, false, false) if self.intersects(Self::NETWORK_OFFLINE) => {
+                Reason::NetworkOffline
+            }
+            (true, false, false) => Reason::UnsupportedNetworkType,
+            (false, true, false) => Reason::AppBackgroundOrTerminate,
+            (false, false, true) => Reason::AccountStopped,
+            (true, true, false) => Reason::NetworkApp,
+            (true, false, true) => Reason::NetworkAccount,
+            (false, true, true) => Reason::AppAccount,
+            (true, true, true) => Reason::NetworkAppAccount,
+        }
+    }
+
+    /// Returns the cause notified for a task waiting on the set, the one of
+    /// the reason it reports.
+    pub(crate) fn cause(self) -> WaitingCause {
+        if self.intersects(Self::PREREQUISITE) {
+            return WaitingCause::Prerequisite;
+        }
+        if self.intersects(Self::START_TIME) {
+            return WaitingCause::StartTime;
+        }
+        if self.intersects(Self::RETRY) {
+            return WaitingCause::Retry;
+        }
+        if self.intersects(Self::STORAGE_SPACE) {
+            return WaitingCause::StorageSpace;
+        }
+        if self.intersects(Self::NETWORK) {
+            return WaitingCause::Network;
+        }
+        if self.intersects(Self::APP_STATE) {
+            return WaitingCause::AppState;
+        }
+        if self.intersects(Self::ACCOUNT) {
+            return WaitingCause::UserState;
+        }
+        WaitingCause::TaskQueue
+    }
+
+    /// Returns every cause of a task waiting on the set, the task queue if it
+    /// contains no condition.
+    pub(crate) fn causes(self) -> Vec<WaitingCause> {
+        let mut causes = vec![];
+        if self.intersects(Self::NETWORK) {
+            causes.push(WaitingCause::Network);
+        }
+        if self.intersects(Self::APP_STATE) {
+            causes.push(WaitingCause::AppState);
+        }
+        if self.intersects(Self::ACCOUNT) {
+            causes.push(WaitingCause::UserState);
+        }
+        if self.intersects(Self::STORAGE_SPACE) {
+            causes.push(WaitingCause::StorageSpace);
+        }
+        if self.intersects(Self::PREREQUISITE) {
+            causes.push(WaitingCause::Prerequisite);
+        }
+        if self.intersects(Self::START_TIME) {
+            causes.push(WaitingCause::StartTime);
+        }
+        if self.intersects(Self::RETRY) {
+            causes.push(WaitingCause::Retry);
+        }
+        if causes.is_empty() {
+            causes.push(WaitingCause::TaskQueue);
+        }
+        causes
+    }
+
+    /// Returns the SQL expression of the persisted conditions of a task.
+    ///
+    /// Tasks persisted before their conditions have them read from their
+    /// reason.
+    pub(crate) fn column_sql() -> String {
+        let mut sql = "CASE WHEN conditions > 0 THEN conditions ELSE CASE reason".to_string();
+        for reason in LEGACY_REASONS {
+            let conditions = Self::from_reason(reason);
+            if !conditions.is_empty() {
+                sql.push_str(&format!(" WHEN {} THEN {}", reason.repr, conditions.bits()));
+            }
+        }
+        sql.push_str(" ELSE 0 END END");
+        sql
+    }
+
+    /// Returns the SQL expression of the persisted conditions of a task once
+    /// `set` are added and `clear` removed.
+    pub(crate) fn updated_sql(set: Self, clear: Self) -> String {
+        format!(
+            "((({}) & ~{}) | {})",
+            Self::column_sql(),
+            clear.bits(),
+            set.bits()
+        )
+    }
+}
+
+#[cfg(test)]
+mod ut_condition {
+    include!("../../tests/ut/task/ut_condition.rs");
+}
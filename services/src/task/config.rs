@@ -31,6 +31,7 @@ cfg_oh! {
     use ipc::parcel::Deserialize;
 }
 
+use super::integrity;
 use super::reason::Reason;
 use super::ATOMIC_SERVICE;
 use crate::manage::account::GetOhosAccountUid;
@@ -111,6 +112,81 @@ pub struct Timeout {
     pub(crate) total_timeout: u64,
 }
 
+/// Retry policy of a network task.
+///
+/// A failed attempt is retried with exponential backoff while its `Reason`,
+/// or the HTTP status of a protocol error, is retryable. A `max_attempts` of
+/// 0 disables the policy.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one.
+    pub(crate) max_attempts: u32,
+    /// Delay in milliseconds before the first retry.
+    pub(crate) base_delay: u64,
+    /// Factor the delay is multiplied by after each retry.
+    pub(crate) backoff_factor: u32,
+    /// Percentage of the delay randomly added or subtracted.
+    pub(crate) jitter: u32,
+    /// Retryable `Reason` values, empty for the defaults.
+    pub(crate) reasons: Vec<u8>,
+    /// Retryable HTTP status codes, empty for the defaults.
+    pub(crate) statuses: Vec<u16>,
+}
+
+/// Recurrence rule of a task.
+///
+/// A recurring task runs again under the same ID after each completion or
+/// failure, deferred to its next occurrence. It cannot use user files.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Recurrence {
+    /// The task runs once.
+    #[default]
+    Once,
+    /// The task runs again every given number of seconds.
+    Interval(u64),
+    /// The task runs once a day within a window, in minutes since midnight
+    /// UTC. The window spans midnight when `start` is after `end`.
+    Daily {
+        /// Start of the window.
+        start: u32,
+        /// End of the window, exclusive.
+        end: u32,
+    },
+}
+
+/// Authentication of the requests of a task.
+///
+/// Sent as the `Authorization` header of the requests, unless the task
+/// headers already set one.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub enum Auth {
+    /// No authentication.
+    #[default]
+    None,
+    /// HTTP Basic authentication, sent with every request.
+    Basic {
+        /// User name, without a colon.
+        username: String,
+        /// Password of the user.
+        password: String,
+    },
+    /// HTTP Digest authentication, answering the challenge of the server.
+    Digest {
+        /// User name.
+        username: String,
+        /// Password of the user.
+        password: String,
+    },
+    /// Bearer token authentication.
+    Bearer {
+        /// Token sent with the requests.
+        token: String,
+        /// Whether to ask the application for a new token when the server
+        /// rejects it.
+        refresh: bool,
+    },
+}
+
 /// Core configuration shared by all types of network tasks.
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -204,6 +280,44 @@ pub struct TaskConfig {
     pub(crate) certs_path: Vec<String>,
     /// Core configuration shared across task types.
     pub(crate) common_data: CommonTaskConfig,
+    /// Number of concurrent range segments for a download (0 or 1 disables
+    /// segmented downloading).
+    pub(crate) segments: u32,
+    /// Expected digest of the downloaded file, as `sha256:<hex>` or plain
+    /// SHA-256 hex (empty disables integrity verification).
+    pub(crate) digest: String,
+    /// Size of each chunk of a resumable chunked upload (0 disables chunked
+    /// uploading).
+    pub(crate) chunk_size: u64,
+    /// Retry policy applied to failed attempts.
+    pub(crate) retry_policy: RetryPolicy,
+    /// IDs of the tasks that must complete before this task starts.
+    pub(crate) prerequisites: Vec<u32>,
+    /// Wall-clock time in milliseconds before which the task does not start
+    /// (0 starts the task immediately).
+    pub(crate) not_before: u64,
+    /// Recurrence rule of the task.
+    pub(crate) recurrence: Recurrence,
+    /// Size in bytes declared by the caller for the downloaded file, checked
+    /// against the free space when the server sends no `Content-Length` (0
+    /// when unknown).
+    pub(crate) expected_size: u64,
+    /// Whether to reserve the space of the downloaded file before writing it.
+    pub(crate) preallocate: bool,
+    /// Whether to transfer the body compressed: downloads accept gzip and
+    /// deflate encoded responses and decode them, uploads of a raw file body
+    /// send it gzip encoded.
+    pub(crate) compression: bool,
+    /// Whether to send the cookies stored for the uid of the task and store
+    /// the cookies set by its responses.
+    pub(crate) cookie_jar: bool,
+    /// Authentication of the requests.
+    pub(crate) auth: Auth,
+    /// Path to the PEM client certificate chain presented in TLS handshakes
+    /// (empty presents no client certificate).
+    pub(crate) client_cert_path: String,
+    /// Path to the PEM private key of the client certificate.
+    pub(crate) client_key_path: String,
 }
 
 impl TaskConfig {
@@ -426,6 +540,20 @@ impl Default for TaskConfig {
                 min_speed: MinSpeed::default(),
                 timeout: Timeout::default(),
             },
+            segments: 0,
+            digest: "".to_string(),
+            chunk_size: 0,
+            retry_policy: RetryPolicy::default(),
+            prerequisites: vec![],
+            not_before: 0,
+            recurrence: Recurrence::Once,
+            expected_size: 0,
+            preallocate: false,
+            compression: false,
+            cookie_jar: false,
+            auth: Auth::None,
+            client_cert_path: "".to_string(),
+            client_key_path: "".to_string(),
         }
     }
 }
@@ -540,6 +668,88 @@ impl ConfigBuilder {
         self.inner.common_data.retry = retry;
         self
     }
+
+    /// Sets the number of concurrent range segments for a download.
+    pub fn segments(&mut self, segments: u32) -> &mut Self {
+        self.inner.segments = segments;
+        self
+    }
+
+    /// Sets the expected digest of the downloaded file.
+    pub fn digest(&mut self, digest: &str) -> &mut Self {
+        self.inner.digest = digest.to_string();
+        self
+    }
+
+    /// Sets the chunk size of a resumable chunked upload.
+    pub fn chunk_size(&mut self, chunk_size: u64) -> &mut Self {
+        self.inner.chunk_size = chunk_size;
+        self
+    }
+
+    /// Sets the retry policy applied to failed attempts.
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.inner.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the IDs of the tasks that must complete before this task starts.
+    pub fn prerequisites(&mut self, prerequisites: Vec<u32>) -> &mut Self {
+        self.inner.prerequisites = prerequisites;
+        self
+    }
+
+    /// Sets the wall-clock time in milliseconds before which the task does
+    /// not start.
+    pub fn not_before(&mut self, not_before: u64) -> &mut Self {
+        self.inner.not_before = not_before;
+        self
+    }
+
+    /// Sets the recurrence rule of the task.
+    pub fn recurrence(&mut self, recurrence: Recurrence) -> &mut Self {
+        self.inner.recurrence = recurrence;
+        self
+    }
+
+    /// Sets the size in bytes the downloaded file is expected to have.
+    pub fn expected_size(&mut self, expected_size: u64) -> &mut Self {
+        self.inner.expected_size = expected_size;
+        self
+    }
+
+    /// Sets whether to reserve the space of the downloaded file before
+    /// writing it.
+    pub fn preallocate(&mut self, preallocate: bool) -> &mut Self {
+        self.inner.preallocate = preallocate;
+        self
+    }
+
+    /// Sets whether to transfer the body compressed.
+    pub fn compression(&mut self, compression: bool) -> &mut Self {
+        self.inner.compression = compression;
+        self
+    }
+
+    /// Sets whether to use the cookie jar of the uid.
+    pub fn cookie_jar(&mut self, cookie_jar: bool) -> &mut Self {
+        self.inner.cookie_jar = cookie_jar;
+        self
+    }
+
+    /// Sets the authentication of the requests.
+    pub fn auth(&mut self, auth: Auth) -> &mut Self {
+        self.inner.auth = auth;
+        self
+    }
+
+    /// Sets the client certificate chain and private key presented in TLS
+    /// handshakes.
+    pub fn client_cert(&mut self, cert_path: String, key_path: String) -> &mut Self {
+        self.inner.client_cert_path = cert_path;
+        self.inner.client_key_path = key_path;
+        self
+    }
 }
 
 #[cfg(feature = "oh")]
@@ -629,6 +839,45 @@ impl Serialize for TaskConfig {
             parcel.write(extra.1)?;
         }
 
+        // Write segmented download configuration
+        parcel.write(&self.segments)?;
+
+        // Write integrity verification configuration
+        parcel.write(&self.digest)?;
+
+        // Write chunked upload configuration
+        parcel.write(&self.chunk_size)?;
+
+        // Write retry policy
+        parcel.write(&self.retry_policy)?;
+
+        // Write prerequisite tasks
+        parcel.write(&(self.prerequisites.len() as u32))?;
+        for task_id in self.prerequisites.iter() {
+            parcel.write(&task_id.to_string())?;
+        }
+
+        // Write deferred start and recurrence
+        parcel.write(&self.not_before)?;
+        parcel.write(&self.recurrence)?;
+
+        // Write storage space options
+        parcel.write(&self.expected_size)?;
+        parcel.write(&self.preallocate)?;
+
+        // Write compressed transfer option
+        parcel.write(&self.compression)?;
+
+        // Write cookie jar option
+        parcel.write(&self.cookie_jar)?;
+
+        // Write authentication
+        parcel.write(&self.auth)?;
+
+        // Write client certificate paths
+        parcel.write(&self.client_cert_path)?;
+        parcel.write(&self.client_key_path)?;
+
         Ok(())
     }
 }
@@ -844,6 +1093,76 @@ impl Deserialize for TaskConfig {
             extras.insert(key, value);
         }
 
+        // Read segmented download configuration
+        let segments: u32 = parcel.read()?;
+
+        // Read integrity verification configuration
+        let digest: String = parcel.read()?;
+        if !digest.is_empty() && integrity::parse_digest(&digest).is_none() {
+            error!("deserialize failed: unsupported digest {}", digest);
+            sys_event!(
+                ExecFault,
+                DfxCode::INVALID_IPC_MESSAGE_A00,
+                &format!("deserialize failed: unsupported digest {}", digest)
+            );
+            return Err(IpcStatusCode::Failed);
+        }
+
+        // Read chunked upload configuration
+        let chunk_size: u64 = parcel.read()?;
+
+        // Read retry policy
+        let retry_policy: RetryPolicy = parcel.read()?;
+
+        // Read prerequisite tasks
+        let prerequisites_size: u32 = parcel.read()?;
+        if prerequisites_size > parcel.readable() as u32 {
+            error!("deserialize failed: prerequisites size too large");
+            sys_event!(
+                ExecFault,
+                DfxCode::INVALID_IPC_MESSAGE_A00,
+                "deserialize failed: prerequisites size too large"
+            );
+            return Err(IpcStatusCode::Failed);
+        }
+        let mut prerequisites = Vec::new();
+        for _ in 0..prerequisites_size {
+            let task_id: String = parcel.read()?;
+            let Ok(task_id) = task_id.parse::<u32>() else {
+                error!("deserialize failed: prerequisite not valid: {}", task_id);
+                sys_event!(
+                    ExecFault,
+                    DfxCode::INVALID_IPC_MESSAGE_A00,
+                    &format!("deserialize failed: prerequisite not valid: {}", task_id)
+                );
+                return Err(IpcStatusCode::Failed);
+            };
+            if !prerequisites.contains(&task_id) {
+                prerequisites.push(task_id);
+            }
+        }
+
+        // Read deferred start and recurrence
+        let not_before: u64 = parcel.read()?;
+        let recurrence: Recurrence = parcel.read()?;
+
+        // Read storage space options
+        let expected_size: u64 = parcel.read()?;
+        let preallocate: bool = parcel.read()?;
+
+        // Read compressed transfer option
+        let compression: bool = parcel.read()?;
+
+        // Read cookie jar option
+        let cookie_jar: bool = parcel.read()?;
+
+        // Read authentication
+        let auth: Auth = parcel.read()?;
+
+        // Read client certificate paths
+        let client_cert_path: String = parcel.read()?;
+        let client_key_path: String = parcel.read()?;
+
         // Determine atomic account based on bundle type
         let atomic_account = if bundle_type == ATOMIC_SERVICE {
             GetOhosAccountUid()
@@ -900,11 +1219,178 @@ impl Deserialize for TaskConfig {
                     total_timeout,
                 },
             },
+            segments,
+            digest,
+            chunk_size,
+            retry_policy,
+            prerequisites,
+            not_before,
+            recurrence,
+            expected_size,
+            preallocate,
+            compression,
+            cookie_jar,
+            auth,
+            client_cert_path,
+            client_key_path,
         };
         Ok(task_config)
     }
 }
 
+#[cfg(feature = "oh")]
+impl Serialize for RetryPolicy {
+    fn serialize(&self, parcel: &mut ipc::parcel::MsgParcel) -> ipc::IpcResult<()> {
+        parcel.write(&self.max_attempts)?;
+        parcel.write(&self.base_delay)?;
+        parcel.write(&self.backoff_factor)?;
+        parcel.write(&self.jitter)?;
+        parcel.write(&(self.reasons.len() as u32))?;
+        for reason in self.reasons.iter() {
+            parcel.write(&(*reason as u32))?;
+        }
+        parcel.write(&(self.statuses.len() as u32))?;
+        for status in self.statuses.iter() {
+            parcel.write(&(*status as u32))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "oh")]
+impl Deserialize for RetryPolicy {
+    fn deserialize(parcel: &mut ipc::parcel::MsgParcel) -> ipc::IpcResult<Self> {
+        let max_attempts: u32 = parcel.read()?;
+        let base_delay: u64 = parcel.read()?;
+        let backoff_factor: u32 = parcel.read()?;
+        let jitter: u32 = parcel.read()?;
+        if jitter > 100 {
+            error!("deserialize failed: retry jitter {} exceeds 100", jitter);
+            sys_event!(
+                ExecFault,
+                DfxCode::INVALID_IPC_MESSAGE_A00,
+                &format!("deserialize failed: retry jitter {} exceeds 100", jitter)
+            );
+            return Err(IpcStatusCode::Failed);
+        }
+
+        let reasons_size: u32 = parcel.read()?;
+        if reasons_size > parcel.readable() as u32 {
+            error!("deserialize failed: retry reasons size too large");
+            sys_event!(
+                ExecFault,
+                DfxCode::INVALID_IPC_MESSAGE_A00,
+                "deserialize failed: retry reasons size too large"
+            );
+            return Err(IpcStatusCode::Failed);
+        }
+        let mut reasons = Vec::new();
+        for _ in 0..reasons_size {
+            let reason: u32 = parcel.read()?;
+            reasons.push(reason as u8);
+        }
+
+        let statuses_size: u32 = parcel.read()?;
+        if statuses_size > parcel.readable() as u32 {
+            error!("deserialize failed: retry statuses size too large");
+            sys_event!(
+                ExecFault,
+                DfxCode::INVALID_IPC_MESSAGE_A00,
+                "deserialize failed: retry statuses size too large"
+            );
+            return Err(IpcStatusCode::Failed);
+        }
+        let mut statuses = Vec::new();
+        for _ in 0..statuses_size {
+            let status: u32 = parcel.read()?;
+            statuses.push(status as u16);
+        }
+
+        Ok(RetryPolicy {
+            max_attempts,
+            base_delay,
+            backoff_factor,
+            jitter,
+            reasons,
+            statuses,
+        })
+    }
+}
+
+#[cfg(feature = "oh")]
+impl Serialize for Recurrence {
+    fn serialize(&self, parcel: &mut ipc::parcel::MsgParcel) -> ipc::IpcResult<()> {
+        let (kind, interval, start, end) = self.to_parts();
+        parcel.write(&kind)?;
+        parcel.write(&interval)?;
+        parcel.write(&start)?;
+        parcel.write(&end)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "oh")]
+impl Deserialize for Recurrence {
+    fn deserialize(parcel: &mut ipc::parcel::MsgParcel) -> ipc::IpcResult<Self> {
+        let kind: u32 = parcel.read()?;
+        let interval: u64 = parcel.read()?;
+        let start: u32 = parcel.read()?;
+        let end: u32 = parcel.read()?;
+        match Recurrence::from_parts(kind, interval, start, end) {
+            Some(recurrence) => Ok(recurrence),
+            None => {
+                error!(
+                    "deserialize failed: recurrence not valid: {} {} {} {}",
+                    kind, interval, start, end
+                );
+                sys_event!(
+                    ExecFault,
+                    DfxCode::INVALID_IPC_MESSAGE_A00,
+                    &format!(
+                        "deserialize failed: recurrence not valid: {} {} {} {}",
+                        kind, interval, start, end
+                    )
+                );
+                Err(IpcStatusCode::Failed)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "oh")]
+impl Serialize for Auth {
+    fn serialize(&self, parcel: &mut ipc::parcel::MsgParcel) -> ipc::IpcResult<()> {
+        let (scheme, username, secret, refresh) = self.to_parts();
+        parcel.write(&scheme)?;
+        parcel.write(&username.to_string())?;
+        parcel.write(&secret.to_string())?;
+        parcel.write(&refresh)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "oh")]
+impl Deserialize for Auth {
+    fn deserialize(parcel: &mut ipc::parcel::MsgParcel) -> ipc::IpcResult<Self> {
+        let scheme: u32 = parcel.read()?;
+        let username: String = parcel.read()?;
+        let secret: String = parcel.read()?;
+        let refresh: bool = parcel.read()?;
+        match Auth::from_parts(scheme, username, secret, refresh) {
+            Some(auth) => Ok(auth),
+            None => {
+                error!("deserialize failed: auth not valid: {}", scheme);
+                sys_event!(
+                    ExecFault,
+                    DfxCode::INVALID_IPC_MESSAGE_A00,
+                    &format!("deserialize failed: auth not valid: {}", scheme)
+                );
+                Err(IpcStatusCode::Failed)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod ut_config {
     include!("../../tests/ut/task/ut_config.rs");
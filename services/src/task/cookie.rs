@@ -0,0 +1,461 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cookie jars shared by the tasks of an application.
+//!
+//! A task configured with `cookie_jar` sends the cookies stored for its uid
+//! and stores the cookies set by its responses, including the responses of a
+//! redirect chain, following the storage and retrieval rules of RFC 6265.
+//! Every task of a uid shares the same jar, so that the session established
+//! by one task is used by the next. Applications may also seed or clear their
+//! jar through the service API.
+//!
+//! The cookies are attached to the first request of a task. The hops of a
+//! redirect chain send the same header, which the client drops when the host
+//! changes.
+//!
+//! No public suffix list is available, so a `Domain` attribute naming a
+//! top-level domain is rejected, while other public suffixes are not.
+
+use std::net::Ipv4Addr;
+
+use request_utils::date;
+use ylong_http_client::async_impl::{RequestBuilder, Response};
+
+use super::request_task::RequestTask;
+use crate::database::cookie as cookie_db;
+use crate::utils::get_current_timestamp;
+
+/// Expiry of a cookie deleted by its `Max-Age` or `Expires` attribute.
+const EXPIRED: u64 = 1;
+
+/// Expiry of a session cookie.
+const SESSION: u64 = 0;
+
+/// A cookie of a jar.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Cookie {
+    pub(crate) name: String,
+    pub(crate) value: String,
+    /// Canonical domain, without a leading dot.
+    pub(crate) domain: String,
+    pub(crate) path: String,
+    /// Whether the cookie is only sent to `domain` itself.
+    pub(crate) host_only: bool,
+    /// Whether the cookie is only sent over secure connections.
+    pub(crate) secure: bool,
+    pub(crate) http_only: bool,
+    /// Expiry time in milliseconds, 0 for a session cookie.
+    pub(crate) expiry: u64,
+    /// Creation time in milliseconds.
+    pub(crate) creation: u64,
+}
+
+/// The parts of a request url relevant to cookies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CookieUrl {
+    pub(crate) secure: bool,
+    pub(crate) host: String,
+    pub(crate) path: String,
+}
+
+impl CookieUrl {
+    /// Parses an http or https url.
+    pub(crate) fn parse(url: &str) -> Option<Self> {
+        let (scheme, rest) = url.split_once("://")?;
+        let secure = match scheme.to_ascii_lowercase().as_str() {
+            "http" => false,
+            "https" => true,
+            _ => return None,
+        };
+        let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+        let (authority, rest) = rest.split_at(authority_end);
+        let host_port = match authority.rfind('@') {
+            Some(i) => &authority[i + 1..],
+            None => authority,
+        };
+        let host = match host_port.strip_prefix('[') {
+            Some(v6) => &host_port[..v6.find(']')? + 2],
+            None => host_port.split(':').next().unwrap_or_default(),
+        };
+        if host.is_empty() {
+            return None;
+        }
+        let path_end = rest.find(['?', '#']).unwrap_or(rest.len());
+        let path = match &rest[..path_end] {
+            path if path.starts_with('/') => path.to_string(),
+            _ => "/".to_string(),
+        };
+        Some(Self {
+            secure,
+            host: host.to_ascii_lowercase(),
+            path,
+        })
+    }
+}
+
+impl Cookie {
+    /// Parses a `Set-Cookie` header value received from `url` at `now`.
+    ///
+    /// Returns `None` if the cookie is ignored by the storage model of RFC
+    /// 6265: it has no name, its `Domain` attribute does not cover the host of
+    /// `url`, or it is `Secure` but not set over a secure connection.
+    pub(crate) fn parse(set_cookie: &str, url: &CookieUrl, now: u64) -> Option<Self> {
+        let (pair, attributes) = match set_cookie.split_once(';') {
+            Some((pair, attributes)) => (pair, attributes),
+            None => (set_cookie, ""),
+        };
+        let (name, value) = pair.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut expires = None;
+        let mut max_age = None;
+        let mut domain = None;
+        let mut path = None;
+        let mut secure = false;
+        let mut http_only = false;
+        for attribute in attributes.split(';') {
+            let (key, value) = match attribute.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => (attribute.trim(), ""),
+            };
+            match key.to_ascii_lowercase().as_str() {
+                "expires" => {
+                    if let Some(time) = parse_cookie_date(value) {
+                        expires = Some(time.max(EXPIRED));
+                    }
+                }
+                "max-age" => {
+                    if let Some(delta) = parse_max_age(value) {
+                        max_age = Some(match delta {
+                            delta if delta <= 0 => EXPIRED,
+                            delta => now.saturating_add((delta as u64).saturating_mul(1000)),
+                        });
+                    }
+                }
+                "domain" if !value.is_empty() => {
+                    let value = value.strip_prefix('.').unwrap_or(value);
+                    domain = Some(value.to_ascii_lowercase());
+                }
+                "path" => {
+                    path = value.starts_with('/').then(|| value.to_string());
+                }
+                "secure" => secure = true,
+                "httponly" => http_only = true,
+                _ => {}
+            }
+        }
+
+        if secure && !url.secure {
+            return None;
+        }
+        let (domain, host_only) = match domain {
+            Some(domain) => {
+                if (domain != url.host && !domain.contains('.'))
+                    || !domain_match(&url.host, &domain)
+                {
+                    return None;
+                }
+                (domain, false)
+            }
+            None => (url.host.clone(), true),
+        };
+        Some(Self {
+            name: name.to_string(),
+            value: value.trim().to_string(),
+            domain,
+            path: path.unwrap_or_else(|| default_path(&url.path).to_string()),
+            host_only,
+            secure,
+            http_only,
+            expiry: max_age.or(expires).unwrap_or(SESSION),
+            creation: now,
+        })
+    }
+
+    /// Checks if the cookie has expired at `now`.
+    pub(crate) fn is_expired(&self, now: u64) -> bool {
+        self.expiry != SESSION && self.expiry <= now
+    }
+
+    /// Checks if the cookie is sent with a request to `url` at `now`.
+    pub(crate) fn matches(&self, url: &CookieUrl, now: u64) -> bool {
+        let domain_matched = if self.host_only {
+            url.host == self.domain
+        } else {
+            domain_match(&url.host, &self.domain)
+        };
+        domain_matched
+            && path_match(&url.path, &self.path)
+            && (!self.secure || url.secure)
+            && !self.is_expired(now)
+    }
+}
+
+/// Builds the `Cookie` header of a request to `url` at `now` from `cookies`.
+///
+/// Longer paths come first, then older cookies.
+pub(crate) fn cookie_header(cookies: &[Cookie], url: &CookieUrl, now: u64) -> Option<String> {
+    let mut cookies = cookies
+        .iter()
+        .filter(|cookie| cookie.matches(url, now))
+        .collect::<Vec<_>>();
+    if cookies.is_empty() {
+        return None;
+    }
+    cookies.sort_by(|a, b| {
+        b.path
+            .len()
+            .cmp(&a.path.len())
+            .then(a.creation.cmp(&b.creation))
+    });
+    Some(
+        cookies
+            .iter()
+            .map(|cookie| format!("{}={}", cookie.name, cookie.value))
+            .collect::<Vec<_>>()
+            .join("; "),
+    )
+}
+
+/// Splits a `Set-Cookie` header value holding several cookies joined by
+/// commas.
+///
+/// A comma only separates two cookies when it is followed by a name and `=`,
+/// which is never the case for the comma of an `Expires` date.
+pub(crate) fn split_set_cookie(value: &str) -> Vec<&str> {
+    let mut cookies = vec![];
+    let mut start = 0;
+    for (i, _) in value.match_indices(',') {
+        let next = &value[i + 1..];
+        let pair_end = next.find([';', ',']).unwrap_or(next.len());
+        if next[..pair_end].contains('=') {
+            cookies.push(value[start..i].trim());
+            start = i + 1;
+        }
+    }
+    cookies.push(value[start..].trim());
+    cookies.retain(|cookie| !cookie.is_empty());
+    cookies
+}
+
+/// Stores the cookies set by `set_cookie`, received from `url`, in the jar of
+/// `uid`, and returns how many were accepted.
+///
+/// A cookie that has already expired removes the stored one instead.
+pub(crate) fn store_cookies(uid: u64, url: &str, set_cookie: &str) -> usize {
+    let url = match CookieUrl::parse(url) {
+        Some(url) => url,
+        None => return 0,
+    };
+    let now = get_current_timestamp();
+    let mut accepted = 0;
+    for value in split_set_cookie(set_cookie) {
+        let Some(cookie) = Cookie::parse(value, &url, now) else {
+            debug!("uid {} ignores a cookie set by {}", uid, url.host);
+            continue;
+        };
+        if cookie.is_expired(now) {
+            cookie_db::remove(uid, &cookie);
+        } else {
+            cookie_db::store(uid, &cookie);
+        }
+        accepted += 1;
+    }
+    accepted
+}
+
+/// Stores the cookies set by `response`, the response to a request to `url`,
+/// in the jar of `uid`.
+pub(crate) fn store_response_cookies(uid: u64, url: &str, response: &Response) {
+    if let Some(set_cookie) = response
+        .headers()
+        .get("set-cookie")
+        .and_then(|v| v.to_string().ok())
+    {
+        store_cookies(uid, url, &set_cookie);
+    }
+}
+
+/// Removes the cookies of the jar of `uid` set for `domain` and its
+/// subdomains, or all of them if `domain` is empty.
+pub(crate) fn clear_cookies(uid: u64, domain: &str) {
+    let domain = domain.trim();
+    let domain = domain.strip_prefix('.').unwrap_or(domain);
+    cookie_db::clear(uid, &domain.to_ascii_lowercase());
+}
+
+impl RequestTask {
+    /// Attaches the cookies of the jar of the task to a request to `url`.
+    ///
+    /// Nothing is added unless the task is configured with `cookie_jar`, or
+    /// if a `Cookie` header is set in the task headers.
+    pub(crate) fn attach_cookies(
+        &self,
+        url: &str,
+        request_builder: RequestBuilder,
+    ) -> RequestBuilder {
+        if !self.conf.cookie_jar
+            || self
+                .conf
+                .headers
+                .keys()
+                .any(|key| key.eq_ignore_ascii_case("cookie"))
+        {
+            return request_builder;
+        }
+        let Some(url) = CookieUrl::parse(url) else {
+            return request_builder;
+        };
+        let cookies = cookie_db::query(self.conf.common_data.uid);
+        match cookie_header(&cookies, &url, get_current_timestamp()) {
+            Some(header) => request_builder.header("Cookie", header.as_str()),
+            None => request_builder,
+        }
+    }
+}
+
+/// Checks if `host` domain-matches `domain`.
+fn domain_match(host: &str, domain: &str) -> bool {
+    host == domain
+        || (host.len() > domain.len()
+            && host.ends_with(domain)
+            && host.as_bytes()[host.len() - domain.len() - 1] == b'.'
+            && !is_ip_address(host))
+}
+
+/// Checks if `path` path-matches the path of a cookie.
+fn path_match(path: &str, cookie_path: &str) -> bool {
+    path == cookie_path
+        || (path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/') || path.as_bytes()[cookie_path.len()] == b'/'))
+}
+
+/// Returns the default path of a cookie set by a request to `path`.
+fn default_path(path: &str) -> &str {
+    match path.rfind('/') {
+        Some(0) | None => "/",
+        Some(i) => &path[..i],
+    }
+}
+
+fn is_ip_address(host: &str) -> bool {
+    host.starts_with('[') || host.parse::<Ipv4Addr>().is_ok()
+}
+
+/// Parses the value of a `Max-Age` attribute in seconds.
+fn parse_max_age(value: &str) -> Option<i64> {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // Overflowing values are as good as no expiry.
+    Some(value.parse().unwrap_or(match value.starts_with('-') {
+        true => i64::MIN,
+        false => i64::MAX,
+    }))
+}
+
+/// Parses a cookie date into milliseconds since the epoch, following the
+/// lenient algorithm of RFC 6265 section 5.1.1.
+///
+/// A date before the epoch is returned as 0.
+pub(crate) fn parse_cookie_date(value: &str) -> Option<u64> {
+    let mut time = None;
+    let mut day = None;
+    let mut month = None;
+    let mut year = None;
+    for token in value.split(is_date_delimiter).filter(|t| !t.is_empty()) {
+        if time.is_none() {
+            if let Some(parsed) = parse_time(token) {
+                time = Some(parsed);
+                continue;
+            }
+        }
+        if day.is_none() {
+            if let Some(parsed) = leading_digits(token, 1, 2) {
+                day = Some(parsed);
+                continue;
+            }
+        }
+        if month.is_none() {
+            // Tokens may contain any character, a month prefix only ASCII.
+            if let Some(parsed) = token.get(..3).and_then(date::month_from_name) {
+                month = Some(parsed);
+                continue;
+            }
+        }
+        if year.is_none() {
+            if let Some(parsed) = leading_digits(token, 2, 4) {
+                year = Some(parsed);
+            }
+        }
+    }
+
+    let (hour, minute, second) = time?;
+    let (day, month, mut year) = (day?, month?, year?);
+    match year {
+        70..=99 => year += 1900,
+        0..=69 => year += 2000,
+        _ => {}
+    }
+    if year < 1601 || second > 59 {
+        return None;
+    }
+    let secs = date::timestamp(year as i64, month, day, hour, minute, second)?;
+    Some((secs.max(0) as u64) * 1000)
+}
+
+fn is_date_delimiter(c: char) -> bool {
+    matches!(c, '\x09' | '\x20'..='\x2f' | '\x3b'..='\x40' | '\x5b'..='\x60' | '\x7b'..='\x7e')
+}
+
+/// Parses the leading `min` to `max` digits of `token`, which must not be
+/// followed by another digit.
+fn leading_digits(token: &str, min: usize, max: usize) -> Option<u32> {
+    let len = token.bytes().take_while(|b| b.is_ascii_digit()).count();
+    if len < min || len > max {
+        return None;
+    }
+    token[..len].parse().ok()
+}
+
+/// Parses a `hh:mm:ss` time token, each field having one or two digits.
+fn parse_time(token: &str) -> Option<(u32, u32, u32)> {
+    let mut fields = token.splitn(3, ':');
+    let hour = fields.next()?;
+    let minute = fields.next()?;
+    let second = fields.next()?;
+    if hour.is_empty()
+        || hour.len() > 2
+        || !hour.bytes().all(|b| b.is_ascii_digit())
+        || minute.is_empty()
+        || minute.len() > 2
+        || !minute.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((
+        hour.parse().ok()?,
+        minute.parse().ok()?,
+        leading_digits(second, 1, 2)?,
+    ))
+}
+
+#[cfg(test)]
+mod ut_cookie {
+    include!("../../tests/ut/task/ut_cookie.rs");
+}
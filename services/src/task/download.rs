@@ -24,20 +24,22 @@
 use std::io::SeekFrom;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 use std::time::Instant;
 
 use ylong_http_client::async_impl::{DownloadOperator, Downloader, Response};
 use ylong_http_client::{ErrorKind, HttpClientError, SpeedLimit, Timeout};
 
+use super::client::client_cert_rejected;
+use super::compression::{ContentCoding, Decoder};
 use super::operator::TaskOperator;
 use super::reason::Reason;
 use super::request_task::{TaskError, TaskPhase};
 use crate::manage::database::RequestDb;
 use crate::task::info::State;
 use crate::task::request_task::RequestTask;
-use crate::task::task_control;
+use crate::task::{integrity, segment, task_control};
 #[cfg(feature = "oh")]
 use crate::trace::Trace;
 use crate::utils::get_current_duration;
@@ -62,7 +64,10 @@ impl DownloadOperator for TaskOperator {
         cx: &mut Context<'_>,
         data: &[u8],
     ) -> Poll<Result<usize, HttpClientError>> {
-        self.poll_write_file(cx, data, 0)
+        match self.decoder.as_ref() {
+            Some(decoder) => self.poll_write_decoded(decoder, data),
+            None => self.poll_write_file(cx, data, 0),
+        }
     }
 
     fn poll_progress(
@@ -85,6 +90,7 @@ impl DownloadOperator for TaskOperator {
 /// * `task` - The download task containing configuration and state information.
 /// * `response` - The HTTP response to download from.
 /// * `abort_flag` - An atomic flag used to signal download cancellation.
+/// * `decoder` - The decoder of the response body, if it is encoded.
 ///
 /// # Returns
 ///
@@ -107,7 +113,7 @@ impl DownloadOperator for TaskOperator {
 /// let abort_flag = Arc::new(AtomicBool::new(false));
 ///
 /// // Build the downloader
-/// let downloader = build_downloader(task, response, abort_flag);
+/// let downloader = build_downloader(task, response, abort_flag, None);
 ///
 /// // Start the download
 /// // tokio::spawn(async move { downloader.download().await });
@@ -116,9 +122,14 @@ pub(crate) fn build_downloader(
     task: Arc<RequestTask>,
     response: Response,
     abort_flag: Arc<AtomicBool>,
+    decoder: Option<Arc<Mutex<Decoder>>>,
 ) -> Downloader<TaskOperator> {
     // Create a task operator to handle file writing and progress updates
-    let task_operator = TaskOperator::new(task, abort_flag);
+    let mut task_operator = TaskOperator::new(task, abort_flag);
+    if decoder.is_some() {
+        task_operator = task_operator.count_wire();
+    }
+    task_operator.decoder = decoder;
 
     // Configure the downloader with appropriate settings
     Downloader::builder()
@@ -157,7 +168,7 @@ pub(crate) fn build_downloader(
 /// tokio::spawn(async move { download(task, abort_flag).await });
 /// ```
 pub(crate) async fn download(task: Arc<RequestTask>, abort_flag: Arc<AtomicBool>) {
-    // Initialize retry counter
+    // Initialize retry counters
     task.tries.store(0, Ordering::SeqCst);
 
     // Main download loop with retry logic
@@ -166,13 +177,19 @@ pub(crate) async fn download(task: Arc<RequestTask>, abort_flag: Arc<AtomicBool>
 
         // Execute the actual download logic
         if let Err(e) = download_inner(task.clone(), abort_flag.clone()).await {
+            // Retry the failure if the retry policy allows it
+            let e = match e {
+                TaskError::Failed(reason) if task.retry_by_policy(reason) => {
+                    TaskError::Waiting(TaskPhase::RetryWaiting)
+                }
+                e => e,
+            };
             match e {
                 TaskError::Waiting(phase) => match phase {
                     // Handle retry case: update timeout and continue the loop
                     TaskPhase::NeedRetry => {
                         // Update the remaining time based on elapsed download time
-                        let download_time = begin_time.elapsed().as_secs();
-                        task.rest_time.fetch_sub(download_time, Ordering::SeqCst);
+                        task.consume_rest_time(begin_time.elapsed().as_secs());
 
                         // Adjust client timeout to match remaining task time
                         let mut client = task.client.lock().await;
@@ -189,6 +206,16 @@ pub(crate) async fn download(task: Arc<RequestTask>, abort_flag: Arc<AtomicBool>
                     TaskPhase::NetworkOffline => {
                         *task.running_result.lock().unwrap() = Some(Err(Reason::NetworkOffline));
                     }
+                    // Handle storage space shortage: wait for space to free up
+                    TaskPhase::InsufficientSpace => {
+                        *task.running_result.lock().unwrap() =
+                            Some(Err(Reason::StorageSpaceWaiting));
+                    }
+                    // Handle policy retry: wait for the retry delay out of the queue
+                    TaskPhase::RetryWaiting => {
+                        task.consume_rest_time(begin_time.elapsed().as_secs());
+                        *task.running_result.lock().unwrap() = Some(Err(Reason::RetryWaiting));
+                    }
                 },
                 // Handle failure errors: record the specific failure reason
                 TaskError::Failed(reason) => {
@@ -206,6 +233,39 @@ pub(crate) async fn download(task: Arc<RequestTask>, abort_flag: Arc<AtomicBool>
 }
 
 impl RequestTask {
+    /// Renames the temporary files of a completed download over their
+    /// destination files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaskError::Failed(Reason::IoError)` if a file cannot be synced
+    /// or renamed.
+    pub(crate) fn commit_temp_files(&self) -> Result<(), TaskError> {
+        for temp_file in self.temp_files.iter() {
+            let Some(file) = self.files.get(temp_file.index) else {
+                continue;
+            };
+            let file = file.lock().unwrap();
+            if let Err(e) = temp_file.commit(&file, self.task_id()) {
+                error!("task {} commit temp file failed: {}", self.task_id(), e);
+                sys_event!(
+                    ExecFault,
+                    DfxCode::TASK_FAULT_04,
+                    &format!("task {} commit temp file failed: {}", self.task_id(), e)
+                );
+                return Err(TaskError::Failed(Reason::IoError));
+            }
+        }
+        self.temp_committed.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Returns whether the downloaded files are in place, that is the task
+    /// has no temporary file or they have been committed.
+    pub(crate) fn temp_files_committed(&self) -> bool {
+        self.temp_files.is_empty() || self.temp_committed.load(Ordering::SeqCst)
+    }
+
     async fn prepare_download(&self) -> Result<(), TaskError> {
         if let Some(file) = self.files.get(0) {
             // Seek to the end of the file to get the current size (for resuming downloads)
@@ -221,6 +281,7 @@ impl RequestTask {
             progress.common_data.state = State::Running.repr; // Set task state to running
             progress.processed = vec![downloaded]; // Track processed bytes for
                                                    // the file
+            self.wire_processed.store(0, Ordering::SeqCst);
         } else {
             // Log and return error if no file is available
             error!("prepare_download err, no file in the task");
@@ -265,6 +326,17 @@ pub(crate) async fn download_inner(
     // Log that the download has started
     info!("{} downloading", task.task_id());
 
+    // Continue a segmented download recorded by an earlier attempt
+    let segments = segment::query_segments(task.task_id());
+
+    // Start hashing for integrity verification, including any resumed prefix
+    integrity::prepare_digest(&task, !segments.is_empty()).await?;
+
+    if !segments.is_empty() {
+        segment::download_segments(task.clone(), abort_flag, segments, None).await?;
+        return finish_download(&task).await;
+    }
+
     // Build the HTTP request for downloading
     let request = RequestTask::build_download_request(task.clone()).await?;
 
@@ -274,8 +346,7 @@ pub(crate) async fn download_inner(
 
     // Acquire the client lock and send the request
     // Send HTTP request and handle response with detailed error categorization
-    let client = task.client.lock().await;
-    let response = client.request(request).await;
+    let response = task.client.lock().await.request(request).await;
 
     // Handle response and categorize errors based on status codes and error types
     match response.as_ref() {
@@ -286,20 +357,31 @@ pub(crate) async fn download_inner(
             task.notify_response(response);
             info!("{} response {}", task.conf.common_data.task_id, status_code);
 
+            // Retry once with renewed credentials when they are rejected, the
+            // client being released while the application is asked for them
+            if task.renew_auth(response).await {
+                return Err(TaskError::Waiting(TaskPhase::NeedRetry));
+            }
+
+            // Without a retry policy, timeouts are retried below
+            let legacy_timeout =
+                status_code.as_u16() == 408 && !task.conf.retry_policy.is_enabled();
+
             // Handle protocol errors (server errors, most client errors, and redirects)
             if status_code.is_server_error()
-                || (status_code.as_u16() != 408 && status_code.is_client_error())
+                || (!legacy_timeout && status_code.is_client_error())
                 || status_code.is_redirection()
             {
                 super::http_error_registry::set_http_status_code(
                     task.conf.common_data.task_id,
                     status_code.as_u16(),
                 );
+                task.record_retry_status(response);
                 return Err(TaskError::Failed(Reason::ProtocolError));
             }
 
             // Handle timeout errors with retry logic
-            if status_code.as_u16() == 408 {
+            if legacy_timeout {
                 if task.timeout_tries.load(Ordering::SeqCst) < 2 {
                     // Retry up to 2 times for timeout errors
                     task.timeout_tries.fetch_add(1, Ordering::SeqCst);
@@ -349,6 +431,16 @@ pub(crate) async fn download_inner(
             // Log the error for debugging purposes
             error!("Task {} {:?}", task.task_id(), e);
 
+            // The client certificate may be rejected during or after the handshake
+            if client_cert_rejected(&task.conf, e) {
+                sys_event!(
+                    ExecFault,
+                    DfxCode::TASK_FAULT_07,
+                    &format!("Task {} {:?}", task.task_id(), e)
+                );
+                return Err(TaskError::Failed(Reason::ClientCertRejected));
+            }
+
             // Categorize errors based on their type for appropriate handling
             match e.error_kind() {
                 ErrorKind::Timeout => {
@@ -441,6 +533,8 @@ pub(crate) async fn download_inner(
         }
     };
 
+    // Hold the client while the body is transferred
+    let client = task.client.lock().await;
     let response = response.unwrap();
     {
         let mut guard = task.progress.lock().unwrap();
@@ -451,11 +545,40 @@ pub(crate) async fn download_inner(
             }
         }
     }
+
+    // A HEAD response carries headers only, which have been reported already
+    if task.is_head_request() {
+        return finish_download(&task).await;
+    }
+
     task.get_file_info(&response)?;
     task.update_progress_in_database();
     RequestDb::get_instance()
         .update_task_sizes(task.task_id(), &task.progress.lock().unwrap().sizes);
 
+    // Wait for storage space rather than failing midway through the body
+    task.check_storage_space(&response).await?;
+
+    // Decode an encoded body on the fly, which cannot be split into ranges
+    let decoder = task
+        .response_decoder(&response)
+        .map(|d| Arc::new(Mutex::new(d)));
+
+    // Split the body into concurrently fetched segments when possible
+    if decoder.is_some() {
+        info!(
+            "task {} decodes {:?} body",
+            task.task_id(),
+            ContentCoding::of_response(&response)
+        );
+    } else if let Some(segments) = segment::plan_segments(&task, &response) {
+        // Segment requests share the client with this one
+        drop(client);
+        segment::record_segments(task.task_id(), &segments);
+        segment::download_segments(task.clone(), abort_flag, segments, Some(response)).await?;
+        return finish_download(&task).await;
+    }
+
     #[cfg(feature = "oh")]
     let _trace = Trace::new(&format!(
         "download file tid:{} size:{}",
@@ -470,17 +593,47 @@ pub(crate) async fn download_inner(
                 &0
             })
     ));
-    let mut downloader = build_downloader(task.clone(), response, abort_flag);
+    let mut downloader = build_downloader(task.clone(), response, abort_flag, decoder.clone());
 
     if let Err(e) = downloader.download().await {
         return task.handle_download_error(e).await;
     }
 
+    // The body may end before the encoded stream does
+    if let Some(decoder) = decoder {
+        if !decoder.lock().unwrap().is_finished() {
+            error!("task {} encoded body truncated", task.task_id());
+            sys_event!(
+                ExecFault,
+                DfxCode::TASK_FAULT_09,
+                &format!("task {} encoded body truncated", task.task_id())
+            );
+            return Err(TaskError::Failed(Reason::OthersError));
+        }
+    }
+
+    finish_download(&task).await
+}
+
+/// Flushes the downloaded file, moves it into place and records its final
+/// size.
+///
+/// # Errors
+///
+/// Returns a `TaskError` if the file cannot be synced or moved, or no longer
+/// exists.
+async fn finish_download(task: &Arc<RequestTask>) -> Result<(), TaskError> {
     let file_mutex = task.files.get(0).unwrap();
     task_control::file_sync_all(file_mutex).await?;
 
+    integrity::verify_digest(task).await?;
+    let commit_task = task.clone();
+    match task_control::runtime_spawn_blocking(move || Ok(commit_task.commit_temp_files())).await {
+        Ok(Ok(res)) => res?,
+        _ => return Err(TaskError::Failed(Reason::IoError)),
+    }
     #[cfg(not(test))]
-    check_file_exist(&task)?;
+    check_file_exist(task)?;
     {
         let mut guard = task.progress.lock().unwrap();
         guard.sizes = vec![guard.processed.first().map_or_else(
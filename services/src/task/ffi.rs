@@ -18,8 +18,8 @@
 //! updates.
 
 use super::config::{
-    Action, CommonTaskConfig, ConfigSet, MinSpeed, Mode, NetworkConfig, TaskConfig, Timeout,
-    Version,
+    Action, Auth, CommonTaskConfig, ConfigSet, MinSpeed, Mode, NetworkConfig, Recurrence,
+    RetryPolicy, TaskConfig, Timeout, Version,
 };
 use super::info::{CommonTaskInfo, InfoSet, TaskInfo, UpdateInfo};
 use super::notify::{CommonProgress, Progress};
@@ -611,6 +611,21 @@ impl TaskConfig {
                     total_timeout: c_struct.common_data.timeout.total_timeout,
                 },
             },
+            // Not part of the C layout, loaded from the `task_config_ext` table
+            segments: 0,
+            digest: "".to_string(),
+            chunk_size: 0,
+            retry_policy: RetryPolicy::default(),
+            prerequisites: vec![],
+            not_before: 0,
+            recurrence: Recurrence::Once,
+            expected_size: 0,
+            preallocate: false,
+            compression: false,
+            cookie_jar: false,
+            auth: Auth::None,
+            client_cert_path: "".to_string(),
+            client_key_path: "".to_string(),
         };
 
         // Under OH feature flag, free C-allocated memory to prevent memory leaks
@@ -35,7 +35,10 @@ use std::sync::{Arc, Mutex};
 // afterwards cannot redirect it.
 const O_NOFOLLOW: i32 = 0o400_000;
 const O_CLOEXEC: i32 = 0o2_000_000;
+// O_DIRECTORY: fail unless the path names a directory.
+const O_DIRECTORY: i32 = 0o200_000;
 
+use crate::database::temp_file;
 use crate::error::{ErrorCode, ServiceError};
 use crate::manage::account;
 use crate::task::bundle::get_name_and_index;
@@ -54,6 +57,8 @@ pub(crate) struct AttachedFiles {
     pub(crate) sizes: Vec<i64>,
     /// Additional body files for complex request scenarios.
     pub(crate) body_files: Files,
+    /// Temporary files the downloaded files are written to.
+    pub(crate) temp_files: Vec<TempFile>,
 }
 
 impl AttachedFiles {
@@ -66,12 +71,13 @@ impl AttachedFiles {
     /// # Errors
     /// Returns a `ServiceError` if any file fails to open.
     pub(crate) fn open(config: &TaskConfig) -> Result<AttachedFiles, ServiceError> {
-        let (files, sizes) = open_task_files(config)?;
+        let (files, sizes, temp_files) = open_task_files(config)?;
         let body_files = open_body_files(config)?;
         Ok(Self {
             files,
             sizes,
             body_files,
+            temp_files,
         })
     }
 }
@@ -79,16 +85,18 @@ impl AttachedFiles {
 /// Opens the main task files based on the provided configuration.
 ///
 /// Handles both upload and download scenarios, opening files in appropriate
-/// modes and collecting their sizes where applicable.
+/// modes and collecting their sizes where applicable. Downloads to the app
+/// storage are written to a sibling temporary file, see [`TempFile`].
 ///
 /// # Errors
 /// Returns a `ServiceError` if file opening or metadata retrieval fails.
-fn open_task_files(config: &TaskConfig) -> Result<(Files, Vec<i64>), ServiceError> {
+fn open_task_files(config: &TaskConfig) -> Result<(Files, Vec<i64>, Vec<TempFile>), ServiceError> {
     let tid = config.common_data.task_id;
     let uid = config.common_data.uid;
 
     let mut files = Vec::new();
     let mut sizes = Vec::new();
+    let mut temp_files = Vec::new();
     // Cache bundle name to avoid redundant calculations for multiple files
     let mut bundle_cache = BundleCache::new(config);
 
@@ -154,10 +162,13 @@ fn open_task_files(config: &TaskConfig) -> Result<(Files, Vec<i64>), ServiceErro
                         }
                     }
                 } else {
-                    // For non-user files, open from the app's storage in read-write mode
+                    // For non-user files, write to a temporary file in the app's storage
                     let bundle_name = bundle_cache.get_value()?;
-                    open_file_readwrite(uid, &bundle_name, &fs.path)
-                        .map_err(ServiceError::IoError)?
+                    let (file, temp_file) = open_temp_file(uid, &bundle_name, &fs.path, tid, idx)
+                        .map_err(ServiceError::IoError)?;
+                    temp_file::store(tid, idx as u32, &temp_file.base, &temp_file.path);
+                    temp_files.push(temp_file);
+                    file
                 };
                 // Use Arc<Mutex<File>> to ensure thread-safe access
                 files.push(Arc::new(Mutex::new(file)));
@@ -167,7 +178,7 @@ fn open_task_files(config: &TaskConfig) -> Result<(Files, Vec<i64>), ServiceErro
             _ => unreachable!("Action::Any in open_task_files should never reach"),
         }
     }
-    Ok((Files::new(files), sizes))
+    Ok((Files::new(files), sizes, temp_files))
 }
 
 /// Opens additional body files specified in the task configuration.
@@ -276,6 +287,185 @@ fn app_base_and_path(uid: u64, bundle_name: &str, path: &str) -> io::Result<(Str
     }
 }
 
+/// Returns the app base directory the file of a download task is stored
+/// under, used to query the free space of its filesystem.
+///
+/// Returns `None` for files passed by descriptor, or when the path or the
+/// bundle of the task cannot be resolved.
+pub(crate) fn download_base_dir(config: &TaskConfig) -> Option<String> {
+    let fs = config.file_specs.first()?;
+    if fs.is_user_file {
+        return None;
+    }
+    let bundle_name = convert_bundle_name(config).ok()?;
+    app_base_and_path(config.common_data.uid, &bundle_name, &fs.path)
+        .ok()
+        .map(|(base, _)| base)
+}
+
+/// Temporary file a download is written to, next to its destination file.
+///
+/// Other readers never see a partially downloaded destination file: the
+/// temporary file is renamed over it only once the download has completed,
+/// and the temporary files of tasks that do not complete are deleted by the
+/// database maintenance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TempFile {
+    /// App base directory both files lie under.
+    pub(crate) base: String,
+    /// Real path of the destination file.
+    pub(crate) path: String,
+    /// Index of the file in the task.
+    pub(crate) index: usize,
+    /// Name of the temporary file, in the directory of the destination file.
+    name: String,
+}
+
+impl TempFile {
+    /// Describes the temporary file of the `index`th file of a task, whose
+    /// destination file is `path` under `base`.
+    pub(crate) fn new(base: String, path: String, task_id: u32, index: usize) -> Self {
+        Self {
+            base,
+            path,
+            index,
+            name: temp_file_name(task_id, index),
+        }
+    }
+
+    /// Syncs `file`, the opened temporary file, and renames it over the
+    /// destination file.
+    ///
+    /// # Errors
+    /// Returns an `io::Error` if the file cannot be synced or renamed, or if
+    /// its directory fails the sandbox check.
+    pub(crate) fn commit(&self, file: &File, task_id: u32) -> io::Result<()> {
+        file.sync_all()?;
+        let (dir, dest) = split_parent(&self.path)?;
+        let dir = open_dir_within_base(&self.base, dir)?;
+        let dir_path = format!("/proc/self/fd/{}", dir.as_raw_fd());
+        std::fs::rename(
+            format!("{}/{}", dir_path, self.name),
+            format!("{}/{}", dir_path, dest),
+        )?;
+        // Persists the rename itself
+        dir.sync_all()?;
+        temp_file::remove(task_id, self.index as u32);
+        Ok(())
+    }
+
+    /// Deletes the temporary file of a task that will not complete.
+    ///
+    /// # Errors
+    /// Returns an `io::Error` if the file cannot be deleted, or if its
+    /// directory fails the sandbox check.
+    pub(crate) fn discard(&self) -> io::Result<()> {
+        let (dir, _) = split_parent(&self.path)?;
+        let dir = open_dir_within_base(&self.base, dir)?;
+        match std::fs::remove_file(format!("/proc/self/fd/{}/{}", dir.as_raw_fd(), self.name)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns the name of the temporary file of the `index`th file of a task.
+///
+/// The name is hidden and derived from the task, so that a resumed download
+/// reopens the temporary file it was writing to.
+pub(crate) fn temp_file_name(task_id: u32, index: usize) -> String {
+    match index {
+        0 => format!(".request_{}.download", task_id),
+        index => format!(".request_{}_{}.download", task_id, index),
+    }
+}
+
+/// Opens or creates the temporary file of a download next to its destination
+/// file at the specified path.
+///
+/// The directory of the destination file is opened first and verified to lie
+/// under the app base directory, then the temporary file is created through
+/// the descriptor of that directory, with the same protections as
+/// [`open_file_readwrite`]. The temporary file is opened in append mode, so
+/// that an interrupted download resumes from its end.
+///
+/// # Errors
+/// Returns an `io::Error` if the file cannot be opened or fails the sandbox
+/// check.
+fn open_temp_file(
+    uid: u64,
+    bundle_name: &str,
+    path: &str,
+    task_id: u32,
+    index: usize,
+) -> io::Result<(File, TempFile)> {
+    let (base, full) = app_base_and_path(uid, bundle_name, path)?;
+    let (dir, dest) = split_parent(&full)?;
+    let dir_file = open_dir_within_base(&base, dir)?;
+    let temp_file = TempFile::new(base, full.clone(), task_id, index);
+    let dir_path = format!("/proc/self/fd/{}", dir_file.as_raw_fd());
+    let file = OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .custom_flags(O_NOFOLLOW | O_CLOEXEC)
+        .open(format!("{}/{}", dir_path, temp_file.name))?;
+    // The renamed file keeps the permissions the client gave its destination
+    if let Ok(metadata) = std::fs::symlink_metadata(format!("{}/{}", dir_path, dest)) {
+        if metadata.is_file() {
+            let _ = file.set_permissions(metadata.permissions());
+        }
+    }
+    let file = cvt_res_error!(
+        verify_within_base(&temp_file.base, file),
+        "open_temp_file failed"
+    );
+    Ok((file, temp_file))
+}
+
+/// Splits a real path into its directory and file name.
+///
+/// # Errors
+/// Returns an `io::Error` if the path has no directory or no file name.
+fn split_parent(path: &str) -> io::Result<(&str, &str)> {
+    match path.rsplit_once('/') {
+        Some((dir, name)) if !dir.is_empty() && !name.is_empty() && name != "." && name != ".." => {
+            Ok((dir, name))
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "path has no directory or file name",
+        )),
+    }
+}
+
+/// Opens a directory and verifies that it is the app base directory or lies
+/// under it.
+///
+/// As for [`verify_within_base`], the check is made on the opened descriptor,
+/// so that paths built on `/proc/self/fd/<fd>` keep resolving to the verified
+/// directory.
+///
+/// # Errors
+/// Returns an `io::Error` if the directory cannot be opened or escaped the
+/// base.
+fn open_dir_within_base(base: &str, dir: &str) -> io::Result<File> {
+    let dir = OpenOptions::new()
+        .read(true)
+        .custom_flags(O_DIRECTORY | O_NOFOLLOW | O_CLOEXEC)
+        .open(dir)?;
+    let real_base = std::fs::canonicalize(base)?;
+    let real_dir = std::fs::read_link(format!("/proc/self/fd/{}", dir.as_raw_fd()))?;
+    if real_dir.starts_with(&real_base) {
+        Ok(dir)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "directory escaped the app base directory",
+        ))
+    }
+}
+
 /// Verifies that an already-opened file lies strictly under the app base
 /// directory, returning it unchanged on success.
 ///
@@ -678,4 +868,92 @@ mod tests {
         let err = verify_within_base(&base, file).unwrap_err();
         assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
     }
+
+    // @tc.name: temp_file_name_is_hidden_and_per_file
+    // @tc.desc: Test that temp file names are hidden and distinct per file
+    // @tc.precon: NA
+    // @tc.step: 1. Build the temp file names of two files of a task
+    // @tc.expect: Names start with a dot and differ
+    // @tc.type: FUNC
+    #[test]
+    fn temp_file_name_is_hidden_and_per_file() {
+        assert_eq!(temp_file_name(123, 0), ".request_123.download");
+        assert_eq!(temp_file_name(123, 1), ".request_123_1.download");
+    }
+
+    // @tc.name: split_parent_rejects_bare_names
+    // @tc.desc: Test that split_parent requires a directory and a file name
+    // @tc.precon: NA
+    // @tc.step: 1. Split valid and invalid paths
+    // @tc.expect: Only paths with both a directory and a file name pass
+    // @tc.type: FUNC
+    #[test]
+    fn split_parent_rejects_bare_names() {
+        assert_eq!(split_parent("/a/b/c.txt").unwrap(), ("/a/b", "c.txt"));
+        assert!(split_parent("c.txt").is_err());
+        assert!(split_parent("/c.txt").is_err());
+        assert!(split_parent("/a/b/").is_err());
+        assert!(split_parent("/a/..").is_err());
+    }
+
+    // @tc.name: temp_file_is_created_beside_destination
+    // @tc.desc: Test that the temp file is created next to its destination and
+    //           discarded without touching it
+    // @tc.precon: NA
+    // @tc.step: 1. Create a destination file under base 2. Create its temp file
+    //           through the verified directory 3. Discard it
+    // @tc.expect: The temp file exists until discarded, the destination keeps
+    //             its content
+    // @tc.type: FUNC
+    #[test]
+    fn temp_file_is_created_beside_destination() {
+        let dir = TestDir::new("temp_file");
+        let base = dir.base();
+        fs::create_dir_all(format!("{}/files", base)).unwrap();
+        let dest = format!("{}/files/a.txt", base);
+        fs::write(&dest, b"old").unwrap();
+
+        let temp_file = TempFile::new(base.clone(), dest.clone(), 7, 0);
+        let dir_file = open_dir_within_base(&base, &format!("{}/files", base)).unwrap();
+        let mut file = OpenOptions::new()
+            .append(true)
+            .create(true)
+            .custom_flags(O_NOFOLLOW | O_CLOEXEC)
+            .open(format!(
+                "/proc/self/fd/{}/{}",
+                dir_file.as_raw_fd(),
+                temp_file.name
+            ))
+            .unwrap();
+        file.write_all(b"new").unwrap();
+        let temp_path = format!("{}/files/{}", base, temp_file_name(7, 0));
+        assert_eq!(fs::read(&temp_path).unwrap(), b"new");
+
+        temp_file.discard().unwrap();
+        assert!(fs::metadata(&temp_path).is_err());
+        assert_eq!(fs::read(&dest).unwrap(), b"old");
+        // Discarding twice is not an error
+        temp_file.discard().unwrap();
+    }
+
+    // @tc.name: directory_outside_base_is_rejected
+    // @tc.desc: Test that a directory reached through a symlink out of the base
+    //           is rejected
+    // @tc.precon: NA
+    // @tc.step: 1. Plant a symlink dir under base 2. Open a directory through it
+    // @tc.expect: Fails with PermissionDenied, the base itself passes
+    // @tc.type: FUNC
+    #[test]
+    fn directory_outside_base_is_rejected() {
+        let dir = TestDir::new("temp_dir_symlink");
+        let base = dir.base();
+        let outside = format!("{}/outside", dir.0);
+        fs::create_dir_all(format!("{}/inner", outside)).unwrap();
+        fs::create_dir_all(&base).unwrap();
+        symlink(&outside, format!("{}/link", base)).unwrap();
+
+        let err = open_dir_within_base(&base, &format!("{}/link/inner", base)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        assert!(open_dir_within_base(&base, &base).is_ok());
+    }
 }
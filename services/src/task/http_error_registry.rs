@@ -43,6 +43,17 @@ impl HttpErrorRegistry {
     pub(crate) fn take(&self, task_id: u32) -> u16 {
         self.codes.lock().unwrap().remove(&task_id).unwrap_or(0)
     }
+
+    /// Returns the HTTP status code recorded for a task without consuming it.
+    /// Returns 0 if no code was recorded.
+    pub(crate) fn peek(&self, task_id: u32) -> u16 {
+        self.codes
+            .lock()
+            .unwrap()
+            .get(&task_id)
+            .copied()
+            .unwrap_or(0)
+    }
 }
 
 static HTTP_ERROR_REGISTRY: LazyLock<HttpErrorRegistry> =
@@ -62,6 +73,12 @@ pub(crate) fn take_http_status_code(task_id: u32) -> u16 {
     HTTP_ERROR_REGISTRY.take(task_id)
 }
 
+/// Returns the HTTP status code recorded for a task without consuming it, for
+/// diagnostics. Returns 0 if no code was recorded.
+pub(crate) fn peek_http_status_code(task_id: u32) -> u16 {
+    HTTP_ERROR_REGISTRY.peek(task_id)
+}
+
 /// Returns the canonical HTTP reason phrase for a given status code.
 /// Uses `ylong_http::StatusCode` to avoid duplicating the status code table.
 pub(crate) fn http_status_reason_phrase(code: u16) -> &'static str {
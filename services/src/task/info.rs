@@ -22,8 +22,8 @@ use std::collections::HashMap;
 /// Task state enumeration.
 pub use ffi::State;
 
-use super::notify::{EachFileStatus, NotifyData, Progress};
-use crate::task::config::{Action, Version};
+use super::notify::{EachFileStatus, NotifyData, Progress, WaitingCause};
+use crate::task::config::{Action, Mode, Version};
 use crate::task::reason::Reason;
 use crate::utils::c_wrapper::{CFileSpec, CFormItem};
 use crate::utils::form_item::{FileSpec, FormItem};
@@ -295,7 +295,7 @@ pub(crate) struct DumpAllInfo {
     pub(crate) vec: Vec<DumpAllEachInfo>,
 }
 
-/// Contains minimal information for a single task in a dump.
+/// Contains the diagnostic information of a single task in a dump.
 #[derive(Debug)]
 pub(crate) struct DumpAllEachInfo {
     /// Task identifier.
@@ -306,19 +306,171 @@ pub(crate) struct DumpAllEachInfo {
     pub(crate) state: State,
     /// Reason for current state.
     pub(crate) reason: Reason,
+    /// User ID that owns the task.
+    pub(crate) uid: u64,
+    /// Bundle name of the application that created the task.
+    pub(crate) bundle: String,
+    /// Host of the task URL.
+    pub(crate) host: String,
+    /// Task mode.
+    pub(crate) mode: Mode,
+    /// Task priority.
+    pub(crate) priority: u32,
+    /// QoS level assigned by the scheduler, `None` when not running.
+    pub(crate) qos: Option<&'static str>,
+    /// Number of bytes processed.
+    pub(crate) processed: u64,
+    /// Total number of bytes to process, -1 if unknown.
+    pub(crate) total: i64,
+    /// Current speed in bytes per second.
+    pub(crate) speed: u64,
+    /// Number of retries made.
+    pub(crate) retries: u32,
+    /// HTTP status of the last failed response, 0 if none.
+    pub(crate) http_status: u16,
+    /// Cause the task is waiting for, `None` when not waiting.
+    pub(crate) waiting: Option<WaitingCause>,
+    /// Every cause the task is waiting for, empty when not waiting.
+    pub(crate) causes: Vec<WaitingCause>,
 }
 
-/// Contains detailed information for a single task dump.
-#[derive(Debug)]
-pub(crate) struct DumpOneInfo {
-    /// Task identifier.
-    pub(crate) task_id: u32,
-    /// Action type (download/upload).
-    pub(crate) action: Action,
-    /// Current task state.
-    pub(crate) state: State,
-    /// Reason for current state.
-    pub(crate) reason: Reason,
+/// Filter applied to the tasks of a dump.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct DumpFilter {
+    /// Only dump the tasks of this user ID.
+    pub(crate) uid: Option<u64>,
+    /// Only dump the tasks in this state.
+    pub(crate) state: Option<State>,
+    /// Only dump the tasks with this action.
+    pub(crate) action: Option<Action>,
+}
+
+impl DumpFilter {
+    /// Returns whether the task passes the filter.
+    pub(crate) fn matches(&self, info: &DumpAllEachInfo) -> bool {
+        self.uid.map_or(true, |uid| uid == info.uid)
+            && self.state.map_or(true, |state| state == info.state)
+            && self.action.map_or(true, |action| action == info.action)
+    }
+}
+
+impl DumpAllEachInfo {
+    /// Header of the dump table.
+    pub(crate) fn table_header() -> String {
+        format!(
+            "{:<12}{:<12}{:<32}{:<24}{:<10}{:<12}{:<10}{:<12}{:<8}{:<8}{:<14}{:<14}{:<12}{:<9}{:<6}{:<10}\n",
+            "id",
+            "uid",
+            "bundle",
+            "host",
+            "action",
+            "mode",
+            "priority",
+            "state",
+            "reason",
+            "qos",
+            "processed",
+            "total",
+            "speed",
+            "retries",
+            "http",
+            "waiting"
+        )
+    }
+
+    /// Formats the task as a row of the dump table.
+    pub(crate) fn table_row(&self) -> String {
+        format!(
+            "{:<12}{:<12}{:<32}{:<24}{:<10}{:<12}{:<10}{:<12}{:<8}{:<8}{:<14}{:<14}{:<12}{:<9}{:<6}{:<10}\n",
+            self.task_id,
+            self.uid,
+            self.bundle,
+            self.host,
+            debug_name(&self.action),
+            debug_name(&self.mode),
+            self.priority,
+            debug_name(&self.state),
+            self.reason.repr,
+            self.qos.unwrap_or("-"),
+            self.processed,
+            self.total,
+            self.speed,
+            self.retries,
+            self.http_status,
+            self.waiting.as_ref().map_or("-".to_string(), debug_name)
+        )
+    }
+
+    /// Formats the task as a JSON object.
+    pub(crate) fn to_json(&self) -> String {
+        format!(
+            "{{\"id\":{},\"uid\":{},\"bundle\":\"{}\",\"host\":\"{}\",\"action\":\"{:?}\",\
+             \"mode\":\"{:?}\",\"priority\":{},\"state\":\"{:?}\",\"reason\":{},\
+             \"reason_msg\":\"{}\",\"qos\":{},\"processed\":{},\"total\":{},\"speed\":{},\
+             \"retries\":{},\"http_status\":{},\"waiting\":{},\"causes\":[{}]}}",
+            self.task_id,
+            self.uid,
+            json_escape(&self.bundle),
+            json_escape(&self.host),
+            self.action,
+            self.mode,
+            self.priority,
+            self.state,
+            self.reason.repr,
+            self.reason.to_str(),
+            self.qos
+                .map_or("null".to_string(), |qos| format!("\"{}\"", qos)),
+            self.processed,
+            self.total,
+            self.speed,
+            self.retries,
+            self.http_status,
+            self.waiting
+                .as_ref()
+                .map_or("null".to_string(), |cause| format!("\"{:?}\"", cause)),
+            self.causes
+                .iter()
+                .map(|cause| format!("\"{:?}\"", cause))
+                .collect::<Vec<_>>()
+                .join(",")
+        )
+    }
+}
+
+/// Returns the host, with its port, of `url`, leaving out any user
+/// information.
+pub(crate) fn url_host(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let authority = rest
+        .split(|c| c == '/' || c == '?' || c == '#')
+        .next()
+        .unwrap_or("");
+    authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host)
+}
+
+/// Returns the `Debug` representation of `value`, which unlike the
+/// representation itself can be padded in a table.
+fn debug_name(value: &impl std::fmt::Debug) -> String {
+    format!("{:?}", value)
+}
+
+/// Escapes `s` for use in a JSON string.
+fn json_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 #[cfg(test)]
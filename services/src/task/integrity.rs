@@ -0,0 +1,215 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integrity verification of downloaded files.
+//!
+//! When a task is configured with an expected digest, the downloaded bytes are
+//! hashed as they are written to the file. A resumed download first re-hashes
+//! the prefix already on disk, and a segmented download, whose writes are out
+//! of order, feeds the digest as the contiguous prefix of the file grows. The
+//! final digest is compared against the expected one before the task is
+//! reported completed.
+
+use std::fs::File;
+use std::io;
+use std::os::unix::fs::FileExt;
+use std::sync::Arc;
+
+use request_utils::hash::Sha256;
+
+use super::reason::Reason;
+use super::request_task::{RequestTask, TaskError};
+use super::task_control::runtime_spawn_blocking;
+
+const SHA256_PREFIX: &str = "sha256:";
+const SHA256_HEX_LEN: usize = 64;
+const HASH_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Parses a configured digest into a lowercase SHA-256 hex string.
+///
+/// Accepts either `sha256:<hex>` or a plain SHA-256 hex string. Returns `None`
+/// if the digest uses another algorithm or is malformed.
+pub(crate) fn parse_digest(digest: &str) -> Option<String> {
+    let hex = match digest.get(..SHA256_PREFIX.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(SHA256_PREFIX) => {
+            &digest[SHA256_PREFIX.len()..]
+        }
+        _ => digest,
+    };
+    if hex.len() != SHA256_HEX_LEN || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some(hex.to_ascii_lowercase())
+}
+
+/// Running digest of a downloaded file.
+pub(crate) struct RunningDigest {
+    hasher: Sha256,
+    /// Number of bytes from the start of the file fed into the digest.
+    hashed: u64,
+}
+
+impl RunningDigest {
+    /// Creates a digest covering no bytes.
+    pub(crate) fn new() -> Self {
+        Self {
+            hasher: Sha256::new(),
+            hashed: 0,
+        }
+    }
+
+    /// Feeds the bytes written at `offset`, if they directly follow those
+    /// already hashed.
+    fn feed(&mut self, offset: u64, data: &[u8]) {
+        if offset == self.hashed {
+            self.hasher.update(data);
+            self.hashed += data.len() as u64;
+        }
+    }
+
+    /// Feeds the bytes of `file` between those already hashed and `end`.
+    fn catch_up(&mut self, file: &File, end: u64) -> io::Result<()> {
+        let mut buf = vec![0u8; HASH_BUFFER_SIZE];
+        while self.hashed < end {
+            let len = (end - self.hashed).min(HASH_BUFFER_SIZE as u64) as usize;
+            let size = file.read_at(&mut buf[..len], self.hashed)?;
+            if size == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "file shorter than the hashed range",
+                ));
+            }
+            self.hasher.update(&buf[..size]);
+            self.hashed += size as u64;
+        }
+        Ok(())
+    }
+}
+
+impl RequestTask {
+    /// Feeds bytes appended to the downloaded file into the running digest,
+    /// if any.
+    pub(crate) fn update_digest(&self, data: &[u8]) {
+        if let Some(digest) = self.hasher.lock().unwrap().as_mut() {
+            let offset = digest.hashed;
+            digest.feed(offset, data);
+        }
+    }
+
+    /// Feeds bytes a segment wrote at `offset` into the running digest, then
+    /// the bytes of `file` up to `prefix_end`, the end of the contiguous
+    /// downloaded prefix.
+    pub(crate) fn update_segment_digest(
+        &self,
+        file: &File,
+        offset: u64,
+        data: &[u8],
+        prefix_end: u64,
+    ) -> io::Result<()> {
+        if let Some(digest) = self.hasher.lock().unwrap().as_mut() {
+            digest.feed(offset, data);
+            digest.catch_up(file, prefix_end)?;
+        }
+        Ok(())
+    }
+
+    /// Restarts the running digest after the downloaded file is truncated.
+    pub(crate) fn reset_digest(&self) {
+        let mut hasher = self.hasher.lock().unwrap();
+        if hasher.is_some() {
+            *hasher = Some(RunningDigest::new());
+        }
+    }
+}
+
+/// Starts the running digest of a download, re-hashing the bytes already
+/// written by an earlier attempt.
+///
+/// Unless the download continues in segments, whose writes catch the digest
+/// up with the contiguous prefix, the whole file is hashed from the start
+/// before new bytes are appended.
+pub(crate) async fn prepare_digest(
+    task: &Arc<RequestTask>,
+    segmented: bool,
+) -> Result<(), TaskError> {
+    if task.conf.digest.is_empty() {
+        return Ok(());
+    }
+    let task = task.clone();
+    runtime_spawn_blocking(move || {
+        let mut digest = RunningDigest::new();
+        if !segmented {
+            if let Some(file) = task.files.get(0) {
+                let file = file.lock().unwrap();
+                let len = file.metadata()?.len();
+                digest.catch_up(&file, len)?;
+            }
+        }
+        *task.hasher.lock().unwrap() = Some(digest);
+        Ok::<(), io::Error>(())
+    })
+    .await
+    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))??;
+    Ok(())
+}
+
+/// Checks the downloaded file against the expected digest.
+///
+/// Completes the running digest with any bytes of the file it does not cover
+/// yet, or hashes the whole file when no digest is kept.
+///
+/// # Errors
+///
+/// Returns `TaskError::Failed(Reason::IntegrityMismatch)` if the digests
+/// differ.
+pub(crate) async fn verify_digest(task: &Arc<RequestTask>) -> Result<(), TaskError> {
+    let expected = match parse_digest(&task.conf.digest) {
+        Some(expected) => expected,
+        None => return Ok(()),
+    };
+    let task_clone = task.clone();
+    let actual = runtime_spawn_blocking(move || {
+        let task = task_clone;
+        let file = match task.files.get(0) {
+            Some(file) => file,
+            None => return Err(io::Error::new(io::ErrorKind::Other, "no file in the task")),
+        };
+        let file = file.lock().unwrap();
+        let len = file.metadata()?.len();
+        let mut digest = match task.hasher.lock().unwrap().take() {
+            Some(digest) if digest.hashed <= len => digest,
+            _ => RunningDigest::new(),
+        };
+        digest.catch_up(&file, len)?;
+        Ok(digest.hasher.finish())
+    })
+    .await
+    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))??;
+
+    if actual != expected {
+        error!(
+            "task {} digest mismatch, expected {} actual {}",
+            task.task_id(),
+            expected,
+            actual
+        );
+        return Err(TaskError::Failed(Reason::IntegrityMismatch));
+    }
+    info!("task {} digest verified", task.task_id());
+    Ok(())
+}
+
+#[cfg(test)]
+mod ut_integrity {
+    include!("../../tests/ut/task/ut_integrity.rs");
+}
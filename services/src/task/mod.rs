@@ -24,18 +24,29 @@ pub mod config;
 pub mod info;
 
 // Internal modules for task implementation
+pub(crate) mod auth; // Authentication of requests
+pub(crate) mod chunk_upload; // Resumable chunked uploads
+pub(crate) mod compression; // Compressed transfer of request bodies
+pub(crate) mod condition; // Conditions blocking waiting tasks
+pub(crate) mod cookie; // Cookie jars shared by the tasks of an application
 pub(crate) mod download; // Download task handling
 pub(crate) mod files; // File management utilities
 pub(crate) mod http_error_registry; // HTTP error code registry for protocol errors
+pub(crate) mod integrity; // Downloaded file integrity verification
 pub(crate) mod notify; // Notification and event handling
 mod operator; // Task operation implementations
 pub(crate) mod reason; // Error and state reason codes
+pub(crate) mod recurrence; // Deferred and recurring tasks
 pub(crate) mod request_task; // Core task abstraction
+pub(crate) mod retry; // Retry policy of failed attempts
+pub(crate) mod segment; // Segmented parallel downloads
+pub(crate) mod space; // Storage space preflight of downloads
 
 /// Constant representing atomic service identifier.
 pub(crate) const ATOMIC_SERVICE: u32 = 1;
 
 // Additional internal modules
+pub(crate) mod bandwidth; // Device and application bandwidth budgets
 pub(crate) mod bundle; // Bundle-related utilities
 pub(crate) mod client; // Client connection management
 pub(crate) mod ffi; // Foreign function interface bindings
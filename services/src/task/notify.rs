@@ -58,6 +58,32 @@ pub(crate) enum WaitingCause {
     AppState,
     /// Task is waiting due to user state constraints.
     UserState,
+    /// Task is waiting for enough storage space.
+    StorageSpace,
+    /// Task is waiting for its prerequisites to complete.
+    Prerequisite,
+    /// Task is waiting for its scheduled start time.
+    StartTime,
+    /// Task is waiting for the retry delay after a failure.
+    Retry,
+}
+
+impl WaitingCause {
+    /// Returns the cause a task waiting with `reason` is waiting for, or
+    /// `None` if `reason` is not a waiting reason.
+    pub(crate) fn from_reason(reason: u8) -> Option<Self> {
+        match Reason::from(reason) {
+            Reason::AppBackgroundOrTerminate => Some(WaitingCause::AppState),
+            Reason::NetworkOffline | Reason::UnsupportedNetworkType => Some(WaitingCause::Network),
+            Reason::RunningTaskMeetLimits => Some(WaitingCause::TaskQueue),
+            Reason::RetryWaiting => Some(WaitingCause::Retry),
+            Reason::PrerequisiteWaiting => Some(WaitingCause::Prerequisite),
+            Reason::StartTimeWaiting => Some(WaitingCause::StartTime),
+            Reason::AccountStopped => Some(WaitingCause::UserState),
+            Reason::StorageSpaceWaiting => Some(WaitingCause::StorageSpace),
+            _ => None,
+        }
+    }
 }
 
 /// Contains task notification data sent to subscribers.
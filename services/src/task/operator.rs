@@ -19,13 +19,14 @@
 use std::cmp::min;
 use std::io::Write;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 
 use ylong_http_client::HttpClientError;
 
 use crate::manage::notifier::Notifier;
 use crate::service::notification_bar::{NotificationDispatcher, NOTIFY_PROGRESS_INTERVAL};
+use crate::task::compression::Decoder;
 use crate::task::request_task::RequestTask;
 use crate::task::speed_limiter::SpeedLimiter;
 use crate::utils::get_current_timestamp;
@@ -44,6 +45,12 @@ pub(crate) struct TaskOperator {
     pub(crate) speed_limiter: SpeedLimiter,
     /// Flag to signal task abortion requests.
     pub(crate) abort_flag: Arc<AtomicBool>,
+    /// Decoder of an encoded download body, shared with the download to
+    /// check that the body is complete.
+    pub(crate) decoder: Option<Arc<Mutex<Decoder>>>,
+    /// Whether the transfer is measured by the encoded bytes counted in
+    /// `RequestTask::wire_processed` rather than by the processed content.
+    wire_counted: bool,
 }
 
 impl TaskOperator {
@@ -54,10 +61,42 @@ impl TaskOperator {
     /// * `task` - The task to operate on.
     /// * `abort_flag` - Flag to signal task abortion requests.
     pub(crate) fn new(task: Arc<RequestTask>, abort_flag: Arc<AtomicBool>) -> Self {
+        // Data processed before the operator exists is not charged to the
+        // bandwidth budgets.
+        let processed = task.progress.lock().unwrap().common_data.total_processed;
+        task.budget_charged
+            .store(processed as u64, Ordering::SeqCst);
         Self {
             task,
             speed_limiter: SpeedLimiter::default(),
             abort_flag,
+            decoder: None,
+            wire_counted: false,
+        }
+    }
+
+    /// Measures the transfer by its bytes on the wire, for an encoded body,
+    /// so that the speed limits and the bandwidth budgets apply to them.
+    pub(crate) fn count_wire(mut self) -> Self {
+        let wire_processed = self.task.wire_processed.load(Ordering::SeqCst);
+        self.task
+            .budget_charged
+            .store(wire_processed, Ordering::SeqCst);
+        self.wire_counted = true;
+        self
+    }
+
+    /// Returns the bytes transferred so far, as measured by the operator.
+    fn transferred(&self) -> u64 {
+        if self.wire_counted {
+            self.task.wire_processed.load(Ordering::SeqCst)
+        } else {
+            self.task
+                .progress
+                .lock()
+                .unwrap()
+                .common_data
+                .total_processed as u64
         }
     }
 
@@ -73,7 +112,8 @@ impl TaskOperator {
     /// # Returns
     ///
     /// - `Poll::Ready(Ok(()))` if ready to continue processing.
-    /// - `Poll::Pending` if the operation is blocked on speed limiting.
+    /// - `Poll::Pending` if the operation is blocked on speed limiting or on a
+    ///   bandwidth budget.
     /// - `Poll::Ready(Err(HttpClientError))` if the task was aborted.
     pub(crate) fn poll_progress_common(
         &mut self,
@@ -93,6 +133,7 @@ impl TaskOperator {
             // Build and send notification data
             let notify_data = self.task.build_notify_data();
             self.task.last_notify.store(current, Ordering::SeqCst);
+            self.task.sample_speed(current);
             Notifier::progress(&self.task.client_manager, notify_data);
         }
 
@@ -108,13 +149,7 @@ impl TaskOperator {
         }
 
         // Apply speed limiting
-        let total_processed = self
-            .task
-            .progress
-            .lock()
-            .unwrap()
-            .common_data
-            .total_processed as u64;
+        let transferred = self.transferred();
 
         let rate_limiting = self.task.rate_limiting.load(Ordering::SeqCst);
         let max_speed = self.task.max_speed.load(Ordering::SeqCst) as u64;
@@ -127,8 +162,22 @@ impl TaskOperator {
         };
 
         self.speed_limiter.update_speed_limit(speed_limit);
-        self.speed_limiter
-            .poll_check_limit(cx, current, total_processed)
+        if let Poll::Pending = self
+            .speed_limiter
+            .poll_check_limit(cx, current, transferred)
+        {
+            return Poll::Pending;
+        }
+
+        // Apply the bandwidth budgets of the device and the application. The
+        // charged size is shared by all operators of the task.
+        let charged = self.task.budget_charged.swap(transferred, Ordering::SeqCst);
+        self.speed_limiter.poll_check_budget(
+            cx,
+            self.task.uid(),
+            current,
+            transferred.saturating_sub(charged),
+        )
     }
 
     /// Polls for file writing operations.
@@ -178,6 +227,7 @@ impl TaskOperator {
         // Perform the write operation
         match file.write(data) {
             Ok(size) => {
+                self.task.update_digest(&data[..size]);
                 // Update progress tracking
                 let mut progress_guard = self.task.progress.lock().unwrap();
                 progress_guard.processed[0] += size;
@@ -187,4 +237,59 @@ impl TaskOperator {
             Err(e) => Poll::Ready(Err(HttpClientError::other(e))),
         }
     }
+
+    /// Polls for writing the decoded content of encoded body data.
+    ///
+    /// The data is decoded and its content written entirely to the first
+    /// file of the task, while the wire bytes are counted apart.
+    ///
+    /// # Returns
+    ///
+    /// - `Poll::Ready(Ok(usize))` with the size of the consumed body data.
+    /// - `Poll::Ready(Err(HttpClientError))` if an error occurs.
+    ///
+    /// # Errors
+    ///
+    /// - Returns an error if the data is not validly encoded.
+    /// - Returns an error if no files are associated with the task.
+    /// - Returns an error if the task was aborted.
+    /// - Returns an error if writing to the file fails.
+    pub(crate) fn poll_write_decoded(
+        &self,
+        decoder: &Mutex<Decoder>,
+        data: &[u8],
+    ) -> Poll<Result<usize, HttpClientError>> {
+        let mut decoded = vec![];
+        if let Err(e) = decoder.lock().unwrap().decode(data, &mut decoded) {
+            error!("task {} decode body err: {}", self.task.task_id(), e);
+            return Poll::Ready(Err(HttpClientError::other(e)));
+        }
+
+        let file_mutex = if let Some(mutex) = self.task.files.get(0) {
+            mutex
+        } else {
+            error!("poll_write_decoded err, no file in the `task`");
+            return Poll::Ready(Err(HttpClientError::other("error msg")));
+        };
+
+        let mut file = file_mutex.lock().unwrap();
+
+        // Check for task abortion before writing
+        if self.abort_flag.load(Ordering::Acquire) {
+            return Poll::Ready(Err(HttpClientError::user_aborted()));
+        }
+
+        // The consumed data is reported, so all its content must be written.
+        if let Err(e) = file.write_all(&decoded) {
+            return Poll::Ready(Err(HttpClientError::other(e)));
+        }
+        self.task.update_digest(&decoded);
+        self.task
+            .wire_processed
+            .fetch_add(data.len() as u64, Ordering::SeqCst);
+        let mut progress_guard = self.task.progress.lock().unwrap();
+        progress_guard.processed[0] += decoded.len();
+        progress_guard.common_data.total_processed += decoded.len();
+        Poll::Ready(Ok(data.len()))
+    }
 }
@@ -84,6 +84,22 @@ mod ffi {
         NetworkAppAccount = 30,
         /// Transfer speed below configured minimum threshold.
         LowSpeed = 31,
+        /// Downloaded file does not match the expected digest.
+        IntegrityMismatch = 32,
+        /// Prerequisite tasks have not completed yet.
+        PrerequisiteWaiting = 33,
+        /// A prerequisite task failed or was removed.
+        PrerequisiteFailed = 34,
+        /// The scheduled start time of the task has not been reached yet.
+        StartTimeWaiting = 35,
+        /// The storage space is not enough for the rest of the download.
+        StorageSpaceWaiting = 36,
+        /// The client certificate or its private key cannot be loaded.
+        ClientCertInvalid = 37,
+        /// The server rejected the client certificate in the TLS handshake.
+        ClientCertRejected = 38,
+        /// The task waits for the delay of its retry policy.
+        RetryWaiting = 39,
     }
 }
 
@@ -126,6 +142,14 @@ impl From<u8> for Reason {
             29 => Reason::AppAccount,
             30 => Reason::NetworkAppAccount,
             31 => Reason::LowSpeed,
+            32 => Reason::IntegrityMismatch,
+            33 => Reason::PrerequisiteWaiting,
+            34 => Reason::PrerequisiteFailed,
+            35 => Reason::StartTimeWaiting,
+            36 => Reason::StorageSpaceWaiting,
+            37 => Reason::ClientCertInvalid,
+            38 => Reason::ClientCertRejected,
+            39 => Reason::RetryWaiting,
             _ => Reason::OthersError, // Fallback for unrecognized values
         }
     }
@@ -166,6 +190,14 @@ impl Reason {
             Reason::AppAccount => "The app is background or terminate and the account is stopped",
             Reason::NetworkAppAccount => "NetWork is offline and the app is background or terminate and the account is stopped",
             Reason::LowSpeed => "Below low speed limit",
+            Reason::IntegrityMismatch => "Integrity check failed",
+            Reason::PrerequisiteWaiting => "Waiting for prerequisite tasks",
+            Reason::PrerequisiteFailed => "A prerequisite task failed or was removed",
+            Reason::StartTimeWaiting => "Waiting for the scheduled start time",
+            Reason::StorageSpaceWaiting => "Waiting for enough storage space",
+            Reason::ClientCertInvalid => "Invalid client certificate or private key",
+            Reason::ClientCertRejected => "Client certificate rejected by the server",
+            Reason::RetryWaiting => "Waiting to retry the failed request",
             _ => "unknown error",
         }
     }
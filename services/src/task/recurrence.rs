@@ -0,0 +1,127 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deferred and recurring tasks.
+//!
+//! A task with a `not_before` time does not start before that wall-clock
+//! time, and a task with a daily `Recurrence` only starts within its daily
+//! window. A recurring task runs again under the same ID after each
+//! completion or failure, with its `not_before` time set to its next
+//! occurrence.
+
+use super::config::{Recurrence, TaskConfig};
+
+/// Minimum interval in seconds of a recurring task.
+pub(crate) const MIN_RECURRENCE_INTERVAL: u64 = 15 * 60;
+
+/// Number of minutes in one day.
+const MINUTES_IN_ONE_DAY: u32 = 24 * 60;
+
+/// Number of milliseconds in one minute.
+const MILLISECONDS_IN_ONE_MINUTE: u64 = 60 * 1000;
+
+/// Number of milliseconds in one day.
+const MILLISECONDS_IN_ONE_DAY: u64 = MINUTES_IN_ONE_DAY as u64 * MILLISECONDS_IN_ONE_MINUTE;
+
+/// Encoding of `Recurrence::Once`.
+const ONCE: u32 = 0;
+
+/// Encoding of `Recurrence::Interval`.
+const INTERVAL: u32 = 1;
+
+/// Encoding of `Recurrence::Daily`.
+const DAILY: u32 = 2;
+
+impl Recurrence {
+    /// Decodes a recurrence rule from its kind, interval in seconds and daily
+    /// window in minutes since midnight UTC.
+    ///
+    /// Returns `None` for an unknown kind, an interval shorter than
+    /// `MIN_RECURRENCE_INTERVAL`, or an empty or out of range daily window.
+    pub(crate) fn from_parts(kind: u32, interval: u64, start: u32, end: u32) -> Option<Self> {
+        match kind {
+            ONCE => Some(Recurrence::Once),
+            INTERVAL if interval >= MIN_RECURRENCE_INTERVAL => Some(Recurrence::Interval(interval)),
+            DAILY if start < MINUTES_IN_ONE_DAY && end < MINUTES_IN_ONE_DAY && start != end => {
+                Some(Recurrence::Daily { start, end })
+            }
+            _ => None,
+        }
+    }
+
+    /// Encodes the recurrence rule as its kind, interval in seconds and daily
+    /// window in minutes since midnight UTC.
+    pub(crate) fn to_parts(&self) -> (u32, u64, u32, u32) {
+        match *self {
+            Recurrence::Once => (ONCE, 0, 0, 0),
+            Recurrence::Interval(interval) => (INTERVAL, interval, 0, 0),
+            Recurrence::Daily { start, end } => (DAILY, 0, start, end),
+        }
+    }
+
+    /// Returns whether the recurrence rule repeats the task.
+    pub(crate) fn is_recurring(&self) -> bool {
+        *self != Recurrence::Once
+    }
+
+    /// Returns whether `now` falls within the daily window of the rule, always
+    /// `true` for rules without a window.
+    pub(crate) fn in_window(&self, now: u64) -> bool {
+        let Recurrence::Daily { start, end } = *self else {
+            return true;
+        };
+        let minute = ((now % MILLISECONDS_IN_ONE_DAY) / MILLISECONDS_IN_ONE_MINUTE) as u32;
+        if start < end {
+            start <= minute && minute < end
+        } else {
+            minute >= start || minute < end
+        }
+    }
+
+    /// Returns the start of the next occurrence after `now` of a task first
+    /// scheduled at `anchor`, or `None` if the task does not recur.
+    pub(crate) fn next_start(&self, anchor: u64, now: u64) -> Option<u64> {
+        match *self {
+            Recurrence::Once => None,
+            Recurrence::Interval(interval) => {
+                let interval = interval * 1000;
+                if now < anchor {
+                    return Some(anchor);
+                }
+                Some(anchor + ((now - anchor) / interval + 1) * interval)
+            }
+            Recurrence::Daily { start, .. } => {
+                let start =
+                    now - now % MILLISECONDS_IN_ONE_DAY + start as u64 * MILLISECONDS_IN_ONE_MINUTE;
+                if start > now {
+                    Some(start)
+                } else {
+                    Some(start + MILLISECONDS_IN_ONE_DAY)
+                }
+            }
+        }
+    }
+}
+
+impl TaskConfig {
+    /// Returns whether the task may start at `now` as far as its `not_before`
+    /// time and recurrence window are concerned.
+    pub(crate) fn is_due(&self, now: u64) -> bool {
+        now >= self.not_before && self.recurrence.in_window(now)
+    }
+}
+
+#[cfg(test)]
+mod ut_recurrence {
+    include!("../../tests/ut/task/ut_recurrence.rs");
+}
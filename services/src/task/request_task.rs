@@ -24,6 +24,7 @@ use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use request_utils::file_control::{belong_app_base, check_standardized_path};
+use request_utils::method::{DOWNLOAD_METHODS, UPLOAD_METHODS};
 use ylong_http_client::async_impl::{Body, Client, Request, RequestBuilder, Response};
 use ylong_http_client::{ErrorKind, HttpClientError};
 
@@ -31,19 +32,23 @@ cfg_oh! {
     use crate::manage::SystemConfig;
 }
 
+use super::auth::AuthState;
+use super::compression::WIRE_PROCESSED;
 use super::config::Version;
 use super::info::{CommonTaskInfo, State, TaskInfo, UpdateInfo};
+use super::integrity::RunningDigest;
 use super::notify::{EachFileStatus, NotifyData, Progress};
 use super::reason::Reason;
+use super::retry::RetryStatus;
 use crate::error::ErrorCode;
 use crate::manage::database::RequestDb;
 use crate::manage::network_manager::NetworkManager;
 use crate::manage::notifier::Notifier;
 use crate::service::client::ClientManagerEntry;
 use crate::service::notification_bar::NotificationDispatcher;
-use crate::task::client::build_client;
+use crate::task::client::{build_client, build_task_identity};
 use crate::task::config::{Action, TaskConfig};
-use crate::task::files::{AttachedFiles, Files};
+use crate::task::files::{AttachedFiles, Files, TempFile};
 use crate::task::task_control;
 use crate::utils::form_item::FileSpec;
 use crate::utils::{get_current_duration, get_current_timestamp};
@@ -72,6 +77,12 @@ pub(crate) struct RequestTask {
     /// Body files for upload operations.
     pub(crate) body_files: Files,
 
+    /// Temporary files the downloaded files are written to until completion.
+    pub(crate) temp_files: Vec<TempFile>,
+
+    /// Whether the temporary files have been renamed over their destination.
+    pub(crate) temp_committed: AtomicBool,
+
     /// Creation timestamp of the task.
     pub(crate) ctime: u64,
 
@@ -131,6 +142,43 @@ pub(crate) struct RequestTask {
 
     /// Remaining time until task timeout.
     pub(crate) rest_time: AtomicU64,
+
+    /// Whether the download may still be split into segments.
+    pub(crate) segmentable: AtomicBool,
+
+    /// Running digest of the downloaded file, present when an expected digest
+    /// is configured.
+    pub(crate) hasher: Mutex<Option<RunningDigest>>,
+
+    /// Number of retries made under the retry policy.
+    pub(crate) retry_attempts: AtomicU32,
+
+    /// Status of the last failed response, consumed by the retry policy.
+    pub(crate) retry_status: Mutex<Option<RetryStatus>>,
+
+    /// Delay before the next retry under the retry policy, consumed when the
+    /// task is requeued as waiting.
+    pub(crate) retry_delay: Mutex<Option<Duration>>,
+
+    /// Delay in milliseconds before the task starts its next run.
+    pub(crate) restart_delay: AtomicU64,
+
+    /// Processed size already charged to the bandwidth budgets.
+    pub(crate) budget_charged: AtomicU64,
+
+    /// Transfer speed in bytes per second, sampled at each progress
+    /// notification.
+    pub(crate) speed: AtomicU64,
+
+    /// Time and processed size of the last speed sample.
+    pub(crate) speed_sample: Mutex<(u64, usize)>,
+
+    /// Bytes transferred on the wire while the body is encoded, reported
+    /// next to the decoded size.
+    pub(crate) wire_processed: AtomicU64,
+
+    /// Credentials renewed while the task runs.
+    pub(crate) auth: Mutex<AuthState>,
 }
 
 impl RequestTask {
@@ -161,6 +209,23 @@ impl RequestTask {
         self.conf.common_data.action
     }
 
+    /// Samples the transfer speed of the task since the previous sample.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - The current time in milliseconds.
+    pub(crate) fn sample_speed(&self, current: u64) {
+        let processed = self.progress.lock().unwrap().common_data.total_processed;
+        let (time, last) = std::mem::replace(
+            &mut *self.speed_sample.lock().unwrap(),
+            (current, processed),
+        );
+        if time != 0 && current > time {
+            let speed = processed.saturating_sub(last) as u64 * 1000 / (current - time);
+            self.speed.store(speed, Ordering::SeqCst);
+        }
+    }
+
     /// Sets the speed limit for the task.
     ///
     /// # Arguments
@@ -174,6 +239,19 @@ impl RequestTask {
         }
     }
 
+    /// Deducts the time spent by an attempt from the remaining task time.
+    ///
+    /// # Arguments
+    ///
+    /// * `secs` - The time spent in seconds.
+    pub(crate) fn consume_rest_time(&self, secs: u64) {
+        let _ = self
+            .rest_time
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |rest| {
+                Some(rest.saturating_sub(secs))
+            });
+    }
+
     /// Attempts to retry the task after a network error.
     ///
     /// # Returns
@@ -275,12 +353,15 @@ impl RequestTask {
         let status = TaskStatus::new(time);
         let progress = Progress::new(sizes);
         let mode = AtomicU8::new(config.common_data.mode.repr);
+        let auth = AuthState::new(&config.auth);
 
         RequestTask {
             conf: config,
             client: ylong_runtime::sync::Mutex::new(client),
             files: files.files,
             body_files: files.body_files,
+            temp_files: files.temp_files,
+            temp_committed: AtomicBool::new(false),
             ctime: time,
             mime_type: Mutex::new(String::new()),
             progress: Mutex::new(progress),
@@ -301,6 +382,17 @@ impl RequestTask {
             start_time: AtomicU64::new(get_current_duration().as_secs()),
             task_time: AtomicU64::new(0),
             rest_time: AtomicU64::new(rest_time),
+            segmentable: AtomicBool::new(true),
+            hasher: Mutex::new(None),
+            retry_attempts: AtomicU32::new(0),
+            retry_status: Mutex::new(None),
+            retry_delay: Mutex::new(None),
+            restart_delay: AtomicU64::new(0),
+            budget_charged: AtomicU64::new(0),
+            speed: AtomicU64::new(0),
+            speed_sample: Mutex::new((0, 0)),
+            wire_processed: AtomicU64::new(0),
+            auth: Mutex::new(auth),
         }
     }
 
@@ -360,12 +452,15 @@ impl RequestTask {
         };
         let progress = info.progress;
         let mode = AtomicU8::new(config.common_data.mode.repr);
+        let auth = AuthState::new(&config.auth);
 
         let mut task = RequestTask {
             conf: config,
             client: ylong_runtime::sync::Mutex::new(client),
             files: files.files,
             body_files: files.body_files,
+            temp_files: files.temp_files,
+            temp_committed: AtomicBool::new(false),
             ctime,
             mime_type: Mutex::new(mime_type),
             progress: Mutex::new(progress),
@@ -386,6 +481,17 @@ impl RequestTask {
             start_time: AtomicU64::new(get_current_duration().as_secs()),
             task_time: AtomicU64::new(info.task_time),
             rest_time: AtomicU64::new(rest_time),
+            segmentable: AtomicBool::new(true),
+            hasher: Mutex::new(None),
+            retry_attempts: AtomicU32::new(0),
+            retry_status: Mutex::new(None),
+            retry_delay: Mutex::new(None),
+            restart_delay: AtomicU64::new(0),
+            budget_charged: AtomicU64::new(0),
+            speed: AtomicU64::new(0),
+            speed_sample: Mutex::new((0, 0)),
+            wire_processed: AtomicU64::new(0),
+            auth: Mutex::new(auth),
         };
         let background_notify = NotificationDispatcher::get_instance().register_task(&task);
         task.background_notify = background_notify;
@@ -400,10 +506,17 @@ impl RequestTask {
     /// notification purposes.
     pub(crate) fn build_notify_data(&self) -> NotifyData {
         let vec = self.get_each_file_status();
+        // `unwrap` for propagating panics among threads.
+        let mut progress = self.progress.lock().unwrap().clone();
+        let wire_processed = self.wire_processed.load(Ordering::SeqCst);
+        if wire_processed > 0 {
+            progress
+                .extras
+                .insert(WIRE_PROCESSED.to_string(), wire_processed.to_string());
+        }
         NotifyData {
             bundle: self.conf.bundle.clone(),
-            // `unwrap` for propagating panics among threads.
-            progress: self.progress.lock().unwrap().clone(),
+            progress,
             action: self.conf.common_data.action,
             version: self.conf.version,
             each_file_status: vec,
@@ -456,27 +569,25 @@ impl RequestTask {
             }
         };
 
-        let method = match self.conf.method.to_uppercase().as_str() {
-            "PUT" => "PUT",
-            "POST" => "POST",
-            "GET" => "GET",
-            _ => match self.conf.common_data.action {
-                Action::Upload => {
-                    if self.conf.version == Version::API10 {
-                        "PUT"
-                    } else {
-                        "POST"
-                    }
-                }
-                Action::Download => "GET",
-                _ => "",
-            },
+        let method = match request_method(&self.conf) {
+            Some(method) => method,
+            None => {
+                error!("task {} unsupported method {}", self.task_id(), self.conf.method);
+                return Err(HttpClientError::other("unsupported method"));
+            }
         };
         let mut request = RequestBuilder::new().method(method).url(url.as_str());
         for (key, value) in self.conf.headers.iter() {
             request = request.header(key.as_str(), value.as_str());
         }
-        Ok(request)
+        let request = self.attach_cookies(url.as_str(), request);
+        Ok(self.attach_auth(method, url.as_str(), request))
+    }
+
+    /// Returns whether the task sends a `HEAD` request, whose response carries
+    /// headers only.
+    pub(crate) fn is_head_request(&self) -> bool {
+        request_method(&self.conf) == Some("HEAD")
     }
 
     /// Builds a download request with proper range handling.
@@ -545,7 +656,9 @@ impl RequestTask {
                     return Err(TaskError::Failed(Reason::UnsupportedRangeRequest));
                 }
             }
-            (false, false) => {}
+            (false, false) => {
+                request_builder = task.accept_encoding(request_builder);
+            }
         };
 
         let request = request_builder.body(Body::slice(task.conf.data.clone()))?;
@@ -563,7 +676,7 @@ impl RequestTask {
     /// # Returns
     ///
     /// The configured request builder with range headers.
-    fn range_request(
+    pub(crate) fn range_request(
         &self,
         request_builder: RequestBuilder,
         begins: u64,
@@ -588,7 +701,7 @@ impl RequestTask {
     ///
     /// A tuple containing the configured request builder and a boolean
     /// indicating whether range requests are supported.
-    fn support_range(&self, mut request_builder: RequestBuilder) -> (RequestBuilder, bool) {
+    pub(crate) fn support_range(&self, mut request_builder: RequestBuilder) -> (RequestBuilder, bool) {
         let progress_guard = self.progress.lock().unwrap();
         let mut support_range = false;
         if let Some(etag) = progress_guard.extras.get("etag") {
@@ -626,6 +739,15 @@ impl RequestTask {
             }
         }
 
+        // The length of an encoded body is not the size of the decoded file.
+        if self.decodes(response) {
+            info!("task {} size unknown before decoding", self.task_id());
+            if self.conf.common_data.precise {
+                return Err(TaskError::Failed(Reason::GetFileSizeFailed));
+            }
+            return Ok(());
+        }
+
         let content_length = response.headers().get("content-length");
         if let Some(Ok(len)) = content_length.map(|v| v.to_string()) {
             match len.parse::<i64>() {
@@ -971,6 +1093,36 @@ fn check_path(path: &str) -> bool {
     true
 }
 
+/// Resolves the HTTP method a task sends.
+///
+/// An empty method falls back to the default of the task action. Methods are
+/// matched case-insensitively against those supported by the action.
+///
+/// # Returns
+///
+/// The uppercase method, or `None` if the method is not supported by the
+/// action.
+pub(crate) fn request_method(config: &TaskConfig) -> Option<&'static str> {
+    let action = config.common_data.action;
+    if config.method.is_empty() {
+        return match action {
+            Action::Upload if config.version == Version::API10 => Some("PUT"),
+            Action::Upload => Some("POST"),
+            Action::Download => Some("GET"),
+            _ => None,
+        };
+    }
+    let methods: &[&'static str] = match action {
+        Action::Download => &DOWNLOAD_METHODS,
+        Action::Upload => &UPLOAD_METHODS,
+        _ => &[],
+    };
+    methods
+        .iter()
+        .find(|method| method.eq_ignore_ascii_case(&config.method))
+        .copied()
+}
+
 /// Validates a task configuration and prepares attached files and client.
 ///
 /// # Arguments
@@ -993,6 +1145,10 @@ pub(crate) fn check_config(
         error!("check_config failed: invalid action {:?}", config.common_data.action);
         return Err(ErrorCode::ParameterCheck);
     }
+    if request_method(config).is_none() {
+        error!("check_config failed: unsupported method {}", config.method);
+        return Err(ErrorCode::ParameterCheck);
+    }
     if !check_file_specs(&config.file_specs) {
         return Err(ErrorCode::Other);
     }
@@ -1002,15 +1158,35 @@ pub(crate) fn check_config(
     if !config.certs_path.iter().all(|path| check_path(path)) {
         return Err(ErrorCode::Other);
     }
+    // A client certificate is only usable together with its private key
+    if config.client_cert_path.is_empty() != config.client_key_path.is_empty() {
+        error!("check_config failed: client cert without its key or the reverse");
+        return Err(ErrorCode::ParameterCheck);
+    }
+    if !config.client_cert_path.is_empty()
+        && !(check_path(&config.client_cert_path) && check_path(&config.client_key_path))
+    {
+        return Err(ErrorCode::Other);
+    }
     let files = AttachedFiles::open(config).map_err(|_| ErrorCode::FileOperationErr)?;
     #[cfg(feature = "oh")]
-    let client = build_client(config, total_timeout, system).map_err(|_| ErrorCode::Other)?;
+    let client =
+        build_client(config, total_timeout, system).map_err(|_| build_client_error(config))?;
 
     #[cfg(not(feature = "oh"))]
-    let client = build_client(config, total_timeout).map_err(|_| ErrorCode::Other)?;
+    let client = build_client(config, total_timeout).map_err(|_| build_client_error(config))?;
     Ok((files, client))
 }
 
+/// Returns the error code of a task whose client cannot be built, telling
+/// invalid key material of its client certificate apart.
+fn build_client_error(config: &TaskConfig) -> ErrorCode {
+    match build_task_identity(config) {
+        Err(_) => ErrorCode::ClientCertInvalid,
+        Ok(_) => ErrorCode::Other,
+    }
+}
+
 /// Calculates the remaining time until task timeout.
 ///
 /// # Arguments
@@ -1074,6 +1250,12 @@ pub enum TaskPhase {
 
     /// The network is offline.
     NetworkOffline,
+
+    /// The storage space is not enough for the rest of the download.
+    InsufficientSpace,
+
+    /// The task waits for the delay of its retry policy.
+    RetryWaiting,
 }
 
 /// Represents errors that can occur during task execution.
@@ -0,0 +1,192 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Retry policy of failed task attempts.
+//!
+//! A task configured with a `RetryPolicy` retries a failed attempt when its
+//! `Reason` is retryable, or, for a protocol error, when the HTTP status of
+//! the response is retryable. The delay before retry `n` is
+//! `base_delay * backoff_factor^n` with a random jitter, or the delay
+//! requested by the server in a `Retry-After` header. The task waits for the
+//! delay in the waiting state instead of holding a running slot.
+
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use request_utils::date::parse_http_date;
+use request_utils::fastrand::fast_random;
+use ylong_http_client::async_impl::Response;
+
+use super::config::RetryPolicy;
+use super::http_error_registry;
+use super::reason::Reason;
+use super::request_task::RequestTask;
+use crate::utils::get_current_duration;
+
+/// Upper bound of a single retry delay in milliseconds.
+pub(crate) const MAX_RETRY_DELAY: u64 = 5 * 60 * 1000;
+
+/// Window in milliseconds over which tasks without a retry policy are
+/// restarted after the network recovers.
+const RECOVERY_SPREAD: u64 = 1000;
+
+/// Reasons retried by a policy that does not list any.
+const DEFAULT_REASONS: [Reason; 4] = [
+    Reason::ContinuousTaskTimeout,
+    Reason::Dns,
+    Reason::Tcp,
+    Reason::LowSpeed,
+];
+
+/// HTTP statuses retried by a policy that does not list any.
+const DEFAULT_STATUSES: [u16; 6] = [408, 429, 500, 502, 503, 504];
+
+/// Status of the last failed response of a task.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct RetryStatus {
+    /// HTTP status code of the response.
+    pub(crate) status: u16,
+    /// Delay requested by the `Retry-After` header of the response.
+    pub(crate) retry_after: Option<Duration>,
+}
+
+impl RetryPolicy {
+    /// Returns whether the policy is configured.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.max_attempts > 0
+    }
+
+    /// Returns whether a failure with `reason` is retryable. `status` is the
+    /// HTTP status of the response that caused a protocol error.
+    pub(crate) fn is_retryable(&self, reason: Reason, status: Option<u16>) -> bool {
+        if reason == Reason::ProtocolError {
+            match status {
+                Some(status) if self.statuses.is_empty() => DEFAULT_STATUSES.contains(&status),
+                Some(status) => self.statuses.contains(&status),
+                None => false,
+            }
+        } else if self.reasons.is_empty() {
+            DEFAULT_REASONS.contains(&reason)
+        } else {
+            self.reasons.contains(&reason.repr)
+        }
+    }
+
+    /// Returns the delay in milliseconds before retry `attempt`, counted from
+    /// 0, without jitter.
+    pub(crate) fn backoff(&self, attempt: u32) -> u64 {
+        let factor = self.backoff_factor.max(1) as u64;
+        let mut delay = self.base_delay.min(MAX_RETRY_DELAY);
+        for _ in 0..attempt {
+            delay = delay.saturating_mul(factor);
+            if delay >= MAX_RETRY_DELAY {
+                return MAX_RETRY_DELAY;
+            }
+        }
+        delay
+    }
+
+    /// Returns the delay before retry `attempt` with jitter applied.
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let delay = self.backoff(attempt);
+        let spread = delay * self.jitter.min(100) as u64 / 100;
+        let delay = if spread == 0 {
+            delay
+        } else {
+            delay - spread + fast_random() % (2 * spread + 1)
+        };
+        Duration::from_millis(delay.min(MAX_RETRY_DELAY))
+    }
+
+    /// Returns the delay before a task is restarted after the network
+    /// recovers, so that running tasks do not reconnect all at once.
+    pub(crate) fn recovery_delay(&self) -> Duration {
+        if self.is_enabled() {
+            self.delay(0)
+        } else {
+            Duration::from_millis(fast_random() % RECOVERY_SPREAD)
+        }
+    }
+}
+
+impl RequestTask {
+    /// Records the status and `Retry-After` delay of a failed response.
+    pub(crate) fn record_retry_status(&self, response: &Response) {
+        let retry_after = response
+            .headers()
+            .get("retry-after")
+            .and_then(|value| value.to_string().ok())
+            .and_then(|value| parse_retry_after(&value, get_current_duration().as_secs()));
+        *self.retry_status.lock().unwrap() = Some(RetryStatus {
+            status: response.status().as_u16(),
+            retry_after,
+        });
+    }
+
+    /// Decides whether a failed attempt is retried under the retry policy of
+    /// the task, and records the retry delay if so.
+    pub(crate) fn retry_by_policy(&self, reason: Reason) -> bool {
+        let policy = &self.conf.retry_policy;
+        let status = self.retry_status.lock().unwrap().take();
+        if !policy.is_enabled() {
+            return false;
+        }
+        let attempt = self.retry_attempts.load(Ordering::SeqCst);
+        if attempt + 1 >= policy.max_attempts {
+            info!(
+                "task {} retry attempts exhausted, reason {:?}",
+                self.task_id(),
+                reason
+            );
+            return false;
+        }
+        if !policy.is_retryable(reason, status.map(|status| status.status)) {
+            return false;
+        }
+
+        let delay = match status.and_then(|status| status.retry_after) {
+            Some(retry_after) => retry_after.min(Duration::from_millis(MAX_RETRY_DELAY)),
+            None => policy.delay(attempt),
+        };
+        self.retry_attempts.fetch_add(1, Ordering::SeqCst);
+        // The HTTP status is reported only when the task finally fails.
+        if reason == Reason::ProtocolError {
+            http_error_registry::take_http_status_code(self.task_id());
+        }
+        info!(
+            "task {} retry {} in {}ms, reason {:?}",
+            self.task_id(),
+            attempt + 1,
+            delay.as_millis(),
+            reason
+        );
+        *self.retry_delay.lock().unwrap() = Some(delay);
+        true
+    }
+}
+
+/// Parses a `Retry-After` header value, either delay seconds or an HTTP
+/// date, into the delay from `now` in seconds since the epoch.
+pub(crate) fn parse_retry_after(value: &str, now: u64) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = parse_http_date(value)?;
+    Some(Duration::from_secs(date.saturating_sub(now)))
+}
+
+#[cfg(test)]
+mod ut_retry {
+    include!("../../tests/ut/task/ut_retry.rs");
+}
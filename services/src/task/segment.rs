@@ -0,0 +1,500 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Segmented parallel downloads.
+//!
+//! When the server advertises `Accept-Ranges: bytes` together with a
+//! `Content-Length`, a download task configured with more than one segment
+//! splits the file into contiguous byte ranges that are fetched over separate
+//! connections and written at their own offsets. The progress of every
+//! segment is kept in the `download_segment` table, so a task resumed after a
+//! restart continues each segment where it stopped.
+
+use std::fs::File;
+use std::future::poll_fn;
+use std::os::unix::fs::FileExt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Once};
+
+use ylong_http_client::async_impl::{Body, Response};
+
+use super::operator::TaskOperator;
+use super::reason::Reason;
+use super::request_task::{RequestTask, TaskError, TaskPhase};
+use crate::database::REQUEST_DB;
+use crate::task::task_control;
+
+/// Upper bound of concurrent segments for a single task.
+pub(crate) const MAX_SEGMENTS: u32 = 8;
+
+/// Smallest segment worth a dedicated connection, in bytes.
+pub(crate) const MIN_SEGMENT_SIZE: u64 = 1024 * 1024;
+
+/// Size of the buffer each segment reads the response body into.
+const SEGMENT_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Bytes written by a segment between two progress records in the database.
+const SEGMENT_RECORD_INTERVAL: u64 = 1024 * 1024;
+
+const CREATE_SEGMENT_TABLE: &str = "CREATE TABLE IF NOT EXISTS download_segment (task_id INTEGER, idx INTEGER, begins INTEGER, ends INTEGER, processed INTEGER, PRIMARY KEY (task_id, idx))";
+
+/// A contiguous byte range of the downloaded file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Segment {
+    /// Offset of the first byte of the segment.
+    pub(crate) begins: u64,
+    /// Offset of the last byte of the segment (inclusive).
+    pub(crate) ends: u64,
+    /// Bytes of the segment already written to the file.
+    pub(crate) processed: u64,
+}
+
+impl Segment {
+    /// Returns the number of bytes covered by the segment.
+    pub(crate) fn len(&self) -> u64 {
+        self.ends - self.begins + 1
+    }
+
+    /// Returns the number of bytes of the segment still to be downloaded.
+    pub(crate) fn remaining(&self) -> u64 {
+        self.len().saturating_sub(self.processed)
+    }
+}
+
+/// Returns the end of the contiguous prefix of the file covered by
+/// `segments`, that is the offset of the first byte not yet downloaded.
+pub(crate) fn contiguous_end(segments: &[Segment]) -> u64 {
+    for segment in segments {
+        if segment.remaining() > 0 {
+            return segment.begins + segment.processed;
+        }
+    }
+    segments.last().map_or(0, |segment| segment.ends + 1)
+}
+
+/// Segments of a download shared between its connections, with the bytes
+/// each of them has written so far.
+struct SharedSegments {
+    segments: Vec<Segment>,
+    processed: Vec<AtomicU64>,
+}
+
+impl SharedSegments {
+    fn new(segments: &[Segment]) -> Self {
+        Self {
+            segments: segments.to_vec(),
+            processed: segments
+                .iter()
+                .map(|segment| AtomicU64::new(segment.processed))
+                .collect(),
+        }
+    }
+
+    /// Returns the end of the contiguous downloaded prefix of the file.
+    fn contiguous_end(&self) -> u64 {
+        let segments: Vec<Segment> = self
+            .segments
+            .iter()
+            .zip(self.processed.iter())
+            .map(|(segment, processed)| Segment {
+                processed: processed.load(Ordering::Acquire),
+                ..*segment
+            })
+            .collect();
+        contiguous_end(&segments)
+    }
+}
+
+/// Splits `total` bytes into at most `count` contiguous segments.
+///
+/// The number of segments is capped by [`MAX_SEGMENTS`] and reduced so that
+/// every segment covers at least [`MIN_SEGMENT_SIZE`] bytes; the last segment
+/// takes the remainder. Returns `None` when fewer than two segments remain,
+/// in which case splitting is not worthwhile.
+pub(crate) fn split_segments(total: u64, count: u32) -> Option<Vec<Segment>> {
+    let count = (count.min(MAX_SEGMENTS) as u64).min(total / MIN_SEGMENT_SIZE);
+    if count < 2 {
+        return None;
+    }
+    let size = total / count;
+    let segments = (0..count)
+        .map(|i| Segment {
+            begins: i * size,
+            ends: if i == count - 1 {
+                total - 1
+            } else {
+                (i + 1) * size - 1
+            },
+            processed: 0,
+        })
+        .collect();
+    Some(segments)
+}
+
+/// Decides whether a fresh download response can be split into segments.
+///
+/// Requires the task to ask for more than one segment, to download the whole
+/// resource from the start, and the response to be a `200` advertising
+/// `Accept-Ranges: bytes` with a known `Content-Length`.
+pub(crate) fn plan_segments(task: &RequestTask, response: &Response) -> Option<Vec<Segment>> {
+    if task.conf.segments < 2
+        || !task.segmentable.load(Ordering::Acquire)
+        || task.require_range()
+        || response.status().as_u16() != 200
+    {
+        return None;
+    }
+    let accept_ranges = response
+        .headers()
+        .get("accept-ranges")
+        .and_then(|v| v.to_string().ok())?;
+    if !accept_ranges.to_lowercase().contains("bytes") {
+        return None;
+    }
+    let processed = task.progress.lock().unwrap().processed.first().copied();
+    if processed != Some(0) {
+        return None;
+    }
+    let total = task.file_total_size.load(Ordering::SeqCst);
+    if total <= 0 {
+        return None;
+    }
+    split_segments(total as u64, task.conf.segments)
+}
+
+/// Downloads all segments of a task concurrently.
+///
+/// `first` is the response of the initial request, which starts at offset
+/// zero and therefore serves the first segment instead of a new range
+/// request. When a segment request is not answered with `206 Partial
+/// Content`, or the file cannot be reopened for positioned writes, segmenting
+/// is disabled for the task, the partial file is cleared and a plain download
+/// is retried.
+///
+/// # Errors
+///
+/// Returns the first error reported by a segment; the remaining segments are
+/// stopped and their progress is recorded for resuming.
+pub(crate) async fn download_segments(
+    task: Arc<RequestTask>,
+    abort_flag: Arc<AtomicBool>,
+    segments: Vec<Segment>,
+    mut first: Option<Response>,
+) -> Result<(), TaskError> {
+    let file = match task.files.get(0) {
+        Some(file) => file,
+        None => {
+            error!("download_segments err, no file in the `task`");
+            return Err(TaskError::Failed(Reason::OthersError));
+        }
+    };
+    // A file that cannot be reopened for positioned writes, such as one the
+    // client handed over as a descriptor, is downloaded over one connection
+    let file = match task_control::file_reopen_positional(file).await {
+        Ok(file) => Arc::new(file),
+        Err(e) => {
+            error!("task {} reopen for segments failed {:?}", task.task_id(), e);
+            task.segmentable.store(false, Ordering::Release);
+            return fall_back(&task).await;
+        }
+    };
+
+    {
+        let downloaded = segments.iter().map(|s| s.processed as usize).sum();
+        let mut progress = task.progress.lock().unwrap();
+        progress.processed = vec![downloaded];
+        progress.common_data.total_processed = downloaded;
+    }
+    info!(
+        "task {} downloading in {} segments",
+        task.task_id(),
+        segments.len()
+    );
+
+    let shared = Arc::new(SharedSegments::new(&segments));
+    let stop = Arc::new(AtomicBool::new(false));
+    let mut handles = Vec::with_capacity(segments.len());
+    for (idx, segment) in segments.into_iter().enumerate() {
+        let response = if idx == 0 && segment.processed == 0 {
+            first.take()
+        } else {
+            None
+        };
+        let operator = TaskOperator::new(task.clone(), abort_flag.clone());
+        handles.push(ylong_runtime::spawn(download_segment(
+            task.clone(),
+            operator,
+            file.clone(),
+            shared.clone(),
+            stop.clone(),
+            idx,
+            segment,
+            response,
+        )));
+    }
+    // An unused initial response would keep its connection busy.
+    drop(first);
+
+    let mut ret = Ok(());
+    for handle in handles {
+        let res = match handle.await {
+            Ok(res) => res,
+            Err(e) => {
+                error!("task {} segment join failed {:?}", task.task_id(), e);
+                Err(TaskError::Failed(Reason::OthersError))
+            }
+        };
+        if ret.is_ok() {
+            ret = res;
+        }
+    }
+
+    if !task.segmentable.load(Ordering::Acquire) {
+        return fall_back(&task).await;
+    }
+    ret?;
+
+    task_control::runtime_spawn_blocking(move || file.sync_all())
+        .await
+        .map_err(|_| TaskError::Failed(Reason::IoError))??;
+    clear_segments(task.task_id());
+    Ok(())
+}
+
+/// Clears the segments and the partial file of a task, so that the retried
+/// attempt downloads it over a single connection.
+async fn fall_back(task: &Arc<RequestTask>) -> Result<(), TaskError> {
+    info!("task {} falls back to a single connection", task.task_id());
+    clear_segments(task.task_id());
+    task_control::clear_downloaded_file(task.clone()).await?;
+    Err(TaskError::Waiting(TaskPhase::NeedRetry))
+}
+
+/// Downloads the remaining bytes of one segment.
+async fn download_segment(
+    task: Arc<RequestTask>,
+    mut operator: TaskOperator,
+    file: Arc<File>,
+    shared: Arc<SharedSegments>,
+    stop: Arc<AtomicBool>,
+    idx: usize,
+    mut segment: Segment,
+    response: Option<Response>,
+) -> Result<(), TaskError> {
+    if segment.remaining() == 0 {
+        return Ok(());
+    }
+    let mut response = match response {
+        Some(response) => response,
+        None => match request_segment(&task, &segment).await {
+            Ok(response) => response,
+            Err(e) => {
+                stop.store(true, Ordering::Release);
+                return Err(e);
+            }
+        },
+    };
+
+    let mut buf = vec![0u8; SEGMENT_BUFFER_SIZE];
+    let mut unrecorded = 0u64;
+    let ret = loop {
+        let remaining = segment.remaining();
+        if remaining == 0 || stop.load(Ordering::Acquire) {
+            break Ok(());
+        }
+        let size = match response.data(&mut buf).await {
+            Ok(0) => {
+                error!("task {} segment {} body ended early", task.task_id(), idx);
+                // The segment keeps its recorded offset, so the next attempt
+                // continues it with a new range request
+                break match task.network_retry().await {
+                    Err(e) => Err(e),
+                    Ok(()) => Err(TaskError::Waiting(TaskPhase::NeedRetry)),
+                };
+            }
+            Ok(size) => (size as u64).min(remaining) as usize,
+            Err(e) => break task.handle_download_error(e).await,
+        };
+
+        let offset = segment.begins + segment.processed;
+        if let Err(e) = file.write_all_at(&buf[..size], offset) {
+            error!("task {} segment {} write failed {:?}", task.task_id(), idx, e);
+            break Err(TaskError::Failed(Reason::IoError));
+        }
+        segment.processed += size as u64;
+        shared.processed[idx].store(segment.processed, Ordering::Release);
+        // Writes are out of order, so the digest follows the contiguous
+        // prefix of the file
+        if !task.conf.digest.is_empty() {
+            let prefix_end = shared.contiguous_end();
+            if let Err(e) = task.update_segment_digest(&file, offset, &buf[..size], prefix_end) {
+                error!(
+                    "task {} segment {} digest failed {:?}",
+                    task.task_id(),
+                    idx,
+                    e
+                );
+                break Err(TaskError::Failed(Reason::IoError));
+            }
+        }
+        {
+            let mut progress = task.progress.lock().unwrap();
+            progress.processed[0] += size;
+            progress.common_data.total_processed += size;
+        }
+
+        unrecorded += size as u64;
+        if unrecorded >= SEGMENT_RECORD_INTERVAL {
+            update_segment(task.task_id(), idx, segment.processed);
+            unrecorded = 0;
+        }
+
+        if let Err(e) = poll_fn(|cx| operator.poll_progress_common(cx)).await {
+            break task.handle_download_error(e).await;
+        }
+    };
+
+    update_segment(task.task_id(), idx, segment.processed);
+    if ret.is_err() {
+        stop.store(true, Ordering::Release);
+    }
+    ret
+}
+
+/// Sends the range request for the remaining bytes of a segment.
+///
+/// The request carries `If-Range` with the validator of the initial
+/// response, so a resource changed since then is answered with a full `200`
+/// body, which disables segmenting for the task. A request whose credentials
+/// are rejected is sent again once with renewed ones.
+async fn request_segment(task: &Arc<RequestTask>, segment: &Segment) -> Result<Response, TaskError> {
+    loop {
+        let request_builder = task.build_request_builder()?;
+        let (request_builder, _) = task.support_range(request_builder);
+        let request_builder = task.range_request(
+            request_builder,
+            segment.begins + segment.processed,
+            segment.ends as i64,
+        );
+        let request = request_builder.body(Body::slice(task.conf.data.clone()))?;
+
+        let response = {
+            let client = task.client.lock().await;
+            client.request(request).await
+        };
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => {
+                task.handle_download_error(e).await?;
+                return Err(TaskError::Failed(Reason::OthersError));
+            }
+        };
+        if task.renew_auth(&response).await {
+            continue;
+        }
+        if response.status().as_u16() == 206 {
+            return Ok(response);
+        }
+        info!(
+            "task {} segment range not honored, status {}",
+            task.task_id(),
+            response.status().as_u16()
+        );
+        task.segmentable.store(false, Ordering::Release);
+        return Err(TaskError::Waiting(TaskPhase::NeedRetry));
+    }
+}
+
+/// Creates the `download_segment` table on first use.
+fn init() {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        if let Err(e) = REQUEST_DB.execute(CREATE_SEGMENT_TABLE, ()) {
+            error!("Failed to create download_segment table: {}", e);
+            sys_event!(
+                ExecFault,
+                DfxCode::RDB_FAULT_04,
+                &format!("Failed to create download_segment table: {}", e)
+            );
+        }
+    });
+}
+
+/// Loads the recorded segments of a task, ordered by segment index.
+pub(crate) fn query_segments(task_id: u32) -> Vec<Segment> {
+    init();
+    match REQUEST_DB.query::<(u64, u64, u64)>(
+        "SELECT begins, ends, processed FROM download_segment WHERE task_id = ? ORDER BY idx",
+        task_id,
+    ) {
+        Ok(rows) => rows
+            .map(|(begins, ends, processed)| Segment {
+                begins,
+                ends,
+                processed,
+            })
+            .collect(),
+        Err(e) => {
+            error!("Failed to query task {} segments: {}", task_id, e);
+            Vec::new()
+        }
+    }
+}
+
+/// Records the segments planned for a task.
+pub(crate) fn record_segments(task_id: u32, segments: &[Segment]) {
+    init();
+    for (idx, segment) in segments.iter().enumerate() {
+        if let Err(e) = REQUEST_DB.execute(
+            "INSERT OR REPLACE INTO download_segment (task_id, idx, begins, ends, processed) VALUES (?, ?, ?, ?, ?)",
+            (
+                task_id,
+                idx as u32,
+                segment.begins,
+                segment.ends,
+                segment.processed,
+            ),
+        ) {
+            error!("Failed to record task {} segment {}: {}", task_id, idx, e);
+            sys_event!(
+                ExecFault,
+                DfxCode::RDB_FAULT_04,
+                &format!("Failed to record task {} segment {}: {}", task_id, idx, e)
+            );
+        }
+    }
+}
+
+/// Records the progress of one segment.
+fn update_segment(task_id: u32, idx: usize, processed: u64) {
+    init();
+    if let Err(e) = REQUEST_DB.execute(
+        "UPDATE download_segment SET processed = ? WHERE task_id = ? AND idx = ?",
+        (processed, task_id, idx as u32),
+    ) {
+        error!("Failed to update task {} segment {}: {}", task_id, idx, e);
+    }
+}
+
+/// Removes the recorded segments of a task.
+pub(crate) fn clear_segments(task_id: u32) {
+    init();
+    if let Err(e) = REQUEST_DB.execute("DELETE FROM download_segment WHERE task_id = ?", task_id) {
+        error!("Failed to clear task {} segments: {}", task_id, e);
+    }
+}
+
+#[cfg(test)]
+mod ut_segment {
+    include!("../../tests/ut/task/ut_segment.rs");
+}
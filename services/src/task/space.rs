@@ -0,0 +1,159 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage space preflight of downloads.
+//!
+//! Before the body of a download is written, the bytes still to download,
+//! known from the `Content-Length` of the response or from the size declared
+//! in the task configuration, are compared against the free space of the
+//! filesystem of the downloaded file, and optionally reserved. The
+//! `Content-Length` of an encoded body is its size on the wire, which only
+//! bounds the decoded size from below. A task short of space waits with
+//! `Reason::StorageSpaceWaiting` instead of failing midway, and is checked
+//! again periodically until the space is available.
+
+use std::io;
+use std::os::fd::AsRawFd;
+
+use ylong_http_client::async_impl::Response;
+
+use super::config::TaskConfig;
+use super::files;
+use super::notify::Progress;
+use super::request_task::{RequestTask, TaskError, TaskPhase};
+use super::task_control::runtime_spawn_blocking;
+use crate::utils::{available_space, preallocate_file};
+
+/// Returns the number of bytes still to be written to the downloaded file.
+///
+/// # Arguments
+///
+/// * `sizes` - Total size of each file, -1 when unknown.
+/// * `processed` - Number of bytes already written to each file.
+/// * `expected_size` - Size declared by the caller, used when the total size
+///   is unknown (0 when undeclared).
+pub(crate) fn required_space(sizes: &[i64], processed: &[usize], expected_size: u64) -> u64 {
+    let total = match sizes.first() {
+        Some(&size) if size >= 0 => size as u64,
+        _ => expected_size,
+    };
+    let downloaded = processed.first().copied().unwrap_or(0) as u64;
+    total.saturating_sub(downloaded)
+}
+
+/// Returns the number of bytes an encoded body needs at least once decoded.
+///
+/// # Arguments
+///
+/// * `wire_length` - `Content-Length` of the encoded body, if any.
+/// * `expected_size` - Size declared by the caller (0 when undeclared).
+pub(crate) fn decoded_required_space(wire_length: Option<u64>, expected_size: u64) -> u64 {
+    wire_length.unwrap_or(0).max(expected_size)
+}
+
+/// Checks whether the filesystem of a stored download has room for the rest
+/// of its file.
+///
+/// Returns `true` when the space cannot be determined, such as for files
+/// passed by descriptor, in which case the download itself checks again.
+pub(crate) fn has_enough_space(config: &TaskConfig, progress: &Progress) -> bool {
+    let needed = required_space(&progress.sizes, &progress.processed, config.expected_size);
+    if needed == 0 {
+        return true;
+    }
+    let Some(dir) = files::download_base_dir(config) else {
+        return true;
+    };
+    available_space(&dir).map_or(true, |available| available >= needed)
+}
+
+impl RequestTask {
+    /// Checks that the filesystem of the downloaded file has room for the rest
+    /// of the download, and reserves it if the task asks to.
+    ///
+    /// Must be called once the size of the response is known.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TaskError::Waiting(TaskPhase::InsufficientSpace)` if the free
+    /// space is not enough.
+    pub(crate) async fn check_storage_space(&self, response: &Response) -> Result<(), TaskError> {
+        let (needed, offset) = {
+            let progress = self.progress.lock().unwrap();
+            (
+                required_space(
+                    &progress.sizes,
+                    &progress.processed,
+                    self.conf.expected_size,
+                ),
+                progress.processed.first().copied().unwrap_or(0) as u64,
+            )
+        };
+        // Encoded bodies are never resumed, so the whole decoded file is
+        // still to be written
+        let needed = if self.decodes(response) {
+            let wire_length = response
+                .headers()
+                .get("content-length")
+                .and_then(|v| v.to_string().ok())
+                .and_then(|v| v.parse::<u64>().ok());
+            decoded_required_space(wire_length, self.conf.expected_size)
+        } else {
+            needed
+        };
+        if needed == 0 {
+            return Ok(());
+        }
+        let Some(file) = self.files.get(0) else {
+            return Ok(());
+        };
+        let task_id = self.task_id();
+        let preallocate = self.conf.preallocate;
+
+        let enough = runtime_spawn_blocking(move || {
+            let fd = file.lock().unwrap().as_raw_fd();
+            if let Some(available) = available_space(&format!("/proc/self/fd/{}", fd)) {
+                if available < needed {
+                    info!(
+                        "task {} needs {} bytes, {} available",
+                        task_id, needed, available
+                    );
+                    return Ok(false);
+                }
+            }
+            if preallocate {
+                match preallocate_file(fd, offset, needed) {
+                    Ok(()) => {}
+                    Err(e) if e.raw_os_error() == Some(libc::ENOSPC) => {
+                        info!("task {} preallocate {} bytes, no space", task_id, needed);
+                        return Ok(false);
+                    }
+                    // Filesystems without preallocation are only checked
+                    Err(e) => info!("task {} preallocate failed: {}", task_id, e),
+                }
+            }
+            Ok::<bool, io::Error>(true)
+        })
+        .await;
+
+        match enough {
+            Ok(Ok(false)) => Err(TaskError::Waiting(TaskPhase::InsufficientSpace)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod ut_space {
+    include!("../../tests/ut/task/ut_space.rs");
+}
@@ -25,6 +25,8 @@ use std::time::Duration;
 use ylong_http_client::HttpClientError;
 use ylong_runtime::time::{sleep, Sleep};
 
+use crate::task::bandwidth;
+
 /// Controls the rate of data transfer operations.
 ///
 /// This struct implements a token bucket-like algorithm to limit the speed of
@@ -42,6 +44,10 @@ pub(crate) struct SpeedLimiter {
 
     /// Optional future for sleep operations when rate limiting is active.
     pub(crate) sleep: Option<Pin<Box<Sleep>>>,
+
+    /// Optional future for sleep operations when a bandwidth budget is
+    /// overdrawn.
+    pub(crate) budget_sleep: Option<Pin<Box<Sleep>>>,
 }
 
 impl SpeedLimiter {
@@ -115,4 +121,41 @@ impl SpeedLimiter {
         }
         Poll::Ready(Ok(()))
     }
+
+    /// Charges transferred data to the device and application bandwidth
+    /// budgets and applies throttling if either of them is overdrawn.
+    ///
+    /// # Arguments
+    ///
+    /// * `cx` - The task context for registering wakeups.
+    /// * `uid` - User ID of the application that owns the task.
+    /// * `current_time` - Current timestamp in milliseconds.
+    /// * `size` - Number of bytes transferred since the last charge.
+    ///
+    /// # Returns
+    ///
+    /// * `Poll::Ready(Ok(()))` - When the operation can proceed without
+    ///   throttling.
+    /// * `Poll::Pending` - When a budget is overdrawn and the operation should
+    ///   wait.
+    pub(crate) fn poll_check_budget(
+        &mut self,
+        cx: &mut Context<'_>,
+        uid: u64,
+        current_time: u64,
+        size: u64,
+    ) -> Poll<Result<(), HttpClientError>> {
+        self.budget_sleep = None;
+        let wait = bandwidth::consume_budget(uid, size, current_time);
+        if wait > 0 {
+            self.budget_sleep = Some(Box::pin(sleep(Duration::from_millis(wait))));
+        }
+
+        if let Some(sleep) = self.budget_sleep.as_mut() {
+            if Pin::new(sleep).poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
 }
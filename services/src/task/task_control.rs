@@ -17,13 +17,15 @@
 //! async context and performing file operations in a thread-safe manner,
 //! primarily used for HTTP request tasks.
 
-use std::fs::{File, Metadata};
+use std::fs::{File, Metadata, OpenOptions};
 use std::io::{self, Seek, SeekFrom, Write};
+use std::os::fd::AsRawFd;
 use std::sync::{Arc, Mutex};
 
 use ylong_runtime::task::JoinHandle;
 
 use crate::task::request_task::RequestTask;
+use crate::task::segment;
 
 /// Spawns a blocking operation that returns a result.
 ///
@@ -197,10 +199,40 @@ pub(crate) async fn file_write_all<'a>(file: Arc<Mutex<File>>, buf: &[u8]) -> io
     .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
 }
 
+/// Opens a second handle to a file for positioned writes asynchronously.
+///
+/// Task files are opened in append mode, where positioned writes still land
+/// at the end of the file. Reopening through `/proc/self/fd` creates a new
+/// open file description without `O_APPEND`, so `write_at` honors its offset.
+///
+/// # Arguments
+///
+/// * `file` - A thread-safe reference to the file.
+///
+/// # Returns
+///
+/// A write-only handle to the same file.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be reopened or if the blocking task
+/// fails.
+pub(crate) async fn file_reopen_positional(file: Arc<Mutex<File>>) -> io::Result<File> {
+    runtime_spawn_blocking(move || {
+        let fd = file.lock().unwrap().as_raw_fd();
+        OpenOptions::new()
+            .write(true)
+            .open(format!("/proc/self/fd/{}", fd))
+    })
+    .await
+    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+}
+
 /// Clears a downloaded file and resets its progress tracking.
 ///
 /// This function truncates the first file in the task to zero length and resets
-/// the progress tracking information.
+/// the progress tracking information, including any recorded download
+/// segments.
 ///
 /// # Arguments
 ///
@@ -233,6 +265,8 @@ pub(crate) async fn clear_downloaded_file(task: Arc<RequestTask>) -> Result<(),
             file.set_len(0)?; // Truncate the file to zero length
             file.seek(SeekFrom::Start(0))?; // Reset file position
         }
+        segment::clear_segments(task.task_id());
+        task.reset_digest();
 
         // Reset progress tracking
         {
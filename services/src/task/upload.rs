@@ -26,10 +26,15 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::Instant;
 
-use ylong_http_client::async_impl::{Body, MultiPart, Part, Request, UploadOperator, Uploader};
+use ylong_http_client::async_impl::{
+    Body, MultiPart, Part, Request, Response, UploadOperator, Uploader,
+};
 use ylong_http_client::{ErrorKind, HttpClientError, ReusableReader, Timeout};
 use ylong_runtime::io::{AsyncRead, ReadBuf};
 
+use super::chunk_upload;
+use super::client::client_cert_rejected;
+use super::compression::{GzipReader, UPLOAD_ENCODING};
 use super::info::State;
 use super::operator::TaskOperator;
 use super::reason::Reason;
@@ -215,7 +220,9 @@ impl UploadOperator for TaskOperator {
 
 /// Builds a streaming upload request for a single file.
 ///
-/// Constructs an HTTP request with a streaming body for file uploads.
+/// Constructs an HTTP request with a streaming body for file uploads. With
+/// `compression`, the body is gzip encoded as it is read and sent chunked, its
+/// encoded length being unknown.
 ///
 /// # Arguments
 ///
@@ -251,6 +258,19 @@ fn build_stream_request(
             }
             debug!("upload length is {}", upload_length);
 
+            if task.conf.compression {
+                request_builder = request_builder
+                    .header("Content-Encoding", UPLOAD_ENCODING)
+                    .header("Transfer-Encoding", "chunked");
+                let uploader = Uploader::builder()
+                    .reader(GzipReader::new(task.clone(), task_reader))
+                    .operator(task_operator.count_wire())
+                    .total_bytes(None)
+                    .build();
+                let request = request_builder.body(Body::stream(uploader));
+                return build_request_common(&task, index, request);
+            }
+
             // Set content length header
             request_builder =
                 request_builder.header("Content-Length", upload_length.to_string().as_str());
@@ -598,11 +618,19 @@ pub(crate) async fn upload(task: Arc<RequestTask>, abort_flag: Arc<AtomicBool>)
 
     // Set task state to running
     task.progress.lock().unwrap().common_data.state = State::Running.repr;
+    task.wire_processed.store(0, Ordering::SeqCst);
     task.tries.store(0, Ordering::SeqCst);
 
     // Main upload loop with retry logic
     loop {
         if let Err(e) = upload_inner(task.clone(), abort_flag.clone()).await {
+            // Retry the failure if the retry policy allows it
+            let e = match e {
+                TaskError::Failed(reason) if task.retry_by_policy(reason) => {
+                    TaskError::Waiting(TaskPhase::RetryWaiting)
+                }
+                e => e,
+            };
             match e {
                 TaskError::Failed(reason) => {
                     // Task failed with specific reason
@@ -620,6 +648,15 @@ pub(crate) async fn upload(task: Arc<RequestTask>, abort_flag: Arc<AtomicBool>)
                         // Network offline error
                         *task.running_result.lock().unwrap() = Some(Err(Reason::NetworkOffline));
                     }
+                    TaskPhase::InsufficientSpace => {
+                        // Storage space shortage, wait for space to free up
+                        *task.running_result.lock().unwrap() =
+                            Some(Err(Reason::StorageSpaceWaiting));
+                    }
+                    TaskPhase::RetryWaiting => {
+                        // Policy retry, wait for the retry delay out of the queue
+                        *task.running_result.lock().unwrap() = Some(Err(Reason::RetryWaiting));
+                    }
                 },
             }
         } else {
@@ -694,6 +731,13 @@ async fn upload_inner(
             #[cfg(feature = "oh")]
             let _trace = Trace::new(&format!("upload file:{} index:{}", task.task_id(), index));
 
+            // Send large raw bodies in resumable chunks
+            if task.conf.chunk_size > 0 && !is_multipart {
+                chunk_upload::upload_file(task.clone(), index, abort_flag.clone()).await?;
+                task.notify_header_receive();
+                continue;
+            }
+
             // Prepare individual file for upload
             if !task.prepare_single_upload(index).await {
                 return Err(TaskError::Failed(Reason::OthersError));
@@ -709,6 +753,9 @@ async fn upload_inner(
         }
     }
 
+    if task.conf.chunk_size > 0 {
+        chunk_upload::clear_committed(task.task_id());
+    }
     info!("{} uploaded", task.task_id());
     Ok(())
 }
@@ -752,8 +799,7 @@ where
     .await;
 
     // Adjust timeout for remaining operations
-    let upload_time = begin_time.elapsed().as_secs();
-    task.rest_time.fetch_sub(upload_time, Ordering::SeqCst);
+    task.consume_rest_time(begin_time.elapsed().as_secs());
     let mut client = task.client.lock().await;
     client.total_timeout(Timeout::from_secs(task.rest_time.load(Ordering::SeqCst)));
 
@@ -814,8 +860,7 @@ where
     };
 
     // Execute the request
-    let client = task.client.lock().await;
-    let response = client.request(request).await;
+    let response = task.client.lock().await.request(request).await;
 
     // Process the response
     match response.as_ref() {
@@ -824,80 +869,13 @@ where
             #[cfg(feature = "oh")]
             task.notify_response(response);
             info!("{} response {}", task.conf.common_data.task_id, status_code,);
-
-            // Handle various HTTP status codes
-            if status_code.is_server_error()
-                || (status_code.as_u16() != 408 && status_code.is_client_error())
-                || status_code.is_redirection()
-            {
-                super::http_error_registry::set_http_status_code(
-                    task.conf.common_data.task_id,
-                    status_code.as_u16(),
-                );
-                return Err(TaskError::Failed(Reason::ProtocolError));
+            // Retry once with renewed credentials when they are rejected
+            if task.renew_auth(response).await {
+                return Err(TaskError::Waiting(TaskPhase::NeedRetry));
             }
-
-            // Special handling for timeout status (408)
-            if status_code.as_u16() == 408 {
-                if task.timeout_tries.load(Ordering::SeqCst) < 2 {
-                    // Retry on timeout, but limit retry attempts
-                    task.timeout_tries.fetch_add(1, Ordering::SeqCst);
-                    return Err(TaskError::Waiting(TaskPhase::NeedRetry));
-                } else {
-                    // Too many timeout retries, fail permanently
-                    super::http_error_registry::set_http_status_code(
-                        task.conf.common_data.task_id,
-                        408,
-                    );
-                    return Err(TaskError::Failed(Reason::ProtocolError));
-                }
-            } else {
-                // Reset timeout counter on successful response
-                task.timeout_tries.store(0, Ordering::SeqCst);
-            }
-        }
-        Err(e) => {
-            // Only log non-abort errors
-            if e.error_kind() != ErrorKind::UserAborted {
-                error!("Task {} {:?}", task.task_id(), e);
-            }
-
-            // Map HTTP client errors to task errors
-            match e.error_kind() {
-                ErrorKind::Timeout => return Err(TaskError::Failed(Reason::ContinuousTaskTimeout)),
-                ErrorKind::Request => return Err(TaskError::Failed(Reason::RequestError)),
-                ErrorKind::Redirect => return Err(TaskError::Failed(Reason::RedirectError)),
-                ErrorKind::Connect | ErrorKind::ConnectionUpgrade => {
-                    // Handle connection errors with retry logic
-                    task.network_retry().await?;
-                    if e.is_dns_error() {
-                        return Err(TaskError::Failed(Reason::Dns));
-                    } else if e.is_tls_error() {
-                        return Err(TaskError::Failed(Reason::Ssl));
-                    } else {
-                        return Err(TaskError::Failed(Reason::Tcp));
-                    }
-                }
-                ErrorKind::BodyTransfer => {
-                    // Handle transfer errors
-                    if format!("{}", e).contains("Below low speed limit") {
-                        return Err(TaskError::Failed(Reason::LowSpeed));
-                    } else {
-                        task.network_retry().await?;
-                        return Err(TaskError::Failed(Reason::OthersError));
-                    }
-                }
-                ErrorKind::UserAborted => return Err(TaskError::Waiting(TaskPhase::UserAbort)),
-                _ => {
-                    // Handle miscellaneous errors
-                    if format!("{}", e).contains("No space left on device") {
-                        return Err(TaskError::Failed(Reason::InsufficientSpace));
-                    } else {
-                        return Err(TaskError::Failed(Reason::OthersError));
-                    }
-                }
-            };
+            check_upload_status(&task, response, false)?;
         }
+        Err(e) => return Err(handle_upload_error(&task, e).await),
     };
 
     // Record the response
@@ -905,6 +883,99 @@ where
     Ok(())
 }
 
+/// Checks the status code of an upload response.
+///
+/// Without a retry policy, `408` is retried up to two times. Server errors,
+/// other client errors and redirections fail the task with `ProtocolError`.
+/// `308 Resume Incomplete` is accepted when `resume_incomplete` is set, as
+/// chunked uploads use it to acknowledge a chunk.
+pub(crate) fn check_upload_status(
+    task: &RequestTask,
+    response: &Response,
+    resume_incomplete: bool,
+) -> Result<(), TaskError> {
+    let status_code = response.status().as_u16();
+    // Special handling for timeout status (408)
+    if status_code == 408 && !task.conf.retry_policy.is_enabled() {
+        if task.timeout_tries.load(Ordering::SeqCst) < 2 {
+            // Retry on timeout, but limit retry attempts
+            task.timeout_tries.fetch_add(1, Ordering::SeqCst);
+            return Err(TaskError::Waiting(TaskPhase::NeedRetry));
+        }
+        // Too many timeout retries, fail permanently
+        super::http_error_registry::set_http_status_code(task.conf.common_data.task_id, 408);
+        return Err(TaskError::Failed(Reason::ProtocolError));
+    }
+    // Reset timeout counter on successful response
+    task.timeout_tries.store(0, Ordering::SeqCst);
+
+    // Handle various HTTP status codes
+    if (300..600).contains(&status_code) && !(resume_incomplete && status_code == 308) {
+        super::http_error_registry::set_http_status_code(
+            task.conf.common_data.task_id,
+            status_code,
+        );
+        task.record_retry_status(response);
+        return Err(TaskError::Failed(Reason::ProtocolError));
+    }
+    Ok(())
+}
+
+/// Maps the error of an upload request to a task error.
+///
+/// Connection and transfer errors wait for the network to come back before
+/// failing, see `RequestTask::network_retry`.
+pub(crate) async fn handle_upload_error(task: &RequestTask, e: &HttpClientError) -> TaskError {
+    // Only log non-abort errors
+    if e.error_kind() != ErrorKind::UserAborted {
+        error!("Task {} {:?}", task.task_id(), e);
+    }
+
+    // The client certificate may be rejected during or after the handshake
+    if client_cert_rejected(&task.conf, e) {
+        return TaskError::Failed(Reason::ClientCertRejected);
+    }
+
+    // Map HTTP client errors to task errors
+    match e.error_kind() {
+        ErrorKind::Timeout => TaskError::Failed(Reason::ContinuousTaskTimeout),
+        ErrorKind::Request => TaskError::Failed(Reason::RequestError),
+        ErrorKind::Redirect => TaskError::Failed(Reason::RedirectError),
+        ErrorKind::Connect | ErrorKind::ConnectionUpgrade => {
+            // Handle connection errors with retry logic
+            if let Err(e) = task.network_retry().await {
+                return e;
+            }
+            if e.is_dns_error() {
+                TaskError::Failed(Reason::Dns)
+            } else if e.is_tls_error() {
+                TaskError::Failed(Reason::Ssl)
+            } else {
+                TaskError::Failed(Reason::Tcp)
+            }
+        }
+        ErrorKind::BodyTransfer => {
+            // Handle transfer errors
+            if format!("{}", e).contains("Below low speed limit") {
+                TaskError::Failed(Reason::LowSpeed)
+            } else if let Err(e) = task.network_retry().await {
+                e
+            } else {
+                TaskError::Failed(Reason::OthersError)
+            }
+        }
+        ErrorKind::UserAborted => TaskError::Waiting(TaskPhase::UserAbort),
+        _ => {
+            // Handle miscellaneous errors
+            if format!("{}", e).contains("No space left on device") {
+                TaskError::Failed(Reason::InsufficientSpace)
+            } else {
+                TaskError::Failed(Reason::OthersError)
+            }
+        }
+    }
+}
+
 /// Unit tests for upload functionality.
 ///
 /// Contains test cases for verifying upload operations under various
@@ -475,6 +475,47 @@ pub(crate) fn is_called_by_hap() -> bool {
     ffi::IsCalledByHAP(token_id)
 }
 
+/// Queries the free space available on the filesystem containing a path.
+///
+/// # Arguments
+///
+/// * `path` - Any path on the filesystem to query
+///
+/// # Returns
+///
+/// Returns the number of bytes available to unprivileged writers, or `None`
+/// if the filesystem cannot be queried.
+pub(crate) fn available_space(path: &str) -> Option<u64> {
+    let space = ffi::GetAvailableSpace(path);
+    if space < 0 {
+        return None;
+    }
+    Some(space as u64)
+}
+
+/// Reserves disk blocks for a range of an open file without changing its
+/// size.
+///
+/// Keeping the size unchanged lets an interrupted download still be resumed
+/// from the end of the bytes actually written.
+///
+/// # Arguments
+///
+/// * `fd` - Raw descriptor of the file, open for writing
+/// * `offset` - Start of the range to reserve
+/// * `len` - Length in bytes of the range to reserve
+///
+/// # Errors
+///
+/// Returns the OS error of the allocation, such as `ENOSPC` when the
+/// filesystem is full or `EOPNOTSUPP` when it does not support preallocation.
+pub(crate) fn preallocate_file(fd: i32, offset: u64, len: u64) -> std::io::Result<()> {
+    match ffi::PreallocateFile(fd, offset as i64, len as i64) {
+        0 => Ok(()),
+        errno => Err(std::io::Error::from_raw_os_error(errno)),
+    }
+}
+
 /// Validates a bundle name according to the OpenHarmony bundle name
 /// specification.
 ///
@@ -540,6 +581,12 @@ mod ffi {
 
         /// Checks if a token ID belongs to a HarmonyOS Ability Package.
         fn IsCalledByHAP(token_id: u32) -> bool;
+
+        /// Returns the bytes available on the filesystem of a path, or -1.
+        fn GetAvailableSpace(path: &str) -> i64;
+
+        /// Reserves blocks for a file range, returning 0 or an errno.
+        fn PreallocateFile(fd: i32, offset: i64, len: i64) -> i32;
     }
 }
 
@@ -14,6 +14,7 @@
 use super::*;
 use crate::config::NetworkConfig;
 use crate::manage::database::RequestDb;
+use crate::task::notify::WaitingCause;
 use crate::tests::{lock_database, test_init};
 use crate::utils::get_current_timestamp;
 use crate::utils::task_id_generator::TaskIdGenerator;
@@ -23,13 +24,24 @@ const PAUSED: u8 = State::Paused.repr;
 const INIT: u8 = State::Initialized.repr;
 const WIFI: u8 = NetworkConfig::Wifi as u8;
 const CELLULAR: u8 = NetworkConfig::Cellular as u8;
+const RUNNING_TASK_MEET_LIMITS: u8 = Reason::RunningTaskMeetLimits.repr;
+const NETWORK_APP: u8 = Reason::NetworkApp.repr;
+const NETWORK_ACCOUNT: u8 = Reason::NetworkAccount.repr;
+const APP_ACCOUNT: u8 = Reason::AppAccount.repr;
+const NETWORK_APP_ACCOUNT: u8 = Reason::NetworkAppAccount.repr;
 
+// The reason of a waiting task is derived from its conditions.
 fn query_state_and_reason(task_id: u32) -> (u8, u8) {
     let db = RequestDb::get_instance();
+    let state = db.query_integer(&format!(
+        "SELECT state FROM request_task where task_id = {task_id}"
+    ))[0];
+    if state == WAITING {
+        let conditions = db.query_task_conditions(task_id).unwrap();
+        return (state, conditions.reason().repr);
+    }
     (
-        db.query_integer(&format!(
-            "SELECT state FROM request_task where task_id = {task_id}"
-        ))[0],
+        state,
         db.query_integer(&format!(
             "SELECT reason FROM request_task where task_id = {task_id}"
         ))[0],
@@ -435,12 +447,7 @@ fn ut_account_unavailable() {
         "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason) VALUES ({task_id}, {uid}, {state}, {RUNNING_TASK_MEET_LIMITS})"
     )).unwrap();
         db.execute(&account_unavailable(&hash_set)).unwrap();
-        let state: u8 = db.query_integer(&format!(
-            "SELECT state FROM request_task where task_id = {task_id}"
-        ))[0];
-        let reason: u8 = db.query_integer(&format!(
-            "SELECT reason FROM request_task where task_id = {task_id}"
-        ))[0];
+        let (state, reason) = query_state_and_reason(task_id);
         assert_eq!(state, WAITING);
         assert_eq!(reason, ACCOUNT_STOPPED);
         hash_set.insert(user + i as u64 + 1);
@@ -785,4 +792,133 @@ fn ut_multi_reason_unailable() {
     let (state, reason) = query_state_and_reason(task_id);
     assert_eq!(state, WAITING);
     assert_eq!(reason, NETWORK_APP_ACCOUNT);
-}
\ No newline at end of file
+}
+fn query_conditions(task_id: u32) -> u32 {
+    RequestDb::get_instance().query_integer(&format!(
+        "SELECT conditions FROM request_task where task_id = {task_id}"
+    ))[0]
+}
+
+// @tc.name: ut_conditions_independent
+// @tc.desc: Test setting and clearing waiting conditions independently
+// @tc.precon: NA
+// @tc.step: 1. Initialize test database
+//           2. Lock database
+//           3. Make the network, app and account unavailable one by one
+//           4. Make them available again in another order
+// @tc.expect: Each state change only sets or clears its own condition, and
+//             the reason is derived from the remaining conditions
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_conditions_independent() {
+    test_init();
+    let _lock = lock_database();
+    let db = RequestDb::get_instance();
+    let task_id = TaskIdGenerator::generate();
+    let uid = get_current_timestamp();
+    let user = uid / 200000;
+    let info = NetworkInfo {
+        network_type: NetworkType::Wifi,
+        is_metered: false,
+        is_roaming: false,
+    };
+
+    db.execute(&format!(
+        "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, network, metered, roaming, mode) VALUES ({task_id}, {uid}, {WAITING}, {RUNNING_TASK_MEET_LIMITS}, {CELLULAR}, 0, 0, {FRONTEND})"
+    )).unwrap();
+
+    db.execute(&network_unavailable(&info).unwrap()).unwrap();
+    db.execute(&app_state_unavailable(uid)).unwrap();
+    db.execute(&account_unavailable(&HashSet::new())).unwrap();
+    assert_eq!(
+        query_conditions(task_id),
+        NETWORK_TYPE_CONDITION | APP_STATE_CONDITION | ACCOUNT_CONDITION
+    );
+    assert_eq!(
+        query_state_and_reason(task_id),
+        (WAITING, NETWORK_APP_ACCOUNT)
+    );
+
+    // the network conditions replace each other
+    db.execute(&network_offline()).unwrap();
+    assert_eq!(
+        query_conditions(task_id),
+        NETWORK_OFFLINE_CONDITION | APP_STATE_CONDITION | ACCOUNT_CONDITION
+    );
+
+    db.execute(&account_available(&HashSet::from([user])))
+        .unwrap();
+    assert_eq!(
+        query_conditions(task_id),
+        NETWORK_OFFLINE_CONDITION | APP_STATE_CONDITION
+    );
+    assert_eq!(query_state_and_reason(task_id), (WAITING, NETWORK_APP));
+    assert_eq!(
+        db.query_task_causes(task_id),
+        vec![WaitingCause::Network, WaitingCause::AppState]
+    );
+
+    let info = NetworkInfo {
+        network_type: NetworkType::Cellular,
+        is_metered: false,
+        is_roaming: false,
+    };
+    db.execute(&network_available(&info)).unwrap();
+    assert_eq!(query_conditions(task_id), APP_STATE_CONDITION);
+    assert_eq!(
+        query_state_and_reason(task_id),
+        (WAITING, APP_BACKGROUND_OR_TERMINATE)
+    );
+
+    db.execute(&app_state_available(uid)).unwrap();
+    assert_eq!(query_conditions(task_id), 0);
+    assert_eq!(
+        query_state_and_reason(task_id),
+        (WAITING, RUNNING_TASK_MEET_LIMITS)
+    );
+    assert!(db.query_task_conditions(task_id).unwrap().is_empty());
+    assert_eq!(db.query_task_causes(task_id), vec![WaitingCause::TaskQueue]);
+}
+
+// @tc.name: ut_conditions_other_reason
+// @tc.desc: Test conditions of a task waiting for another reason
+// @tc.precon: NA
+// @tc.step: 1. Initialize test database
+//           2. Lock database
+//           3. Make the app unavailable for a task waiting for prerequisites
+//           4. Make the app available again
+// @tc.expect: The condition is tracked along the prerequisite one, which
+//             takes precedence in the reason
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_conditions_other_reason() {
+    test_init();
+    let _lock = lock_database();
+    let db = RequestDb::get_instance();
+    let task_id = TaskIdGenerator::generate();
+    let uid = get_current_timestamp();
+    let prerequisite_waiting = Reason::PrerequisiteWaiting.repr;
+
+    db.execute(&format!(
+        "INSERT OR REPLACE INTO request_task (task_id, uid, state, reason, mode) VALUES ({task_id}, {uid}, {WAITING}, {prerequisite_waiting}, {FRONTEND})"
+    )).unwrap();
+    db.execute(&app_state_unavailable(uid)).unwrap();
+    let prerequisite = Conditions::PREREQUISITE.bits();
+    assert_eq!(
+        query_conditions(task_id),
+        prerequisite | APP_STATE_CONDITION
+    );
+    assert_eq!(
+        query_state_and_reason(task_id),
+        (WAITING, prerequisite_waiting)
+    );
+
+    db.execute(&app_state_available(uid)).unwrap();
+    assert_eq!(query_conditions(task_id), prerequisite);
+    assert_eq!(
+        query_state_and_reason(task_id),
+        (WAITING, prerequisite_waiting)
+    );
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_prerequisites_from_states
+// @tc.desc: Test combining the states of the prerequisites of a task
+// @tc.precon: NA
+// @tc.step: 1. Combine no state, completed states, running and failed states,
+//              and removed or missing prerequisites
+// @tc.expect: Completed only when all completed, failed when any is removed
+//             or missing, pending otherwise
+// @tc.type: FUNC
+#[test]
+fn ut_prerequisites_from_states() {
+    let completed = Some(State::Completed.repr);
+    assert_eq!(
+        Prerequisites::from_states(Vec::<Option<u8>>::new()),
+        Prerequisites::Completed
+    );
+    assert_eq!(
+        Prerequisites::from_states([completed, completed]),
+        Prerequisites::Completed
+    );
+    assert_eq!(
+        Prerequisites::from_states([completed, Some(State::Running.repr)]),
+        Prerequisites::Pending
+    );
+    // A failed prerequisite may still be restarted by the user
+    assert_eq!(
+        Prerequisites::from_states([Some(State::Failed.repr)]),
+        Prerequisites::Pending
+    );
+    assert_eq!(
+        Prerequisites::from_states([Some(State::Waiting.repr), Some(State::Removed.repr)]),
+        Prerequisites::Failed
+    );
+    assert_eq!(
+        Prerequisites::from_states([completed, None]),
+        Prerequisites::Failed
+    );
+}
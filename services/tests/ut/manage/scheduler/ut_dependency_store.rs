@@ -0,0 +1,98 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use request_utils::fastrand::fast_random;
+
+use super::*;
+use crate::tests::lock_database;
+
+// @tc.name: ut_dependency_store_query
+// @tc.desc: Test storing and querying the dependency graph
+// @tc.precon: NA
+// @tc.step: 1. Store two prerequisites for a task and one for another task
+//           2. Query prerequisites and dependents
+// @tc.expect: Both directions of every edge are returned
+// @tc.type: FUNC
+#[test]
+fn ut_dependency_store_query() {
+    let _lock = lock_database();
+    let a = fast_random() as u32;
+    let b = a.wrapping_add(1);
+    let c = a.wrapping_add(2);
+    let d = a.wrapping_add(3);
+
+    store(c, &[a, b]);
+    store(d, &[c]);
+    store(a, &[]);
+
+    let mut pre = prerequisites(c);
+    pre.sort();
+    let mut expected = vec![a, b];
+    expected.sort();
+    assert_eq!(pre, expected);
+    assert_eq!(prerequisites(d), vec![c]);
+    assert!(prerequisites(a).is_empty());
+    assert_eq!(dependents(a), vec![c]);
+    assert_eq!(dependents(c), vec![d]);
+
+    remove(c);
+    remove(d);
+}
+
+// @tc.name: ut_dependency_satisfy
+// @tc.desc: Test that satisfied edges are no longer pending
+// @tc.precon: NA
+// @tc.step: 1. Store two prerequisites for a task
+//           2. Satisfy one of them
+// @tc.expect: Only the other prerequisite is pending, both are still listed
+// @tc.type: FUNC
+#[test]
+fn ut_dependency_satisfy() {
+    let _lock = lock_database();
+    let a = fast_random() as u32;
+    let b = a.wrapping_add(1);
+    let c = a.wrapping_add(2);
+
+    store(c, &[a, b]);
+    satisfy(a);
+
+    assert_eq!(pending_prerequisites(c), vec![b]);
+    assert_eq!(prerequisites(c).len(), 2);
+    assert!(dependents(a).is_empty());
+    assert_eq!(dependents(b), vec![c]);
+
+    remove(c);
+}
+
+// @tc.name: ut_dependency_remove
+// @tc.desc: Test that removing a task removes the edges in both directions
+// @tc.precon: NA
+// @tc.step: 1. Store a chain of three tasks
+//           2. Remove the middle task
+// @tc.expect: Neither neighbour refers to the removed task any more
+// @tc.type: FUNC
+#[test]
+fn ut_dependency_remove() {
+    let _lock = lock_database();
+    let a = fast_random() as u32;
+    let b = a.wrapping_add(1);
+    let c = a.wrapping_add(2);
+
+    store(b, &[a]);
+    store(c, &[b]);
+    remove(b);
+
+    assert!(dependents(a).is_empty());
+    assert!(prerequisites(b).is_empty());
+    assert!(prerequisites(c).is_empty());
+}
@@ -124,4 +124,34 @@ fn ut_database_app_task_qos_info() {
     assert_eq!(info.mode, Mode::FrontEnd.repr);
     assert_eq!(info.state, State::Completed.repr);
     assert_eq!(info.priority, priority);
-}
\ No newline at end of file
+}
+// @tc.name: ut_database_reset_task
+// @tc.desc: Test resetting a finished task to run it again
+// @tc.precon: NA
+// @tc.step: 1. Insert a completed task with progress
+//           2. Call reset_task method with the task ID
+// @tc.expect: The task is initialized again without progress
+// @tc.type: FUNC
+// @tc.require: issueNumber
+#[test]
+fn ut_database_reset_task() {
+    test_init();
+    let _lock = lock_database();
+    let task_id = TaskIdGenerator::generate();
+    let db = RequestDb::get_instance();
+    db.execute(&format!(
+        "INSERT INTO request_task (task_id, state, idx, total_processed, processed) VALUES ({}, {}, 1, 30, '[10, 20]')",
+        task_id,
+        State::Completed.repr,
+    ))
+    .unwrap();
+
+    db.reset_task(task_id, 2);
+    assert_eq!(db.query_task_state(task_id), Some(State::Initialized.repr));
+    assert_eq!(db.query_task_total_processed(task_id), Some(0));
+    let idx: Vec<i64> = db.query_integer(&format!(
+        "SELECT idx FROM request_task WHERE task_id = {}",
+        task_id
+    ));
+    assert_eq!(idx, vec![0]);
+}
@@ -0,0 +1,67 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn parse(args: &[&str]) -> Result<DumpArgs, String> {
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    DumpArgs::parse(&args)
+}
+
+// @tc.name: ut_dump_args_parse
+// @tc.desc: Test parsing the arguments of the dump command
+// @tc.precon: NA
+// @tc.step: 1. Parse a task id, the json flag and filters
+// @tc.expect: The arguments are parsed in any order, states and actions by
+//             name or number
+// @tc.type: FUNC
+#[test]
+fn ut_dump_args_parse() {
+    assert_eq!(parse(&[]), Ok(DumpArgs::default()));
+
+    let args = parse(&["12", "--json"]).unwrap();
+    assert_eq!(args.task_id, Some(12));
+    assert!(args.json);
+
+    let args = parse(&["--uid", "100", "--state", "running", "--action", "1"]).unwrap();
+    assert_eq!(args.task_id, None);
+    assert_eq!(
+        args.filter,
+        DumpFilter {
+            uid: Some(100),
+            state: Some(State::Running),
+            action: Some(Action::Upload),
+        }
+    );
+
+    let args = parse(&["--state", "16", "--action", "Download"]).unwrap();
+    assert_eq!(args.filter.state, Some(State::Waiting));
+    assert_eq!(args.filter.action, Some(Action::Download));
+}
+
+// @tc.name: ut_dump_args_parse_invalid
+// @tc.desc: Test parsing invalid arguments of the dump command
+// @tc.precon: NA
+// @tc.step: 1. Parse invalid task ids, filters and missing values
+// @tc.expect: Each invalid argument is rejected
+// @tc.type: FUNC
+#[test]
+fn ut_dump_args_parse_invalid() {
+    assert!(parse(&["abc"]).is_err());
+    assert!(parse(&["1", "2"]).is_err());
+    assert!(parse(&["--uid"]).is_err());
+    assert!(parse(&["--uid", "-1"]).is_err());
+    assert!(parse(&["--state", "any"]).is_err());
+    assert!(parse(&["--state", "97"]).is_err());
+    assert!(parse(&["--action", "2"]).is_err());
+}
@@ -0,0 +1,284 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+const RFC7616_NONCE: &str = "7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v";
+const RFC7616_CNONCE: &str = "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ";
+const RFC7616_OPAQUE: &str = "FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS";
+
+fn rfc7616_challenge(algorithm: DigestAlgorithm) -> DigestChallenge {
+    DigestChallenge {
+        realm: "http-auth@example.org".to_string(),
+        nonce: RFC7616_NONCE.to_string(),
+        opaque: Some(RFC7616_OPAQUE.to_string()),
+        algorithm,
+        qop: true,
+    }
+}
+
+// @tc.name: ut_auth_base64
+// @tc.desc: Test encoding bytes in base64
+// @tc.precon: NA
+// @tc.step: 1. Encode the RFC 4648 test vectors
+// @tc.expect: Each vector is encoded with the expected padding
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_auth_base64() {
+    assert_eq!(base64(b""), "");
+    assert_eq!(base64(b"f"), "Zg==");
+    assert_eq!(base64(b"fo"), "Zm8=");
+    assert_eq!(base64(b"foo"), "Zm9v");
+    assert_eq!(base64(b"foob"), "Zm9vYg==");
+    assert_eq!(base64(b"fooba"), "Zm9vYmE=");
+    assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    assert_eq!(base64(&[0xfb, 0xff]), "+/8=");
+}
+
+// @tc.name: ut_auth_basic_credentials
+// @tc.desc: Test building the credentials of the Basic scheme
+// @tc.precon: NA
+// @tc.step: 1. Build the credentials of the RFC 7617 example
+// @tc.expect: The user name and password are joined and encoded
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_auth_basic_credentials() {
+    assert_eq!(
+        basic_credentials("Aladdin", "open sesame"),
+        "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ=="
+    );
+}
+
+// @tc.name: ut_auth_parts
+// @tc.desc: Test encoding and decoding authentications as their parts
+// @tc.precon: NA
+// @tc.step: 1. Round trip every scheme through its parts
+//           2. Decode an unknown scheme and a Basic user name with a colon
+// @tc.expect: Valid parts round trip and invalid ones are rejected
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_auth_parts() {
+    let auths = [
+        Auth::None,
+        Auth::Basic {
+            username: "user".to_string(),
+            password: "pass".to_string(),
+        },
+        Auth::Digest {
+            username: "user:name".to_string(),
+            password: "pass".to_string(),
+        },
+        Auth::Bearer {
+            token: "token".to_string(),
+            refresh: true,
+        },
+    ];
+    for auth in auths {
+        let (scheme, username, secret, refresh) = auth.to_parts();
+        let decoded = Auth::from_parts(scheme, username.to_string(), secret.to_string(), refresh);
+        assert_eq!(decoded, Some(auth));
+    }
+
+    assert!(Auth::from_parts(4, String::new(), String::new(), false).is_none());
+    assert!(Auth::from_parts(BASIC, "a:b".to_string(), String::new(), false).is_none());
+}
+
+// @tc.name: ut_auth_debug_redacted
+// @tc.desc: Test that the debug output of an authentication has no secrets
+// @tc.precon: NA
+// @tc.step: 1. Format a Basic and a Bearer authentication
+// @tc.expect: Passwords and tokens are left out
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_auth_debug_redacted() {
+    let basic = Auth::Basic {
+        username: "user".to_string(),
+        password: "secret".to_string(),
+    };
+    assert_eq!(format!("{:?}", basic), "Basic(user)");
+    let bearer = Auth::Bearer {
+        token: "secret".to_string(),
+        refresh: false,
+    };
+    assert_eq!(format!("{:?}", bearer), "Bearer(refresh: false)");
+}
+
+// @tc.name: ut_auth_parse_digest_challenge
+// @tc.desc: Test parsing the Digest challenge of a WWW-Authenticate header
+// @tc.precon: NA
+// @tc.step: 1. Parse a header with challenges of several schemes
+//           2. Parse quoted values with escapes and commas
+// @tc.expect: The Digest challenge and its parameters are extracted
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_auth_parse_digest_challenge() {
+    let header = "Basic realm=\"basic\", Digest realm=\"http-auth@example.org\", \
+                  qop=\"auth, auth-int\", algorithm=SHA-256, \
+                  nonce=\"7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v\", \
+                  opaque=\"FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS\", Bearer";
+    let challenge = parse_digest_challenge(header).unwrap();
+    assert_eq!(challenge, rfc7616_challenge(DigestAlgorithm::Sha256));
+
+    let challenge =
+        parse_digest_challenge("digest REALM=\"a \\\"b\\\", c\", nonce=n, algorithm=md5-SESS")
+            .unwrap();
+    assert_eq!(challenge.realm, "a \"b\", c");
+    assert_eq!(challenge.nonce, "n");
+    assert_eq!(challenge.algorithm, DigestAlgorithm::Md5Sess);
+    assert!(challenge.opaque.is_none());
+    assert!(!challenge.qop);
+}
+
+// @tc.name: ut_auth_parse_digest_challenge_unsupported
+// @tc.desc: Test skipping Digest challenges that cannot be answered
+// @tc.precon: NA
+// @tc.step: 1. Parse challenges with only auth-int, an unknown algorithm or
+//              no nonce
+//           2. Parse an unsupported challenge followed by a supported one
+// @tc.expect: Unsupported challenges are skipped
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_auth_parse_digest_challenge_unsupported() {
+    assert!(parse_digest_challenge("Digest realm=r, nonce=n, qop=\"auth-int\"").is_none());
+    assert!(parse_digest_challenge("Digest realm=r, nonce=n, algorithm=SHA-512-256").is_none());
+    assert!(parse_digest_challenge("Digest realm=r").is_none());
+    assert!(parse_digest_challenge("Basic realm=r").is_none());
+
+    let header = "Digest realm=r, nonce=a, algorithm=SHA-512-256, Digest realm=r, nonce=b";
+    let challenge = parse_digest_challenge(header).unwrap();
+    assert_eq!(challenge.nonce, "b");
+    assert_eq!(challenge.algorithm, DigestAlgorithm::Md5);
+}
+
+// @tc.name: ut_auth_request_target
+// @tc.desc: Test extracting the uri of Digest credentials from urls
+// @tc.precon: NA
+// @tc.step: 1. Extract the target of urls with paths, queries and fragments
+// @tc.expect: The path and query are kept
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_auth_request_target() {
+    assert_eq!(
+        request_target("http://example.com/dir/index.html?a=b#frag"),
+        "/dir/index.html?a=b"
+    );
+    assert_eq!(request_target("https://user@example.com:8080"), "/");
+    assert_eq!(request_target("http://example.com?x"), "/?x");
+}
+
+// @tc.name: ut_auth_digest_credentials_rfc2617
+// @tc.desc: Test answering the MD5 Digest challenge of RFC 2617
+// @tc.precon: NA
+// @tc.step: 1. Build the credentials of the RFC 2617 example
+// @tc.expect: The response matches the RFC and every field is sent
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_auth_digest_credentials_rfc2617() {
+    let challenge = DigestChallenge {
+        realm: "testrealm@host.com".to_string(),
+        nonce: "dcd98b7102dd2f0e8b11d0f600bfb0c093".to_string(),
+        opaque: Some("5ccc069c403ebaf9f0171e9517f40e41".to_string()),
+        algorithm: DigestAlgorithm::Md5,
+        qop: true,
+    };
+    let credentials = digest_credentials(
+        &challenge,
+        "Mufasa",
+        "Circle Of Life",
+        "GET",
+        "/dir/index.html",
+        1,
+        "0a4f113b",
+    );
+    assert_eq!(
+        credentials,
+        "Digest username=\"Mufasa\", realm=\"testrealm@host.com\", \
+         nonce=\"dcd98b7102dd2f0e8b11d0f600bfb0c093\", uri=\"/dir/index.html\", \
+         algorithm=MD5, response=\"6629fae49393a05397450978507c4ef1\", qop=auth, \
+         nc=00000001, cnonce=\"0a4f113b\", opaque=\"5ccc069c403ebaf9f0171e9517f40e41\""
+    );
+}
+
+// @tc.name: ut_auth_digest_credentials_rfc7616
+// @tc.desc: Test answering the MD5 and SHA-256 Digest challenges of RFC 7616
+// @tc.precon: NA
+// @tc.step: 1. Build the credentials of the RFC 7616 examples
+// @tc.expect: The responses match the RFC
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_auth_digest_credentials_rfc7616() {
+    let expected = [
+        (DigestAlgorithm::Md5, "8ca523f5e9506fed4657c9700eebdbec"),
+        (
+            DigestAlgorithm::Sha256,
+            "753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1",
+        ),
+    ];
+    for (algorithm, response) in expected {
+        let credentials = digest_credentials(
+            &rfc7616_challenge(algorithm),
+            "Mufasa",
+            "Circle of Life",
+            "GET",
+            "/dir/index.html",
+            1,
+            RFC7616_CNONCE,
+        );
+        assert!(credentials.contains(&format!("response=\"{}\"", response)));
+        assert!(credentials.contains(&format!("algorithm={},", algorithm.name())));
+    }
+}
+
+// @tc.name: ut_auth_complete_token_refresh
+// @tc.desc: Test handing over tokens to tasks waiting for a refresh
+// @tc.precon: NA
+// @tc.step: 1. Complete the refresh of a task that is not waiting
+//           2. Complete the refresh of a task with two waiting requests
+// @tc.expect: Only the waiting requests receive the token, all of them
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_auth_complete_token_refresh() {
+    const TASK_ID: u32 = 0xA17;
+    assert!(!complete_token_refresh(TASK_ID, "token".to_string()));
+
+    let (tx, rx) = oneshot::channel();
+    let (other_tx, other_rx) = oneshot::channel();
+    PENDING_REFRESH
+        .lock()
+        .unwrap()
+        .insert(TASK_ID, vec![tx, other_tx]);
+    assert!(complete_token_refresh(TASK_ID, "token".to_string()));
+    assert_eq!(ylong_runtime::block_on(rx).unwrap(), "token");
+    assert_eq!(ylong_runtime::block_on(other_rx).unwrap(), "token");
+    assert!(!complete_token_refresh(TASK_ID, "token".to_string()));
+}
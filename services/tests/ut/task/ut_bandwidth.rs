@@ -0,0 +1,166 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_token_bucket_unlimited
+// @tc.desc: Test that a bucket without a rate never waits
+// @tc.precon: NA
+// @tc.step: 1. Consume a large size from a default TokenBucket
+// @tc.expect: No wait is returned
+// @tc.type: FUNC
+#[test]
+fn ut_token_bucket_unlimited() {
+    let mut bucket = TokenBucket::default();
+    assert_eq!(bucket.consume(u32::MAX as u64, 1), 0);
+}
+
+// @tc.name: ut_token_bucket_burst
+// @tc.desc: Test that a new bucket allows one burst interval of transfer
+// @tc.precon: NA
+// @tc.step: 1. Set a rate of 1000 bytes per second
+//           2. Consume 1000 bytes, then 500 more at the same time
+// @tc.expect: The first transfer goes on, the second waits 500ms
+// @tc.type: FUNC
+#[test]
+fn ut_token_bucket_burst() {
+    let mut bucket = TokenBucket::default();
+    bucket.set_rate(1000);
+    assert_eq!(bucket.consume(1000, 100), 0);
+    assert_eq!(bucket.consume(500, 100), 500);
+}
+
+// @tc.name: ut_token_bucket_refill
+// @tc.desc: Test that a bucket refills at its rate and up to its capacity
+// @tc.precon: NA
+// @tc.step: 1. Drain a bucket of 1000 bytes per second
+//           2. Consume again after 200ms and after a long idle time
+// @tc.expect: The refill covers 200 bytes, and at most one burst after idling
+// @tc.type: FUNC
+#[test]
+fn ut_token_bucket_refill() {
+    let mut bucket = TokenBucket::default();
+    bucket.set_rate(1000);
+    assert_eq!(bucket.consume(1000, 100), 0);
+    assert_eq!(bucket.consume(200, 300), 0);
+    assert_eq!(bucket.consume(100, 300), 100);
+
+    assert_eq!(bucket.consume(1000, 100_000), 0);
+    assert_eq!(bucket.consume(1, 100_000), 1);
+}
+
+// @tc.name: ut_token_bucket_set_rate
+// @tc.desc: Test that changing the rate restarts a bucket
+// @tc.precon: NA
+// @tc.step: 1. Overdraw a bucket of 1000 bytes per second
+//           2. Set the same rate, then a new rate
+// @tc.expect: The same rate keeps the debt, a new rate starts full
+// @tc.type: FUNC
+#[test]
+fn ut_token_bucket_set_rate() {
+    let mut bucket = TokenBucket::default();
+    bucket.set_rate(1000);
+    assert_eq!(bucket.consume(2000, 100), 1000);
+    bucket.set_rate(1000);
+    assert_eq!(bucket.consume(0, 100), 1000);
+    bucket.set_rate(2000);
+    assert_eq!(bucket.consume(2000, 100), 0);
+}
+
+// @tc.name: ut_bandwidth_cap_rate
+// @tc.desc: Test the cap selected by the network type
+// @tc.precon: NA
+// @tc.step: 1. Create caps for metered and unmetered networks
+// @tc.expect: The cap of the network type is returned
+// @tc.type: FUNC
+#[test]
+fn ut_bandwidth_cap_rate() {
+    let cap = BandwidthCap {
+        metered: 100,
+        unmetered: 0,
+    };
+    assert_eq!(cap.rate(true), 100);
+    assert_eq!(cap.rate(false), 0);
+    assert!(!cap.is_unlimited());
+    assert!(BandwidthCap::default().is_unlimited());
+    assert_eq!(BudgetScope::from_u32(1), Some(BudgetScope::Application));
+    assert_eq!(BudgetScope::from_u32(2), None);
+}
+
+// @tc.name: ut_bandwidth_budget_application
+// @tc.desc: Test that an application budget only limits its own tasks
+// @tc.precon: NA
+// @tc.step: 1. Set an application cap for uid 1
+//           2. Consume for uid 1 and uid 2
+// @tc.expect: Only uid 1 waits, and removing the cap lifts the limit
+// @tc.type: FUNC
+#[test]
+fn ut_bandwidth_budget_application() {
+    let mut budget = BandwidthBudget::default();
+    assert!(budget.is_unlimited());
+    let cap = BandwidthCap {
+        metered: 1000,
+        unmetered: 1000,
+    };
+    budget.set_cap(BudgetScope::Application, 1, cap);
+    assert!(!budget.is_unlimited());
+    assert_eq!(budget.cap(BudgetScope::Application, 1), cap);
+    assert_eq!(budget.consume(1, 2000, false, 100), 1000);
+    assert_eq!(budget.consume(2, 2000, false, 100), 0);
+
+    budget.set_cap(BudgetScope::Application, 1, BandwidthCap::default());
+    assert!(budget.is_unlimited());
+    assert_eq!(budget.consume(1, 2000, false, 100), 0);
+}
+
+// @tc.name: ut_bandwidth_budget_hierarchy
+// @tc.desc: Test that the device and application budgets both apply
+// @tc.precon: NA
+// @tc.step: 1. Set a device cap and a stricter application cap on metered
+//              networks
+//           2. Consume on metered and unmetered networks
+// @tc.expect: The longest wait of both budgets is returned
+// @tc.type: FUNC
+#[test]
+fn ut_bandwidth_budget_hierarchy() {
+    let mut budget = BandwidthBudget::default();
+    budget.set_cap(
+        BudgetScope::Device,
+        0,
+        BandwidthCap {
+            metered: 2000,
+            unmetered: 4000,
+        },
+    );
+    budget.set_cap(
+        BudgetScope::Application,
+        1,
+        BandwidthCap {
+            metered: 1000,
+            unmetered: 0,
+        },
+    );
+    assert_eq!(budget.consume(1, 3000, true, 100), 2000);
+    assert_eq!(budget.consume(2, 1000, true, 100), 1000);
+
+    let mut budget = BandwidthBudget::default();
+    budget.set_cap(
+        BudgetScope::Device,
+        0,
+        BandwidthCap {
+            metered: 2000,
+            unmetered: 4000,
+        },
+    );
+    assert_eq!(budget.consume(1, 6000, false, 100), 500);
+}
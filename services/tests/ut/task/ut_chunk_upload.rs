@@ -0,0 +1,71 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_parse_committed
+// @tc.desc: Test parse_committed with valid Range header values
+// @tc.precon: NA
+// @tc.step: 1. Parse ranges with and without the `bytes=` unit
+// @tc.expect: The number of committed bytes is returned
+// @tc.type: FUNC
+#[test]
+fn ut_parse_committed() {
+    assert_eq!(parse_committed("bytes=0-1023"), Some(1024));
+    assert_eq!(parse_committed("0-0"), Some(1));
+    assert_eq!(parse_committed(" bytes=0-99 "), Some(100));
+}
+
+// @tc.name: ut_parse_committed_invalid
+// @tc.desc: Test parse_committed with malformed Range header values
+// @tc.precon: NA
+// @tc.step: 1. Parse an empty value, a range not starting at zero and a
+//              non-numeric range
+// @tc.expect: None is returned for all of them
+// @tc.type: FUNC
+#[test]
+fn ut_parse_committed_invalid() {
+    assert_eq!(parse_committed(""), None);
+    assert_eq!(parse_committed("bytes=100-199"), None);
+    assert_eq!(parse_committed("bytes=0-abc"), None);
+}
+
+// @tc.name: ut_content_range
+// @tc.desc: Test content_range formatting
+// @tc.precon: NA
+// @tc.step: 1. Format the first and last chunks of a file
+// @tc.expect: Values follow the `bytes a-b/total` form
+// @tc.type: FUNC
+#[test]
+fn ut_content_range() {
+    assert_eq!(content_range(0, 1023, 4096), "bytes 0-1023/4096");
+    assert_eq!(content_range(4000, 4095, 4096), "bytes 4000-4095/4096");
+}
+
+// @tc.name: ut_committed_encoding
+// @tc.desc: Test encoding the committed offsets persisted in the database
+// @tc.precon: NA
+// @tc.step: 1. Encode and decode offsets of several files
+//           2. Decode an empty value and an unreadable offset
+// @tc.expect: Offsets round-trip as comma separated decimals, an unreadable
+//             offset is read as zero
+// @tc.type: FUNC
+#[test]
+fn ut_committed_encoding() {
+    let offsets = [0, 1024, u64::MAX];
+    let encoded = encode_committed(&offsets);
+    assert_eq!(encoded, format!("0,1024,{}", u64::MAX));
+    assert_eq!(decode_committed(&encoded), offsets);
+    assert!(decode_committed("").is_empty());
+    assert_eq!(decode_committed("7,abc"), [7, 0]);
+}
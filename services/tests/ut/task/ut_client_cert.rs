@@ -0,0 +1,41 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_client_cert_rejection
+// @tc.desc: Test telling a rejected client certificate from other TLS errors
+// @tc.precon: NA
+// @tc.step: 1. Check the reasons of the alerts sent for a rejected client
+//              certificate, with TLS 1.2 and TLS 1.3
+//           2. Check the reasons of other TLS errors
+// @tc.expect: Only the alerts of a rejected client certificate are detected
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_client_cert_rejection() {
+    assert!(is_client_cert_rejection(
+        "error:0A000412:SSL routines::sslv3 alert bad certificate"
+    ));
+    assert!(is_client_cert_rejection(
+        "error:0A00045C:SSL routines::tlsv13 alert certificate required"
+    ));
+    assert!(is_client_cert_rejection("SSLV3 ALERT CERTIFICATE EXPIRED"));
+    assert!(is_client_cert_rejection("tlsv1 alert unknown ca"));
+    assert!(!is_client_cert_rejection(
+        "error:0A000086:SSL routines::certificate verify failed"
+    ));
+    assert!(!is_client_cert_rejection("tlsv1 alert protocol version"));
+    assert!(!is_client_cert_rejection(""));
+}
@@ -0,0 +1,148 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// "hello" in the zlib format.
+const ZLIB_HELLO: [u8; 13] = [
+    0x78, 0x9c, 0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00, 0x06, 0x2c, 0x02, 0x15,
+];
+
+// "hello" as raw deflate data.
+const RAW_DEFLATE_HELLO: [u8; 7] = [0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00];
+
+// "hello" in the brotli format.
+const BROTLI_HELLO: [u8; 9] = [0x0b, 0x02, 0x80, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x03];
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = Encoder::new();
+    let mut encoded = vec![];
+    encoder.encode(data, &mut encoded).unwrap();
+    encoder.finish(&mut encoded).unwrap();
+    assert!(encoder.is_finished());
+    encoded
+}
+
+// @tc.name: ut_content_coding_parse
+// @tc.desc: Test parsing the Content-Encoding header
+// @tc.precon: NA
+// @tc.step: 1. Parse known, unknown and chained codings
+// @tc.expect: Only a single gzip, deflate or brotli coding is decoded
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_content_coding_parse() {
+    assert_eq!(ContentCoding::parse(""), ContentCoding::Identity);
+    assert_eq!(ContentCoding::parse("identity"), ContentCoding::Identity);
+    assert_eq!(ContentCoding::parse(" GZIP "), ContentCoding::Gzip);
+    assert_eq!(ContentCoding::parse("x-gzip"), ContentCoding::Gzip);
+    assert_eq!(ContentCoding::parse("deflate"), ContentCoding::Deflate);
+    assert_eq!(ContentCoding::parse("br"), ContentCoding::Brotli);
+    assert_eq!(ContentCoding::parse("compress"), ContentCoding::Unsupported);
+    assert_eq!(
+        ContentCoding::parse("deflate, gzip"),
+        ContentCoding::Unsupported
+    );
+    assert!(Decoder::new(ContentCoding::Identity).is_none());
+    assert!(Decoder::new(ContentCoding::Unsupported).is_none());
+}
+
+// @tc.name: ut_compression_gzip_round_trip
+// @tc.desc: Test decoding a gzip body received in small pieces
+// @tc.precon: NA
+// @tc.step: 1. Gzip encode a text payload
+//           2. Decode the encoded body a few bytes at a time
+// @tc.expect: The decoded body equals the payload and is smaller on the wire
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_compression_gzip_round_trip() {
+    let payload = "{\"key\":\"value\"}".repeat(1000).into_bytes();
+    let encoded = gzip(&payload);
+    assert!(encoded.len() < payload.len());
+
+    let mut decoder = Decoder::new(ContentCoding::Gzip).unwrap();
+    let mut decoded = vec![];
+    for piece in encoded.chunks(7) {
+        assert!(!decoder.is_finished());
+        decoder.decode(piece, &mut decoded).unwrap();
+    }
+    assert!(decoder.is_finished());
+    assert_eq!(decoded, payload);
+}
+
+// @tc.name: ut_compression_deflate
+// @tc.desc: Test decoding the deflate coding in both formats sent by servers
+// @tc.precon: NA
+// @tc.step: 1. Decode a zlib format body
+//           2. Decode a raw deflate body
+// @tc.expect: Both bodies are decoded
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_compression_deflate() {
+    for body in [&ZLIB_HELLO[..], &RAW_DEFLATE_HELLO[..]] {
+        let mut decoder = Decoder::new(ContentCoding::Deflate).unwrap();
+        let mut decoded = vec![];
+        decoder.decode(body, &mut decoded).unwrap();
+        assert!(decoder.is_finished());
+        assert_eq!(decoded, b"hello");
+    }
+}
+
+// @tc.name: ut_compression_brotli
+// @tc.desc: Test decoding a brotli body received in small pieces
+// @tc.precon: NA
+// @tc.step: 1. Decode a brotli body a few bytes at a time
+// @tc.expect: The body is decoded and the stream finished
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_compression_brotli() {
+    let mut decoder = Decoder::new(ContentCoding::Brotli).unwrap();
+    let mut decoded = vec![];
+    for piece in BROTLI_HELLO.chunks(2) {
+        assert!(!decoder.is_finished());
+        decoder.decode(piece, &mut decoded).unwrap();
+    }
+    assert!(decoder.is_finished());
+    assert_eq!(decoded, b"hello");
+}
+
+// @tc.name: ut_compression_invalid_body
+// @tc.desc: Test decoding invalid and truncated bodies
+// @tc.precon: NA
+// @tc.step: 1. Decode a body which is not gzip encoded
+//           2. Decode a gzip body without its end
+// @tc.expect: The invalid body fails, the truncated one is not finished
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_compression_invalid_body() {
+    let mut decoder = Decoder::new(ContentCoding::Gzip).unwrap();
+    let mut decoded = vec![];
+    assert!(decoder.decode(b"not gzip data", &mut decoded).is_err());
+
+    let encoded = gzip(b"hello");
+    let mut decoder = Decoder::new(ContentCoding::Gzip).unwrap();
+    let mut decoded = vec![];
+    decoder
+        .decode(&encoded[..encoded.len() - 4], &mut decoded)
+        .unwrap();
+    assert!(!decoder.is_finished());
+}
@@ -0,0 +1,178 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_conditions_bits
+// @tc.desc: Test combining conditions and their persisted bits
+// @tc.precon: NA
+// @tc.step: 1. Add the network, app and account conditions one by one
+//           2. Restore the set from its bits
+//           3. Remove them from all the conditions
+// @tc.expect: Each condition has its own bit and the set round-trips
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_conditions_bits() {
+    assert!(Conditions::NONE.is_empty());
+    let conditions = Conditions::NONE
+        .union(Conditions::NETWORK_OFFLINE)
+        .union(Conditions::APP_STATE)
+        .union(Conditions::ACCOUNT);
+    assert_eq!(conditions.bits(), 0b1101);
+    assert!(conditions.intersects(Conditions::NETWORK));
+    assert!(!conditions.intersects(Conditions::NETWORK_TYPE));
+    assert_eq!(Conditions::from_bits(conditions.bits()), conditions);
+    let others = Conditions::STORAGE_SPACE
+        .union(Conditions::START_TIME)
+        .union(Conditions::PREREQUISITE)
+        .union(Conditions::RETRY);
+    assert_eq!(
+        Conditions::ALL.difference(Conditions::NETWORK.union(conditions)),
+        others
+    );
+    assert_eq!(others.bits(), 0b1111_0000);
+}
+
+// @tc.name: ut_conditions_reason
+// @tc.desc: Test the legacy reason derived from conditions
+// @tc.precon: NA
+// @tc.step: 1. Derive the reason of each combination of conditions
+//           2. Add the storage space, retry, start time and prerequisite
+//              conditions
+// @tc.expect: The combined reasons match the combinations of conditions, the
+//             prerequisites, start time, retry delay and storage space take
+//             precedence
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_conditions_reason() {
+    let offline = Conditions::NETWORK_OFFLINE;
+    let app = Conditions::APP_STATE;
+    let account = Conditions::ACCOUNT;
+    assert_eq!(Conditions::NONE.reason(), Reason::RunningTaskMeetLimits);
+    assert_eq!(offline.reason(), Reason::NetworkOffline);
+    assert_eq!(
+        Conditions::NETWORK_TYPE.reason(),
+        Reason::UnsupportedNetworkType
+    );
+    assert_eq!(Conditions::NETWORK.reason(), Reason::NetworkOffline);
+    assert_eq!(app.reason(), Reason::AppBackgroundOrTerminate);
+    assert_eq!(account.reason(), Reason::AccountStopped);
+    assert_eq!(offline.union(app).reason(), Reason::NetworkApp);
+    assert_eq!(
+        Conditions::NETWORK_TYPE.union(account).reason(),
+        Reason::NetworkAccount
+    );
+    assert_eq!(app.union(account).reason(), Reason::AppAccount);
+    let system = Conditions::NETWORK.union(app).union(account);
+    assert_eq!(system.reason(), Reason::NetworkAppAccount);
+
+    let storage = system.union(Conditions::STORAGE_SPACE);
+    assert_eq!(storage.reason(), Reason::StorageSpaceWaiting);
+    let retry = storage.union(Conditions::RETRY);
+    assert_eq!(retry.reason(), Reason::RetryWaiting);
+    let start_time = retry.union(Conditions::START_TIME);
+    assert_eq!(start_time.reason(), Reason::StartTimeWaiting);
+    assert_eq!(Conditions::ALL.reason(), Reason::PrerequisiteWaiting);
+}
+
+// @tc.name: ut_conditions_from_reason
+// @tc.desc: Test the conditions of tasks persisted with a reason only
+// @tc.precon: NA
+// @tc.step: 1. Read the conditions of each legacy reason
+//           2. Read the conditions of other reasons
+// @tc.expect: Legacy reasons round-trip, other reasons have no condition
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_conditions_from_reason() {
+    for reason in LEGACY_REASONS {
+        assert_eq!(Conditions::from_reason(reason).reason(), reason);
+    }
+    assert_eq!(
+        Conditions::from_reason(Reason::PrerequisiteWaiting),
+        Conditions::PREREQUISITE
+    );
+    assert_eq!(
+        Conditions::from_reason(Reason::StorageSpaceWaiting),
+        Conditions::STORAGE_SPACE
+    );
+    assert_eq!(
+        Conditions::from_reason(Reason::RetryWaiting),
+        Conditions::RETRY
+    );
+    assert!(Conditions::from_reason(Reason::UserOperation).is_empty());
+    assert!(Conditions::from_reason(Reason::PrerequisiteFailed).is_empty());
+}
+
+// @tc.name: ut_conditions_cause
+// @tc.desc: Test the waiting cause notified for conditions
+// @tc.precon: NA
+// @tc.step: 1. Get the cause of an empty set
+//           2. Get the cause of sets adding conditions in order of precedence
+// @tc.expect: The cause follows the reason reported for the set
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_conditions_cause() {
+    assert_eq!(Conditions::NONE.cause(), WaitingCause::TaskQueue);
+    let account = Conditions::ACCOUNT;
+    assert_eq!(account.cause(), WaitingCause::UserState);
+    let app = account.union(Conditions::APP_STATE);
+    assert_eq!(app.cause(), WaitingCause::AppState);
+    let network = app.union(Conditions::NETWORK_TYPE);
+    assert_eq!(network.cause(), WaitingCause::Network);
+    let storage = network.union(Conditions::STORAGE_SPACE);
+    assert_eq!(storage.cause(), WaitingCause::StorageSpace);
+    let retry = storage.union(Conditions::RETRY);
+    assert_eq!(retry.cause(), WaitingCause::Retry);
+    let start_time = retry.union(Conditions::START_TIME);
+    assert_eq!(start_time.cause(), WaitingCause::StartTime);
+    assert_eq!(Conditions::ALL.cause(), WaitingCause::Prerequisite);
+}
+
+// @tc.name: ut_conditions_causes
+// @tc.desc: Test the waiting causes reported for conditions
+// @tc.precon: NA
+// @tc.step: 1. Get the causes of an empty set
+//           2. Get the causes of all conditions
+// @tc.expect: An empty set waits for the task queue, otherwise every active
+//             cause is reported once
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_conditions_causes() {
+    assert_eq!(Conditions::NONE.causes(), vec![WaitingCause::TaskQueue]);
+    assert_eq!(
+        Conditions::ALL.causes(),
+        vec![
+            WaitingCause::Network,
+            WaitingCause::AppState,
+            WaitingCause::UserState,
+            WaitingCause::StorageSpace,
+            WaitingCause::Prerequisite,
+            WaitingCause::StartTime,
+            WaitingCause::Retry
+        ]
+    );
+    assert_eq!(
+        Conditions::NETWORK_TYPE.union(Conditions::ACCOUNT).causes(),
+        vec![WaitingCause::Network, WaitingCause::UserState]
+    );
+}
@@ -0,0 +1,249 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// 2015-10-21 07:28:00 UTC in milliseconds.
+const OCT_21_2015: u64 = 1_445_412_480_000;
+
+fn url(url: &str) -> CookieUrl {
+    CookieUrl::parse(url).unwrap()
+}
+
+// @tc.name: ut_cookie_url_parse
+// @tc.desc: Test extracting the cookie relevant parts of request urls
+// @tc.precon: NA
+// @tc.step: 1. Parse urls with user info, ports, queries and IPv6 hosts
+//           2. Parse urls that are not http or https
+// @tc.expect: Scheme security, lowercase host and path are extracted
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cookie_url_parse() {
+    let parsed = url("HTTPS://user:pw@WWW.Example.com:8443/a/b?c=d#e");
+    assert!(parsed.secure);
+    assert_eq!(parsed.host, "www.example.com");
+    assert_eq!(parsed.path, "/a/b");
+
+    let parsed = url("http://example.com?x=1");
+    assert!(!parsed.secure);
+    assert_eq!(parsed.path, "/");
+
+    assert_eq!(url("http://[::1]:80/x").host, "[::1]");
+    assert!(CookieUrl::parse("ftp://example.com/").is_none());
+    assert!(CookieUrl::parse("example.com/path").is_none());
+}
+
+// @tc.name: ut_cookie_parse_attributes
+// @tc.desc: Test parsing the attributes of a Set-Cookie value
+// @tc.precon: NA
+// @tc.step: 1. Parse a cookie with every attribute
+//           2. Parse a cookie without attributes
+// @tc.expect: Attributes are applied and defaults follow the request url
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cookie_parse_attributes() {
+    let cookie = Cookie::parse(
+        " sid = abc ; Domain=.Example.com; Path=/app; Secure; HttpOnly; Max-Age=60",
+        &url("https://www.example.com/login/form"),
+        1000,
+    )
+    .unwrap();
+    assert_eq!(cookie.name, "sid");
+    assert_eq!(cookie.value, "abc");
+    assert_eq!(cookie.domain, "example.com");
+    assert!(!cookie.host_only);
+    assert_eq!(cookie.path, "/app");
+    assert!(cookie.secure);
+    assert!(cookie.http_only);
+    assert_eq!(cookie.expiry, 61_000);
+
+    let cookie = Cookie::parse("a=1", &url("http://example.com/login/form"), 1000).unwrap();
+    assert_eq!(cookie.domain, "example.com");
+    assert!(cookie.host_only);
+    assert_eq!(cookie.path, "/login");
+    assert_eq!(cookie.expiry, 0);
+    assert_eq!(cookie.creation, 1000);
+
+    let cookie = Cookie::parse("a=1; Path=relative", &url("http://example.com/"), 0).unwrap();
+    assert_eq!(cookie.path, "/");
+}
+
+// @tc.name: ut_cookie_parse_rejected
+// @tc.desc: Test the cookies ignored by the storage model
+// @tc.precon: NA
+// @tc.step: 1. Parse cookies without a name or a pair
+//           2. Parse cookies with a Domain not covering the host
+//           3. Parse a Secure cookie set over http
+// @tc.expect: All of them are ignored
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cookie_parse_rejected() {
+    let host = url("http://www.example.com/");
+    assert!(Cookie::parse("=value", &host, 0).is_none());
+    assert!(Cookie::parse("novalue", &host, 0).is_none());
+    assert!(Cookie::parse("a=1; Domain=other.com", &host, 0).is_none());
+    assert!(Cookie::parse("a=1; Domain=ample.com", &host, 0).is_none());
+    assert!(Cookie::parse("a=1; Domain=com", &host, 0).is_none());
+    assert!(Cookie::parse("a=1; Domain=sub.www.example.com", &host, 0).is_none());
+    assert!(Cookie::parse("a=1; Secure", &host, 0).is_none());
+
+    let ip = url("http://10.0.0.1/");
+    assert!(Cookie::parse("a=1; Domain=0.0.1", &ip, 0).is_none());
+    assert!(Cookie::parse("a=1; Domain=10.0.0.1", &ip, 0).is_some());
+}
+
+// @tc.name: ut_cookie_parse_expiry
+// @tc.desc: Test the expiry given by Expires and Max-Age
+// @tc.precon: NA
+// @tc.step: 1. Parse cookies with Expires, Max-Age or both
+//           2. Parse cookies with an invalid or a non-positive Max-Age
+// @tc.expect: Max-Age takes precedence and past expiries have expired
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cookie_parse_expiry() {
+    let host = url("http://example.com/");
+    let expires = "a=1; Expires=Wed, 21 Oct 2015 07:28:00 GMT";
+    assert_eq!(
+        Cookie::parse(expires, &host, 0).unwrap().expiry,
+        OCT_21_2015
+    );
+
+    let both = format!("{}; Max-Age=10", expires);
+    assert_eq!(Cookie::parse(&both, &host, 5).unwrap().expiry, 10_005);
+    let both = format!("a=1; Max-Age=10; {}", &expires[5..]);
+    assert_eq!(Cookie::parse(&both, &host, 5).unwrap().expiry, 10_005);
+
+    let cookie = Cookie::parse("a=1; Max-Age=0", &host, 5).unwrap();
+    assert!(cookie.is_expired(5));
+    let cookie = Cookie::parse("a=1; Max-Age=-3", &host, 5).unwrap();
+    assert!(cookie.is_expired(5));
+    let cookie = Cookie::parse("a=1; Max-Age=1x", &host, 5).unwrap();
+    assert_eq!(cookie.expiry, 0);
+    assert!(!cookie.is_expired(u64::MAX));
+
+    let cookie = Cookie::parse("a=1; Expires=Thu, 01 Jan 1970 00:00:00 GMT", &host, 5).unwrap();
+    assert!(cookie.is_expired(5));
+}
+
+// @tc.name: ut_cookie_parse_date
+// @tc.desc: Test the lenient cookie date format
+// @tc.precon: NA
+// @tc.step: 1. Parse dates in the common formats
+//           2. Parse dates with invalid fields
+//           3. Parse dates with non-ASCII tokens
+// @tc.expect: Common formats give the same time, invalid dates are ignored
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cookie_parse_date() {
+    for date in [
+        "Wed, 21 Oct 2015 07:28:00 GMT",
+        "Wednesday, 21-Oct-15 07:28:00 GMT",
+        "Wed Oct 21 07:28:00 2015",
+        "21 october 2015 7:28:0",
+    ] {
+        assert_eq!(parse_cookie_date(date), Some(OCT_21_2015), "{}", date);
+    }
+    assert_eq!(
+        parse_cookie_date("Thu, 29 Feb 2024 00:00:00 GMT"),
+        Some(1_709_164_800_000)
+    );
+    assert_eq!(parse_cookie_date("Sat, 01 Jan 1601 00:00:00 GMT"), Some(0));
+
+    assert!(parse_cookie_date("Fri, 29 Feb 2023 00:00:00 GMT").is_none());
+    assert!(parse_cookie_date("Wed, 32 Oct 2015 07:28:00 GMT").is_none());
+    assert!(parse_cookie_date("Wed, 21 Oct 2015 24:00:00 GMT").is_none());
+    assert!(parse_cookie_date("Wed, 21 Foo 2015 07:28:00 GMT").is_none());
+    assert!(parse_cookie_date("Wed, 21 Oct 1600 07:28:00 GMT").is_none());
+    assert!(parse_cookie_date("Wed, 21 Oct 2015").is_none());
+    assert!(parse_cookie_date("Wed, 21 aaé 2015 07:28:00 GMT").is_none());
+    assert!(parse_cookie_date("Wed, 21 é 2015 07:28:00 GMT").is_none());
+    assert_eq!(
+        parse_cookie_date("Mié, 21 Oct 2015 07:28:00 GMT"),
+        Some(OCT_21_2015)
+    );
+}
+
+// @tc.name: ut_cookie_header
+// @tc.desc: Test selecting and ordering the cookies of a request
+// @tc.precon: NA
+// @tc.step: 1. Store cookies with different domains, paths and flags
+//           2. Build the Cookie header of several request urls
+// @tc.expect: Only matching cookies are sent, longer paths first
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cookie_header() {
+    let origin = url("https://www.example.com/app/login");
+    let cookies = [
+        "root=1; Path=/; Domain=example.com",
+        "app=2; Path=/app",
+        "host=3; Path=/",
+        "secure=4; Path=/; Secure",
+        "expired=5; Path=/; Max-Age=1",
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, set_cookie)| Cookie::parse(set_cookie, &origin, i as u64).unwrap())
+    .collect::<Vec<_>>();
+
+    assert_eq!(
+        cookie_header(&cookies, &url("https://www.example.com/app/data"), 10_000).unwrap(),
+        "app=2; root=1; host=3; secure=4"
+    );
+    assert_eq!(
+        cookie_header(&cookies, &url("http://www.example.com/application"), 10_000).unwrap(),
+        "root=1; host=3"
+    );
+    assert_eq!(
+        cookie_header(&cookies, &url("http://api.example.com/app"), 10_000).unwrap(),
+        "root=1"
+    );
+    assert_eq!(
+        cookie_header(&cookies, &url("https://www.example.com/"), 0).unwrap(),
+        "root=1; host=3; secure=4; expired=5"
+    );
+    assert!(cookie_header(&cookies, &url("http://example.org/"), 10_000).is_none());
+}
+
+// @tc.name: ut_cookie_split_set_cookie
+// @tc.desc: Test splitting Set-Cookie values joined by commas
+// @tc.precon: NA
+// @tc.step: 1. Split joined cookies, some of them with an Expires date
+// @tc.expect: Commas of the dates do not split the cookies
+// @tc.type: FUNC
+// @tc.require: issueNumber
+// @tc.level: Level 1
+#[test]
+fn ut_cookie_split_set_cookie() {
+    assert_eq!(
+        split_set_cookie("a=1; Expires=Wed, 21 Oct 2015 07:28:00 GMT, b=2, c=3; Path=/"),
+        vec![
+            "a=1; Expires=Wed, 21 Oct 2015 07:28:00 GMT",
+            "b=2",
+            "c=3; Path=/"
+        ]
+    );
+    assert_eq!(split_set_cookie("a=1"), vec!["a=1"]);
+    assert!(split_set_cookie(" ").is_empty());
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+const DIGEST: &str = "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855";
+
+// @tc.name: ut_parse_digest_plain
+// @tc.desc: Test parse_digest with a plain SHA-256 hex string
+// @tc.precon: NA
+// @tc.step: 1. Parse an uppercase 64-digit hex string
+// @tc.expect: The digest is returned in lowercase
+// @tc.type: FUNC
+#[test]
+fn ut_parse_digest_plain() {
+    assert_eq!(parse_digest(DIGEST).unwrap(), DIGEST.to_ascii_lowercase());
+}
+
+// @tc.name: ut_parse_digest_prefixed
+// @tc.desc: Test parse_digest with an algorithm prefix
+// @tc.precon: NA
+// @tc.step: 1. Parse `sha256:<hex>` and `SHA256:<hex>`
+// @tc.expect: The prefix is stripped in both cases
+// @tc.type: FUNC
+#[test]
+fn ut_parse_digest_prefixed() {
+    let expected = DIGEST.to_ascii_lowercase();
+    assert_eq!(
+        parse_digest(&format!("sha256:{}", DIGEST)).unwrap(),
+        expected
+    );
+    assert_eq!(
+        parse_digest(&format!("SHA256:{}", DIGEST)).unwrap(),
+        expected
+    );
+}
+
+// @tc.name: ut_parse_digest_invalid
+// @tc.desc: Test parse_digest with malformed or unsupported digests
+// @tc.precon: NA
+// @tc.step: 1. Parse an empty string, a short digest, a non-hex digest and
+//              another algorithm
+// @tc.expect: All of them are rejected
+// @tc.type: FUNC
+#[test]
+fn ut_parse_digest_invalid() {
+    assert!(parse_digest("").is_none());
+    assert!(parse_digest(&DIGEST[1..]).is_none());
+    assert!(parse_digest(&DIGEST.replace('E', "G")).is_none());
+    assert!(parse_digest(&format!("md5:{}", DIGEST)).is_none());
+}
+
+// @tc.name: ut_running_digest_resume
+// @tc.desc: Test that a running digest re-hashes a resumed prefix from disk
+// @tc.precon: NA
+// @tc.step: 1. Write the first half of some data to a file
+//           2. Catch a new digest up with the file from the start
+//           3. Feed out-of-order bytes, then the rest of the data in order
+// @tc.expect: The digest equals the one of the whole data and out-of-order
+//             bytes are ignored
+// @tc.type: FUNC
+#[test]
+fn ut_running_digest_resume() {
+    let _ = std::fs::create_dir("test_files/");
+    let path = "test_files/ut_running_digest_resume.txt";
+    let data: Vec<u8> = (0..200_000u32).map(|i| i as u8).collect();
+    std::fs::write(path, &data[..100_000]).unwrap();
+    let file = File::open(path).unwrap();
+
+    let mut digest = RunningDigest::new();
+    digest.catch_up(&file, 100_000).unwrap();
+    assert_eq!(digest.hashed, 100_000);
+
+    digest.feed(150_000, &data[150_000..]);
+    assert_eq!(digest.hashed, 100_000);
+    digest.feed(100_000, &data[100_000..]);
+    assert_eq!(digest.hashed, data.len() as u64);
+
+    let mut whole = Sha256::new();
+    whole.update(&data);
+    assert_eq!(digest.hasher.finish(), whole.finish());
+}
@@ -55,6 +55,10 @@ fn ut_waiting_cause_enum_values() {
     assert_eq!(WaitingCause::Network as u8, 1);
     assert_eq!(WaitingCause::AppState as u8, 2);
     assert_eq!(WaitingCause::UserState as u8, 3);
+    assert_eq!(WaitingCause::StorageSpace as u8, 4);
+    assert_eq!(WaitingCause::Prerequisite as u8, 5);
+    assert_eq!(WaitingCause::StartTime as u8, 6);
+    assert_eq!(WaitingCause::Retry as u8, 7);
 }
 
 // @tc.name: ut_each_file_status_create_empty_files
@@ -49,6 +49,14 @@ fn ut_reason_enum_values() {
     assert_eq!(Reason::AppAccount.repr, 29);
     assert_eq!(Reason::NetworkAppAccount.repr, 30);
     assert_eq!(Reason::LowSpeed.repr, 31);
+    assert_eq!(Reason::IntegrityMismatch.repr, 32);
+    assert_eq!(Reason::PrerequisiteWaiting.repr, 33);
+    assert_eq!(Reason::PrerequisiteFailed.repr, 34);
+    assert_eq!(Reason::StartTimeWaiting.repr, 35);
+    assert_eq!(Reason::StorageSpaceWaiting.repr, 36);
+    assert_eq!(Reason::ClientCertInvalid.repr, 37);
+    assert_eq!(Reason::ClientCertRejected.repr, 38);
+    assert_eq!(Reason::RetryWaiting.repr, 39);
 }
 
 // @tc.name: ut_reason_from_u8_valid_values
@@ -89,6 +97,14 @@ fn ut_reason_from_u8_valid_values() {
     assert_eq!(Reason::from(29), Reason::AppAccount);
     assert_eq!(Reason::from(30), Reason::NetworkAppAccount);
     assert_eq!(Reason::from(31), Reason::LowSpeed);
+    assert_eq!(Reason::from(32), Reason::IntegrityMismatch);
+    assert_eq!(Reason::from(33), Reason::PrerequisiteWaiting);
+    assert_eq!(Reason::from(34), Reason::PrerequisiteFailed);
+    assert_eq!(Reason::from(35), Reason::StartTimeWaiting);
+    assert_eq!(Reason::from(36), Reason::StorageSpaceWaiting);
+    assert_eq!(Reason::from(37), Reason::ClientCertInvalid);
+    assert_eq!(Reason::from(38), Reason::ClientCertRejected);
+    assert_eq!(Reason::from(39), Reason::RetryWaiting);
 }
 
 // @tc.name: ut_reason_from_u8_invalid_values
@@ -102,7 +118,7 @@ fn ut_reason_from_u8_valid_values() {
 // @tc.level: Level 2
 #[test]
 fn ut_reason_from_u8_invalid_values() {
-    let invalid_values = vec![2, 3, 9, 13, 22, 32, 100, 200, 255];
+    let invalid_values = vec![2, 3, 9, 13, 22, 40, 100, 200, 255];
     for value in invalid_values {
         assert_eq!(Reason::from(value), Reason::OthersError);
     }
@@ -146,6 +162,14 @@ fn ut_reason_to_str_all_variants() {
     assert_eq!(Reason::AppAccount.to_str(), "The app is background or terminate and the account is stopped");
     assert_eq!(Reason::NetworkAppAccount.to_str(), "NetWork is offline and the app is background or terminate and the account is stopped");
     assert_eq!(Reason::LowSpeed.to_str(), "Below low speed limit");
+    assert_eq!(Reason::IntegrityMismatch.to_str(), "Integrity check failed");
+    assert_eq!(Reason::PrerequisiteWaiting.to_str(), "Waiting for prerequisite tasks");
+    assert_eq!(Reason::PrerequisiteFailed.to_str(), "A prerequisite task failed or was removed");
+    assert_eq!(Reason::StartTimeWaiting.to_str(), "Waiting for the scheduled start time");
+    assert_eq!(Reason::StorageSpaceWaiting.to_str(), "Waiting for enough storage space");
+    assert_eq!(Reason::ClientCertInvalid.to_str(), "Invalid client certificate or private key");
+    assert_eq!(Reason::ClientCertRejected.to_str(), "Client certificate rejected by the server");
+    assert_eq!(Reason::RetryWaiting.to_str(), "Waiting to retry the failed request");
 }
 
 // @tc.name: ut_reason_partial_eq
@@ -0,0 +1,142 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// 2026-01-01T00:00:00Z in milliseconds.
+const MIDNIGHT: u64 = 1_767_225_600_000;
+
+fn at(hour: u64, minute: u64) -> u64 {
+    MIDNIGHT + (hour * 60 + minute) * MILLISECONDS_IN_ONE_MINUTE
+}
+
+// @tc.name: ut_recurrence_parts
+// @tc.desc: Test encoding and decoding recurrence rules
+// @tc.precon: NA
+// @tc.step: 1. Encode and decode each kind of rule
+//           2. Decode invalid rules
+// @tc.expect: Valid rules round-trip, invalid rules are rejected
+// @tc.type: FUNC
+#[test]
+fn ut_recurrence_parts() {
+    for recurrence in [
+        Recurrence::Once,
+        Recurrence::Interval(MIN_RECURRENCE_INTERVAL),
+        Recurrence::Daily { start: 0, end: 60 },
+        Recurrence::Daily {
+            start: 23 * 60,
+            end: 60,
+        },
+    ] {
+        let (kind, interval, start, end) = recurrence.to_parts();
+        assert_eq!(
+            Recurrence::from_parts(kind, interval, start, end),
+            Some(recurrence)
+        );
+    }
+    assert_eq!(Recurrence::from_parts(INTERVAL, 60, 0, 0), None);
+    assert_eq!(Recurrence::from_parts(DAILY, 0, 60, 60), None);
+    assert_eq!(
+        Recurrence::from_parts(DAILY, 0, 0, MINUTES_IN_ONE_DAY),
+        None
+    );
+    assert_eq!(Recurrence::from_parts(3, 0, 0, 0), None);
+}
+
+// @tc.name: ut_recurrence_in_window
+// @tc.desc: Test the daily window of a recurrence rule
+// @tc.precon: NA
+// @tc.step: 1. Check times inside and outside a daily window
+//           2. Check a window spanning midnight
+// @tc.expect: Only times within the window are accepted
+// @tc.type: FUNC
+#[test]
+fn ut_recurrence_in_window() {
+    let day = Recurrence::Daily {
+        start: 2 * 60,
+        end: 4 * 60,
+    };
+    assert!(!day.in_window(at(1, 59)));
+    assert!(day.in_window(at(2, 0)));
+    assert!(day.in_window(at(3, 59)));
+    assert!(!day.in_window(at(4, 0)));
+
+    let night = Recurrence::Daily {
+        start: 23 * 60,
+        end: 60,
+    };
+    assert!(night.in_window(at(23, 30)));
+    assert!(night.in_window(at(0, 30)));
+    assert!(!night.in_window(at(12, 0)));
+
+    assert!(Recurrence::Once.in_window(at(12, 0)));
+    assert!(Recurrence::Interval(MIN_RECURRENCE_INTERVAL).in_window(at(12, 0)));
+}
+
+// @tc.name: ut_recurrence_next_start
+// @tc.desc: Test the next occurrence of a recurring task
+// @tc.precon: NA
+// @tc.step: 1. Compute the next start of interval and daily rules
+// @tc.expect: Interval rules stay aligned to the anchor, daily rules start
+//             at the next window
+// @tc.type: FUNC
+#[test]
+fn ut_recurrence_next_start() {
+    assert_eq!(Recurrence::Once.next_start(MIDNIGHT, at(1, 0)), None);
+
+    let hourly = Recurrence::Interval(60 * 60);
+    assert_eq!(hourly.next_start(MIDNIGHT, at(0, 10)), Some(at(1, 0)));
+    assert_eq!(hourly.next_start(MIDNIGHT, at(1, 0)), Some(at(2, 0)));
+    assert_eq!(hourly.next_start(MIDNIGHT, at(5, 30)), Some(at(6, 0)));
+    assert_eq!(hourly.next_start(at(3, 0), at(1, 0)), Some(at(3, 0)));
+
+    let day = Recurrence::Daily {
+        start: 2 * 60,
+        end: 4 * 60,
+    };
+    assert_eq!(day.next_start(MIDNIGHT, at(1, 0)), Some(at(2, 0)));
+    assert_eq!(day.next_start(MIDNIGHT, at(2, 30)), Some(at(26, 0)));
+
+    let night = Recurrence::Daily {
+        start: 23 * 60,
+        end: 60,
+    };
+    assert_eq!(night.next_start(MIDNIGHT, at(0, 30)), Some(at(23, 0)));
+    assert_eq!(night.next_start(MIDNIGHT, at(23, 30)), Some(at(47, 0)));
+}
+
+// @tc.name: ut_task_config_is_due
+// @tc.desc: Test whether a deferred task may start
+// @tc.precon: NA
+// @tc.step: 1. Check a task before and after its not_before time
+//           2. Check a daily task after its not_before time
+// @tc.expect: The task is due once not_before has passed and within its
+//             daily window
+// @tc.type: FUNC
+#[test]
+fn ut_task_config_is_due() {
+    let mut config = TaskConfig::default();
+    assert!(config.is_due(at(0, 0)));
+
+    config.not_before = at(2, 0);
+    assert!(!config.is_due(at(1, 59)));
+    assert!(config.is_due(at(2, 0)));
+
+    config.recurrence = Recurrence::Daily {
+        start: 3 * 60,
+        end: 4 * 60,
+    };
+    assert!(!config.is_due(at(2, 30)));
+    assert!(config.is_due(at(3, 30)));
+    assert!(!config.is_due(at(4, 30)));
+}
@@ -11,7 +11,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::task::request_task::change_upload_size;
+use crate::task::config::{Action, ConfigBuilder};
+use crate::task::request_task::{change_upload_size, request_method};
 
 // @tc.name: ut_upload_size
 // @tc.desc: Test the change_upload_size function with various parameters
@@ -35,4 +36,85 @@ fn ut_upload_size() {
     assert_eq!(change_upload_size(0, 30, 30), 30);
     assert_eq!(change_upload_size(0, 0, 0), 0);
     assert_eq!(change_upload_size(10, 9, 100), 100);
-}
\ No newline at end of file
+}
+
+// @tc.name: ut_request_method_download
+// @tc.desc: Test request_method for download tasks
+// @tc.precon: NA
+// @tc.step: 1. Resolve every supported download method in mixed case
+//           2. Resolve PUT and an unknown method
+// @tc.expect: Supported methods resolve to uppercase, others are rejected
+// @tc.type: FUNC
+#[test]
+fn ut_request_method_download() {
+    for method in ["get", "Post", "PATCH", "delete", "HEAD", "options"] {
+        let config = ConfigBuilder::new()
+            .action(Action::Download)
+            .method(method)
+            .build();
+        assert_eq!(
+            request_method(&config),
+            Some(method.to_uppercase()).as_deref()
+        );
+    }
+    for method in ["PUT", "FOO"] {
+        let config = ConfigBuilder::new()
+            .action(Action::Download)
+            .method(method)
+            .build();
+        assert_eq!(request_method(&config), None);
+    }
+}
+
+// @tc.name: ut_request_method_upload
+// @tc.desc: Test request_method for upload tasks
+// @tc.precon: NA
+// @tc.step: 1. Resolve PUT, POST and PATCH
+//           2. Resolve methods without a request body
+// @tc.expect: Body methods resolve, others are rejected
+// @tc.type: FUNC
+#[test]
+fn ut_request_method_upload() {
+    for method in ["PUT", "POST", "PATCH"] {
+        let config = ConfigBuilder::new()
+            .action(Action::Upload)
+            .method(method)
+            .build();
+        assert_eq!(request_method(&config), Some(method));
+    }
+    for method in ["GET", "HEAD", "DELETE"] {
+        let config = ConfigBuilder::new()
+            .action(Action::Upload)
+            .method(method)
+            .build();
+        assert_eq!(request_method(&config), None);
+    }
+}
+
+// @tc.name: ut_request_method_default
+// @tc.desc: Test request_method with an empty method
+// @tc.precon: NA
+// @tc.step: 1. Resolve an empty method for API10 download and upload tasks
+//           2. Resolve an empty method for an API9 upload task
+// @tc.expect: The action default is used
+// @tc.type: FUNC
+#[test]
+fn ut_request_method_default() {
+    let config = ConfigBuilder::new()
+        .action(Action::Download)
+        .method("")
+        .build();
+    assert_eq!(request_method(&config), Some("GET"));
+    let config = ConfigBuilder::new()
+        .action(Action::Upload)
+        .version(2)
+        .method("")
+        .build();
+    assert_eq!(request_method(&config), Some("PUT"));
+    let config = ConfigBuilder::new()
+        .action(Action::Upload)
+        .version(1)
+        .method("")
+        .build();
+    assert_eq!(request_method(&config), Some("POST"));
+}
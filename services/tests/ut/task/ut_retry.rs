@@ -0,0 +1,145 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn policy(base_delay: u64, backoff_factor: u32, jitter: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 5,
+        base_delay,
+        backoff_factor,
+        jitter,
+        reasons: vec![],
+        statuses: vec![],
+    }
+}
+
+// @tc.name: ut_retry_policy_disabled
+// @tc.desc: Test that the default retry policy is disabled
+// @tc.precon: NA
+// @tc.step: 1. Create a default RetryPolicy
+// @tc.expect: The policy is not enabled
+// @tc.type: FUNC
+#[test]
+fn ut_retry_policy_disabled() {
+    assert!(!RetryPolicy::default().is_enabled());
+    assert!(policy(100, 2, 0).is_enabled());
+}
+
+// @tc.name: ut_retry_policy_default_retryable
+// @tc.desc: Test the retryable reasons and statuses of a policy listing none
+// @tc.precon: NA
+// @tc.step: 1. Check network reasons, other reasons and protocol errors
+// @tc.expect: Network reasons and 408/429/5xx statuses are retryable
+// @tc.type: FUNC
+#[test]
+fn ut_retry_policy_default_retryable() {
+    let policy = policy(100, 2, 0);
+    assert!(policy.is_retryable(Reason::Tcp, None));
+    assert!(policy.is_retryable(Reason::Dns, None));
+    assert!(!policy.is_retryable(Reason::IoError, None));
+    assert!(policy.is_retryable(Reason::ProtocolError, Some(429)));
+    assert!(policy.is_retryable(Reason::ProtocolError, Some(503)));
+    assert!(!policy.is_retryable(Reason::ProtocolError, Some(404)));
+    assert!(!policy.is_retryable(Reason::ProtocolError, None));
+}
+
+// @tc.name: ut_retry_policy_custom_retryable
+// @tc.desc: Test the retryable reasons and statuses listed by a policy
+// @tc.precon: NA
+// @tc.step: 1. Configure IoError and status 404 as retryable
+//           2. Check listed and unlisted failures
+// @tc.expect: Only the listed failures are retryable
+// @tc.type: FUNC
+#[test]
+fn ut_retry_policy_custom_retryable() {
+    let mut policy = policy(100, 2, 0);
+    policy.reasons = vec![Reason::IoError.repr];
+    policy.statuses = vec![404];
+    assert!(policy.is_retryable(Reason::IoError, None));
+    assert!(!policy.is_retryable(Reason::Tcp, None));
+    assert!(policy.is_retryable(Reason::ProtocolError, Some(404)));
+    assert!(!policy.is_retryable(Reason::ProtocolError, Some(503)));
+}
+
+// @tc.name: ut_retry_policy_backoff
+// @tc.desc: Test the exponential backoff of a policy
+// @tc.precon: NA
+// @tc.step: 1. Compute the backoff of successive attempts
+// @tc.expect: The delay grows by the factor and is capped
+// @tc.type: FUNC
+#[test]
+fn ut_retry_policy_backoff() {
+    let policy = policy(100, 3, 0);
+    assert_eq!(policy.backoff(0), 100);
+    assert_eq!(policy.backoff(1), 300);
+    assert_eq!(policy.backoff(2), 900);
+    assert_eq!(policy.backoff(30), MAX_RETRY_DELAY);
+
+    // A factor of 0 keeps the delay constant
+    let constant = self::policy(100, 0, 0);
+    assert_eq!(constant.backoff(3), 100);
+}
+
+// @tc.name: ut_retry_policy_jitter
+// @tc.desc: Test that jitter keeps the delay within its spread
+// @tc.precon: NA
+// @tc.step: 1. Compute the delay with 50% jitter repeatedly
+// @tc.expect: Every delay is within 50% of the backoff
+// @tc.type: FUNC
+#[test]
+fn ut_retry_policy_jitter() {
+    let policy = policy(1000, 2, 50);
+    for _ in 0..100 {
+        let delay = policy.delay(1).as_millis() as u64;
+        assert!((1000..=3000).contains(&delay));
+    }
+    let exact = self::policy(1000, 2, 0);
+    assert_eq!(exact.delay(1).as_millis(), 2000);
+}
+
+// @tc.name: ut_parse_retry_after
+// @tc.desc: Test parse_retry_after with seconds and HTTP dates
+// @tc.precon: NA
+// @tc.step: 1. Parse delay seconds, a future date, a past date and garbage
+// @tc.expect: Delays are returned, past dates give zero, garbage is rejected
+// @tc.type: FUNC
+#[test]
+fn ut_parse_retry_after() {
+    // Sun, 06 Nov 1994 08:49:37 GMT
+    let date = 784111777;
+    assert_eq!(
+        parse_retry_after(" 120 ", date),
+        Some(Duration::from_secs(120))
+    );
+    assert_eq!(
+        parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", date - 30),
+        Some(Duration::from_secs(30))
+    );
+    assert_eq!(
+        parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", date + 30),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(
+        parse_retry_after("Thu, 01 Jan 1970 00:00:00 GMT", 0),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(
+        parse_retry_after("Tue, 29 Feb 2000 00:00:10 GMT", 951782400),
+        Some(Duration::from_secs(10))
+    );
+    assert!(parse_retry_after("Sun, 06 Foo 1994 08:49:37 GMT", 0).is_none());
+    assert!(parse_retry_after("Sun, 06 Nov 1994 08:49:37", 0).is_none());
+    assert!(parse_retry_after("-1", 0).is_none());
+    assert!(parse_retry_after("soon", 0).is_none());
+}
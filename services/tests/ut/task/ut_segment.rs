@@ -0,0 +1,405 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+
+use ylong_runtime::sync::mpsc::unbounded_channel;
+
+use super::*;
+use crate::ability::SYSTEM_CONFIG_MANAGER;
+use crate::config::{Action, ConfigBuilder, Mode};
+use crate::service::client::ClientManagerEntry;
+use crate::task::request_task::{check_config, get_rest_time};
+use crate::tests::test_init;
+
+const CONTENT_LEN: u64 = 2 * MIN_SEGMENT_SIZE;
+
+fn content() -> Vec<u8> {
+    (0..CONTENT_LEN).map(|i| (i % 251) as u8).collect()
+}
+
+fn build_task(server: &str, file: File, task_id: u32) -> Arc<RequestTask> {
+    let (tx, _) = unbounded_channel();
+    let client_manager = ClientManagerEntry::new(tx);
+    let system_config = unsafe { SYSTEM_CONFIG_MANAGER.assume_init_ref().system_config() };
+    let mut config = ConfigBuilder::new()
+        .action(Action::Download)
+        .mode(Mode::BackGround)
+        .url(&format!("http://{}/", server))
+        .segments(2)
+        .file_spec(file)
+        .build();
+    config.common_data.task_id = task_id;
+    let rest_time = get_rest_time(&config, 0);
+    let (files, client) = check_config(
+        &config,
+        rest_time,
+        #[cfg(feature = "oh")]
+        system_config,
+    )
+    .unwrap();
+    Arc::new(RequestTask::new(
+        config,
+        files,
+        client,
+        client_manager,
+        false,
+        rest_time,
+    ))
+}
+
+/// Serves `content()`, answering range requests with `206 Partial Content`
+/// only when `honor_range` is set.
+fn range_server(honor_range: bool) -> String {
+    let server = "127.0.0.1";
+    let mut port = 7978;
+    let listener = loop {
+        match TcpListener::bind((server, port)) {
+            Ok(listener) => break listener,
+            Err(_) => port += 1,
+        }
+    };
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            std::thread::spawn(move || handle_range(stream, honor_range));
+        }
+    });
+    format!("{}:{}", server, port)
+}
+
+fn handle_range(mut stream: TcpStream, honor_range: bool) {
+    let content = content();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    loop {
+        let mut range = None;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            let line = line.trim_end().to_ascii_lowercase();
+            if line.is_empty() {
+                break;
+            }
+            if let Some(value) = line.strip_prefix("range: bytes=") {
+                let (begins, ends) = value.split_once('-').unwrap();
+                let begins = begins.parse::<usize>().unwrap();
+                let ends = ends.parse::<usize>().unwrap_or(content.len() - 1);
+                range = Some((begins, ends));
+            }
+        }
+        let (head, body) = match range {
+            Some((begins, ends)) if honor_range => (
+                format!(
+                    "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes {}-{}/{}\r\nContent-Length: {}\r\n\r\n",
+                    begins,
+                    ends,
+                    content.len(),
+                    ends - begins + 1
+                ),
+                &content[begins..=ends],
+            ),
+            _ => (
+                format!(
+                    "HTTP/1.1 200 OK\r\nAccept-Ranges: bytes\r\nContent-Length: {}\r\n\r\n",
+                    content.len()
+                ),
+                &content[..],
+            ),
+        };
+        if stream.write_all(head.as_bytes()).is_err() || stream.write_all(body).is_err() {
+            return;
+        }
+    }
+}
+
+/// Answers every range request with `206 Partial Content` but closes the
+/// connection after half of the requested bytes.
+fn truncating_server() -> String {
+    let server = "127.0.0.1";
+    let mut port = 8078;
+    let listener = loop {
+        match TcpListener::bind((server, port)) {
+            Ok(listener) => break listener,
+            Err(_) => port += 1,
+        }
+    };
+    std::thread::spawn(move || {
+        let content = content();
+        for mut stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut range = (0, content.len() - 1);
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                    break;
+                }
+                let line = line.trim_end().to_ascii_lowercase();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(value) = line.strip_prefix("range: bytes=") {
+                    let (begins, ends) = value.split_once('-').unwrap();
+                    range.0 = begins.parse::<usize>().unwrap();
+                    range.1 = ends.parse::<usize>().unwrap_or(content.len() - 1);
+                }
+            }
+            let (begins, ends) = range;
+            let head = format!(
+                "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes {}-{}/{}\r\nConnection: close\r\n\r\n",
+                begins,
+                ends,
+                content.len()
+            );
+            let half = begins + (ends - begins + 1) / 2;
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(&content[begins..half]);
+        }
+    });
+    format!("{}:{}", server, port)
+}
+
+fn create_file(path: &str) -> File {
+    File::options()
+        .read(true)
+        .write(true)
+        .truncate(true)
+        .create(true)
+        .open(path)
+        .unwrap()
+}
+
+// @tc.name: ut_split_segments_even
+// @tc.desc: Test split_segments with a size divisible by the segment count
+// @tc.precon: NA
+// @tc.step: 1. Split 4 MiB into 4 segments
+// @tc.expect: Four contiguous 1 MiB segments covering the whole file
+// @tc.type: FUNC
+#[test]
+fn ut_split_segments_even() {
+    let segments = split_segments(4 * MIN_SEGMENT_SIZE, 4).unwrap();
+    assert_eq!(segments.len(), 4);
+    for (i, segment) in segments.iter().enumerate() {
+        assert_eq!(segment.begins, i as u64 * MIN_SEGMENT_SIZE);
+        assert_eq!(segment.len(), MIN_SEGMENT_SIZE);
+        assert_eq!(segment.processed, 0);
+    }
+    assert_eq!(segments[3].ends, 4 * MIN_SEGMENT_SIZE - 1);
+}
+
+// @tc.name: ut_split_segments_remainder
+// @tc.desc: Test that the last segment takes the remainder
+// @tc.precon: NA
+// @tc.step: 1. Split a size that is not divisible by the segment count
+// @tc.expect: Segments are contiguous and the last one ends at the last byte
+// @tc.type: FUNC
+#[test]
+fn ut_split_segments_remainder() {
+    let total = 3 * MIN_SEGMENT_SIZE + 7;
+    let segments = split_segments(total, 3).unwrap();
+    assert_eq!(segments.len(), 3);
+    for pair in segments.windows(2) {
+        assert_eq!(pair[0].ends + 1, pair[1].begins);
+    }
+    assert_eq!(segments[2].ends, total - 1);
+    assert_eq!(segments.iter().map(Segment::len).sum::<u64>(), total);
+}
+
+// @tc.name: ut_split_segments_limits
+// @tc.desc: Test the segment count caps of split_segments
+// @tc.precon: NA
+// @tc.step: 1. Request more than MAX_SEGMENTS segments of a large file
+//           2. Request more segments than MIN_SEGMENT_SIZE allows
+//           3. Request segments of a file smaller than two segments
+// @tc.expect: Counts are capped, and small files are not split
+// @tc.type: FUNC
+#[test]
+fn ut_split_segments_limits() {
+    let segments = split_segments(100 * MIN_SEGMENT_SIZE, 64).unwrap();
+    assert_eq!(segments.len(), MAX_SEGMENTS as usize);
+
+    let segments = split_segments(3 * MIN_SEGMENT_SIZE, 8).unwrap();
+    assert_eq!(segments.len(), 3);
+
+    assert!(split_segments(MIN_SEGMENT_SIZE, 4).is_none());
+    assert!(split_segments(100 * MIN_SEGMENT_SIZE, 1).is_none());
+}
+
+// @tc.name: ut_segment_remaining
+// @tc.desc: Test Segment::remaining with partial progress
+// @tc.precon: NA
+// @tc.step: 1. Create a segment with some bytes processed
+// @tc.expect: Remaining bytes equal the length minus processed bytes
+// @tc.type: FUNC
+#[test]
+fn ut_segment_remaining() {
+    let segment = Segment {
+        begins: 100,
+        ends: 199,
+        processed: 40,
+    };
+    assert_eq!(segment.len(), 100);
+    assert_eq!(segment.remaining(), 60);
+}
+
+// @tc.name: ut_segment_contiguous_end
+// @tc.desc: Test contiguous_end with segments in several states
+// @tc.precon: NA
+// @tc.step: 1. Compute the end with the first segment partially downloaded
+//           2. Complete the first segment while the second is partial
+//           3. Complete all segments
+// @tc.expect: The end stops at the first byte not yet downloaded, or at the
+//             end of the file once every segment completes
+// @tc.type: FUNC
+#[test]
+fn ut_segment_contiguous_end() {
+    let mut segments = split_segments(3 * MIN_SEGMENT_SIZE, 3).unwrap();
+    segments[0].processed = 10;
+    segments[2].processed = MIN_SEGMENT_SIZE;
+    assert_eq!(contiguous_end(&segments), 10);
+
+    segments[0].processed = MIN_SEGMENT_SIZE;
+    segments[1].processed = 20;
+    assert_eq!(contiguous_end(&segments), MIN_SEGMENT_SIZE + 20);
+
+    segments[1].processed = MIN_SEGMENT_SIZE;
+    assert_eq!(contiguous_end(&segments), 3 * MIN_SEGMENT_SIZE);
+    assert_eq!(contiguous_end(&[]), 0);
+}
+
+// @tc.name: ut_segment_resume_after_restart
+// @tc.desc: Test that a segmented download continues after a restart
+// @tc.precon: NA
+// @tc.step: 1. Write part of every segment and record their progress
+//           2. Build a new task and load the recorded segments
+//           3. Download the remaining bytes of the segments
+// @tc.expect: The loaded segments match the recorded ones, only the missing
+//             bytes are requested, the file equals the served content and the
+//             records are cleared
+// @tc.type: FUNC
+#[test]
+fn ut_segment_resume_after_restart() {
+    test_init();
+    let task_id = 0x5e6_0001;
+    let content = content();
+    let path = "test_files/ut_segment_resume_after_restart.txt";
+    let file = create_file(path);
+    file.set_len(CONTENT_LEN).unwrap();
+
+    let mut segments = split_segments(CONTENT_LEN, 2).unwrap();
+    segments[0].processed = 1000;
+    segments[1].processed = 2000;
+    for segment in segments.iter() {
+        let end = (segment.begins + segment.processed) as usize;
+        file.write_all_at(&content[segment.begins as usize..end], segment.begins)
+            .unwrap();
+    }
+    record_segments(task_id, &segments);
+
+    let server = range_server(true);
+    let task = build_task(&server, file, task_id);
+    let loaded = query_segments(task_id);
+    assert_eq!(loaded, segments);
+
+    ylong_runtime::block_on(download_segments(
+        task.clone(),
+        Arc::new(AtomicBool::new(false)),
+        loaded,
+        None,
+    ))
+    .unwrap();
+    assert_eq!(std::fs::read(path).unwrap(), content);
+    assert_eq!(
+        task.progress.lock().unwrap().processed[0] as u64,
+        CONTENT_LEN
+    );
+    assert!(query_segments(task_id).is_empty());
+}
+
+// @tc.name: ut_segment_fallback_without_partial_content
+// @tc.desc: Test that segmenting is disabled when ranges are not honored
+// @tc.precon: NA
+// @tc.step: 1. Record the segments of a download
+//           2. Download them from a server answering ranges with `200`
+// @tc.expect: The download asks for a retry, segmenting is disabled, the
+//             records are cleared and the partial file is truncated
+// @tc.type: FUNC
+#[test]
+fn ut_segment_fallback_without_partial_content() {
+    test_init();
+    let task_id = 0x5e6_0002;
+    let path = "test_files/ut_segment_fallback_without_partial_content.txt";
+    let file = create_file(path);
+
+    let segments = split_segments(CONTENT_LEN, 2).unwrap();
+    record_segments(task_id, &segments);
+
+    let server = range_server(false);
+    let task = build_task(&server, file, task_id);
+    let res = ylong_runtime::block_on(download_segments(
+        task.clone(),
+        Arc::new(AtomicBool::new(false)),
+        segments,
+        None,
+    ));
+    assert!(matches!(res, Err(TaskError::Waiting(TaskPhase::NeedRetry))));
+    assert!(!task.segmentable.load(Ordering::Acquire));
+    assert!(query_segments(task_id).is_empty());
+    assert_eq!(std::fs::metadata(path).unwrap().len(), 0);
+}
+
+// @tc.name: ut_segment_short_body_resumable
+// @tc.desc: Test that a segment whose body ends early stays resumable
+// @tc.precon: NA
+// @tc.step: 1. Record the segments of a download with no network retry left
+//           2. Download them from a server closing the body halfway
+// @tc.expect: The download asks for a retry and the recorded progress of
+//             every segment matches the bytes written to the file
+// @tc.type: FUNC
+#[test]
+fn ut_segment_short_body_resumable() {
+    test_init();
+    let task_id = 0x5e6_0003;
+    let content = content();
+    let path = "test_files/ut_segment_short_body_resumable.txt";
+    let file = create_file(path);
+
+    let segments = split_segments(CONTENT_LEN, 2).unwrap();
+    record_segments(task_id, &segments);
+
+    let server = truncating_server();
+    let task = build_task(&server, file, task_id);
+    task.tries.store(u32::MAX, Ordering::SeqCst);
+    let res = ylong_runtime::block_on(download_segments(
+        task.clone(),
+        Arc::new(AtomicBool::new(false)),
+        segments,
+        None,
+    ));
+    assert!(matches!(res, Err(TaskError::Waiting(TaskPhase::NeedRetry))));
+    assert!(task.segmentable.load(Ordering::Acquire));
+
+    let loaded = query_segments(task_id);
+    assert_eq!(loaded.len(), 2);
+    assert!(loaded.iter().any(|segment| segment.processed > 0));
+    let written = std::fs::read(path).unwrap();
+    for segment in loaded.iter().filter(|segment| segment.processed > 0) {
+        let (begins, end) = (
+            segment.begins as usize,
+            (segment.begins + segment.processed) as usize,
+        );
+        assert_eq!(written[begins..end], content[begins..end]);
+    }
+    clear_segments(task_id);
+}
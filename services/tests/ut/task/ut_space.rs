@@ -0,0 +1,68 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+// @tc.name: ut_required_space
+// @tc.desc: Test the space needed by the rest of a download
+// @tc.precon: NA
+// @tc.step: 1. Compute the space of downloads with known and unknown sizes
+//           2. Compute the space of resumed and complete downloads
+// @tc.expect: The space is the total size minus the downloaded bytes, using
+//             the declared size when the total size is unknown
+// @tc.type: FUNC
+#[test]
+fn ut_required_space() {
+    assert_eq!(required_space(&[1000], &[0], 0), 1000);
+    assert_eq!(required_space(&[1000], &[400], 0), 600);
+    assert_eq!(required_space(&[1000], &[400], 5000), 600);
+    assert_eq!(required_space(&[-1], &[0], 0), 0);
+    assert_eq!(required_space(&[-1], &[100], 500), 400);
+    assert_eq!(required_space(&[1000], &[1000], 0), 0);
+    assert_eq!(required_space(&[-1], &[800], 500), 0);
+    assert_eq!(required_space(&[], &[], 300), 300);
+}
+
+// @tc.name: ut_has_enough_space_unknown
+// @tc.desc: Test the space check of downloads without a known size
+// @tc.precon: NA
+// @tc.step: 1. Check a stored download without size nor declared size
+//           2. Check a download whose file path cannot be resolved
+// @tc.expect: Both are considered to have enough space
+// @tc.type: FUNC
+#[test]
+fn ut_has_enough_space_unknown() {
+    let mut config = TaskConfig::default();
+    let progress = Progress::new(vec![-1]);
+    assert!(has_enough_space(&config, &progress));
+
+    config.expected_size = u64::MAX;
+    assert!(has_enough_space(&config, &progress));
+}
+
+// @tc.name: ut_decoded_required_space
+// @tc.desc: Test the space needed by an encoded body
+// @tc.precon: NA
+// @tc.step: 1. Compute the space with and without a wire length
+//           2. Compute the space with a declared size above the wire length
+// @tc.expect: The space is the larger of the wire length and the declared
+//             size
+// @tc.type: FUNC
+#[test]
+fn ut_decoded_required_space() {
+    assert_eq!(decoded_required_space(Some(1000), 0), 1000);
+    assert_eq!(decoded_required_space(None, 0), 0);
+    assert_eq!(decoded_required_space(None, 500), 500);
+    assert_eq!(decoded_required_space(Some(1000), 5000), 5000);
+    assert_eq!(decoded_required_space(Some(1000), 200), 1000);
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2026 Huawei Device Co., Ltd.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use request_utils::fastrand::fast_random;
+
+use super::*;
+use crate::tests::lock_database;
+
+// @tc.name: ut_temp_file_stale_without_task
+// @tc.desc: Test that the temp files of tasks missing from request_task are
+//           stale
+// @tc.precon: NA
+// @tc.step: 1. Store two temp files of a task that does not exist
+//           2. Query the stale temp files
+//           3. Remove one of them and query again
+// @tc.expect: Both files are stale, then only the remaining one
+// @tc.type: FUNC
+#[test]
+fn ut_temp_file_stale_without_task() {
+    let _lock = lock_database();
+    let task_id = fast_random() as u32;
+
+    store(task_id, 0, "/base", "/base/a.txt");
+    store(task_id, 1, "/base", "/base/b.txt");
+    let mut files = stale()
+        .into_iter()
+        .filter(|(id, _, _, _)| *id == task_id)
+        .collect::<Vec<_>>();
+    files.sort();
+    assert_eq!(
+        files,
+        vec![
+            (task_id, 0, "/base".to_string(), "/base/a.txt".to_string()),
+            (task_id, 1, "/base".to_string(), "/base/b.txt".to_string()),
+        ]
+    );
+
+    remove(task_id, 0);
+    let files = stale()
+        .into_iter()
+        .filter(|(id, _, _, _)| *id == task_id)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        files,
+        vec![(task_id, 1, "/base".to_string(), "/base/b.txt".to_string())]
+    );
+
+    remove(task_id, 1);
+    assert!(stale().iter().all(|(id, _, _, _)| *id != task_id));
+}
+
+// @tc.name: ut_temp_file_store_replaces
+// @tc.desc: Test that storing the temp file of the same file twice keeps one
+//           row
+// @tc.precon: NA
+// @tc.step: 1. Store the temp file of a file twice with different paths
+//           2. Query the stale temp files
+// @tc.expect: Only the last path is recorded
+// @tc.type: FUNC
+#[test]
+fn ut_temp_file_store_replaces() {
+    let _lock = lock_database();
+    let task_id = fast_random() as u32;
+
+    store(task_id, 0, "/base", "/base/a.txt");
+    store(task_id, 0, "/base", "/base/b.txt");
+    let files = stale()
+        .into_iter()
+        .filter(|(id, _, _, _)| *id == task_id)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        files,
+        vec![(task_id, 0, "/base".to_string(), "/base/b.txt".to_string())]
+    );
+
+    remove(task_id, 0);
+}
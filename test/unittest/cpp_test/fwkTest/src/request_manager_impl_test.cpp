@@ -91,6 +91,7 @@ public:
     MOCK_METHOD(int32_t, AttachGroup, (const std::string &gid, const std::vector<std::string> &tid), (override));
     MOCK_METHOD(int32_t, DeleteGroup, (const std::string &gid), (override));
     MOCK_METHOD(int32_t, SetMaxSpeed, (const std::string &tid, const int64_t maxSpeed), (override));
+    MOCK_METHOD(int32_t, SetAuthToken, (const std::string &tid, const std::string &token), (override));
     MOCK_METHOD(ExceptionErrorCode, SetMaxSpeeds,
         (const std::vector<SpeedConfig> &speedConfig, std::vector<ExceptionErrorCode> &rets), (override));
     MOCK_METHOD(ExceptionErrorCode, DisableTaskNotification,